@@ -6,3 +6,5 @@ pub mod bezier_c0_points;
 pub mod delete_bezier_c0_points;
 pub mod rename_bezier_c0;
 pub mod set_bezier_c0_draw_polygon;
+pub mod split_bezier_c0;
+pub mod transform_bezier_c0_into_bezier_c2;
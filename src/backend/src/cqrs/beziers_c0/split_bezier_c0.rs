@@ -0,0 +1,118 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend_events::points::selected_points_merged::SelectedPointsMerged;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::bezier_c0::{BezierC0, BezierC0Point};
+use crate::domain::events::beziers_c0::bezier_c0_created::BezierC0Created;
+use crate::domain::events::beziers_c0::bezier_c0_deleted::BezierC0Deleted;
+use crate::domain::events::points::point_created::PointCreated;
+use crate::domain::point::Point;
+use crate::domain::transformer::LittleTransformer;
+
+pub struct SplitBezierC0 {
+    pub id: u64,
+    pub parameter: f64,
+}
+
+impl Command<SplitBezierC0> for SplitBezierC0 {
+    fn execute(command: &SplitBezierC0, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let bezier = backend.storage.beziers_c0.get(&command.id).unwrap();
+        let Some(split) = bezier.split(command.parameter, &backend.storage.points) else {
+            return;
+        };
+        let name = bezier.name.clone();
+        let point_ids = bezier.points.iter().map(|p| p.id).collect::<Vec<_>>();
+
+        let new_points = split
+            .left_points
+            .iter()
+            .chain(split.right_points.iter())
+            .map(|position| {
+                Point::new(
+                    backend.services.id_generator.next(),
+                    LittleTransformer {
+                        position: *position,
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+        let (left_points, right_points) = new_points.split_at(split.left_points.len());
+        let shared_point = left_points
+            .last()
+            .map(|p| p.id)
+            .unwrap_or(point_ids[split.start]);
+
+        let first_bezier = BezierC0::new_with_name(
+            backend.services.id_generator.next(),
+            format!("{} (1)", name),
+            point_ids[..=split.start]
+                .iter()
+                .copied()
+                .chain(left_points.iter().map(|p| p.id))
+                .map(|id| BezierC0Point { id })
+                .collect(),
+        );
+        let second_bezier = BezierC0::new_with_name(
+            backend.services.id_generator.next(),
+            format!("{} (2)", name),
+            [shared_point]
+                .into_iter()
+                .chain(right_points.iter().map(|p| p.id))
+                .chain(point_ids[split.end..].iter().copied())
+                .map(|id| BezierC0Point { id })
+                .collect(),
+        );
+
+        let points_created = new_points
+            .iter()
+            .map(|p| Rc::new(PointCreated::new(p.id, p.name.clone())))
+            .collect::<Vec<_>>();
+        let bezier_c0_deleted = Rc::new(BezierC0Deleted::new(command.id));
+        let beziers_c0_created = [
+            Rc::new(BezierC0Created::new(first_bezier.id)),
+            Rc::new(BezierC0Created::new(second_bezier.id)),
+        ];
+
+        for point in new_points {
+            backend.storage.points.insert(point.id, point);
+        }
+        backend.storage.beziers_c0.remove(&command.id);
+        backend
+            .storage
+            .beziers_c0
+            .insert(first_bezier.id, first_bezier);
+        backend
+            .storage
+            .beziers_c0
+            .insert(second_bezier.id, second_bezier);
+        backend
+            .storage
+            .selected_objects
+            .retain(|object| object.bezier_c0_id != Some(command.id));
+        let orphaned_points = &point_ids[split.start + 1..split.end];
+        backend.storage.remove_unused_points(orphaned_points);
+        let points_removed = orphaned_points
+            .iter()
+            .any(|id| !backend.storage.points.contains_key(id));
+
+        drop(backend);
+        let backend = app_state.borrow();
+        for point_created in points_created {
+            backend.services.event_publisher.publish(point_created);
+        }
+        backend.services.event_publisher.publish(bezier_c0_deleted);
+        for bezier_c0_created in beziers_c0_created {
+            backend.services.event_publisher.publish(bezier_c0_created);
+        }
+        if points_removed {
+            backend
+                .services
+                .event_publisher
+                .publish(Rc::new(SelectedPointsMerged));
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend_events::points::selected_points_merged::SelectedPointsMerged;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::bezier_c2::BezierC2;
+use crate::domain::curve_fitting::{b_spline_from_bezier_chain, fit_b_spline};
+use crate::domain::events::beziers_c0::bezier_c0_deleted::BezierC0Deleted;
+use crate::domain::events::beziers_c2::bezier_c2_created::BezierC2Created;
+use crate::domain::events::points::point_created::PointCreated;
+use crate::domain::point::Point;
+use crate::domain::transformer::LittleTransformer;
+
+const SAMPLES_PER_SEGMENT: usize = 32;
+
+pub struct TransformBezierC0IntoBezierC2 {
+    pub id: u64,
+    pub tolerance: f64,
+    pub keep_source: bool,
+}
+
+impl Command<TransformBezierC0IntoBezierC2> for TransformBezierC0IntoBezierC2 {
+    fn execute(command: &TransformBezierC0IntoBezierC2, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let bezier_c0 = &backend.storage.beziers_c0[&command.id];
+        let control_points = b_spline_from_bezier_chain(
+            &bezier_c0.positions(&backend.storage.points),
+            command.tolerance,
+        )
+        .or_else(|| {
+            fit_b_spline(
+                &bezier_c0.sample(SAMPLES_PER_SEGMENT, &backend.storage.points),
+                false,
+                command.tolerance,
+            )
+            .map(|fit| fit.control_points)
+        });
+        let Some(control_points) = control_points else {
+            return;
+        };
+
+        let id_generator = &mut backend.services.id_generator;
+        let points = control_points
+            .iter()
+            .map(|p| {
+                Point::new(
+                    id_generator.next(),
+                    LittleTransformer {
+                        position: (p.x, p.y, p.z),
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+        let bezier_c2 = BezierC2::new(id_generator.next(), points.clone());
+        let point_created_events = points
+            .iter()
+            .map(|p| PointCreated::new(p.id, p.name.clone()))
+            .collect::<Vec<_>>();
+        let bezier_c2_created_event = BezierC2Created::new(bezier_c2.id);
+        backend
+            .storage
+            .points
+            .extend(points.into_iter().map(|p| (p.id, p)));
+        backend.storage.beziers_c2.insert(bezier_c2.id, bezier_c2);
+        backend.storage.selected_objects.clear();
+        let mut points_removed = false;
+        if !command.keep_source {
+            let source = backend.storage.beziers_c0.remove(&command.id).unwrap();
+            let source_points = source.points.iter().map(|p| p.id).collect::<Vec<_>>();
+            backend.storage.remove_unused_points(&source_points);
+            points_removed = source_points
+                .iter()
+                .any(|id| !backend.storage.points.contains_key(id));
+        }
+        drop(backend);
+        let backend = app_state.borrow();
+        for event in point_created_events {
+            backend.services.event_publisher.publish(Rc::new(event));
+        }
+        backend
+            .services
+            .event_publisher
+            .publish(Rc::new(bezier_c2_created_event));
+        if !command.keep_source {
+            backend
+                .services
+                .event_publisher
+                .publish(Rc::new(BezierC0Deleted::new(command.id)));
+        }
+        if points_removed {
+            backend
+                .services
+                .event_publisher
+                .publish(Rc::new(SelectedPointsMerged));
+        }
+    }
+}
@@ -23,6 +23,7 @@ impl Command<AddPointToBezierC2> for AddPointToBezierC2 {
         let point = backend.storage.points.get(&command.point_id).unwrap();
         let point_name = point.name.clone();
         points.push(point.clone());
+        let index = points.len() - 1;
         let bezier = backend.storage.beziers_c2.get_mut(&command.id).unwrap();
         bezier.update_points(points);
         drop(backend);
@@ -31,6 +32,7 @@ impl Command<AddPointToBezierC2> for AddPointToBezierC2 {
             command.point_id,
             command.id,
             point_name,
+            index,
         ));
         backend
             .services
@@ -39,6 +39,7 @@ impl Query<AllBeziersC2, Vec<BezierC2DTO>> for AllBeziersC2 {
                         }
                     })
                     .collect(),
+                closed: bezier.closed,
             })
             .collect()
     }
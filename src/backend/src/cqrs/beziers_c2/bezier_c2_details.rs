@@ -15,6 +15,7 @@ pub struct BezierC2DTO {
     pub name: String,
     pub bernstein_points: Vec<BezierC2BernsteinPointDTO>,
     pub b_spline_points: Vec<BezierC2BSplinePointDTO>,
+    pub closed: bool,
 }
 
 pub struct BezierC2BSplinePointDTO {
@@ -49,6 +50,7 @@ impl Query<BezierC2Details, BezierC2DTO> for BezierC2Details {
                     }
                 })
                 .collect(),
+            closed: bezier_c2.closed,
         }
     }
 }
@@ -0,0 +1,68 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::points::point_added_to_bezier_c2::PointAddedToBezierC2;
+use crate::domain::events::points::point_created::PointCreated;
+use crate::domain::events::points::point_moved::PointMoved;
+use crate::domain::point::Point;
+use crate::domain::transformer::LittleTransformer;
+
+pub struct InsertBezierC2Knot {
+    pub id: u64,
+    pub parameter: f64,
+}
+
+impl Command<InsertBezierC2Knot> for InsertBezierC2Knot {
+    fn execute(command: &InsertBezierC2Knot, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let bezier = backend.storage.beziers_c2.get(&command.id).unwrap();
+        let Some(insertion) = bezier.knot_insertion(command.parameter, &backend.storage.points)
+        else {
+            return;
+        };
+
+        for (id, position) in insertion.moved_points.iter() {
+            backend
+                .storage
+                .points
+                .get_mut(id)
+                .unwrap()
+                .transform(*position);
+        }
+
+        let point = Point::new(
+            backend.services.id_generator.next(),
+            LittleTransformer {
+                position: insertion.inserted_point,
+            },
+        );
+        let point_created = Rc::new(PointCreated::new(point.id, point.name.clone()));
+        let point_added_to_bezier = Rc::new(PointAddedToBezierC2::new(
+            point.id,
+            command.id,
+            point.name.clone(),
+            insertion.index,
+        ));
+        backend.storage.points.insert(point.id, point);
+
+        let storage = &mut backend.storage;
+        storage.selected_objects.clear();
+        let bezier = storage.beziers_c2.get_mut(&command.id).unwrap();
+        bezier.insert_knot(&insertion, point_created.id, &storage.points);
+        drop(backend);
+        let backend = app_state.borrow();
+        backend.services.event_publisher.publish(point_created);
+        backend
+            .services
+            .event_publisher
+            .publish(point_added_to_bezier);
+        for (id, position) in insertion.moved_points {
+            backend
+                .services
+                .event_publisher
+                .publish(Rc::new(PointMoved::new(id, position)));
+        }
+    }
+}
@@ -5,9 +5,13 @@ pub mod bezier_c2_b_spline_points;
 pub mod bezier_c2_bernstein_points;
 pub mod bezier_c2_details;
 pub mod delete_bezier_c2_points;
+pub mod insert_bezier_c2_knot;
 pub mod move_bezier_c2_selected_bernstein_point;
 pub mod rename_bezier_c2;
+pub mod set_bezier_c2_closed;
 pub mod set_bezier_c2_draw_b_spline_polygon;
 pub mod set_bezier_c2_draw_bernstein_points;
 pub mod set_bezier_c2_draw_bernstein_polygon;
 pub mod set_bezier_c2_selected_bernstein_point;
+pub mod split_bezier_c2;
+pub mod transform_bezier_c2_into_bezier_c0;
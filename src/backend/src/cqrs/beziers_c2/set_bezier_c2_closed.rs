@@ -0,0 +1,24 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::beziers_c2::bezier_c2_closed_set::BezierC2ClosedSet;
+
+pub struct SetBezierC2Closed {
+    pub id: u64,
+    pub closed: bool,
+}
+
+impl Command<SetBezierC2Closed> for SetBezierC2Closed {
+    fn execute(command: &SetBezierC2Closed, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let storage = &mut backend.storage;
+        let bezier = storage.beziers_c2.get_mut(&command.id).unwrap();
+        bezier.set_closed(command.closed, &storage.points);
+        let event = Rc::new(BezierC2ClosedSet::new(bezier.id, bezier.closed));
+        drop(backend);
+        let backend = app_state.borrow();
+        backend.services.event_publisher.publish(event);
+    }
+}
@@ -0,0 +1,102 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::beziers_c2::insert_bezier_c2_knot::InsertBezierC2Knot;
+use crate::cqrs::cqrs::{Command, CQRS};
+use crate::domain::bezier_c2::BezierC2;
+use crate::domain::events::beziers_c2::bezier_c2_created::BezierC2Created;
+use crate::domain::events::beziers_c2::bezier_c2_deleted::BezierC2Deleted;
+use crate::domain::events::points::point_created::PointCreated;
+use crate::domain::point::Point;
+
+pub struct SplitBezierC2 {
+    pub id: u64,
+    pub parameter: f64,
+}
+
+impl Command<SplitBezierC2> for SplitBezierC2 {
+    fn execute(command: &SplitBezierC2, app_state: Rc<RefCell<Backend>>) {
+        let backend = app_state.borrow();
+        let bezier = backend.storage.beziers_c2.get(&command.id).unwrap();
+        if bezier.split(command.parameter).is_none() {
+            return;
+        }
+        drop(backend);
+
+        let mut cqrs = CQRS::new(app_state.clone());
+        cqrs.execute(&InsertBezierC2Knot {
+            id: command.id,
+            parameter: command.parameter,
+        });
+
+        let mut backend = app_state.borrow_mut();
+        let bezier = backend.storage.beziers_c2.get(&command.id).unwrap();
+        let Some(mut split) = bezier.split(command.parameter) else {
+            return;
+        };
+        let name = bezier.name.clone();
+        let first_id = backend.services.id_generator.next();
+        let second_id = backend.services.id_generator.next();
+
+        let mut points_created = vec![];
+        for id in split.right_points.iter_mut().take(3) {
+            let point = Point::new(
+                backend.services.id_generator.next(),
+                backend.storage.points[id].transformer.clone(),
+            );
+            points_created.push(Rc::new(PointCreated::new(point.id, point.name.clone())));
+            *id = point.id;
+            backend.storage.points.insert(point.id, point);
+        }
+
+        let points = &backend.storage.points;
+        let mut first_bezier = BezierC2::new_with_name(
+            first_id,
+            format!("{} (1)", name),
+            split
+                .left_points
+                .iter()
+                .map(|id| points[id].clone())
+                .collect(),
+        );
+        first_bezier.set_knot_intervals(split.left_knot_intervals, points);
+        let mut second_bezier = BezierC2::new_with_name(
+            second_id,
+            format!("{} (2)", name),
+            split
+                .right_points
+                .iter()
+                .map(|id| points[id].clone())
+                .collect(),
+        );
+        second_bezier.set_knot_intervals(split.right_knot_intervals, points);
+
+        let bezier_c2_deleted = Rc::new(BezierC2Deleted::new(command.id));
+        let beziers_c2_created = [
+            Rc::new(BezierC2Created::new(first_bezier.id)),
+            Rc::new(BezierC2Created::new(second_bezier.id)),
+        ];
+
+        backend.storage.beziers_c2.remove(&command.id);
+        backend
+            .storage
+            .beziers_c2
+            .insert(first_bezier.id, first_bezier);
+        backend
+            .storage
+            .beziers_c2
+            .insert(second_bezier.id, second_bezier);
+        backend.storage.selected_objects.clear();
+
+        drop(backend);
+        let backend = app_state.borrow();
+        for point_created in points_created {
+            backend.services.event_publisher.publish(point_created);
+        }
+        backend.services.event_publisher.publish(bezier_c2_deleted);
+        for bezier_c2_created in beziers_c2_created {
+            backend.services.event_publisher.publish(bezier_c2_created);
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::rc::Rc;
 
 use crate::backend::Backend;
 use crate::cqrs::beziers_int::bezier_int_details::{BezierIntDTO, BezierIntPointDTO};
+use crate::cqrs::beziers_int::set_bezier_int_options::BezierIntParameterizationDTO;
 use crate::cqrs::cqrs::Query;
 
 pub struct AllBeziersInt;
@@ -28,6 +29,19 @@ impl Query<AllBeziersInt, Vec<BezierIntDTO>> for AllBeziersInt {
                         }
                     })
                     .collect(),
+                closed: bezier.closed,
+                clamped: bezier.clamped,
+                start_tangent: {
+                    let t = bezier.end_tangents[0];
+                    (t.x, t.y, t.z)
+                },
+                end_tangent: {
+                    let t = bezier.end_tangents[1];
+                    (t.x, t.y, t.z)
+                },
+                parameterization: BezierIntParameterizationDTO::from_parameterization(
+                    bezier.parameterization,
+                ),
             })
             .collect()
     }
@@ -4,6 +4,8 @@ use std::rc::Rc;
 use crate::backend::Backend;
 use crate::cqrs::cqrs::Query;
 
+use super::set_bezier_int_options::BezierIntParameterizationDTO;
+
 pub struct BezierIntDetails {
     pub id: u64,
 }
@@ -12,6 +14,11 @@ pub struct BezierIntDTO {
     pub id: u64,
     pub name: String,
     pub points: Vec<BezierIntPointDTO>,
+    pub closed: bool,
+    pub clamped: bool,
+    pub start_tangent: (f64, f64, f64),
+    pub end_tangent: (f64, f64, f64),
+    pub parameterization: BezierIntParameterizationDTO,
 }
 
 pub struct BezierIntPointDTO {
@@ -37,6 +44,19 @@ impl Query<BezierIntDetails, BezierIntDTO> for BezierIntDetails {
                     }
                 })
                 .collect(),
+            closed: bezier_int.closed,
+            clamped: bezier_int.clamped,
+            start_tangent: {
+                let t = bezier_int.end_tangents[0];
+                (t.x, t.y, t.z)
+            },
+            end_tangent: {
+                let t = bezier_int.end_tangents[1];
+                (t.x, t.y, t.z)
+            },
+            parameterization: BezierIntParameterizationDTO::from_parameterization(
+                bezier_int.parameterization,
+            ),
         }
     }
 }
@@ -5,3 +5,7 @@ pub mod bezier_int_bernstein_points;
 pub mod bezier_int_details;
 pub mod delete_bezier_int_points;
 pub mod rename_bezier_int;
+pub mod set_bezier_int_end_tangents;
+pub mod set_bezier_int_options;
+pub mod split_bezier_int;
+pub mod transform_bezier_int_into_bezier_c0;
@@ -0,0 +1,32 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use nalgebra::Vector3;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::beziers_int::bezier_int_bernstein_point_moved::BezierIntBernsteinPointMoved;
+
+pub struct SetBezierIntEndTangents {
+    pub id: u64,
+    pub start_tangent: (f64, f64, f64),
+    pub end_tangent: (f64, f64, f64),
+}
+
+impl Command<SetBezierIntEndTangents> for SetBezierIntEndTangents {
+    fn execute(command: &SetBezierIntEndTangents, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let storage = &mut backend.storage;
+        let bezier_int = storage.beziers_int.get_mut(&command.id).unwrap();
+        bezier_int.set_end_tangents(
+            [command.start_tangent, command.end_tangent].map(|t| Vector3::new(t.0, t.1, t.2)),
+            &storage.points,
+        );
+        drop(backend);
+        let backend = app_state.borrow();
+        backend
+            .services
+            .event_publisher
+            .publish(Rc::new(BezierIntBernsteinPointMoved::new(command.id)));
+    }
+}
@@ -0,0 +1,59 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::bezier_int::BezierIntParameterization;
+use crate::domain::events::beziers_int::bezier_int_bernstein_point_moved::BezierIntBernsteinPointMoved;
+
+pub struct SetBezierIntOptions {
+    pub id: u64,
+    pub closed: bool,
+    pub clamped: bool,
+    pub parameterization: BezierIntParameterizationDTO,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BezierIntParameterizationDTO {
+    Uniform,
+    Centripetal,
+    ChordLength,
+}
+
+impl Command<SetBezierIntOptions> for SetBezierIntOptions {
+    fn execute(command: &SetBezierIntOptions, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let storage = &mut backend.storage;
+        let bezier_int = storage.beziers_int.get_mut(&command.id).unwrap();
+        bezier_int.set_options(
+            command.closed,
+            command.clamped,
+            command.parameterization.to_parameterization(),
+            &storage.points,
+        );
+        drop(backend);
+        let backend = app_state.borrow();
+        backend
+            .services
+            .event_publisher
+            .publish(Rc::new(BezierIntBernsteinPointMoved::new(command.id)));
+    }
+}
+
+impl BezierIntParameterizationDTO {
+    pub fn from_parameterization(parameterization: BezierIntParameterization) -> Self {
+        match parameterization {
+            BezierIntParameterization::Uniform => Self::Uniform,
+            BezierIntParameterization::Centripetal => Self::Centripetal,
+            BezierIntParameterization::ChordLength => Self::ChordLength,
+        }
+    }
+
+    pub fn to_parameterization(self) -> BezierIntParameterization {
+        match self {
+            Self::Uniform => BezierIntParameterization::Uniform,
+            Self::Centripetal => BezierIntParameterization::Centripetal,
+            Self::ChordLength => BezierIntParameterization::ChordLength,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::bezier_int::BezierInt;
+use crate::domain::events::beziers_int::bezier_int_created::BezierIntCreated;
+use crate::domain::events::beziers_int::bezier_int_deleted::BezierIntDeleted;
+
+pub struct SplitBezierInt {
+    pub id: u64,
+    pub point_id: u64,
+}
+
+impl Command<SplitBezierInt> for SplitBezierInt {
+    fn execute(command: &SplitBezierInt, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let bezier = backend.storage.beziers_int.get(&command.id).unwrap();
+        let Some(index) = bezier.points.iter().position(|p| p.id == command.point_id) else {
+            return;
+        };
+        if bezier.closed || index == 0 || index == bezier.points.len() - 1 {
+            return;
+        }
+        let name = bezier.name.clone();
+        let clamped = bezier.clamped;
+        let [start_tangent, end_tangent] = bezier.end_tangents;
+        let parameterization = bezier.parameterization;
+        let point_ids = bezier.points.iter().map(|p| p.id).collect::<Vec<_>>();
+        let first_id = backend.services.id_generator.next();
+        let second_id = backend.services.id_generator.next();
+
+        let points = &backend.storage.points;
+        let mut first_bezier = BezierInt::new_with_name(
+            first_id,
+            format!("{} (1)", name),
+            point_ids[..=index]
+                .iter()
+                .map(|id| points[id].clone())
+                .collect(),
+        );
+        first_bezier.set_options(false, clamped, parameterization, points);
+        if clamped {
+            first_bezier.set_end_tangents([start_tangent, first_bezier.end_tangents[1]], points);
+        }
+        let mut second_bezier = BezierInt::new_with_name(
+            second_id,
+            format!("{} (2)", name),
+            point_ids[index..]
+                .iter()
+                .map(|id| points[id].clone())
+                .collect(),
+        );
+        second_bezier.set_options(false, clamped, parameterization, points);
+        if clamped {
+            second_bezier.set_end_tangents([second_bezier.end_tangents[0], end_tangent], points);
+        }
+
+        let bezier_int_deleted = Rc::new(BezierIntDeleted::new(command.id));
+        let beziers_int_created = [
+            Rc::new(BezierIntCreated::new(first_id)),
+            Rc::new(BezierIntCreated::new(second_id)),
+        ];
+
+        backend.storage.beziers_int.remove(&command.id);
+        backend.storage.beziers_int.insert(first_id, first_bezier);
+        backend.storage.beziers_int.insert(second_id, second_bezier);
+        backend
+            .storage
+            .selected_objects
+            .retain(|object| object.bezier_int_id != Some(command.id));
+
+        drop(backend);
+        let backend = app_state.borrow();
+        backend.services.event_publisher.publish(bezier_int_deleted);
+        for bezier_int_created in beziers_int_created {
+            backend.services.event_publisher.publish(bezier_int_created);
+        }
+    }
+}
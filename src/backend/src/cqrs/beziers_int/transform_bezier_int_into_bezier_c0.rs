@@ -0,0 +1,78 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend_events::points::selected_points_merged::SelectedPointsMerged;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::beziers_c0::bezier_c0_created::BezierC0Created;
+use crate::domain::events::beziers_int::bezier_int_deleted::BezierIntDeleted;
+use crate::domain::events::points::point_created::PointCreated;
+use crate::services::convert_curve::bezier_c0_from_bernstein_points;
+
+pub struct TransformBezierIntIntoBezierC0 {
+    pub id: u64,
+    pub keep_source: bool,
+}
+
+impl Command<TransformBezierIntIntoBezierC0> for TransformBezierIntIntoBezierC0 {
+    fn execute(command: &TransformBezierIntIntoBezierC0, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let bezier_int = &backend.storage.beziers_int[&command.id];
+        let bernstein_points = bezier_int
+            .bernstein_points
+            .iter()
+            .map(|p| p.transformer.clone())
+            .collect::<Vec<_>>();
+        let closed = bezier_int.closed;
+        let Some((bezier_c0, points)) = bezier_c0_from_bernstein_points(
+            bernstein_points,
+            closed,
+            &mut backend.services.id_generator,
+        ) else {
+            return;
+        };
+
+        let point_created_events = points
+            .iter()
+            .map(|p| PointCreated::new(p.id, p.name.clone()))
+            .collect::<Vec<_>>();
+        let bezier_c0_created_event = BezierC0Created::new(bezier_c0.id);
+        backend
+            .storage
+            .points
+            .extend(points.into_iter().map(|p| (p.id, p)));
+        backend.storage.beziers_c0.insert(bezier_c0.id, bezier_c0);
+        backend.storage.selected_objects.clear();
+        let mut points_removed = false;
+        if !command.keep_source {
+            let source = backend.storage.beziers_int.remove(&command.id).unwrap();
+            let source_points = source.points.iter().map(|p| p.id).collect::<Vec<_>>();
+            backend.storage.remove_unused_points(&source_points);
+            points_removed = source_points
+                .iter()
+                .any(|id| !backend.storage.points.contains_key(id));
+        }
+        drop(backend);
+        let backend = app_state.borrow();
+        for event in point_created_events {
+            backend.services.event_publisher.publish(Rc::new(event));
+        }
+        backend
+            .services
+            .event_publisher
+            .publish(Rc::new(bezier_c0_created_event));
+        if !command.keep_source {
+            backend
+                .services
+                .event_publisher
+                .publish(Rc::new(BezierIntDeleted::new(command.id)));
+        }
+        if points_removed {
+            backend
+                .services
+                .event_publisher
+                .publish(Rc::new(SelectedPointsMerged));
+        }
+    }
+}
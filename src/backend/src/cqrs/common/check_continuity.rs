@@ -0,0 +1,44 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Query;
+use crate::cqrs::points::point_details::LittleTransformerDTO;
+use crate::domain::continuity::boundary_continuity;
+
+pub struct CheckContinuity {
+    pub samples: usize,
+    pub tolerance: f64,
+}
+
+#[derive(Clone)]
+pub struct BoundaryContinuityDTO {
+    pub first: String,
+    pub second: String,
+    pub positions: Vec<LittleTransformerDTO>,
+    pub max_gap: f64,
+    pub max_angle: f64,
+}
+
+impl Query<CheckContinuity, Vec<BoundaryContinuityDTO>> for CheckContinuity {
+    fn get(query: &CheckContinuity, app_state: Rc<RefCell<Backend>>) -> Vec<BoundaryContinuityDTO> {
+        let app_state = app_state.borrow();
+        let patches = app_state.storage.continuity_patches();
+        boundary_continuity(&patches, query.samples, query.tolerance)
+            .iter()
+            .map(|boundary| BoundaryContinuityDTO {
+                first: patches[boundary.first].name.clone(),
+                second: patches[boundary.second].name.clone(),
+                positions: boundary
+                    .positions
+                    .iter()
+                    .map(|p| LittleTransformerDTO {
+                        position: (p.x, p.y, p.z),
+                    })
+                    .collect(),
+                max_gap: boundary.max_gap,
+                max_angle: boundary.max_angle,
+            })
+            .collect()
+    }
+}
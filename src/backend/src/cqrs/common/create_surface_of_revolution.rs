@@ -0,0 +1,101 @@
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+
+use nalgebra::Vector3;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::surfaces_c0::surface_c0_created::SurfaceC0Created;
+use crate::domain::events::surfaces_c2::surface_c2_created::SurfaceC2Created;
+use crate::services::create_surface::{
+    create_surface_c0_of_revolution, create_surface_c2_of_revolution, insert_points,
+};
+
+pub struct CreateSurfaceOfRevolution {
+    pub id: u64,
+    pub profile_id: u64,
+    pub axis_direction: (f64, f64, f64),
+    pub segments: u32,
+}
+
+impl Command<CreateSurfaceOfRevolution> for CreateSurfaceOfRevolution {
+    fn execute(command: &CreateSurfaceOfRevolution, app_state: Rc<RefCell<Backend>>) {
+        let mut binding = app_state.borrow_mut();
+        let backend = binding.deref_mut();
+        let storage = &mut backend.storage;
+        let axis_direction = Vector3::new(
+            command.axis_direction.0,
+            command.axis_direction.1,
+            command.axis_direction.2,
+        );
+        if axis_direction.norm() < 1e-9 || command.segments < 3 {
+            return;
+        }
+
+        if let Some(bezier) = storage.beziers_c0.get(&command.profile_id) {
+            let profile = bezier.bernstein_positions(&storage.points);
+            if profile.len() < 4 {
+                return;
+            }
+
+            let (surface, points) = create_surface_c0_of_revolution(
+                command.id,
+                &profile,
+                command.segments,
+                &storage.cursor.transformer,
+                &axis_direction,
+                &mut backend.services.id_generator,
+            );
+            let size = surface.size;
+            storage.surfaces_c0.insert(command.id, surface);
+            storage.selected_objects.clear();
+            let events = insert_points(storage, points);
+
+            drop(binding);
+            let backend = app_state.borrow();
+            for event in events {
+                backend.services.event_publisher.publish(event);
+            }
+            backend
+                .services
+                .event_publisher
+                .publish(Rc::new(SurfaceC0Created::new(command.id, size, true)));
+        } else if let Some(bezier) = storage.beziers_c2.get(&command.profile_id) {
+            if bezier.b_spline_points.len() < 4 || !bezier.is_uniform() {
+                return;
+            }
+
+            let profile = bezier
+                .b_spline_points
+                .iter()
+                .map(|p| {
+                    let position = storage.points[&p.id].transformer.position;
+                    Vector3::new(position.0, position.1, position.2)
+                })
+                .collect::<Vec<_>>();
+            let (surface, points) = create_surface_c2_of_revolution(
+                command.id,
+                &profile,
+                command.segments,
+                &storage.cursor.transformer,
+                &axis_direction,
+                &mut backend.services.id_generator,
+            );
+            let size = surface.size;
+            storage.surfaces_c2.insert(command.id, surface);
+            storage.selected_objects.clear();
+            let events = insert_points(storage, points);
+
+            drop(binding);
+            let backend = app_state.borrow();
+            for event in events {
+                backend.services.event_publisher.publish(event);
+            }
+            backend
+                .services
+                .event_publisher
+                .publish(Rc::new(SurfaceC2Created::new(command.id, size, true)));
+        }
+    }
+}
@@ -9,8 +9,11 @@ use crate::domain::events::beziers_c2::bezier_c2_deleted::BezierC2Deleted;
 use crate::domain::events::beziers_int::bezier_int_deleted::BezierIntDeleted;
 use crate::domain::events::gregories::gregory_deleted::GregoryDeleted;
 use crate::domain::events::intersections::intersection_deleted::IntersectionDeleted;
+use crate::domain::events::nurbs_curves::nurbs_curve_deleted::NurbsCurveDeleted;
+use crate::domain::events::nurbs_surfaces::nurbs_surface_deleted::NurbsSurfaceDeleted;
 use crate::domain::events::surfaces_c0::surface_c0_deleted::SurfaceC0Deleted;
 use crate::domain::events::surfaces_c2::surface_c2_deleted::SurfaceC2Deleted;
+use crate::domain::events::toolpaths::toolpath_deleted::ToolpathDeleted;
 use crate::domain::events::toruses::torus_deleted::TorusDeleted;
 use crate::domain::intersection::IntersectionObjectId;
 
@@ -59,6 +62,13 @@ impl Command<DeleteSelectedObjects> for DeleteSelectedObjects {
                 .iter()
                 .any(|object| object.bezier_int_id == Some(bezier.id))
         });
+        backend.storage.nurbs_curves.retain(|_, curve| {
+            !backend
+                .storage
+                .selected_objects
+                .iter()
+                .any(|object| object.nurbs_curve_id == Some(curve.id))
+        });
         backend.storage.surfaces_c0.retain(|_, surface| {
             !backend
                 .storage
@@ -81,6 +91,17 @@ impl Command<DeleteSelectedObjects> for DeleteSelectedObjects {
                         || i.object2_id == IntersectionObjectId::SurfaceC2(surface.id)
                 })
         });
+        backend.storage.nurbs_surfaces.retain(|_, surface| {
+            !backend
+                .storage
+                .selected_objects
+                .iter()
+                .any(|object| object.nurbs_surface_id == Some(surface.id))
+                || backend.storage.intersections.values().any(|i| {
+                    i.object1_id == IntersectionObjectId::NurbsSurface(surface.id)
+                        || i.object2_id == IntersectionObjectId::NurbsSurface(surface.id)
+                })
+        });
         backend.storage.gregories.retain(|_, gregory| {
             !backend
                 .storage
@@ -88,6 +109,13 @@ impl Command<DeleteSelectedObjects> for DeleteSelectedObjects {
                 .iter()
                 .any(|object| object.gregory_id == Some(gregory.id))
         });
+        backend.storage.toolpaths.retain(|_, toolpath| {
+            !backend
+                .storage
+                .selected_objects
+                .iter()
+                .any(|object| object.toolpath_id == Some(toolpath.id))
+        });
         backend.storage.points.retain(|_, point| {
             !backend
                 .storage
@@ -109,6 +137,11 @@ impl Command<DeleteSelectedObjects> for DeleteSelectedObjects {
                     .beziers_int
                     .values()
                     .any(|b| b.points.iter().any(|p| p.id == point.id))
+                || backend
+                    .storage
+                    .nurbs_curves
+                    .values()
+                    .any(|c| c.points.iter().any(|p| p.id == point.id))
                 || backend
                     .storage
                     .surfaces_c0
@@ -118,6 +151,11 @@ impl Command<DeleteSelectedObjects> for DeleteSelectedObjects {
                     .storage
                     .surfaces_c2
                     .values()
+                    .any(|s| s.related_points().contains(&point.id))
+                || backend
+                    .storage
+                    .nurbs_surfaces
+                    .values()
                     .any(|s| s.points.iter().any(|p| p.id == point.id))
                 || backend
                     .storage
@@ -162,6 +200,13 @@ impl Command<DeleteSelectedObjects> for DeleteSelectedObjects {
             .filter_map(|object| object.bezier_int_id)
             .collect::<Vec<_>>();
 
+        let deleted_nurbs_curves = backend
+            .storage
+            .selected_objects
+            .iter()
+            .filter_map(|object| object.nurbs_curve_id)
+            .collect::<Vec<_>>();
+
         let deleted_surfaces_c0 = backend
             .storage
             .selected_objects
@@ -178,6 +223,14 @@ impl Command<DeleteSelectedObjects> for DeleteSelectedObjects {
             .filter(|id| !backend.storage.surfaces_c2.contains_key(&id))
             .collect::<Vec<_>>();
 
+        let deleted_nurbs_surfaces = backend
+            .storage
+            .selected_objects
+            .iter()
+            .filter_map(|object| object.nurbs_surface_id)
+            .filter(|id| !backend.storage.nurbs_surfaces.contains_key(&id))
+            .collect::<Vec<_>>();
+
         let deleted_gregories = backend
             .storage
             .selected_objects
@@ -185,6 +238,13 @@ impl Command<DeleteSelectedObjects> for DeleteSelectedObjects {
             .filter_map(|object| object.gregory_id)
             .collect::<Vec<_>>();
 
+        let deleted_toolpaths = backend
+            .storage
+            .selected_objects
+            .iter()
+            .filter_map(|object| object.toolpath_id)
+            .collect::<Vec<_>>();
+
         backend.storage.selected_objects.clear();
 
         drop(binding);
@@ -220,6 +280,12 @@ impl Command<DeleteSelectedObjects> for DeleteSelectedObjects {
                 .event_publisher
                 .publish(Rc::new(BezierIntDeleted::new(*id)));
         });
+        deleted_nurbs_curves.iter().for_each(|id| {
+            backend
+                .services
+                .event_publisher
+                .publish(Rc::new(NurbsCurveDeleted::new(*id)));
+        });
         deleted_surfaces_c0.iter().for_each(|id| {
             backend
                 .services
@@ -232,11 +298,23 @@ impl Command<DeleteSelectedObjects> for DeleteSelectedObjects {
                 .event_publisher
                 .publish(Rc::new(SurfaceC2Deleted::new(*id)));
         });
+        deleted_nurbs_surfaces.iter().for_each(|id| {
+            backend
+                .services
+                .event_publisher
+                .publish(Rc::new(NurbsSurfaceDeleted::new(*id)));
+        });
         deleted_gregories.iter().for_each(|id| {
             backend
                 .services
                 .event_publisher
                 .publish(Rc::new(GregoryDeleted::new(*id)));
         });
+        deleted_toolpaths.iter().for_each(|id| {
+            backend
+                .services
+                .event_publisher
+                .publish(Rc::new(ToolpathDeleted::new(*id)));
+        });
     }
 }
@@ -0,0 +1,340 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::DerefMut;
+use std::rc::Rc;
+
+use backend_events::points::selected_points_merged::SelectedPointsMerged;
+use nalgebra::Vector3;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::data_access::storage::Storage;
+use crate::domain::bezier_c0::{BezierC0, BezierC0Point};
+use crate::domain::bezier_c2::BezierC2;
+use crate::domain::bezier_int::BezierInt;
+use crate::domain::curve_joining::{chain_curves, join_curves, JoinedCurveCandidate};
+use crate::domain::events::beziers_c0::bezier_c0_created::BezierC0Created;
+use crate::domain::events::beziers_c0::bezier_c0_deleted::BezierC0Deleted;
+use crate::domain::events::beziers_c2::bezier_c2_created::BezierC2Created;
+use crate::domain::events::beziers_c2::bezier_c2_deleted::BezierC2Deleted;
+use crate::domain::events::beziers_int::bezier_int_created::BezierIntCreated;
+use crate::domain::events::beziers_int::bezier_int_deleted::BezierIntDeleted;
+use crate::domain::events::points::point_created::PointCreated;
+use crate::domain::point::Point;
+use crate::domain::transformer::LittleTransformer;
+
+const POINT_TOLERANCE: f64 = 1e-6;
+
+pub struct JoinSelectedCurves {
+    pub id: u64,
+    pub merge_end_points: bool,
+}
+
+impl Command<JoinSelectedCurves> for JoinSelectedCurves {
+    fn execute(command: &JoinSelectedCurves, app_state: Rc<RefCell<Backend>>) {
+        let mut binding = app_state.borrow_mut();
+        let backend = binding.deref_mut();
+        let storage = &mut backend.storage;
+        let selected_objects_count = storage.selected_objects.len();
+
+        let beziers_c0 = storage
+            .selected_objects
+            .iter()
+            .filter_map(|object| object.bezier_c0_id)
+            .collect::<Vec<_>>();
+        let beziers_c2 = storage
+            .selected_objects
+            .iter()
+            .filter_map(|object| object.bezier_c2_id)
+            .collect::<Vec<_>>();
+        let beziers_int = storage
+            .selected_objects
+            .iter()
+            .filter_map(|object| object.bezier_int_id)
+            .collect::<Vec<_>>();
+
+        if selected_objects_count < 2 {
+            return;
+        }
+
+        if beziers_c0.len() == selected_objects_count {
+            let candidates = beziers_c0
+                .iter()
+                .map(|id| {
+                    let points = storage.beziers_c0[id]
+                        .points
+                        .iter()
+                        .map(|p| p.id)
+                        .collect::<Vec<_>>();
+                    candidate(points, &storage.points)
+                })
+                .collect::<Option<Vec<_>>>();
+            let Some(joined) = candidates.and_then(|candidates| {
+                join_curves(&candidates, &storage.points, command.merge_end_points)
+            }) else {
+                return;
+            };
+            let last = joined.chain.len() - 1;
+            if joined
+                .chain
+                .iter()
+                .enumerate()
+                .any(|(position, (index, reversed))| {
+                    let count = storage.beziers_c0[&beziers_c0[*index]].points.len();
+                    (position < last || *reversed) && !(count - 1).is_multiple_of(3)
+                })
+            {
+                return;
+            }
+
+            merge_points(storage, &joined.merged_points);
+            for id in beziers_c0.iter() {
+                storage.beziers_c0.remove(id);
+            }
+            let bezier = BezierC0::new(
+                command.id,
+                joined
+                    .points
+                    .iter()
+                    .map(|id| BezierC0Point { id: *id })
+                    .collect(),
+            );
+            storage.beziers_c0.insert(command.id, bezier);
+            storage.selected_objects.clear();
+
+            drop(binding);
+            let backend = app_state.borrow();
+            for id in beziers_c0 {
+                backend
+                    .services
+                    .event_publisher
+                    .publish(Rc::new(BezierC0Deleted::new(id)));
+            }
+            backend
+                .services
+                .event_publisher
+                .publish(Rc::new(BezierC0Created::new(command.id)));
+            publish_merged_points(&backend, &joined.merged_points);
+        } else if beziers_c2.len() == selected_objects_count {
+            if beziers_c2.iter().any(|id| storage.beziers_c2[id].closed) {
+                return;
+            }
+
+            let candidates = beziers_c2
+                .iter()
+                .map(|id| {
+                    let bezier = &storage.beziers_c2[id];
+                    let (Some(start), Some(end)) = (
+                        bezier.bernstein_points.first(),
+                        bezier.bernstein_points.last(),
+                    ) else {
+                        return None;
+                    };
+                    Some(JoinedCurveCandidate {
+                        points: bezier.b_spline_points.iter().map(|p| p.id).collect(),
+                        start: to_vector(&start.transformer),
+                        end: to_vector(&end.transformer),
+                    })
+                })
+                .collect::<Option<Vec<_>>>();
+            let Some(chain) = candidates.and_then(|candidates| chain_curves(&candidates)) else {
+                return;
+            };
+
+            let mut bernstein_points = vec![];
+            let mut knot_intervals = vec![];
+            let mut candidate_ids: Vec<Vec<u64>> = vec![];
+            for (position, (index, reversed)) in chain.into_iter().enumerate() {
+                let bezier = &storage.beziers_c2[&beziers_c2[index]];
+                let mut curve_bernstein_points = bezier
+                    .bernstein_points
+                    .iter()
+                    .map(|p| to_vector(&p.transformer))
+                    .collect::<Vec<_>>();
+                let mut curve_knot_intervals = bezier.effective_knot_intervals();
+                let mut curve_ids = bezier
+                    .b_spline_points
+                    .iter()
+                    .map(|p| vec![p.id])
+                    .collect::<Vec<_>>();
+                if reversed {
+                    curve_bernstein_points.reverse();
+                    curve_knot_intervals.reverse();
+                    curve_ids.reverse();
+                }
+
+                if position == 0 {
+                    bernstein_points = curve_bernstein_points;
+                    knot_intervals = curve_knot_intervals;
+                    candidate_ids = curve_ids;
+                } else {
+                    bernstein_points.extend(curve_bernstein_points.into_iter().skip(1));
+                    knot_intervals.truncate(knot_intervals.len() - 3);
+                    knot_intervals.extend(curve_knot_intervals.into_iter().skip(3));
+                    let seam = candidate_ids.len() - 3;
+                    for (ids, curve_ids) in
+                        candidate_ids[seam..].iter_mut().zip(curve_ids.drain(..3))
+                    {
+                        ids.extend(curve_ids);
+                    }
+                    candidate_ids.extend(curve_ids);
+                }
+            }
+
+            let Some(positions) =
+                BezierC2::b_spline_from_bernstein_points(&bernstein_points, &knot_intervals)
+            else {
+                return;
+            };
+
+            let mut ids = vec![];
+            let mut merged_points = vec![];
+            let mut created_points = vec![];
+            for (candidates, position) in candidate_ids.iter().zip(positions.iter()) {
+                let mut matching = candidates
+                    .iter()
+                    .copied()
+                    .filter(|id| !ids.contains(id))
+                    .filter(|id| {
+                        (to_vector(&storage.points[id].transformer) - position).norm()
+                            < POINT_TOLERANCE
+                    });
+                let id = match matching.next() {
+                    Some(id) => {
+                        if command.merge_end_points {
+                            merged_points.extend(matching.map(|other| (id, other)));
+                        }
+                        id
+                    }
+                    None => {
+                        let point = Point::new(
+                            backend.services.id_generator.next(),
+                            LittleTransformer {
+                                position: (position.x, position.y, position.z),
+                            },
+                        );
+                        created_points.push(PointCreated::new(point.id, point.name.clone()));
+                        let id = point.id;
+                        storage.points.insert(id, point);
+                        id
+                    }
+                };
+                ids.push(id);
+            }
+
+            for id in beziers_c2.iter() {
+                storage.beziers_c2.remove(id);
+            }
+            merge_points(storage, &merged_points);
+            let mut bezier = BezierC2::new(
+                command.id,
+                ids.iter().map(|id| storage.points[id].clone()).collect(),
+            );
+            bezier.set_knot_intervals(knot_intervals, &storage.points);
+            storage.beziers_c2.insert(command.id, bezier);
+            storage.selected_objects.clear();
+
+            drop(binding);
+            let backend = app_state.borrow();
+            for event in created_points {
+                backend.services.event_publisher.publish(Rc::new(event));
+            }
+            for id in beziers_c2 {
+                backend
+                    .services
+                    .event_publisher
+                    .publish(Rc::new(BezierC2Deleted::new(id)));
+            }
+            backend
+                .services
+                .event_publisher
+                .publish(Rc::new(BezierC2Created::new(command.id)));
+            publish_merged_points(&backend, &merged_points);
+        } else if beziers_int.len() == selected_objects_count {
+            if beziers_int.iter().any(|id| storage.beziers_int[id].closed) {
+                return;
+            }
+
+            let candidates = beziers_int
+                .iter()
+                .map(|id| {
+                    let points = storage.beziers_int[id]
+                        .points
+                        .iter()
+                        .map(|p| p.id)
+                        .collect::<Vec<_>>();
+                    candidate(points, &storage.points)
+                })
+                .collect::<Option<Vec<_>>>();
+            let Some(joined) = candidates.and_then(|candidates| {
+                join_curves(&candidates, &storage.points, command.merge_end_points)
+            }) else {
+                return;
+            };
+
+            let first = &storage.beziers_int[&beziers_int[0]];
+            let clamped = first.clamped;
+            let parameterization = first.parameterization;
+
+            merge_points(storage, &joined.merged_points);
+            for id in beziers_int.iter() {
+                storage.beziers_int.remove(id);
+            }
+            let mut bezier = BezierInt::new(
+                command.id,
+                joined
+                    .points
+                    .iter()
+                    .map(|id| storage.points[id].clone())
+                    .collect(),
+            );
+            bezier.set_options(false, clamped, parameterization, &storage.points);
+            storage.beziers_int.insert(command.id, bezier);
+            storage.selected_objects.clear();
+
+            drop(binding);
+            let backend = app_state.borrow();
+            for id in beziers_int {
+                backend
+                    .services
+                    .event_publisher
+                    .publish(Rc::new(BezierIntDeleted::new(id)));
+            }
+            backend
+                .services
+                .event_publisher
+                .publish(Rc::new(BezierIntCreated::new(command.id)));
+            publish_merged_points(&backend, &joined.merged_points);
+        }
+    }
+}
+
+fn candidate(points: Vec<u64>, all_points: &HashMap<u64, Point>) -> Option<JoinedCurveCandidate> {
+    let start = to_vector(&all_points[points.first()?].transformer);
+    let end = to_vector(&all_points[points.last()?].transformer);
+    Some(JoinedCurveCandidate { points, start, end })
+}
+
+fn to_vector(transformer: &LittleTransformer) -> Vector3<f64> {
+    Vector3::new(
+        transformer.position.0,
+        transformer.position.1,
+        transformer.position.2,
+    )
+}
+
+fn merge_points(storage: &mut Storage, merged_points: &[(u64, u64)]) {
+    for (kept_point, removed_point) in merged_points.iter() {
+        storage.points.remove(removed_point);
+        storage.replace_point(*removed_point, *kept_point);
+    }
+}
+
+fn publish_merged_points(backend: &Backend, merged_points: &[(u64, u64)]) {
+    if !merged_points.is_empty() {
+        backend
+            .services
+            .event_publisher
+            .publish(Rc::new(SelectedPointsMerged));
+    }
+}
@@ -23,8 +23,12 @@ impl Command<LoadScene> for LoadScene {
             .max(backend.storage.beziers_c0.keys().max())
             .max(backend.storage.beziers_c2.keys().max())
             .max(backend.storage.beziers_int.keys().max())
+            .max(backend.storage.nurbs_curves.keys().max())
             .max(backend.storage.surfaces_c0.keys().max())
-            .max(backend.storage.surfaces_c2.keys().max());
+            .max(backend.storage.surfaces_c2.keys().max())
+            .max(backend.storage.nurbs_surfaces.keys().max())
+            .max(backend.storage.gregories.keys().max())
+            .max(backend.storage.toolpaths.keys().max());
         let next_id = max_id.map(|id| id + 1).unwrap_or(1);
         drop(backend);
         app_state
@@ -0,0 +1,88 @@
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+
+use nalgebra::Vector3;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::points::point_created::PointCreated;
+use crate::domain::events::surfaces_c2::surface_c2_created::SurfaceC2Created;
+use crate::services::create_surface::create_loft_surface_c2;
+
+pub struct LoftCurves {
+    pub id: u64,
+    pub curves: Vec<u64>,
+    pub closed: bool,
+    pub interpolate: bool,
+}
+
+impl Command<LoftCurves> for LoftCurves {
+    fn execute(command: &LoftCurves, app_state: Rc<RefCell<Backend>>) {
+        let mut binding = app_state.borrow_mut();
+        let backend = binding.deref_mut();
+        let storage = &mut backend.storage;
+        if command.curves.len() < 2
+            || command
+                .curves
+                .iter()
+                .any(|id| !storage.beziers_c2.contains_key(id))
+        {
+            return;
+        }
+
+        let count = storage.beziers_c2[&command.curves[0]].b_spline_points.len();
+        if command.curves.iter().any(|id| {
+            let bezier = &storage.beziers_c2[id];
+            bezier.b_spline_points.len() != count || !bezier.is_uniform()
+        }) {
+            return;
+        }
+
+        let sections = command
+            .curves
+            .iter()
+            .map(|id| {
+                storage.beziers_c2[id]
+                    .b_spline_points
+                    .iter()
+                    .map(|p| {
+                        let position = storage.points[&p.id].transformer.position;
+                        (p.id, Vector3::new(position.0, position.1, position.2))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let surface = create_loft_surface_c2(
+            command.id,
+            &sections,
+            command.closed,
+            command.interpolate,
+            &mut backend.services.id_generator,
+        );
+
+        let mut points_created = vec![];
+        let mut surface_created = None;
+        if let Some((surface, points)) = surface {
+            surface_created = Some(Rc::new(SurfaceC2Created::new(
+                command.id,
+                surface.size,
+                surface.is_cylinder,
+            )));
+            storage.surfaces_c2.insert(command.id, surface);
+            for point in points {
+                points_created.push(Rc::new(PointCreated::new(point.id, point.name.clone())));
+                storage.points.insert(point.id, point);
+            }
+        }
+
+        drop(binding);
+        let backend = app_state.borrow();
+        for event in points_created {
+            backend.services.event_publisher.publish(event);
+        }
+        if let Some(event) = surface_created {
+            backend.services.event_publisher.publish(event);
+        }
+    }
+}
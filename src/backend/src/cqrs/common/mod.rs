@@ -1,7 +1,14 @@
+pub mod check_continuity;
+pub mod create_surface_of_revolution;
 pub mod delete_selected_objects;
+pub mod join_selected_curves;
 pub mod load_scene;
+pub mod loft_curves;
 pub mod new_id;
 pub mod save_scene;
 pub mod select_objects;
 pub mod selected_objects_center;
+pub mod surface_display_mode;
+pub mod sweep_curve;
 pub mod transform_selected_objects;
+pub mod weld_selected_objects;
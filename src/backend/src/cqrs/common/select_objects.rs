@@ -22,10 +22,13 @@ pub enum ObjectTypeDTO {
     BezierC0,
     BezierC2,
     BezierInt,
+    NurbsCurve,
     SurfaceC0,
     SurfaceC2,
+    NurbsSurface,
     Gregory,
     Intersection,
+    Toolpath,
 }
 
 impl Command<SelectObjects> for SelectObjects {
@@ -40,10 +43,13 @@ impl Command<SelectObjects> for SelectObjects {
                 ObjectTypeDTO::BezierC0 => SelectedObject::new_bezier_c0(obj.id),
                 ObjectTypeDTO::BezierC2 => SelectedObject::new_bezier_c2(obj.id),
                 ObjectTypeDTO::BezierInt => SelectedObject::new_bezier_int(obj.id),
+                ObjectTypeDTO::NurbsCurve => SelectedObject::new_nurbs_curve(obj.id),
                 ObjectTypeDTO::SurfaceC0 => SelectedObject::new_surface_c0(obj.id),
                 ObjectTypeDTO::SurfaceC2 => SelectedObject::new_surface_c2(obj.id),
+                ObjectTypeDTO::NurbsSurface => SelectedObject::new_nurbs_surface(obj.id),
                 ObjectTypeDTO::Gregory => SelectedObject::new_gregory(obj.id),
                 ObjectTypeDTO::Intersection => SelectedObject::new_intersection(obj.id),
+                ObjectTypeDTO::Toolpath => SelectedObject::new_toolpath(obj.id),
             })
             .collect();
     }
@@ -0,0 +1,23 @@
+use backend_events::common::surface_display_mode::SurfaceDisplayModeDTO;
+
+use crate::domain::surface_display_mode::SurfaceDisplayMode;
+
+pub(crate) fn display_mode_from_dto(display_mode: SurfaceDisplayModeDTO) -> SurfaceDisplayMode {
+    match display_mode {
+        SurfaceDisplayModeDTO::Wireframe => SurfaceDisplayMode::Wireframe,
+        SurfaceDisplayModeDTO::Normals => SurfaceDisplayMode::Normals,
+        SurfaceDisplayModeDTO::GaussianCurvature => SurfaceDisplayMode::GaussianCurvature,
+        SurfaceDisplayModeDTO::MeanCurvature => SurfaceDisplayMode::MeanCurvature,
+        SurfaceDisplayModeDTO::Zebra => SurfaceDisplayMode::Zebra,
+    }
+}
+
+pub(crate) fn display_mode_to_dto(display_mode: SurfaceDisplayMode) -> SurfaceDisplayModeDTO {
+    match display_mode {
+        SurfaceDisplayMode::Wireframe => SurfaceDisplayModeDTO::Wireframe,
+        SurfaceDisplayMode::Normals => SurfaceDisplayModeDTO::Normals,
+        SurfaceDisplayMode::GaussianCurvature => SurfaceDisplayModeDTO::GaussianCurvature,
+        SurfaceDisplayMode::MeanCurvature => SurfaceDisplayModeDTO::MeanCurvature,
+        SurfaceDisplayMode::Zebra => SurfaceDisplayModeDTO::Zebra,
+    }
+}
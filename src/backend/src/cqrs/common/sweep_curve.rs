@@ -0,0 +1,106 @@
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+
+use nalgebra::Vector3;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::surfaces_c0::surface_c0_created::SurfaceC0Created;
+use crate::domain::events::surfaces_c2::surface_c2_created::SurfaceC2Created;
+use crate::services::create_surface::{
+    create_sweep_surface_c0, create_sweep_surface_c2, insert_points,
+};
+
+pub struct SweepCurve {
+    pub id: u64,
+    pub profile_id: u64,
+    pub path_id: u64,
+    pub segments: u32,
+}
+
+impl Command<SweepCurve> for SweepCurve {
+    fn execute(command: &SweepCurve, app_state: Rc<RefCell<Backend>>) {
+        let mut binding = app_state.borrow_mut();
+        let backend = binding.deref_mut();
+        let storage = &mut backend.storage;
+        if command.profile_id == command.path_id {
+            return;
+        }
+        let Some(path) = storage.curve_analysis(command.path_id) else {
+            return;
+        };
+
+        if let Some(bezier) = storage.beziers_c0.get(&command.profile_id) {
+            let profile = bezier.bernstein_positions(&storage.points);
+            let closed = bezier.points.len() > 1
+                && (bezier.points[0].id == bezier.points[bezier.points.len() - 1].id
+                    || (profile[0] - profile[profile.len() - 1]).norm() < 1e-9);
+            let Some((surface, points)) = create_sweep_surface_c0(
+                command.id,
+                &profile,
+                closed,
+                &path,
+                command.segments,
+                &mut backend.services.id_generator,
+            ) else {
+                return;
+            };
+
+            let surface_created = Rc::new(SurfaceC0Created::new(
+                command.id,
+                surface.size,
+                surface.is_cylinder,
+            ));
+            storage.surfaces_c0.insert(command.id, surface);
+            storage.selected_objects.clear();
+            let events = insert_points(storage, points);
+
+            drop(binding);
+            let backend = app_state.borrow();
+            for event in events {
+                backend.services.event_publisher.publish(event);
+            }
+            backend.services.event_publisher.publish(surface_created);
+        } else if let Some(bezier) = storage.beziers_c2.get(&command.profile_id) {
+            if !bezier.is_uniform() {
+                return;
+            }
+
+            let profile = bezier
+                .b_spline_points
+                .iter()
+                .map(|p| {
+                    let position = storage.points[&p.id].transformer.position;
+                    Vector3::new(position.0, position.1, position.2)
+                })
+                .collect::<Vec<_>>();
+            let Some((surface, points)) = create_sweep_surface_c2(
+                command.id,
+                &profile,
+                bezier.closed,
+                &path,
+                command.segments,
+                &mut backend.services.id_generator,
+            ) else {
+                return;
+            };
+
+            let surface_created = Rc::new(SurfaceC2Created::new(
+                command.id,
+                surface.size,
+                surface.is_cylinder,
+            ));
+            storage.surfaces_c2.insert(command.id, surface);
+            storage.selected_objects.clear();
+            let events = insert_points(storage, points);
+
+            drop(binding);
+            let backend = app_state.borrow();
+            for event in events {
+                backend.services.event_publisher.publish(event);
+            }
+            backend.services.event_publisher.publish(surface_created);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::{HashMap, HashSet};
+use std::{cell::RefCell, ops::DerefMut, rc::Rc};
+
+use backend_events::points::selected_points_merged::SelectedPointsMerged;
+
+use crate::{
+    backend::Backend,
+    cqrs::cqrs::Command,
+    domain::{point::Point, transformer::LittleTransformer},
+};
+
+pub struct WeldSelectedObjects {
+    pub tolerance: f64,
+}
+
+impl Command<WeldSelectedObjects> for WeldSelectedObjects {
+    fn execute(command: &WeldSelectedObjects, app_state: Rc<RefCell<Backend>>) {
+        let mut binding = app_state.borrow_mut();
+        let backend = binding.deref_mut();
+        let storage = &mut backend.storage;
+
+        let objects = storage
+            .selected_objects
+            .iter()
+            .filter_map(|o| {
+                if let Some(id) = o.bezier_c0_id {
+                    Some(
+                        storage.beziers_c0[&id]
+                            .points
+                            .iter()
+                            .map(|p| p.id)
+                            .collect(),
+                    )
+                } else if let Some(id) = o.bezier_c2_id {
+                    Some(
+                        storage.beziers_c2[&id]
+                            .b_spline_points
+                            .iter()
+                            .map(|p| p.id)
+                            .collect(),
+                    )
+                } else if let Some(id) = o.bezier_int_id {
+                    Some(
+                        storage.beziers_int[&id]
+                            .points
+                            .iter()
+                            .map(|p| p.id)
+                            .collect(),
+                    )
+                } else if let Some(id) = o.nurbs_curve_id {
+                    Some(
+                        storage.nurbs_curves[&id]
+                            .points
+                            .iter()
+                            .map(|p| p.id)
+                            .collect(),
+                    )
+                } else if let Some(id) = o.surface_c0_id {
+                    Some(
+                        storage.surfaces_c0[&id]
+                            .points
+                            .iter()
+                            .map(|p| p.id)
+                            .collect(),
+                    )
+                } else if let Some(id) = o.surface_c2_id {
+                    Some(
+                        storage.surfaces_c2[&id]
+                            .points
+                            .iter()
+                            .map(|p| p.id)
+                            .collect(),
+                    )
+                } else {
+                    o.nurbs_surface_id.map(|id| {
+                        storage.nurbs_surfaces[&id]
+                            .points
+                            .iter()
+                            .map(|p| p.id)
+                            .collect()
+                    })
+                }
+            })
+            .collect::<Vec<Vec<u64>>>();
+
+        let mut owners = HashMap::new();
+        for (object, ids) in objects.iter().enumerate() {
+            for id in ids {
+                owners.entry(*id).or_insert(object);
+            }
+        }
+        let points = owners
+            .iter()
+            .map(|(id, owner)| (*id, *owner, storage.points[id].transformer.position))
+            .collect::<Vec<_>>();
+
+        let mut pairs = vec![];
+        for i in 0..points.len() {
+            for j in (i + 1)..points.len() {
+                let (a, b) = (points[i].2, points[j].2);
+                let distance =
+                    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2) + (a.2 - b.2).powi(2)).sqrt();
+                if points[i].1 != points[j].1 && distance <= command.tolerance {
+                    pairs.push((distance, i, j));
+                }
+            }
+        }
+        pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut parents = (0..points.len()).collect::<Vec<_>>();
+        let mut cluster_owners = points
+            .iter()
+            .map(|p| HashSet::from([p.1]))
+            .collect::<Vec<_>>();
+        for (_, i, j) in pairs {
+            let (ri, rj) = (root(&mut parents, i), root(&mut parents, j));
+            if ri == rj || !cluster_owners[ri].is_disjoint(&cluster_owners[rj]) {
+                continue;
+            }
+            parents[ri] = rj;
+            let owners = std::mem::take(&mut cluster_owners[ri]);
+            cluster_owners[rj].extend(owners);
+        }
+
+        let mut clusters = HashMap::<usize, Vec<usize>>::new();
+        for i in 0..points.len() {
+            clusters.entry(root(&mut parents, i)).or_default().push(i);
+        }
+        let clusters = clusters
+            .into_values()
+            .filter(|cluster| cluster.len() > 1)
+            .collect::<Vec<_>>();
+        if clusters.is_empty() {
+            return;
+        }
+
+        for cluster in clusters {
+            let n = cluster.len() as f64;
+            let position = cluster.iter().fold((0.0, 0.0, 0.0), |sum, i| {
+                let p = points[*i].2;
+                (sum.0 + p.0 / n, sum.1 + p.1 / n, sum.2 + p.2 / n)
+            });
+            let point = Point::new(
+                backend.services.id_generator.next(),
+                LittleTransformer { position },
+            );
+            let point_id = point.id;
+            storage.points.insert(point.id, point);
+
+            for i in cluster {
+                let old_point = points[i].0;
+                storage.points.remove(&old_point);
+                storage.replace_point(old_point, point_id);
+                storage
+                    .selected_objects
+                    .retain(|o| o.point_id != Some(old_point));
+            }
+        }
+
+        drop(binding);
+
+        app_state
+            .borrow()
+            .services
+            .event_publisher
+            .publish(Rc::new(SelectedPointsMerged));
+    }
+}
+
+fn root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
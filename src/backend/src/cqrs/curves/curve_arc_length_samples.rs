@@ -0,0 +1,36 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Query;
+use crate::cqrs::points::point_details::LittleTransformerDTO;
+
+pub struct CurveArcLengthSamples {
+    pub id: u64,
+    pub count: usize,
+}
+
+#[derive(Clone)]
+pub struct CurveSampleDTO {
+    pub parameter: f64,
+    pub transformer: LittleTransformerDTO,
+}
+
+impl Query<CurveArcLengthSamples, Vec<CurveSampleDTO>> for CurveArcLengthSamples {
+    fn get(query: &CurveArcLengthSamples, app_state: Rc<RefCell<Backend>>) -> Vec<CurveSampleDTO> {
+        let app_state = app_state.borrow();
+        let Some(analysis) = app_state.storage.curve_analysis(query.id) else {
+            return vec![];
+        };
+        analysis
+            .arc_length_samples(query.count)
+            .iter()
+            .map(|sample| CurveSampleDTO {
+                parameter: sample.parameter,
+                transformer: LittleTransformerDTO {
+                    position: (sample.position.x, sample.position.y, sample.position.z),
+                },
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,39 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Query;
+use crate::cqrs::points::point_details::LittleTransformerDTO;
+
+pub struct CurveCurvature {
+    pub id: u64,
+    pub parameter: f64,
+}
+
+#[derive(Clone)]
+pub struct CurveCurvatureDTO {
+    pub transformer: LittleTransformerDTO,
+    pub tangent: (f64, f64, f64),
+    pub normal: (f64, f64, f64),
+    pub curvature: f64,
+    pub torsion: f64,
+}
+
+impl Query<CurveCurvature, Option<CurveCurvatureDTO>> for CurveCurvature {
+    fn get(query: &CurveCurvature, app_state: Rc<RefCell<Backend>>) -> Option<CurveCurvatureDTO> {
+        let app_state = app_state.borrow();
+        let frame = app_state
+            .storage
+            .curve_analysis(query.id)?
+            .frame(query.parameter)?;
+        Some(CurveCurvatureDTO {
+            transformer: LittleTransformerDTO {
+                position: (frame.position.x, frame.position.y, frame.position.z),
+            },
+            tangent: (frame.tangent.x, frame.tangent.y, frame.tangent.z),
+            normal: (frame.normal.x, frame.normal.y, frame.normal.z),
+            curvature: frame.curvature,
+            torsion: frame.torsion,
+        })
+    }
+}
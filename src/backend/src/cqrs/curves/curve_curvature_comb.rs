@@ -0,0 +1,42 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Query;
+use crate::cqrs::points::point_details::LittleTransformerDTO;
+
+pub struct CurveCurvatureComb {
+    pub id: u64,
+    pub samples_per_segment: usize,
+    pub scale: f64,
+}
+
+#[derive(Clone)]
+pub struct CurvatureCombToothDTO {
+    pub base: LittleTransformerDTO,
+    pub tip: LittleTransformerDTO,
+}
+
+impl Query<CurveCurvatureComb, Vec<CurvatureCombToothDTO>> for CurveCurvatureComb {
+    fn get(
+        query: &CurveCurvatureComb,
+        app_state: Rc<RefCell<Backend>>,
+    ) -> Vec<CurvatureCombToothDTO> {
+        let app_state = app_state.borrow();
+        let Some(analysis) = app_state.storage.curve_analysis(query.id) else {
+            return vec![];
+        };
+        analysis
+            .curvature_comb(query.samples_per_segment, query.scale)
+            .iter()
+            .map(|(base, tip)| CurvatureCombToothDTO {
+                base: LittleTransformerDTO {
+                    position: (base.x, base.y, base.z),
+                },
+                tip: LittleTransformerDTO {
+                    position: (tip.x, tip.y, tip.z),
+                },
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,19 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Query;
+
+pub struct CurveLength {
+    pub id: u64,
+}
+
+impl Query<CurveLength, Option<f64>> for CurveLength {
+    fn get(query: &CurveLength, app_state: Rc<RefCell<Backend>>) -> Option<f64> {
+        let app_state = app_state.borrow();
+        app_state
+            .storage
+            .curve_analysis(query.id)
+            .map(|analysis| analysis.length())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod curve_arc_length_samples;
+pub mod curve_curvature;
+pub mod curve_curvature_comb;
+pub mod curve_length;
@@ -1,6 +1,9 @@
 use std::{cell::RefCell, rc::Rc};
 
-use crate::{backend::Backend, cqrs::cqrs::Query};
+use crate::{
+    backend::Backend,
+    cqrs::{common::surface_display_mode::display_mode_to_dto, cqrs::Query},
+};
 
 use super::gregory_details::{GregoryDTO, GregoryVectorDTO};
 
@@ -17,6 +20,7 @@ impl Query<AllGregories, Vec<GregoryDTO>> for AllGregories {
                 id: g.id,
                 name: g.name.clone(),
                 tess_level: g.tess_level,
+                display_mode: display_mode_to_dto(g.display_mode),
                 points: g
                     .patches
                     .iter()
@@ -37,6 +41,10 @@ impl Query<AllGregories, Vec<GregoryDTO>> for AllGregories {
                     .map(|v| GregoryVectorDTO { points: v.points })
                     .collect(),
                 draw_vectors: g.draw_vectors,
+                tangent_scale: g.shape.tangent_scale,
+                center_offset: g.shape.center_offset,
+                twist_blend: g.shape.twist_blend,
+                coons: g.coons,
             })
             .collect()
     }
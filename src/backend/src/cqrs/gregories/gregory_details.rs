@@ -1,8 +1,12 @@
 use std::{cell::RefCell, rc::Rc};
 
+use backend_events::common::surface_display_mode::SurfaceDisplayModeDTO;
 use math::vector3::Vector3;
 
-use crate::{backend::Backend, cqrs::cqrs::Query};
+use crate::{
+    backend::Backend,
+    cqrs::{common::surface_display_mode::display_mode_to_dto, cqrs::Query},
+};
 
 pub struct GregoryDetails {
     pub gregory_id: u64,
@@ -12,9 +16,14 @@ pub struct GregoryDTO {
     pub id: u64,
     pub name: String,
     pub tess_level: u8,
+    pub display_mode: SurfaceDisplayModeDTO,
     pub points: Vec<Vector3>,
     pub vectors: Vec<GregoryVectorDTO>,
     pub draw_vectors: bool,
+    pub tangent_scale: f32,
+    pub center_offset: Vector3,
+    pub twist_blend: f32,
+    pub coons: bool,
 }
 
 pub struct GregoryVectorDTO {
@@ -29,6 +38,7 @@ impl Query<GregoryDetails, GregoryDTO> for GregoryDetails {
             id: gregory.id,
             name: gregory.name.clone(),
             tess_level: gregory.tess_level,
+            display_mode: display_mode_to_dto(gregory.display_mode),
             points: gregory
                 .patches
                 .iter()
@@ -49,6 +59,10 @@ impl Query<GregoryDetails, GregoryDTO> for GregoryDetails {
                 .map(|v| GregoryVectorDTO { points: v.points })
                 .collect(),
             draw_vectors: gregory.draw_vectors,
+            tangent_scale: gregory.shape.tangent_scale,
+            center_offset: gregory.shape.center_offset,
+            twist_blend: gregory.shape.twist_blend,
+            coons: gregory.coons,
         }
     }
 }
@@ -3,3 +3,4 @@ pub mod calculate_gregories;
 pub mod gregory_details;
 pub mod rename_gregory;
 pub mod update_gregory_settings;
+pub mod update_gregory_shape;
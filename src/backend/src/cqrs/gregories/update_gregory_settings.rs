@@ -1,7 +1,10 @@
 use std::{cell::RefCell, rc::Rc};
 
+use backend_events::common::surface_display_mode::SurfaceDisplayModeDTO;
+
 use crate::{
-    backend::Backend, cqrs::cqrs::Command,
+    backend::Backend,
+    cqrs::{common::surface_display_mode::display_mode_from_dto, cqrs::Command},
     domain::events::gregories::gregory_settings_updated::GregorySettingsUpdated,
 };
 
@@ -9,6 +12,7 @@ pub struct UpdateGregorySettings {
     pub id: u64,
     pub tess_level: u8,
     pub draw_vectors: bool,
+    pub display_mode: SurfaceDisplayModeDTO,
 }
 
 impl Command<UpdateGregorySettings> for UpdateGregorySettings {
@@ -16,10 +20,12 @@ impl Command<UpdateGregorySettings> for UpdateGregorySettings {
         let mut backend = app_state.borrow_mut();
         let gregory = backend.storage.gregories.get_mut(&command.id).unwrap();
         gregory.update_settings(command.tess_level, command.draw_vectors);
+        gregory.set_display_mode(display_mode_from_dto(command.display_mode));
         let gregory_settings_updated = Rc::new(GregorySettingsUpdated::new(
             gregory.id,
             gregory.tess_level,
             gregory.draw_vectors,
+            gregory.display_mode,
         ));
         drop(backend);
         let backend = app_state.borrow();
@@ -0,0 +1,42 @@
+use std::{cell::RefCell, ops::DerefMut, rc::Rc};
+
+use math::vector3::Vector3;
+
+use crate::{
+    backend::Backend,
+    cqrs::cqrs::Command,
+    domain::{
+        events::gregories::gregory_mesh_recalculated::GregoryMeshRecalculated,
+        gregory::GregoryShape,
+    },
+};
+
+pub struct UpdateGregoryShape {
+    pub id: u64,
+    pub tangent_scale: f32,
+    pub center_offset: Vector3,
+    pub twist_blend: f32,
+}
+
+impl Command<UpdateGregoryShape> for UpdateGregoryShape {
+    fn execute(command: &UpdateGregoryShape, app_state: Rc<RefCell<Backend>>) {
+        let mut binding = app_state.borrow_mut();
+        let storage = &mut binding.deref_mut().storage;
+        let gregory = storage.gregories.get_mut(&command.id).unwrap();
+        gregory.update_shape(
+            GregoryShape::new(
+                command.tangent_scale,
+                command.center_offset,
+                command.twist_blend,
+            ),
+            &storage.points,
+        );
+        let gregory_mesh_recalculated = Rc::new(GregoryMeshRecalculated::new(gregory.id));
+        drop(binding);
+        let backend = app_state.borrow();
+        backend
+            .services
+            .event_publisher
+            .publish(gregory_mesh_recalculated);
+    }
+}
@@ -26,6 +26,7 @@ pub enum IntersectionObjectIdDTO {
     Torus(u64),
     SurfaceC0(u64),
     SurfaceC2(u64),
+    NurbsSurface(u64),
 }
 
 impl Command<FindIntersection> for FindIntersection {
@@ -105,5 +106,10 @@ fn get_intersection_object(
             let points = backend.storage.points.values().cloned().collect::<Vec<_>>();
             surface.get_intersection_object(&points)
         }
+        IntersectionObjectIdDTO::NurbsSurface(id) => {
+            let surface = backend.storage.nurbs_surfaces.get(id).unwrap();
+            let points = backend.storage.points.values().cloned().collect::<Vec<_>>();
+            surface.get_intersection_object(&points)
+        }
     }
 }
@@ -98,5 +98,10 @@ fn get_intersection_object(
             let points = backend.storage.points.values().cloned().collect::<Vec<_>>();
             surface.get_intersection_object(&points)
         }
+        IntersectionObjectIdDTO::NurbsSurface(id) => {
+            let surface = backend.storage.nurbs_surfaces.get(id).unwrap();
+            let points = backend.storage.points.values().cloned().collect::<Vec<_>>();
+            surface.get_intersection_object(&points)
+        }
     }
 }
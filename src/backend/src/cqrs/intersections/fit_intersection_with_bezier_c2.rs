@@ -0,0 +1,89 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    backend::Backend,
+    cqrs::cqrs::Operation,
+    domain::{
+        bezier_c2::BezierC2,
+        curve_fitting::fit_b_spline,
+        events::{
+            beziers_c2::bezier_c2_created::BezierC2Created, points::point_created::PointCreated,
+        },
+        point::Point,
+        transformer::LittleTransformer,
+    },
+};
+
+pub struct FitIntersectionWithBezierC2 {
+    pub id: u64,
+    pub tolerance: f64,
+}
+
+pub struct IntersectionFitDTO {
+    pub bezier_id: u64,
+    pub control_points_count: usize,
+    pub max_deviation: f64,
+}
+
+impl Operation<FitIntersectionWithBezierC2, Option<IntersectionFitDTO>>
+    for FitIntersectionWithBezierC2
+{
+    fn handle(
+        operation: &FitIntersectionWithBezierC2,
+        app_state: Rc<RefCell<Backend>>,
+    ) -> Option<IntersectionFitDTO> {
+        let mut backend = app_state.borrow_mut();
+        let intersection = &backend.storage.intersections[&operation.id];
+        let wrap = intersection.wrap;
+        let samples = intersection
+            .intersection_points
+            .iter()
+            .map(|p| nalgebra::Vector3::new(p.x as f64, p.y as f64, p.z as f64))
+            .collect::<Vec<_>>();
+
+        let fit = fit_b_spline(&samples, wrap, operation.tolerance)?;
+
+        let id_generator = &mut backend.services.id_generator;
+        let points = fit
+            .control_points
+            .iter()
+            .map(|p| {
+                Point::new(
+                    id_generator.next(),
+                    LittleTransformer {
+                        position: (p.x, p.y, p.z),
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut bezier_c2 = BezierC2::new(id_generator.next(), points.clone());
+        let point_created_events = points
+            .iter()
+            .map(|p| PointCreated::new(p.id, p.name.clone()))
+            .collect::<Vec<_>>();
+        let bezier_c2_created_event = BezierC2Created::new(bezier_c2.id);
+        let result = IntersectionFitDTO {
+            bezier_id: bezier_c2.id,
+            control_points_count: points.len(),
+            max_deviation: fit.max_deviation,
+        };
+        backend.storage.selected_objects.clear();
+        backend
+            .storage
+            .points
+            .extend(points.iter().map(|p| (p.id, p.clone())));
+        bezier_c2.set_closed(wrap, &backend.storage.points);
+        backend.storage.beziers_c2.insert(bezier_c2.id, bezier_c2);
+        drop(backend);
+        let backend = app_state.borrow();
+        for event in point_created_events {
+            backend.services.event_publisher.publish(Rc::new(event));
+        }
+        backend
+            .services
+            .event_publisher
+            .publish(Rc::new(bezier_c2_created_event));
+
+        Some(result)
+    }
+}
@@ -69,5 +69,6 @@ fn map_id(id: &IntersectionObjectIdDTO) -> IntersectionObjectId {
         IntersectionObjectIdDTO::Torus(id) => IntersectionObjectId::Torus(*id),
         IntersectionObjectIdDTO::SurfaceC0(id) => IntersectionObjectId::SurfaceC0(*id),
         IntersectionObjectIdDTO::SurfaceC2(id) => IntersectionObjectId::SurfaceC2(*id),
+        IntersectionObjectIdDTO::NurbsSurface(id) => IntersectionObjectId::NurbsSurface(*id),
     }
 }
@@ -1,6 +1,7 @@
 pub mod all_intersections;
 pub mod find_intersection;
 pub mod find_self_intersection;
+pub mod fit_intersection_with_bezier_c2;
 pub mod intersection_object_texture;
 pub mod set_intersection_textures_draw;
 pub mod transform_intersection_into_bezier_int;
@@ -4,7 +4,7 @@ use crate::{
     backend::Backend,
     cqrs::cqrs::Command,
     domain::{
-        bezier_int::BezierInt,
+        bezier_int::{BezierInt, BezierIntParameterization},
         events::{
             beziers_int::bezier_int_created::BezierIntCreated,
             intersections::intersection_deleted::IntersectionDeleted,
@@ -13,7 +13,6 @@ use crate::{
         point::Point,
         transformer::LittleTransformer,
     },
-    extensions::iterator_extensions::IteratorExtensions,
 };
 
 pub struct TransformIntersectionIntoBezierInt {
@@ -39,14 +38,7 @@ impl Command<TransformIntersectionIntoBezierInt> for TransformIntersectionIntoBe
                 )
             })
             .collect::<Vec<_>>();
-        let bezier_int = BezierInt::new(
-            id_generator.next(),
-            points
-                .iter()
-                .cloned()
-                .chain_if([points[0].clone()].into_iter(), wrap)
-                .collect(),
-        );
+        let mut bezier_int = BezierInt::new(id_generator.next(), points.clone());
         let point_created_events = points
             .iter()
             .map(|p| PointCreated::new(p.id, p.name.clone()))
@@ -57,6 +49,12 @@ impl Command<TransformIntersectionIntoBezierInt> for TransformIntersectionIntoBe
             .storage
             .points
             .extend(points.iter().map(|p| (p.id, p.clone())));
+        bezier_int.set_options(
+            wrap,
+            false,
+            BezierIntParameterization::ChordLength,
+            &backend.storage.points,
+        );
         backend
             .storage
             .beziers_int
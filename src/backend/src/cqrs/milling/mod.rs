@@ -0,0 +1 @@
+pub mod simulate_milling;
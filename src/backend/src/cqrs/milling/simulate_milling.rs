@@ -0,0 +1,144 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use math::vector3::Vector3;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Query;
+use crate::domain::cutter::{Cutter, CutterType};
+use crate::domain::milling_simulator::{MaterialBlock, MillingErrorType, MillingSimulator};
+
+pub struct SimulateMilling {
+    pub path: Vec<(f64, f64, f64)>,
+    pub cutter: CutterDTO,
+    pub block: MaterialBlockDTO,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CutterTypeDTO {
+    Flat,
+    Spherical,
+}
+
+#[derive(Debug, Clone)]
+pub struct CutterDTO {
+    pub cutter_type: CutterTypeDTO,
+    pub radius: f64,
+    pub cutting_length: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MaterialBlockDTO {
+    pub size: (f64, f64),
+    pub height: f64,
+    pub min_height: f64,
+    pub resolution: (usize, usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MillingErrorTypeDTO {
+    TooDeep,
+    FlatCutterVerticalDescent,
+    ShankCollision,
+}
+
+#[derive(Debug, Clone)]
+pub struct MillingErrorDTO {
+    pub move_index: usize,
+    pub error_type: MillingErrorTypeDTO,
+    pub position: (f64, f64, f64),
+}
+
+#[derive(Debug, Clone)]
+pub struct MillingMoveDTO {
+    pub move_index: usize,
+    pub length: f64,
+    pub removed_volume: f64,
+    pub cut_cells: usize,
+    pub lowest_point: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct HeightMapDTO {
+    pub size: (f64, f64),
+    pub resolution: (usize, usize),
+    pub heights: Vec<f32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MillingSimulationDTO {
+    pub moves: Vec<MillingMoveDTO>,
+    pub errors: Vec<MillingErrorDTO>,
+    pub height_map: HeightMapDTO,
+}
+
+impl Query<SimulateMilling, Option<MillingSimulationDTO>> for SimulateMilling {
+    fn get(
+        query: &SimulateMilling,
+        _app_state: Rc<RefCell<Backend>>,
+    ) -> Option<MillingSimulationDTO> {
+        let cutter = Cutter::new(
+            match query.cutter.cutter_type {
+                CutterTypeDTO::Flat => CutterType::Flat,
+                CutterTypeDTO::Spherical => CutterType::Spherical,
+            },
+            query.cutter.radius as f32,
+            query.cutter.cutting_length as f32,
+        );
+        let block = MaterialBlock {
+            size: (query.block.size.0 as f32, query.block.size.1 as f32),
+            height: query.block.height as f32,
+            min_height: query.block.min_height as f32,
+            resolution: query.block.resolution,
+        };
+        let path = query
+            .path
+            .iter()
+            .map(|p| Vector3::new(p.0 as f32, p.1 as f32, p.2 as f32))
+            .collect::<Vec<_>>();
+
+        let mut simulator = MillingSimulator::new(block, cutter)?;
+        let report = simulator.simulate(&path);
+
+        Some(MillingSimulationDTO {
+            moves: report
+                .moves
+                .iter()
+                .map(|m| MillingMoveDTO {
+                    move_index: m.move_index,
+                    length: m.length as f64,
+                    removed_volume: m.removed_volume as f64,
+                    cut_cells: m.cut_cells,
+                    lowest_point: m.lowest_point as f64,
+                })
+                .collect(),
+            errors: report
+                .errors
+                .iter()
+                .map(|e| MillingErrorDTO {
+                    move_index: e.move_index,
+                    error_type: match e.error_type {
+                        MillingErrorType::TooDeep => MillingErrorTypeDTO::TooDeep,
+                        MillingErrorType::FlatCutterVerticalDescent => {
+                            MillingErrorTypeDTO::FlatCutterVerticalDescent
+                        }
+                        MillingErrorType::ShankCollision => MillingErrorTypeDTO::ShankCollision,
+                    },
+                    position: (
+                        e.position.x as f64,
+                        e.position.y as f64,
+                        e.position.z as f64,
+                    ),
+                })
+                .collect(),
+            height_map: HeightMapDTO {
+                size: (
+                    simulator.height_map.size.0 as f64,
+                    simulator.height_map.size.1 as f64,
+                ),
+                resolution: simulator.height_map.resolution,
+                heights: simulator.height_map.heights.clone(),
+            },
+        })
+    }
+}
@@ -4,9 +4,14 @@ pub mod beziers_int;
 pub mod common;
 pub mod cqrs;
 pub mod cursors;
+pub mod curves;
 pub mod gregories;
 pub mod intersections;
+pub mod milling;
+pub mod nurbs_curves;
+pub mod nurbs_surfaces;
 pub mod points;
 pub mod surfaces_c0;
 pub mod surfaces_c2;
+pub mod toolpaths;
 pub mod toruses;
@@ -0,0 +1,33 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::nurbs_curves::nurbs_curve_created::NurbsCurveCreated;
+use crate::domain::nurbs_curve::{NurbsCurve, NurbsCurvePoint};
+
+pub struct AddNurbsCurve {
+    pub id: u64,
+}
+
+impl Command<AddNurbsCurve> for AddNurbsCurve {
+    fn execute(command: &AddNurbsCurve, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let points = backend
+            .storage
+            .selected_objects
+            .iter()
+            .filter_map(|object| object.point_id)
+            .filter(|id| backend.storage.points.contains_key(id))
+            .map(|id| NurbsCurvePoint { id, weight: 1.0 })
+            .collect();
+        let curve = NurbsCurve::new(command.id, points, &backend.storage.points);
+        backend.storage.nurbs_curves.insert(command.id, curve);
+        drop(backend);
+        let backend = app_state.borrow();
+        backend
+            .services
+            .event_publisher
+            .publish(Rc::new(NurbsCurveCreated::new(command.id)));
+    }
+}
@@ -0,0 +1,34 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::points::point_added_to_nurbs_curve::PointAddedToNurbsCurve;
+
+pub struct AddPointToNurbsCurve {
+    pub id: u64,
+    pub point_id: u64,
+}
+
+impl Command<AddPointToNurbsCurve> for AddPointToNurbsCurve {
+    fn execute(command: &AddPointToNurbsCurve, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let storage = &mut backend.storage;
+        let point_name = storage.points.get(&command.point_id).unwrap().name.clone();
+        let curve = storage.nurbs_curves.get_mut(&command.id).unwrap();
+        curve.add_point(command.point_id, &storage.points);
+        let index = curve.points.len() - 1;
+        drop(backend);
+        let backend = app_state.borrow();
+        let point_added_to_curve = Rc::new(PointAddedToNurbsCurve::new(
+            command.point_id,
+            command.id,
+            point_name,
+            index,
+        ));
+        backend
+            .services
+            .event_publisher
+            .publish(point_added_to_curve);
+    }
+}
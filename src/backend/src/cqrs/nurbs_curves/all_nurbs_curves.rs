@@ -0,0 +1,36 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Query;
+use crate::cqrs::nurbs_curves::nurbs_curve_details::{NurbsCurveDTO, NurbsCurvePointDTO};
+
+pub struct AllNurbsCurves;
+
+impl Query<AllNurbsCurves, Vec<NurbsCurveDTO>> for AllNurbsCurves {
+    fn get(_: &AllNurbsCurves, app_state: Rc<RefCell<Backend>>) -> Vec<NurbsCurveDTO> {
+        let app_state = app_state.borrow();
+        app_state
+            .storage
+            .nurbs_curves
+            .values()
+            .map(|curve| NurbsCurveDTO {
+                id: curve.id,
+                name: curve.name.clone(),
+                points: curve
+                    .points
+                    .iter()
+                    .map(|cp| {
+                        let p = app_state.storage.points.get(&cp.id).unwrap();
+                        NurbsCurvePointDTO {
+                            id: p.id,
+                            name: p.name.clone(),
+                            weight: cp.weight,
+                        }
+                    })
+                    .collect(),
+                draw_polygon: curve.draw_polygon,
+            })
+            .collect()
+    }
+}
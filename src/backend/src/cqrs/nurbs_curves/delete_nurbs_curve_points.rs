@@ -0,0 +1,27 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::nurbs_curves::nurbs_curve_points_deleted::NurbsCurvePointsDeleted;
+
+pub struct DeleteNurbsCurvePoints {
+    pub id: u64,
+    pub points: Vec<u64>,
+}
+
+impl Command<DeleteNurbsCurvePoints> for DeleteNurbsCurvePoints {
+    fn execute(command: &DeleteNurbsCurvePoints, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let storage = &mut backend.storage;
+        let curve = storage.nurbs_curves.get_mut(&command.id).unwrap();
+        curve.delete_points(&command.points, &storage.points);
+        drop(backend);
+        let backend = app_state.borrow();
+        let points_deleted = Rc::new(NurbsCurvePointsDeleted::new(
+            command.id,
+            command.points.clone(),
+        ));
+        backend.services.event_publisher.publish(points_deleted);
+    }
+}
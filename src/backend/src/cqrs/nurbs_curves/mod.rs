@@ -0,0 +1,11 @@
+pub mod add_nurbs_curve;
+pub mod add_point_to_nurbs_curve;
+pub mod all_nurbs_curves;
+pub mod delete_nurbs_curve_points;
+pub mod nurbs_curve_bernstein_points;
+pub mod nurbs_curve_control_points;
+pub mod nurbs_curve_details;
+pub mod nurbs_curve_point_at;
+pub mod rename_nurbs_curve;
+pub mod set_nurbs_curve_draw_polygon;
+pub mod set_nurbs_curve_point_weight;
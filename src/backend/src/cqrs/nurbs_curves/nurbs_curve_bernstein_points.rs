@@ -0,0 +1,37 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Query;
+use crate::cqrs::points::point_details::LittleTransformerDTO;
+
+pub struct NurbsCurveBernsteinPoints {
+    pub id: u64,
+}
+
+pub struct NurbsCurveBernsteinPointDTO {
+    pub transformer: LittleTransformerDTO,
+    pub weight: f64,
+}
+
+impl Query<NurbsCurveBernsteinPoints, Vec<NurbsCurveBernsteinPointDTO>>
+    for NurbsCurveBernsteinPoints
+{
+    fn get(
+        query: &NurbsCurveBernsteinPoints,
+        app_state: Rc<RefCell<Backend>>,
+    ) -> Vec<NurbsCurveBernsteinPointDTO> {
+        let app_state = app_state.borrow();
+        let curve = app_state.storage.nurbs_curves.get(&query.id).unwrap();
+        curve
+            .bernstein_points
+            .iter()
+            .map(|point| NurbsCurveBernsteinPointDTO {
+                transformer: LittleTransformerDTO {
+                    position: point.transformer.position,
+                },
+                weight: point.weight,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,31 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Query;
+use crate::cqrs::points::point_details::{LittleTransformerDTO, PointDTO};
+
+pub struct NurbsCurveControlPoints {
+    pub id: u64,
+}
+
+impl Query<NurbsCurveControlPoints, Vec<PointDTO>> for NurbsCurveControlPoints {
+    fn get(query: &NurbsCurveControlPoints, app_state: Rc<RefCell<Backend>>) -> Vec<PointDTO> {
+        let app_state = app_state.borrow();
+        let curve = app_state.storage.nurbs_curves.get(&query.id).unwrap();
+        curve
+            .points
+            .iter()
+            .map(|point| {
+                let p = app_state.storage.points.get(&point.id).unwrap();
+                PointDTO {
+                    id: p.id,
+                    name: p.name.clone(),
+                    transformer: LittleTransformerDTO {
+                        position: p.transformer.position,
+                    },
+                }
+            })
+            .collect()
+    }
+}
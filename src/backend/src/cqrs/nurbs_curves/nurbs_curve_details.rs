@@ -0,0 +1,46 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Query;
+
+pub struct NurbsCurveDetails {
+    pub id: u64,
+}
+
+pub struct NurbsCurveDTO {
+    pub id: u64,
+    pub name: String,
+    pub points: Vec<NurbsCurvePointDTO>,
+    pub draw_polygon: bool,
+}
+
+pub struct NurbsCurvePointDTO {
+    pub id: u64,
+    pub name: String,
+    pub weight: f64,
+}
+
+impl Query<NurbsCurveDetails, NurbsCurveDTO> for NurbsCurveDetails {
+    fn get(query: &NurbsCurveDetails, app_state: Rc<RefCell<Backend>>) -> NurbsCurveDTO {
+        let app_state = app_state.borrow();
+        let curve = app_state.storage.nurbs_curves.get(&query.id).unwrap();
+        NurbsCurveDTO {
+            id: curve.id,
+            name: curve.name.clone(),
+            points: curve
+                .points
+                .iter()
+                .map(|cp| {
+                    let p = app_state.storage.points.get(&cp.id).unwrap();
+                    NurbsCurvePointDTO {
+                        id: p.id,
+                        name: p.name.clone(),
+                        weight: cp.weight,
+                    }
+                })
+                .collect(),
+            draw_polygon: curve.draw_polygon,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Query;
+use crate::cqrs::points::point_details::LittleTransformerDTO;
+
+pub struct NurbsCurvePointAt {
+    pub id: u64,
+    pub parameter: f64,
+}
+
+impl Query<NurbsCurvePointAt, Option<LittleTransformerDTO>> for NurbsCurvePointAt {
+    fn get(
+        query: &NurbsCurvePointAt,
+        app_state: Rc<RefCell<Backend>>,
+    ) -> Option<LittleTransformerDTO> {
+        let app_state = app_state.borrow();
+        let curve = app_state.storage.nurbs_curves.get(&query.id).unwrap();
+        curve
+            .evaluate(query.parameter, &app_state.storage.points)
+            .map(|p| LittleTransformerDTO {
+                position: (p.x, p.y, p.z),
+            })
+    }
+}
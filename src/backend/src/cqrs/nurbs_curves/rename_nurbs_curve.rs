@@ -0,0 +1,23 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::nurbs_curves::nurbs_curve_renamed::NurbsCurveRenamed;
+
+pub struct RenameNurbsCurve {
+    pub id: u64,
+    pub name: String,
+}
+
+impl Command<RenameNurbsCurve> for RenameNurbsCurve {
+    fn execute(command: &RenameNurbsCurve, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let curve = backend.storage.nurbs_curves.get_mut(&command.id).unwrap();
+        curve.rename(&command.name);
+        let curve_renamed = Rc::new(NurbsCurveRenamed::new(curve));
+        drop(backend);
+        let backend = app_state.borrow();
+        backend.services.event_publisher.publish(curve_renamed);
+    }
+}
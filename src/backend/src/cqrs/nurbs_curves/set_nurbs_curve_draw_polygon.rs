@@ -0,0 +1,23 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::nurbs_curves::nurbs_curve_draw_polygon_set::NurbsCurveDrawPolygonSet;
+
+pub struct SetNurbsCurveDrawPolygon {
+    pub id: u64,
+    pub draw_polygon: bool,
+}
+
+impl Command<SetNurbsCurveDrawPolygon> for SetNurbsCurveDrawPolygon {
+    fn execute(command: &SetNurbsCurveDrawPolygon, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let curve = backend.storage.nurbs_curves.get_mut(&command.id).unwrap();
+        curve.set_draw_polygon(command.draw_polygon);
+        let event = Rc::new(NurbsCurveDrawPolygonSet::new(curve.id, curve.draw_polygon));
+        drop(backend);
+        let backend = app_state.borrow();
+        backend.services.event_publisher.publish(event);
+    }
+}
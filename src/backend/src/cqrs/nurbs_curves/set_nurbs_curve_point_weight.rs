@@ -0,0 +1,34 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::nurbs_curves::nurbs_curve_weight_set::NurbsCurveWeightSet;
+
+pub struct SetNurbsCurvePointWeight {
+    pub id: u64,
+    pub point_id: u64,
+    pub weight: f64,
+}
+
+impl Command<SetNurbsCurvePointWeight> for SetNurbsCurvePointWeight {
+    fn execute(command: &SetNurbsCurvePointWeight, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let storage = &mut backend.storage;
+        let curve = storage.nurbs_curves.get_mut(&command.id).unwrap();
+        curve.set_weight(command.point_id, command.weight, &storage.points);
+        let weight = curve
+            .points
+            .iter()
+            .find(|p| p.id == command.point_id)
+            .map_or(command.weight, |p| p.weight);
+        let event = Rc::new(NurbsCurveWeightSet::new(
+            command.id,
+            command.point_id,
+            weight,
+        ));
+        drop(backend);
+        let backend = app_state.borrow();
+        backend.services.event_publisher.publish(event);
+    }
+}
@@ -0,0 +1,20 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Query;
+use crate::cqrs::nurbs_surfaces::nurbs_surface_details::NurbsSurfaceDTO;
+
+pub struct AllNurbsSurfaces;
+
+impl Query<AllNurbsSurfaces, Vec<NurbsSurfaceDTO>> for AllNurbsSurfaces {
+    fn get(_: &AllNurbsSurfaces, app_state: Rc<RefCell<Backend>>) -> Vec<NurbsSurfaceDTO> {
+        let app_state = app_state.borrow();
+        app_state
+            .storage
+            .nurbs_surfaces
+            .values()
+            .map(|surface| NurbsSurfaceDTO::from_surface(surface, &app_state.storage))
+            .collect()
+    }
+}
@@ -0,0 +1,50 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::cqrs::surfaces_c0::create_surface_c0::CreateSurfaceInfoDTO;
+use crate::domain::events::nurbs_surfaces::nurbs_surface_created::NurbsSurfaceCreated;
+use crate::domain::events::points::point_created::PointCreated;
+use crate::services::create_surface::create_nurbs_surface;
+
+pub struct CreateNurbsSurface {
+    pub id: u64,
+    pub create_surface_info: CreateSurfaceInfoDTO,
+}
+
+impl Command<CreateNurbsSurface> for CreateNurbsSurface {
+    fn execute(command: &CreateNurbsSurface, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let cursor_position = backend.storage.cursor.transformer.clone();
+        let id_generator = &mut backend.services.id_generator;
+        let (surface, points) = create_nurbs_surface(
+            command.id,
+            &command.create_surface_info,
+            id_generator,
+            &cursor_position,
+        );
+        let (size, is_cylinder) = (surface.size, surface.is_cylinder);
+        backend.storage.nurbs_surfaces.insert(command.id, surface);
+        let events = points
+            .iter()
+            .map(|point| Rc::new(PointCreated::new(point.id, point.name.clone())))
+            .collect::<Vec<_>>();
+        for point in points {
+            backend.storage.points.insert(point.id, point);
+        }
+        drop(backend);
+        let backend = app_state.borrow();
+        for event in events {
+            backend.services.event_publisher.publish(event);
+        }
+        backend
+            .services
+            .event_publisher
+            .publish(Rc::new(NurbsSurfaceCreated::new(
+                command.id,
+                size,
+                is_cylinder,
+            )));
+    }
+}
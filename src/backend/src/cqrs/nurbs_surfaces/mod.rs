@@ -0,0 +1,8 @@
+pub mod all_nurbs_surfaces;
+pub mod create_nurbs_surface;
+pub mod nurbs_surface_details;
+pub mod nurbs_surface_points;
+pub mod rename_nurbs_surface;
+pub mod select_nurbs_surface_points;
+pub mod set_nurbs_surface_point_weight;
+pub mod update_nurbs_surface;
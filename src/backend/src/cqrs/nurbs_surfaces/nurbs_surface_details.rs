@@ -0,0 +1,62 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use itertools::Itertools;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Query;
+use crate::data_access::storage::Storage;
+use crate::domain::nurbs_surface::NurbsSurface;
+
+pub struct NurbsSurfaceDetails {
+    pub id: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct NurbsSurfaceDTO {
+    pub id: u64,
+    pub name: String,
+    pub draw_polygon: bool,
+    pub tess_level: u8,
+    pub size: (u32, u32),
+    pub is_cylinder: bool,
+    pub points: Vec<NurbsSurfacePointDTO>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NurbsSurfacePointDTO {
+    pub id: u64,
+    pub name: String,
+    pub weight: f64,
+}
+
+impl Query<NurbsSurfaceDetails, NurbsSurfaceDTO> for NurbsSurfaceDetails {
+    fn get(query: &NurbsSurfaceDetails, app_state: Rc<RefCell<Backend>>) -> NurbsSurfaceDTO {
+        let app_state = app_state.borrow();
+        let surface = app_state.storage.nurbs_surfaces.get(&query.id).unwrap();
+        NurbsSurfaceDTO::from_surface(surface, &app_state.storage)
+    }
+}
+
+impl NurbsSurfaceDTO {
+    pub(crate) fn from_surface(surface: &NurbsSurface, storage: &Storage) -> Self {
+        Self {
+            id: surface.id,
+            name: surface.name.clone(),
+            draw_polygon: surface.draw_polygon,
+            tess_level: surface.tess_level,
+            size: surface.size,
+            is_cylinder: surface.is_cylinder,
+            points: surface
+                .points
+                .iter()
+                .unique_by(|p| p.id)
+                .map(|sp| NurbsSurfacePointDTO {
+                    id: sp.id,
+                    name: storage.points[&sp.id].name.clone(),
+                    weight: sp.weight,
+                })
+                .collect(),
+        }
+    }
+}
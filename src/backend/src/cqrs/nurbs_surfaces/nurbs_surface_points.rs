@@ -0,0 +1,40 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Query;
+use crate::cqrs::points::point_details::LittleTransformerDTO;
+
+pub struct NurbsSurfacePoints {
+    pub id: u64,
+}
+
+pub struct NurbsSurfaceControlPointDTO {
+    pub id: u64,
+    pub transformer: LittleTransformerDTO,
+    pub weight: f64,
+}
+
+impl Query<NurbsSurfacePoints, Vec<NurbsSurfaceControlPointDTO>> for NurbsSurfacePoints {
+    fn get(
+        query: &NurbsSurfacePoints,
+        app_state: Rc<RefCell<Backend>>,
+    ) -> Vec<NurbsSurfaceControlPointDTO> {
+        let app_state = app_state.borrow();
+        let surface = app_state.storage.nurbs_surfaces.get(&query.id).unwrap();
+        surface
+            .points
+            .iter()
+            .map(|sp| {
+                let p = app_state.storage.points.get(&sp.id).unwrap();
+                NurbsSurfaceControlPointDTO {
+                    id: p.id,
+                    transformer: LittleTransformerDTO {
+                        position: p.transformer.position,
+                    },
+                    weight: sp.weight,
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,18 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+
+pub struct RenameNurbsSurface {
+    pub id: u64,
+    pub name: String,
+}
+
+impl Command<RenameNurbsSurface> for RenameNurbsSurface {
+    fn execute(command: &RenameNurbsSurface, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let surface = backend.storage.nurbs_surfaces.get_mut(&command.id).unwrap();
+        surface.rename(&command.name);
+    }
+}
@@ -0,0 +1,35 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use itertools::Itertools;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::nurbs_surfaces::nurbs_surface_points_selected::NurbsSurfacePointsSelected;
+use crate::domain::selected_object::SelectedObject;
+
+pub struct SelectNurbsSurfacePoints {
+    pub surface_id: u64,
+}
+
+impl Command<SelectNurbsSurfacePoints> for SelectNurbsSurfacePoints {
+    fn execute(command: &SelectNurbsSurfacePoints, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let surface = backend
+            .storage
+            .nurbs_surfaces
+            .get(&command.surface_id)
+            .unwrap();
+        let points = surface.points.clone();
+        let event = Rc::new(NurbsSurfacePointsSelected::new(surface.id));
+        backend.storage.selected_objects.extend(
+            points
+                .iter()
+                .unique_by(|p| p.id)
+                .map(|p| SelectedObject::new_point(p.id)),
+        );
+        drop(backend);
+        let backend = app_state.borrow();
+        backend.services.event_publisher.publish(event);
+    }
+}
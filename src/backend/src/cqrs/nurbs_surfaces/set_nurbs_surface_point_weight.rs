@@ -0,0 +1,33 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::nurbs_surfaces::nurbs_surface_weight_set::NurbsSurfaceWeightSet;
+
+pub struct SetNurbsSurfacePointWeight {
+    pub id: u64,
+    pub point_id: u64,
+    pub weight: f64,
+}
+
+impl Command<SetNurbsSurfacePointWeight> for SetNurbsSurfacePointWeight {
+    fn execute(command: &SetNurbsSurfacePointWeight, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let surface = backend.storage.nurbs_surfaces.get_mut(&command.id).unwrap();
+        surface.set_weight(command.point_id, command.weight);
+        let weight = surface
+            .points
+            .iter()
+            .find(|p| p.id == command.point_id)
+            .map_or(command.weight, |p| p.weight);
+        let event = Rc::new(NurbsSurfaceWeightSet::new(
+            command.id,
+            command.point_id,
+            weight,
+        ));
+        drop(backend);
+        let backend = app_state.borrow();
+        backend.services.event_publisher.publish(event);
+    }
+}
@@ -0,0 +1,31 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::nurbs_surfaces::nurbs_surface_updated::NurbsSurfaceUpdated;
+
+pub struct UpdateNurbsSurface {
+    pub id: u64,
+    pub draw_polygon: bool,
+    pub tess_level: u8,
+}
+
+impl Command<UpdateNurbsSurface> for UpdateNurbsSurface {
+    fn execute(command: &UpdateNurbsSurface, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let surface = backend.storage.nurbs_surfaces.get_mut(&command.id).unwrap();
+        surface.set_draw_polygon(command.draw_polygon);
+        surface.set_tess_level(command.tess_level);
+        drop(backend);
+        let backend = app_state.borrow();
+        backend
+            .services
+            .event_publisher
+            .publish(Rc::new(NurbsSurfaceUpdated::new(
+                command.id,
+                command.draw_polygon,
+                command.tess_level,
+            )));
+    }
+}
@@ -46,8 +46,6 @@ impl Command<MergeSelectedPoints> for MergeSelectedPoints {
 
         backend.storage.points.insert(point.id, point);
 
-        let points = backend.storage.points.clone();
-
         for p_id in backend
             .storage
             .selected_objects
@@ -56,51 +54,7 @@ impl Command<MergeSelectedPoints> for MergeSelectedPoints {
             .collect::<Vec<_>>()
         {
             backend.storage.points.remove(&p_id);
-
-            backend
-                .storage
-                .beziers_c0
-                .iter_mut()
-                .filter(|(_, b)| b.points.iter().any(|p| p.id == p_id))
-                .for_each(|(_, b)| {
-                    b.replace_point(p_id, point_id);
-                });
-
-            backend
-                .storage
-                .beziers_c2
-                .iter_mut()
-                .filter(|(_, b)| b.b_spline_points.iter().any(|p| p.id == p_id))
-                .for_each(|(_, b)| {
-                    b.replace_point(p_id, point_id, &points);
-                });
-
-            backend
-                .storage
-                .beziers_int
-                .iter_mut()
-                .filter(|(_, b)| b.points.iter().any(|p| p.id == p_id))
-                .for_each(|(_, b)| {
-                    b.replace_point(p_id, point_id, &points);
-                });
-
-            backend
-                .storage
-                .surfaces_c0
-                .iter_mut()
-                .filter(|(_, s)| s.points.iter().any(|p| p.id == p_id))
-                .for_each(|(_, s)| {
-                    s.replace_point(p_id, point_id);
-                });
-
-            backend
-                .storage
-                .surfaces_c2
-                .iter_mut()
-                .filter(|(_, s)| s.points.iter().any(|p| p.id == p_id))
-                .for_each(|(_, s)| {
-                    s.replace_point(p_id, point_id);
-                });
+            backend.storage.replace_point(p_id, point_id);
         }
 
         backend.storage.selected_objects.clear();
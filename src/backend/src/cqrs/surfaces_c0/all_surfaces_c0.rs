@@ -2,6 +2,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::backend::Backend;
+use crate::cqrs::common::surface_display_mode::display_mode_to_dto;
 use crate::cqrs::cqrs::Query;
 use crate::cqrs::surfaces_c0::surface_c0_details::SurfaceC0DTO;
 
@@ -19,8 +20,10 @@ impl Query<AllSurfacesC0, Vec<SurfaceC0DTO>> for AllSurfacesC0 {
                 name: surface_c0.name.clone(),
                 draw_polygon: surface_c0.draw_polygon,
                 tess_level: surface_c0.tess_level,
+                display_mode: display_mode_to_dto(surface_c0.display_mode),
                 size: surface_c0.size,
                 is_cylinder: surface_c0.is_cylinder,
+                is_closed_v: surface_c0.is_closed_v(),
             })
             .collect()
     }
@@ -0,0 +1,78 @@
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::surfaces_c0::surface_c0_edit_refused::SurfaceC0EditRefused;
+use crate::domain::events::surfaces_c0::surface_c0_resized::SurfaceC0Resized;
+use crate::domain::intersection::IntersectionObjectId;
+use crate::services::edit_surface::{
+    append_patches_c0, rebuild_surface_c0, surface_grid, SurfaceSide,
+};
+
+pub struct AppendSurfaceC0Patches {
+    pub id: u64,
+    pub side: SurfaceSideDTO,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SurfaceSideDTO {
+    StartX,
+    EndX,
+    StartY,
+    EndY,
+}
+
+impl SurfaceSideDTO {
+    pub fn is_x(self) -> bool {
+        matches!(self, Self::StartX | Self::EndX)
+    }
+
+    pub fn to_side(self) -> SurfaceSide {
+        match self {
+            Self::StartX => SurfaceSide::StartX,
+            Self::EndX => SurfaceSide::EndX,
+            Self::StartY => SurfaceSide::StartY,
+            Self::EndY => SurfaceSide::EndY,
+        }
+    }
+}
+
+impl Command<AppendSurfaceC0Patches> for AppendSurfaceC0Patches {
+    fn execute(command: &AppendSurfaceC0Patches, app_state: Rc<RefCell<Backend>>) {
+        let mut binding = app_state.borrow_mut();
+        let backend = binding.deref_mut();
+        let storage = &mut backend.storage;
+        let surface = &storage.surfaces_c0[&command.id];
+        let result = if surface.is_cylinder && command.side.is_x()
+            || surface.is_closed_v() && !command.side.is_x()
+        {
+            Err("Surface is closed in this direction".to_string())
+        } else if storage.is_intersected(IntersectionObjectId::SurfaceC0(command.id)) {
+            Err("Surface is intersected".to_string())
+        } else {
+            let ids = surface.points.iter().map(|p| p.id).collect::<Vec<_>>();
+            let grid = surface_grid(&ids, 3 * surface.size.1 as usize + 1, &storage.points);
+            rebuild_surface_c0(
+                storage,
+                command.id,
+                &append_patches_c0(grid, command.side.to_side()),
+                &mut backend.services.id_generator,
+            )
+        };
+
+        drop(binding);
+        let backend = app_state.borrow();
+        match result {
+            Ok(size) => backend
+                .services
+                .event_publisher
+                .publish(Rc::new(SurfaceC0Resized::new(command.id, size))),
+            Err(reason) => backend
+                .services
+                .event_publisher
+                .publish(Rc::new(SurfaceC0EditRefused::new(command.id, reason))),
+        }
+    }
+}
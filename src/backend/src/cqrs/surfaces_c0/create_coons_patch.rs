@@ -0,0 +1,104 @@
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+
+use nalgebra::Vector3;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::gregories::gregory_created::GregoryCreated;
+use crate::domain::events::points::point_created::PointCreated;
+use crate::domain::events::surfaces_c0::surface_c0_created::SurfaceC0Created;
+use crate::domain::gregory::{edge_loops, Edge, Gregory, Polygon};
+use crate::services::create_surface::create_coons_surface_c0;
+
+pub struct CreateCoonsPatch {
+    pub id: u64,
+    pub g1: bool,
+}
+
+impl Command<CreateCoonsPatch> for CreateCoonsPatch {
+    fn execute(command: &CreateCoonsPatch, app_state: Rc<RefCell<Backend>>) {
+        let mut binding = app_state.borrow_mut();
+        let backend = binding.deref_mut();
+        let storage = &mut backend.storage;
+
+        let curve_edges = storage
+            .selected_objects
+            .iter()
+            .filter_map(|o| o.bezier_c0_id)
+            .map(|id| &storage.beziers_c0[&id])
+            .filter(|bezier| bezier.points.len() == 4)
+            .map(|bezier| {
+                let edge_points = [
+                    bezier.points[0].id,
+                    bezier.points[1].id,
+                    bezier.points[2].id,
+                    bezier.points[3].id,
+                ];
+                Edge::new(edge_points, [edge_points; 4], false)
+            });
+        let surface_edges = storage
+            .selected_objects
+            .iter()
+            .filter_map(|o| o.surface_c0_id)
+            .map(|id| &storage.surfaces_c0[&id])
+            .filter(|surface| !surface.is_cylinder)
+            .flat_map(|surface| surface.border_edges());
+        let edges = curve_edges.chain(surface_edges).collect::<Vec<_>>();
+
+        let Some(edge_loop) = edge_loops(&edges, 4, &storage.points).into_iter().next() else {
+            return;
+        };
+
+        storage.selected_objects.clear();
+
+        if command.g1 {
+            let gregory = Gregory::new_coons(command.id, Polygon::new(edge_loop), &storage.points);
+            let gregory_created = Rc::new(GregoryCreated::new(
+                gregory.id,
+                gregory.name.clone(),
+                gregory.tess_level,
+                gregory.draw_vectors,
+                gregory.coons,
+            ));
+            storage.gregories.insert(gregory.id, gregory);
+
+            drop(binding);
+            let backend = app_state.borrow();
+            backend.services.event_publisher.publish(gregory_created);
+            return;
+        }
+
+        let position = |id: &u64| {
+            let position = storage.points[id].transformer.position;
+            Vector3::new(position.0, position.1, position.2)
+        };
+        let boundaries =
+            [0, 1, 2, 3].map(|i| edge_loop[i].edge_points.map(|id| (id, position(&id))));
+
+        let (surface, points) =
+            create_coons_surface_c0(command.id, &boundaries, &mut backend.services.id_generator);
+
+        let surface_created = Rc::new(SurfaceC0Created::new(
+            command.id,
+            surface.size,
+            surface.is_cylinder,
+        ));
+        storage.surfaces_c0.insert(command.id, surface);
+        let events = points
+            .iter()
+            .map(|point| Rc::new(PointCreated::new(point.id, point.name.clone())))
+            .collect::<Vec<_>>();
+        for point in points {
+            storage.points.insert(point.id, point);
+        }
+
+        drop(binding);
+        let backend = app_state.borrow();
+        for event in events {
+            backend.services.event_publisher.publish(event);
+        }
+        backend.services.event_publisher.publish(surface_created);
+    }
+}
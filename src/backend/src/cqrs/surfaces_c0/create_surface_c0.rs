@@ -13,14 +13,24 @@ pub struct CreateSurfaceC0 {
 }
 
 pub struct CreateSurfaceInfoDTO {
-    pub is_cylinder: bool,
+    pub shape: SurfaceShapeDTO,
     pub length: Option<f64>,
     pub width: Option<f64>,
     pub height: Option<f64>,
     pub radius: Option<f64>,
+    pub minor_radius: Option<f64>,
     pub size: (u32, u32),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SurfaceShapeDTO {
+    Sheet,
+    Cylinder,
+    Torus,
+    Sphere,
+    Cone,
+}
+
 impl Command<CreateSurfaceC0> for CreateSurfaceC0 {
     fn execute(command: &CreateSurfaceC0, app_state: Rc<RefCell<Backend>>) {
         let mut backend = app_state.borrow_mut();
@@ -32,6 +42,7 @@ impl Command<CreateSurfaceC0> for CreateSurfaceC0 {
             id_generator,
             &cursor_position,
         );
+        let (size, is_cylinder) = (surface.size, surface.is_cylinder);
         backend.storage.surfaces_c0.insert(command.id, surface);
         let events = points
             .iter()
@@ -50,8 +61,8 @@ impl Command<CreateSurfaceC0> for CreateSurfaceC0 {
             .event_publisher
             .publish(Rc::new(SurfaceC0Created::new(
                 command.id,
-                command.create_surface_info.size,
-                command.create_surface_info.is_cylinder,
+                size,
+                is_cylinder,
             )));
     }
 }
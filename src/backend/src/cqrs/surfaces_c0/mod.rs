@@ -1,5 +1,9 @@
 pub mod all_surfaces_c0;
+pub mod append_surface_c0_patches;
+pub mod create_coons_patch;
 pub mod create_surface_c0;
+pub mod refine_surface_c0;
+pub mod remove_surface_c0_patches;
 pub mod rename_surface_c0;
 pub mod select_surface_c0_points;
 pub mod surface_c0_details;
@@ -0,0 +1,44 @@
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::surfaces_c0::surface_c0_edit_refused::SurfaceC0EditRefused;
+use crate::domain::events::surfaces_c0::surface_c0_resized::SurfaceC0Resized;
+use crate::domain::intersection::IntersectionObjectId;
+use crate::services::edit_surface::refine_surface_c0;
+
+pub struct RefineSurfaceC0 {
+    pub id: u64,
+}
+
+impl Command<RefineSurfaceC0> for RefineSurfaceC0 {
+    fn execute(command: &RefineSurfaceC0, app_state: Rc<RefCell<Backend>>) {
+        let mut binding = app_state.borrow_mut();
+        let backend = binding.deref_mut();
+        let storage = &mut backend.storage;
+        let result = if storage.is_intersected(IntersectionObjectId::SurfaceC0(command.id)) {
+            Err("Surface is intersected".to_string())
+        } else {
+            refine_surface_c0(storage, command.id, &mut backend.services.id_generator)
+        };
+
+        drop(binding);
+        let backend = app_state.borrow();
+        match result {
+            Ok(sizes) => {
+                for (id, size) in sizes {
+                    backend
+                        .services
+                        .event_publisher
+                        .publish(Rc::new(SurfaceC0Resized::new(id, size)));
+                }
+            }
+            Err(reason) => backend
+                .services
+                .event_publisher
+                .publish(Rc::new(SurfaceC0EditRefused::new(command.id, reason))),
+        }
+    }
+}
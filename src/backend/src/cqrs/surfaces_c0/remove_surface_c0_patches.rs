@@ -0,0 +1,61 @@
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::cqrs::surfaces_c0::append_surface_c0_patches::SurfaceSideDTO;
+use crate::domain::events::surfaces_c0::surface_c0_edit_refused::SurfaceC0EditRefused;
+use crate::domain::events::surfaces_c0::surface_c0_resized::SurfaceC0Resized;
+use crate::domain::intersection::IntersectionObjectId;
+use crate::services::edit_surface::{rebuild_surface_c0, remove_patches_c0, surface_grid};
+
+pub struct RemoveSurfaceC0Patches {
+    pub id: u64,
+    pub side: SurfaceSideDTO,
+}
+
+impl Command<RemoveSurfaceC0Patches> for RemoveSurfaceC0Patches {
+    fn execute(command: &RemoveSurfaceC0Patches, app_state: Rc<RefCell<Backend>>) {
+        let mut binding = app_state.borrow_mut();
+        let backend = binding.deref_mut();
+        let storage = &mut backend.storage;
+        let surface = &storage.surfaces_c0[&command.id];
+        let patches = if command.side.is_x() {
+            surface.size.0
+        } else {
+            surface.size.1
+        };
+        let result = if patches < 2 {
+            Err("Surface has only one patch in this direction".to_string())
+        } else if surface.is_cylinder && command.side.is_x()
+            || surface.is_closed_v() && !command.side.is_x()
+        {
+            Err("Surface is closed in this direction".to_string())
+        } else if storage.is_intersected(IntersectionObjectId::SurfaceC0(command.id)) {
+            Err("Surface is intersected".to_string())
+        } else {
+            let ids = surface.points.iter().map(|p| p.id).collect::<Vec<_>>();
+            let grid = surface_grid(&ids, 3 * surface.size.1 as usize + 1, &storage.points);
+            rebuild_surface_c0(
+                storage,
+                command.id,
+                &remove_patches_c0(grid, command.side.to_side()),
+                &mut backend.services.id_generator,
+            )
+        };
+
+        drop(binding);
+        let backend = app_state.borrow();
+        match result {
+            Ok(size) => backend
+                .services
+                .event_publisher
+                .publish(Rc::new(SurfaceC0Resized::new(command.id, size))),
+            Err(reason) => backend
+                .services
+                .event_publisher
+                .publish(Rc::new(SurfaceC0EditRefused::new(command.id, reason))),
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use backend_events::common::surface_display_mode::SurfaceDisplayModeDTO;
+
 use crate::backend::Backend;
+use crate::cqrs::common::surface_display_mode::display_mode_to_dto;
 use crate::cqrs::cqrs::Query;
 
 pub struct SurfaceC0Details {
@@ -14,8 +17,10 @@ pub struct SurfaceC0DTO {
     pub name: String,
     pub draw_polygon: bool,
     pub tess_level: u8,
+    pub display_mode: SurfaceDisplayModeDTO,
     pub size: (u32, u32),
     pub is_cylinder: bool,
+    pub is_closed_v: bool,
 }
 
 impl Query<SurfaceC0Details, SurfaceC0DTO> for SurfaceC0Details {
@@ -27,8 +32,10 @@ impl Query<SurfaceC0Details, SurfaceC0DTO> for SurfaceC0Details {
             name: surface_c0.name.clone(),
             draw_polygon: surface_c0.draw_polygon,
             tess_level: surface_c0.tess_level,
+            display_mode: display_mode_to_dto(surface_c0.display_mode),
             size: surface_c0.size,
             is_cylinder: surface_c0.is_cylinder,
+            is_closed_v: surface_c0.is_closed_v(),
         }
     }
 }
@@ -2,6 +2,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::backend::Backend;
+use crate::cqrs::common::surface_display_mode::display_mode_to_dto;
 use crate::cqrs::cqrs::Query;
 use crate::cqrs::surfaces_c2::surface_c2_details::SurfaceC2DTO;
 
@@ -19,8 +20,10 @@ impl Query<AllSurfacesC2, Vec<SurfaceC2DTO>> for AllSurfacesC2 {
                 name: surface_c2.name.clone(),
                 draw_polygon: surface_c2.draw_polygon,
                 tess_level: surface_c2.tess_level,
+                display_mode: display_mode_to_dto(surface_c2.display_mode),
                 size: surface_c2.size,
                 is_cylinder: surface_c2.is_cylinder,
+                is_closed_v: surface_c2.is_closed_v(),
             })
             .collect()
     }
@@ -0,0 +1,54 @@
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::cqrs::surfaces_c0::append_surface_c0_patches::SurfaceSideDTO;
+use crate::domain::events::surfaces_c2::surface_c2_edit_refused::SurfaceC2EditRefused;
+use crate::domain::events::surfaces_c2::surface_c2_resized::SurfaceC2Resized;
+use crate::domain::intersection::IntersectionObjectId;
+use crate::services::edit_surface::{append_patches_c2, rebuild_surface_c2, surface_grid};
+
+pub struct AppendSurfaceC2Patches {
+    pub id: u64,
+    pub side: SurfaceSideDTO,
+}
+
+impl Command<AppendSurfaceC2Patches> for AppendSurfaceC2Patches {
+    fn execute(command: &AppendSurfaceC2Patches, app_state: Rc<RefCell<Backend>>) {
+        let mut binding = app_state.borrow_mut();
+        let backend = binding.deref_mut();
+        let storage = &mut backend.storage;
+        let surface = &storage.surfaces_c2[&command.id];
+        let result = if surface.is_cylinder && command.side.is_x()
+            || surface.is_closed_v() && !command.side.is_x()
+        {
+            Err("Surface is closed in this direction".to_string())
+        } else if storage.is_intersected(IntersectionObjectId::SurfaceC2(command.id)) {
+            Err("Surface is intersected".to_string())
+        } else {
+            let ids = surface.points.iter().map(|p| p.id).collect::<Vec<_>>();
+            let grid = surface_grid(&ids, surface.size.1 as usize + 3, &storage.points);
+            rebuild_surface_c2(
+                storage,
+                command.id,
+                &append_patches_c2(grid, command.side.to_side()),
+                &mut backend.services.id_generator,
+            )
+        };
+
+        drop(binding);
+        let backend = app_state.borrow();
+        match result {
+            Ok(size) => backend
+                .services
+                .event_publisher
+                .publish(Rc::new(SurfaceC2Resized::new(command.id, size))),
+            Err(reason) => backend
+                .services
+                .event_publisher
+                .publish(Rc::new(SurfaceC2EditRefused::new(command.id, reason))),
+        }
+    }
+}
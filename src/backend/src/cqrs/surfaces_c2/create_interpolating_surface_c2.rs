@@ -0,0 +1,75 @@
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+
+use nalgebra::Vector3;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::points::point_created::PointCreated;
+use crate::domain::events::surfaces_c2::surface_c2_created::SurfaceC2Created;
+use crate::services::create_surface::create_interpolating_surface_c2;
+
+pub struct CreateInterpolatingSurfaceC2 {
+    pub id: u64,
+    pub points: Vec<u64>,
+    pub size_x: u32,
+}
+
+impl Command<CreateInterpolatingSurfaceC2> for CreateInterpolatingSurfaceC2 {
+    fn execute(command: &CreateInterpolatingSurfaceC2, app_state: Rc<RefCell<Backend>>) {
+        let mut binding = app_state.borrow_mut();
+        let backend = binding.deref_mut();
+        let storage = &mut backend.storage;
+        if command.size_x == 0
+            || !command.points.len().is_multiple_of(command.size_x as usize)
+            || command
+                .points
+                .iter()
+                .any(|id| !storage.points.contains_key(id))
+        {
+            return;
+        }
+
+        let grid = command
+            .points
+            .chunks(command.points.len() / command.size_x as usize)
+            .map(|column| {
+                column
+                    .iter()
+                    .map(|id| {
+                        let position = storage.points[id].transformer.position;
+                        (*id, Vector3::new(position.0, position.1, position.2))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let Some((surface, points)) =
+            create_interpolating_surface_c2(command.id, &grid, &mut backend.services.id_generator)
+        else {
+            return;
+        };
+
+        storage.selected_objects.clear();
+        let surface_created = Rc::new(SurfaceC2Created::new(
+            command.id,
+            surface.size,
+            surface.is_cylinder,
+        ));
+        storage.surfaces_c2.insert(command.id, surface);
+        let events = points
+            .iter()
+            .map(|point| Rc::new(PointCreated::new(point.id, point.name.clone())))
+            .collect::<Vec<_>>();
+        for point in points {
+            storage.points.insert(point.id, point);
+        }
+
+        drop(binding);
+        let backend = app_state.borrow();
+        for event in events {
+            backend.services.event_publisher.publish(event);
+        }
+        backend.services.event_publisher.publish(surface_created);
+    }
+}
@@ -24,6 +24,7 @@ impl Command<CreateSurfaceC2> for CreateSurfaceC2 {
             id_generator,
             &cursor_position,
         );
+        let (size, is_cylinder) = (surface.size, surface.is_cylinder);
         backend.storage.surfaces_c2.insert(command.id, surface);
         let events = points
             .iter()
@@ -42,8 +43,8 @@ impl Command<CreateSurfaceC2> for CreateSurfaceC2 {
             .event_publisher
             .publish(Rc::new(SurfaceC2Created::new(
                 command.id,
-                command.create_surface_info.size,
-                command.create_surface_info.is_cylinder,
+                size,
+                is_cylinder,
             )));
     }
 }
@@ -1,5 +1,9 @@
 pub mod all_surfaces_c2;
+pub mod append_surface_c2_patches;
+pub mod create_interpolating_surface_c2;
 pub mod create_surface_c2;
+pub mod refine_surface_c2;
+pub mod remove_surface_c2_patches;
 pub mod rename_surface_c2;
 pub mod select_surface_c2_points;
 pub mod surface_c2_details;
@@ -0,0 +1,44 @@
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+
+use crate::backend::Backend;
+use crate::cqrs::cqrs::Command;
+use crate::domain::events::surfaces_c2::surface_c2_edit_refused::SurfaceC2EditRefused;
+use crate::domain::events::surfaces_c2::surface_c2_resized::SurfaceC2Resized;
+use crate::domain::intersection::IntersectionObjectId;
+use crate::services::edit_surface::refine_surface_c2;
+
+pub struct RefineSurfaceC2 {
+    pub id: u64,
+}
+
+impl Command<RefineSurfaceC2> for RefineSurfaceC2 {
+    fn execute(command: &RefineSurfaceC2, app_state: Rc<RefCell<Backend>>) {
+        let mut binding = app_state.borrow_mut();
+        let backend = binding.deref_mut();
+        let storage = &mut backend.storage;
+        let result = if storage.is_intersected(IntersectionObjectId::SurfaceC2(command.id)) {
+            Err("Surface is intersected".to_string())
+        } else {
+            refine_surface_c2(storage, command.id, &mut backend.services.id_generator)
+        };
+
+        drop(binding);
+        let backend = app_state.borrow();
+        match result {
+            Ok(sizes) => {
+                for (id, size) in sizes {
+                    backend
+                        .services
+                        .event_publisher
+                        .publish(Rc::new(SurfaceC2Resized::new(id, size)));
+                }
+            }
+            Err(reason) => backend
+                .services
+                .event_publisher
+                .publish(Rc::new(SurfaceC2EditRefused::new(command.id, reason))),
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use backend_events::common::surface_display_mode::SurfaceDisplayModeDTO;
+
 use crate::backend::Backend;
+use crate::cqrs::common::surface_display_mode::display_mode_to_dto;
 use crate::cqrs::cqrs::Query;
 
 pub struct SurfaceC2Details {
@@ -14,8 +17,10 @@ pub struct SurfaceC2DTO {
     pub name: String,
     pub draw_polygon: bool,
     pub tess_level: u8,
+    pub display_mode: SurfaceDisplayModeDTO,
     pub size: (u32, u32),
     pub is_cylinder: bool,
+    pub is_closed_v: bool,
 }
 
 impl Query<SurfaceC2Details, SurfaceC2DTO> for SurfaceC2Details {
@@ -27,8 +32,10 @@ impl Query<SurfaceC2Details, SurfaceC2DTO> for SurfaceC2Details {
             name: surface_c2.name.clone(),
             draw_polygon: surface_c2.draw_polygon,
             tess_level: surface_c2.tess_level,
+            display_mode: display_mode_to_dto(surface_c2.display_mode),
             size: surface_c2.size,
             is_cylinder: surface_c2.is_cylinder,
+            is_closed_v: surface_c2.is_closed_v(),
         }
     }
 }
@@ -1,7 +1,10 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use backend_events::common::surface_display_mode::SurfaceDisplayModeDTO;
+
 use crate::backend::Backend;
+use crate::cqrs::common::surface_display_mode::display_mode_from_dto;
 use crate::cqrs::cqrs::Command;
 use crate::domain::events::surfaces_c2::surface_c2_updated::SurfaceC2Updated;
 
@@ -9,14 +12,17 @@ pub struct UpdateSurfaceC2 {
     pub id: u64,
     pub draw_polygon: bool,
     pub tess_level: u8,
+    pub display_mode: SurfaceDisplayModeDTO,
 }
 
 impl Command<UpdateSurfaceC2> for UpdateSurfaceC2 {
     fn execute(command: &UpdateSurfaceC2, app_state: Rc<RefCell<Backend>>) {
+        let display_mode = display_mode_from_dto(command.display_mode);
         let mut backend = app_state.borrow_mut();
         let surface_c2 = backend.storage.surfaces_c2.get_mut(&command.id).unwrap();
         surface_c2.set_draw_polygon(command.draw_polygon);
         surface_c2.set_tess_level(command.tess_level);
+        surface_c2.set_display_mode(display_mode);
         drop(backend);
         let backend = app_state.borrow();
         backend
@@ -26,6 +32,7 @@ impl Command<UpdateSurfaceC2> for UpdateSurfaceC2 {
                 command.id,
                 command.draw_polygon,
                 command.tess_level,
+                display_mode,
             )));
     }
 }
@@ -0,0 +1,58 @@
+use std::{cell::RefCell, rc::Rc};
+
+use math::vector3::Vector3;
+
+use crate::{
+    backend::Backend,
+    cqrs::{
+        cqrs::Command,
+        milling::simulate_milling::{CutterDTO, CutterTypeDTO},
+    },
+    domain::{
+        cutter::{Cutter, CutterType},
+        events::toolpaths::toolpath_created::ToolpathCreated,
+        toolpath::Toolpath,
+    },
+};
+
+pub struct AddToolpath {
+    pub id: u64,
+    pub name: String,
+    pub cutter: CutterDTO,
+    pub points: Vec<(f64, f64, f64)>,
+}
+
+impl Command<AddToolpath> for AddToolpath {
+    fn execute(command: &AddToolpath, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let cutter = Cutter::new(
+            match command.cutter.cutter_type {
+                CutterTypeDTO::Flat => CutterType::Flat,
+                CutterTypeDTO::Spherical => CutterType::Spherical,
+            },
+            command.cutter.radius as f32,
+            command.cutter.cutting_length as f32,
+        );
+        let toolpath = Toolpath::new_with_name(
+            command.id,
+            command.name.clone(),
+            cutter,
+            command
+                .points
+                .iter()
+                .map(|p| Vector3::new(p.0 as f32, p.1 as f32, p.2 as f32))
+                .collect(),
+        );
+        let toolpath_created = Rc::new(ToolpathCreated::new(
+            toolpath.id,
+            toolpath.name.clone(),
+            toolpath.cutter.name(),
+            toolpath.points.clone(),
+            toolpath.visible,
+        ));
+        backend.storage.toolpaths.insert(command.id, toolpath);
+        drop(backend);
+        let backend = app_state.borrow();
+        backend.services.event_publisher.publish(toolpath_created);
+    }
+}
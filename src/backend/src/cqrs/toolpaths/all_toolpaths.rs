@@ -0,0 +1,19 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{backend::Backend, cqrs::cqrs::Query};
+
+use super::toolpath_details::ToolpathDTO;
+
+pub struct AllToolpaths;
+
+impl Query<AllToolpaths, Vec<ToolpathDTO>> for AllToolpaths {
+    fn get(_: &AllToolpaths, app_state: Rc<RefCell<Backend>>) -> Vec<ToolpathDTO> {
+        let backend = app_state.borrow();
+        backend
+            .storage
+            .toolpaths
+            .values()
+            .map(ToolpathDTO::from_toolpath)
+            .collect()
+    }
+}
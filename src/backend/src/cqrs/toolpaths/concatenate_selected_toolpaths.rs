@@ -0,0 +1,44 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    backend::Backend,
+    cqrs::cqrs::Command,
+    domain::{events::toolpaths::toolpath_created::ToolpathCreated, toolpath::Toolpath},
+};
+
+pub struct ConcatenateSelectedToolpaths {
+    pub id: u64,
+}
+
+impl Command<ConcatenateSelectedToolpaths> for ConcatenateSelectedToolpaths {
+    fn execute(command: &ConcatenateSelectedToolpaths, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let toolpaths = backend
+            .storage
+            .selected_objects
+            .iter()
+            .filter_map(|object| object.toolpath_id)
+            .filter_map(|id| backend.storage.toolpaths.get(&id))
+            .collect::<Vec<_>>();
+
+        if toolpaths.len() < 2 {
+            return;
+        }
+
+        let Some(toolpath) = Toolpath::concatenate(command.id, &toolpaths) else {
+            return;
+        };
+
+        let toolpath_created = Rc::new(ToolpathCreated::new(
+            toolpath.id,
+            toolpath.name.clone(),
+            toolpath.cutter.name(),
+            toolpath.points.clone(),
+            toolpath.visible,
+        ));
+        backend.storage.toolpaths.insert(command.id, toolpath);
+        drop(backend);
+        let backend = app_state.borrow();
+        backend.services.event_publisher.publish(toolpath_created);
+    }
+}
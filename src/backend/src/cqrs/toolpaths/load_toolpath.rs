@@ -0,0 +1,40 @@
+use std::{cell::RefCell, path::Path, rc::Rc};
+
+use crate::{
+    backend::Backend,
+    cqrs::cqrs::Command,
+    domain::{events::toolpaths::toolpath_created::ToolpathCreated, toolpath::Toolpath},
+    services::toolpath_file::load_toolpath,
+};
+
+pub struct LoadToolpath {
+    pub id: u64,
+    pub file_path: String,
+}
+
+impl Command<LoadToolpath> for LoadToolpath {
+    fn execute(command: &LoadToolpath, app_state: Rc<RefCell<Backend>>) {
+        let Some((cutter, points)) = load_toolpath(&command.file_path) else {
+            return;
+        };
+
+        let mut backend = app_state.borrow_mut();
+        let name = Path::new(&command.file_path)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(|n| n.to_string())
+            .unwrap_or(format!("Toolpath {}", command.id));
+        let toolpath = Toolpath::new_with_name(command.id, name, cutter, points);
+        let toolpath_created = Rc::new(ToolpathCreated::new(
+            toolpath.id,
+            toolpath.name.clone(),
+            toolpath.cutter.name(),
+            toolpath.points.clone(),
+            toolpath.visible,
+        ));
+        backend.storage.toolpaths.insert(command.id, toolpath);
+        drop(backend);
+        let backend = app_state.borrow();
+        backend.services.event_publisher.publish(toolpath_created);
+    }
+}
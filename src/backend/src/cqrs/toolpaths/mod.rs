@@ -0,0 +1,10 @@
+pub mod add_toolpath;
+pub mod all_toolpaths;
+pub mod concatenate_selected_toolpaths;
+pub mod load_toolpath;
+pub mod rename_toolpath;
+pub mod reverse_toolpath;
+pub mod save_toolpath;
+pub mod set_toolpath_visible;
+pub mod toolpath_details;
+pub mod trim_toolpath;
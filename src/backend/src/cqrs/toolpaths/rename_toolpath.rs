@@ -0,0 +1,23 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    backend::Backend, cqrs::cqrs::Command,
+    domain::events::toolpaths::toolpath_renamed::ToolpathRenamed,
+};
+
+pub struct RenameToolpath {
+    pub id: u64,
+    pub name: String,
+}
+
+impl Command<RenameToolpath> for RenameToolpath {
+    fn execute(command: &RenameToolpath, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let toolpath = backend.storage.toolpaths.get_mut(&command.id).unwrap();
+        toolpath.rename(&command.name);
+        let toolpath_renamed = Rc::new(ToolpathRenamed::new(toolpath.id, toolpath.name.clone()));
+        drop(backend);
+        let backend = app_state.borrow();
+        backend.services.event_publisher.publish(toolpath_renamed);
+    }
+}
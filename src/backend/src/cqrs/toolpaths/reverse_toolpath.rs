@@ -0,0 +1,26 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    backend::Backend, cqrs::cqrs::Command,
+    domain::events::toolpaths::toolpath_updated::ToolpathUpdated,
+};
+
+pub struct ReverseToolpath {
+    pub id: u64,
+}
+
+impl Command<ReverseToolpath> for ReverseToolpath {
+    fn execute(command: &ReverseToolpath, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let toolpath = backend.storage.toolpaths.get_mut(&command.id).unwrap();
+        toolpath.reverse();
+        let toolpath_updated = Rc::new(ToolpathUpdated::new(
+            toolpath.id,
+            toolpath.points.clone(),
+            toolpath.visible,
+        ));
+        drop(backend);
+        let backend = app_state.borrow();
+        backend.services.event_publisher.publish(toolpath_updated);
+    }
+}
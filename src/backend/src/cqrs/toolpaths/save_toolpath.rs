@@ -0,0 +1,16 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{backend::Backend, cqrs::cqrs::Command, services::toolpath_file::save_toolpath};
+
+pub struct SaveToolpath {
+    pub id: u64,
+    pub file_path: String,
+}
+
+impl Command<SaveToolpath> for SaveToolpath {
+    fn execute(command: &SaveToolpath, app_state: Rc<RefCell<Backend>>) {
+        let backend = app_state.borrow();
+        let toolpath = backend.storage.toolpaths.get(&command.id).unwrap();
+        save_toolpath(&command.file_path, &toolpath.points);
+    }
+}
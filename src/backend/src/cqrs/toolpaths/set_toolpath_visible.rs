@@ -0,0 +1,27 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    backend::Backend, cqrs::cqrs::Command,
+    domain::events::toolpaths::toolpath_updated::ToolpathUpdated,
+};
+
+pub struct SetToolpathVisible {
+    pub id: u64,
+    pub visible: bool,
+}
+
+impl Command<SetToolpathVisible> for SetToolpathVisible {
+    fn execute(command: &SetToolpathVisible, app_state: Rc<RefCell<Backend>>) {
+        let mut backend = app_state.borrow_mut();
+        let toolpath = backend.storage.toolpaths.get_mut(&command.id).unwrap();
+        toolpath.set_visible(command.visible);
+        let toolpath_updated = Rc::new(ToolpathUpdated::new(
+            toolpath.id,
+            toolpath.points.clone(),
+            toolpath.visible,
+        ));
+        drop(backend);
+        let backend = app_state.borrow();
+        backend.services.event_publisher.publish(toolpath_updated);
+    }
+}
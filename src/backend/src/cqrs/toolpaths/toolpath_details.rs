@@ -0,0 +1,54 @@
+use std::{cell::RefCell, rc::Rc};
+
+use math::vector3::Vector3;
+
+use crate::{
+    backend::Backend,
+    cqrs::{
+        cqrs::Query,
+        milling::simulate_milling::{CutterDTO, CutterTypeDTO},
+    },
+    domain::{cutter::CutterType, toolpath::Toolpath},
+};
+
+pub struct ToolpathDetails {
+    pub id: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ToolpathDTO {
+    pub id: u64,
+    pub name: String,
+    pub cutter: CutterDTO,
+    pub cutter_name: String,
+    pub points: Vec<Vector3>,
+    pub visible: bool,
+}
+
+impl Query<ToolpathDetails, ToolpathDTO> for ToolpathDetails {
+    fn get(query: &ToolpathDetails, app_state: Rc<RefCell<Backend>>) -> ToolpathDTO {
+        let backend = app_state.borrow();
+        let toolpath = backend.storage.toolpaths.get(&query.id).unwrap();
+        ToolpathDTO::from_toolpath(toolpath)
+    }
+}
+
+impl ToolpathDTO {
+    pub fn from_toolpath(toolpath: &Toolpath) -> Self {
+        Self {
+            id: toolpath.id,
+            name: toolpath.name.clone(),
+            cutter: CutterDTO {
+                cutter_type: match toolpath.cutter.cutter_type {
+                    CutterType::Flat => CutterTypeDTO::Flat,
+                    CutterType::Spherical => CutterTypeDTO::Spherical,
+                },
+                radius: toolpath.cutter.radius as f64,
+                cutting_length: toolpath.cutter.cutting_length as f64,
+            },
+            cutter_name: toolpath.cutter.name(),
+            points: toolpath.points.clone(),
+            visible: toolpath.visible,
+        }
+    }
+}
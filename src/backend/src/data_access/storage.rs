@@ -1,15 +1,22 @@
 use std::collections::HashMap;
 
+use nalgebra::Vector3;
+
 use crate::domain::bezier_c0::BezierC0;
 use crate::domain::bezier_c2::BezierC2;
 use crate::domain::bezier_int::BezierInt;
+use crate::domain::continuity::{ContinuityPatch, PatchGeometry};
 use crate::domain::cursor::Cursor;
+use crate::domain::curve_analysis::CurveAnalysis;
 use crate::domain::gregory::Gregory;
-use crate::domain::intersection::Intersection;
+use crate::domain::intersection::{Intersection, IntersectionObjectId};
+use crate::domain::nurbs_curve::NurbsCurve;
+use crate::domain::nurbs_surface::NurbsSurface;
 use crate::domain::point::Point;
 use crate::domain::selected_object::SelectedObject;
 use crate::domain::surface_c0::SurfaceC0;
 use crate::domain::surface_c2::SurfaceC2;
+use crate::domain::toolpath::Toolpath;
 use crate::domain::torus::Torus;
 
 pub struct Storage {
@@ -18,10 +25,13 @@ pub struct Storage {
     pub beziers_c0: HashMap<u64, BezierC0>,
     pub beziers_c2: HashMap<u64, BezierC2>,
     pub beziers_int: HashMap<u64, BezierInt>,
+    pub nurbs_curves: HashMap<u64, NurbsCurve>,
     pub surfaces_c0: HashMap<u64, SurfaceC0>,
     pub surfaces_c2: HashMap<u64, SurfaceC2>,
+    pub nurbs_surfaces: HashMap<u64, NurbsSurface>,
     pub gregories: HashMap<u64, Gregory>,
     pub intersections: HashMap<u64, Intersection>,
+    pub toolpaths: HashMap<u64, Toolpath>,
     pub selected_objects: Vec<SelectedObject>,
     pub cursor: Cursor,
 }
@@ -34,12 +44,217 @@ impl Storage {
             beziers_c0: HashMap::new(),
             beziers_c2: HashMap::new(),
             beziers_int: HashMap::new(),
+            nurbs_curves: HashMap::new(),
             surfaces_c0: HashMap::new(),
             surfaces_c2: HashMap::new(),
+            nurbs_surfaces: HashMap::new(),
             gregories: HashMap::new(),
             intersections: HashMap::new(),
+            toolpaths: HashMap::new(),
             selected_objects: Vec::new(),
             cursor: Cursor::new(),
         }
     }
+    pub fn replace_point(&mut self, old_point: u64, new_point: u64) {
+        let points = &self.points;
+
+        self.beziers_c0
+            .values_mut()
+            .filter(|b| b.points.iter().any(|p| p.id == old_point))
+            .for_each(|b| {
+                b.replace_point(old_point, new_point);
+            });
+
+        self.beziers_c2
+            .values_mut()
+            .filter(|b| b.b_spline_points.iter().any(|p| p.id == old_point))
+            .for_each(|b| {
+                b.replace_point(old_point, new_point, points);
+            });
+
+        self.beziers_int
+            .values_mut()
+            .filter(|b| b.points.iter().any(|p| p.id == old_point))
+            .for_each(|b| {
+                b.replace_point(old_point, new_point, points);
+            });
+
+        self.nurbs_curves
+            .values_mut()
+            .filter(|c| c.points.iter().any(|p| p.id == old_point))
+            .for_each(|c| {
+                c.replace_point(old_point, new_point, points);
+            });
+
+        self.surfaces_c0
+            .values_mut()
+            .filter(|s| s.points.iter().any(|p| p.id == old_point))
+            .for_each(|s| {
+                s.replace_point(old_point, new_point);
+            });
+
+        self.surfaces_c2
+            .values_mut()
+            .filter(|s| s.related_points().contains(&old_point))
+            .for_each(|s| {
+                s.replace_point(old_point, new_point);
+            });
+
+        self.nurbs_surfaces
+            .values_mut()
+            .filter(|s| s.points.iter().any(|p| p.id == old_point))
+            .for_each(|s| {
+                s.replace_point(old_point, new_point);
+            });
+
+        self.gregories
+            .values_mut()
+            .filter(|g| g.related_points().contains(&old_point))
+            .for_each(|g| {
+                g.replace_point(old_point, new_point);
+                g.recalculate_mesh(points);
+            });
+    }
+
+    pub fn is_point_used(&self, id: u64) -> bool {
+        self.beziers_c0
+            .values()
+            .any(|b| b.points.iter().any(|p| p.id == id))
+            || self
+                .beziers_c2
+                .values()
+                .any(|b| b.b_spline_points.iter().any(|p| p.id == id))
+            || self
+                .beziers_int
+                .values()
+                .any(|b| b.points.iter().any(|p| p.id == id))
+            || self
+                .nurbs_curves
+                .values()
+                .any(|c| c.points.iter().any(|p| p.id == id))
+            || self
+                .surfaces_c0
+                .values()
+                .any(|s| s.points.iter().any(|p| p.id == id))
+            || self
+                .surfaces_c2
+                .values()
+                .any(|s| s.related_points().contains(&id))
+            || self
+                .nurbs_surfaces
+                .values()
+                .any(|s| s.points.iter().any(|p| p.id == id))
+            || self
+                .gregories
+                .values()
+                .any(|g| g.related_points().contains(&id))
+    }
+
+    pub fn remove_unused_points(&mut self, ids: &[u64]) {
+        for id in ids {
+            if !self.is_point_used(*id) {
+                self.points.remove(id);
+                self.selected_objects.retain(|o| o.point_id != Some(*id));
+            }
+        }
+    }
+
+    pub fn is_intersected(&self, id: IntersectionObjectId) -> bool {
+        self.intersections
+            .values()
+            .any(|i| i.object1_id == id || i.object2_id == id)
+    }
+
+    pub fn curve_analysis(&self, id: u64) -> Option<CurveAnalysis> {
+        let to_vector =
+            |position: (f64, f64, f64)| Vector3::new(position.0, position.1, position.2);
+
+        let analysis = if let Some(bezier) = self.beziers_c0.get(&id) {
+            CurveAnalysis::from_bernstein_points(&bezier.bernstein_positions(&self.points))
+        } else if let Some(bezier) = self.beziers_c2.get(&id) {
+            CurveAnalysis::from_bernstein_points(
+                &bezier
+                    .bernstein_points
+                    .iter()
+                    .map(|p| to_vector(p.transformer.position))
+                    .collect::<Vec<_>>(),
+            )
+        } else if let Some(bezier) = self.beziers_int.get(&id) {
+            CurveAnalysis::from_bernstein_points(
+                &bezier
+                    .bernstein_points
+                    .iter()
+                    .map(|p| to_vector(p.transformer.position))
+                    .collect::<Vec<_>>(),
+            )
+        } else if let Some(curve) = self.nurbs_curves.get(&id) {
+            CurveAnalysis::from_weighted_bernstein_points(
+                &curve
+                    .bernstein_points
+                    .iter()
+                    .map(|p| (to_vector(p.transformer.position), p.weight))
+                    .collect::<Vec<_>>(),
+            )
+        } else {
+            return None;
+        };
+
+        (!analysis.is_empty()).then_some(analysis)
+    }
+
+    pub fn continuity_patches(&self) -> Vec<ContinuityPatch> {
+        let position = |id: u64| {
+            let position = self.points[&id].transformer.position;
+            Vector3::new(position.0, position.1, position.2)
+        };
+
+        let surfaces_c0 = self.surfaces_c0.values().flat_map(|surface| {
+            let s1 = surface.size.1 as usize * 3 + 1;
+            (0..surface.size.0 as usize)
+                .flat_map(move |x| (0..surface.size.1 as usize).map(move |y| (x, y)))
+                .map(move |(x, y)| ContinuityPatch {
+                    name: format!("{} ({}, {})", surface.name, x, y),
+                    geometry: PatchGeometry::Bezier(std::array::from_fn(|i| {
+                        std::array::from_fn(|j| {
+                            position(surface.points[(3 * x + i) * s1 + 3 * y + j].id)
+                        })
+                    })),
+                })
+        });
+        let surfaces_c2 = self.surfaces_c2.values().flat_map(|surface| {
+            let s1 = surface.size.1 as usize + 3;
+            (0..surface.size.0 as usize)
+                .flat_map(move |x| (0..surface.size.1 as usize).map(move |y| (x, y)))
+                .map(move |(x, y)| {
+                    ContinuityPatch::from_de_boor(
+                        format!("{} ({}, {})", surface.name, x, y),
+                        std::array::from_fn(|i| {
+                            std::array::from_fn(|j| {
+                                position(surface.points[(x + i) * s1 + y + j].id)
+                            })
+                        }),
+                    )
+                })
+        });
+        let gregories = self.gregories.values().flat_map(|gregory| {
+            gregory.patches.iter().enumerate().map(move |(i, patch)| {
+                let points = patch
+                    .top
+                    .iter()
+                    .chain(patch.top_sides.iter())
+                    .chain(patch.bottom_sides.iter())
+                    .chain(patch.bottom.iter())
+                    .chain(patch.u_inner.iter())
+                    .chain(patch.v_inner.iter())
+                    .map(|p| Vector3::new(p.x as f64, p.y as f64, p.z as f64))
+                    .collect::<Vec<_>>();
+                ContinuityPatch {
+                    name: format!("{} ({})", gregory.name, i),
+                    geometry: PatchGeometry::Gregory(std::array::from_fn(|k| points[k])),
+                }
+            })
+        });
+
+        surfaces_c0.chain(surfaces_c2).chain(gregories).collect()
+    }
 }
@@ -1,3 +1,9 @@
+use std::collections::HashMap;
+
+use nalgebra::Vector3;
+
+use crate::domain::point::Point;
+
 pub struct BezierC0 {
     pub id: u64,
     pub name: String,
@@ -9,6 +15,13 @@ pub struct BezierC0Point {
     pub id: u64,
 }
 
+pub struct BezierC0Split {
+    pub start: usize,
+    pub end: usize,
+    pub left_points: Vec<(f64, f64, f64)>,
+    pub right_points: Vec<(f64, f64, f64)>,
+}
+
 impl BezierC0 {
     pub fn new(id: u64, points: Vec<BezierC0Point>) -> Self {
         Self {
@@ -51,4 +64,118 @@ impl BezierC0 {
             }
         }
     }
+    pub fn split(&self, parameter: f64, all_points: &HashMap<u64, Point>) -> Option<BezierC0Split> {
+        let count = self.points.len();
+        if count < 2 {
+            return None;
+        }
+
+        let segments = (count - 1).div_ceil(3);
+        let u = parameter.clamp(0.0, 1.0) * segments as f64;
+        let segment = (u.floor() as usize).min(segments - 1);
+        let t = u - segment as f64;
+        let start = 3 * segment;
+        let end = (start + 3).min(count - 1);
+
+        let split_at = |index: usize| {
+            (index > 0 && index < count - 1).then(|| BezierC0Split {
+                start: index,
+                end: index + 1,
+                left_points: vec![],
+                right_points: vec![],
+            })
+        };
+        if t < 1e-6 {
+            return split_at(start);
+        }
+        if t > 1.0 - 1e-6 {
+            return split_at(end);
+        }
+
+        let mut level = self.positions(all_points)[start..=end].to_vec();
+        let mut left_points = vec![];
+        let mut right_points = vec![];
+        while level.len() > 1 {
+            level = level
+                .windows(2)
+                .map(|w| w[0] * (1.0 - t) + w[1] * t)
+                .collect();
+            left_points.push(level[0]);
+            right_points.push(level[level.len() - 1]);
+        }
+        right_points.reverse();
+        right_points.remove(0);
+
+        Some(BezierC0Split {
+            start,
+            end,
+            left_points: left_points.iter().map(|p| (p.x, p.y, p.z)).collect(),
+            right_points: right_points.iter().map(|p| (p.x, p.y, p.z)).collect(),
+        })
+    }
+
+    pub fn sample(
+        &self,
+        samples_per_segment: usize,
+        all_points: &HashMap<u64, Point>,
+    ) -> Vec<Vector3<f64>> {
+        let positions = self.positions(all_points);
+        if positions.len() < 2 {
+            return positions;
+        }
+
+        let mut samples = vec![positions[0]];
+        for start in (0..positions.len() - 1).step_by(3) {
+            let end = (start + 3).min(positions.len() - 1);
+            for k in 1..=samples_per_segment {
+                let t = k as f64 / samples_per_segment as f64;
+                let mut level = positions[start..=end].to_vec();
+                while level.len() > 1 {
+                    level = level
+                        .windows(2)
+                        .map(|w| w[0] * (1.0 - t) + w[1] * t)
+                        .collect();
+                }
+                samples.push(level[0]);
+            }
+        }
+        samples
+    }
+
+    pub fn bernstein_positions(&self, all_points: &HashMap<u64, Point>) -> Vec<Vector3<f64>> {
+        let positions = self.positions(all_points);
+        if positions.len() < 2 {
+            return vec![];
+        }
+
+        let mut bernstein_positions = vec![positions[0]];
+        for start in (0..positions.len() - 1).step_by(3) {
+            let end = (start + 3).min(positions.len() - 1);
+            let segment = &positions[start..=end];
+            match segment.len() {
+                2 => bernstein_positions.extend([
+                    segment[0] * (2.0 / 3.0) + segment[1] / 3.0,
+                    segment[0] / 3.0 + segment[1] * (2.0 / 3.0),
+                    segment[1],
+                ]),
+                3 => bernstein_positions.extend([
+                    segment[0] / 3.0 + segment[1] * (2.0 / 3.0),
+                    segment[1] * (2.0 / 3.0) + segment[2] / 3.0,
+                    segment[2],
+                ]),
+                _ => bernstein_positions.extend_from_slice(&segment[1..]),
+            }
+        }
+        bernstein_positions
+    }
+
+    pub fn positions(&self, all_points: &HashMap<u64, Point>) -> Vec<Vector3<f64>> {
+        self.points
+            .iter()
+            .map(|p| {
+                let position = all_points[&p.id].transformer.position;
+                Vector3::new(position.0, position.1, position.2)
+            })
+            .collect()
+    }
 }
@@ -1,8 +1,13 @@
 use std::collections::HashMap;
 
+use math::linear_systems::solve_banded_symmetric;
+use nalgebra::{Vector3, Vector4};
+
 use crate::domain::point::Point;
 use crate::domain::transformer::LittleTransformer;
 
+const REPRODUCTION_TOLERANCE: f64 = 1e-3;
+
 pub struct BezierC2 {
     pub id: u64,
     pub name: String,
@@ -12,6 +17,8 @@ pub struct BezierC2 {
     pub b_spline_points: Vec<BezierC2BSplinePoint>,
     pub bernstein_points: Vec<BezierC2BernsteinPoint>,
     pub selected_bernstein_point: Option<usize>,
+    pub closed: bool,
+    pub knot_intervals: Vec<f64>,
 }
 
 pub struct BezierC2BSplinePoint {
@@ -22,9 +29,23 @@ pub struct BezierC2BernsteinPoint {
     pub transformer: LittleTransformer,
 }
 
+pub struct BezierC2KnotInsertion {
+    pub moved_points: Vec<(u64, (f64, f64, f64))>,
+    pub inserted_point: (f64, f64, f64),
+    pub index: usize,
+    pub knot_intervals: Vec<f64>,
+}
+
+pub struct BezierC2Split {
+    pub left_points: Vec<u64>,
+    pub left_knot_intervals: Vec<f64>,
+    pub right_points: Vec<u64>,
+    pub right_knot_intervals: Vec<f64>,
+}
+
 impl BezierC2 {
     pub fn new(id: u64, b_spline_points: Vec<Point>) -> Self {
-        let bernstein_points = Self::get_bernstein_points(&b_spline_points);
+        let bernstein_points = Self::get_bernstein_points(&b_spline_points, false, &[]);
 
         let b_spline_points = b_spline_points
             .iter()
@@ -40,11 +61,13 @@ impl BezierC2 {
             b_spline_points,
             bernstein_points,
             selected_bernstein_point: None,
+            closed: false,
+            knot_intervals: vec![],
         }
     }
 
     pub fn new_with_name(id: u64, name: String, b_spline_points: Vec<Point>) -> Self {
-        let bernstein_points = Self::get_bernstein_points(&b_spline_points);
+        let bernstein_points = Self::get_bernstein_points(&b_spline_points, false, &[]);
 
         let b_spline_points = b_spline_points
             .iter()
@@ -60,59 +83,304 @@ impl BezierC2 {
             b_spline_points,
             bernstein_points,
             selected_bernstein_point: None,
+            closed: false,
+            knot_intervals: vec![],
         }
     }
 
     pub fn update_points(&mut self, b_spline_points: Vec<Point>) {
+        if b_spline_points.len() != self.b_spline_points.len() && !self.knot_intervals.is_empty() {
+            self.knot_intervals = self.spliced_knot_intervals(&b_spline_points);
+        }
         self.b_spline_points = b_spline_points
             .iter()
             .map(|point| BezierC2BSplinePoint { id: point.id })
             .collect();
-        self.bernstein_points = Self::get_bernstein_points(&b_spline_points);
+        self.bernstein_points =
+            Self::get_bernstein_points(&b_spline_points, self.closed, &self.knot_intervals);
+    }
+
+    fn spliced_knot_intervals(&self, b_spline_points: &[Point]) -> Vec<f64> {
+        let old_ids = self
+            .b_spline_points
+            .iter()
+            .map(|p| p.id)
+            .collect::<Vec<_>>();
+        let new_ids = b_spline_points.iter().map(|p| p.id).collect::<Vec<_>>();
+        let closed = |count: usize| self.closed && count >= 3;
+        if closed(old_ids.len()) != closed(new_ids.len()) {
+            return vec![];
+        }
+
+        let mut knot_intervals = self.effective_knot_intervals();
+        let (mut i, mut j) = (0, 0);
+        while i < old_ids.len() || j < new_ids.len() {
+            if i < old_ids.len() && j < new_ids.len() && old_ids[i] == new_ids[j] {
+                i += 1;
+                j += 1;
+                continue;
+            }
+
+            let inserted = new_ids.len() - j > old_ids.len() - i;
+            let trailing = if inserted {
+                i == old_ids.len()
+            } else {
+                j == new_ids.len()
+            };
+            let index = if trailing && !closed(new_ids.len()) {
+                knot_intervals.len() - 1
+            } else {
+                j % knot_intervals.len()
+            };
+            if inserted {
+                knot_intervals.insert(index, knot_intervals[index]);
+                j += 1;
+            } else {
+                knot_intervals.remove(index);
+                i += 1;
+            }
+        }
+
+        knot_intervals
+    }
+
+    pub fn set_closed(&mut self, closed: bool, all_points: &HashMap<u64, Point>) {
+        self.closed = closed;
+        self.knot_intervals.clear();
+        self.selected_bernstein_point = None;
+
+        let b_spline_points = self
+            .b_spline_points
+            .iter()
+            .map(|p| all_points[&p.id].clone())
+            .collect::<Vec<_>>();
+
+        self.bernstein_points =
+            Self::get_bernstein_points(&b_spline_points, self.closed, &self.knot_intervals);
+    }
+
+    pub fn set_knot_intervals(
+        &mut self,
+        knot_intervals: Vec<f64>,
+        all_points: &HashMap<u64, Point>,
+    ) {
+        self.knot_intervals = knot_intervals;
+
+        let b_spline_points = self
+            .b_spline_points
+            .iter()
+            .map(|p| all_points[&p.id].clone())
+            .collect::<Vec<_>>();
+
+        self.bernstein_points =
+            Self::get_bernstein_points(&b_spline_points, self.closed, &self.knot_intervals);
     }
 
     pub fn rename(&mut self, name: &str) {
         self.name = name.to_string();
     }
 
-    fn get_bernstein_points(b_spline_points: &[Point]) -> Vec<BezierC2BernsteinPoint> {
-        if b_spline_points.len() < 4 {
-            vec![]
+    fn get_bernstein_points(
+        b_spline_points: &[Point],
+        closed: bool,
+        knot_intervals: &[f64],
+    ) -> Vec<BezierC2BernsteinPoint> {
+        let positions = Self::wrapped_positions(b_spline_points, closed);
+        if positions.len() < 4 {
+            return vec![];
+        }
+
+        let knots = Self::knots(b_spline_points.len(), closed, knot_intervals);
+        let mut points = vec![Self::blossom(&positions, &knots, 3, [knots[3]; 3])];
+        for i in 3..positions.len() {
+            let (a, b) = (knots[i], knots[i + 1]);
+            points.push(Self::blossom(&positions, &knots, i, [a, a, b]));
+            points.push(Self::blossom(&positions, &knots, i, [a, b, b]));
+            points.push(Self::blossom(&positions, &knots, i, [b, b, b]));
+        }
+
+        points
+            .iter()
+            .map(|p| BezierC2BernsteinPoint {
+                transformer: LittleTransformer {
+                    position: (p.x, p.y, p.z),
+                },
+            })
+            .collect()
+    }
+
+    fn wrapped_positions(b_spline_points: &[Point], closed: bool) -> Vec<Vector3<f64>> {
+        let positions = b_spline_points.iter().map(|p| {
+            Vector3::new(
+                p.transformer.position.0,
+                p.transformer.position.1,
+                p.transformer.position.2,
+            )
+        });
+
+        if closed && b_spline_points.len() >= 3 {
+            positions.clone().chain(positions.take(3)).collect()
         } else {
-            let mut g = 1.0 / 3.0 * b_spline_points[0].transformer.to_vec3()
-                + 2.0 / 3.0 * b_spline_points[1].transformer.to_vec3();
-            let mut f;
-            let mut points = vec![];
-            for i in 1..(b_spline_points.len() - 2) {
-                f = 2.0 / 3.0 * b_spline_points[i].transformer.to_vec3()
-                    + 1.0 / 3.0 * b_spline_points[i + 1].transformer.to_vec3();
-                let e = (f + g) * 0.5;
-                g = 1.0 / 3.0 * b_spline_points[i].transformer.to_vec3()
-                    + 2.0 / 3.0 * b_spline_points[i + 1].transformer.to_vec3();
-                points.push(BezierC2BernsteinPoint {
-                    transformer: LittleTransformer::from_vec3(e),
-                });
-                points.push(BezierC2BernsteinPoint {
-                    transformer: LittleTransformer::from_vec3(f),
-                });
-                points.push(BezierC2BernsteinPoint {
-                    transformer: LittleTransformer::from_vec3(g),
-                });
+            positions.collect()
+        }
+    }
+
+    fn knots(count: usize, closed: bool, knot_intervals: &[f64]) -> Vec<f64> {
+        let closed = closed && count >= 3;
+        let intervals_count = if closed { count } else { count + 3 };
+        let uniform = vec![1.0; intervals_count];
+        let intervals = if knot_intervals.len() == intervals_count
+            && knot_intervals.iter().all(|interval| *interval > 0.0)
+        {
+            knot_intervals
+        } else {
+            &uniform
+        };
+
+        let knots_count = if closed { count + 7 } else { count + 4 };
+        let mut knots = vec![0.0];
+        for k in 0..knots_count - 1 {
+            knots.push(knots[k] + intervals[k % intervals_count]);
+        }
+        knots
+    }
+
+    fn blossom(
+        positions: &[Vector3<f64>],
+        knots: &[f64],
+        span: usize,
+        arguments: [f64; 3],
+    ) -> Vector3<f64> {
+        let mut points = positions[span - 3..=span].to_vec();
+        for (level, argument) in arguments.iter().enumerate() {
+            let r = level + 1;
+            for j in (r..4).rev() {
+                let k = span - 3 + j;
+                let alpha = (argument - knots[k]) / (knots[k + 4 - r] - knots[k]);
+                points[j] = points[j - 1] * (1.0 - alpha) + points[j] * alpha;
             }
-            f = 2.0 / 3.0
-                * b_spline_points[b_spline_points.len() - 2]
-                    .transformer
-                    .to_vec3()
-                + 1.0 / 3.0
-                    * b_spline_points[b_spline_points.len() - 1]
-                        .transformer
-                        .to_vec3();
-            let e = (f + g) * 0.5;
-            points.push(BezierC2BernsteinPoint {
-                transformer: LittleTransformer::from_vec3(e),
-            });
-            points
         }
+        points[3]
+    }
+
+    pub fn knot_insertion(
+        &self,
+        parameter: f64,
+        all_points: &HashMap<u64, Point>,
+    ) -> Option<BezierC2KnotInsertion> {
+        let b_spline_points = self
+            .b_spline_points
+            .iter()
+            .map(|p| all_points[&p.id].clone())
+            .collect::<Vec<_>>();
+        let count = b_spline_points.len();
+        let positions = Self::wrapped_positions(&b_spline_points, self.closed);
+        if positions.len() < 4 {
+            return None;
+        }
+
+        let knots = Self::knots(count, self.closed, &self.knot_intervals);
+        let last_span = positions.len() - 1;
+        let knot = knots[3] + parameter.clamp(0.0, 1.0) * (knots[last_span + 1] - knots[3]);
+        let span = (3..=last_span)
+            .find(|i| knot < knots[i + 1])
+            .unwrap_or(last_span);
+        if knot - knots[span] < 1e-9 || knots[span + 1] - knot < 1e-9 {
+            return None;
+        }
+
+        let new_positions = (span - 2..=span)
+            .map(|j| {
+                let alpha = (knot - knots[j]) / (knots[j + 3] - knots[j]);
+                positions[j - 1] * (1.0 - alpha) + positions[j] * alpha
+            })
+            .collect::<Vec<_>>();
+
+        let index = span % count;
+        let mut knot_intervals = self.effective_knot_intervals();
+        knot_intervals.splice(index..=index, [knot - knots[span], knots[span + 1] - knot]);
+
+        Some(BezierC2KnotInsertion {
+            moved_points: vec![
+                (
+                    b_spline_points[(span - 2) % count].id,
+                    (new_positions[0].x, new_positions[0].y, new_positions[0].z),
+                ),
+                (
+                    b_spline_points[(span - 1) % count].id,
+                    (new_positions[1].x, new_positions[1].y, new_positions[1].z),
+                ),
+            ],
+            inserted_point: (new_positions[2].x, new_positions[2].y, new_positions[2].z),
+            index,
+            knot_intervals,
+        })
+    }
+
+    pub fn widest_span_parameter(&self) -> Option<f64> {
+        let count = self.b_spline_points.len();
+        let last_span = if self.closed && count >= 3 {
+            count + 2
+        } else if count >= 4 {
+            count - 1
+        } else {
+            return None;
+        };
+
+        let knots = Self::knots(count, self.closed, &self.knot_intervals);
+        let span = (3..=last_span)
+            .max_by(|a, b| (knots[a + 1] - knots[*a]).total_cmp(&(knots[b + 1] - knots[*b])))?;
+        Some(((knots[span] + knots[span + 1]) / 2.0 - knots[3]) / (knots[last_span + 1] - knots[3]))
+    }
+
+    pub fn insert_knot(
+        &mut self,
+        insertion: &BezierC2KnotInsertion,
+        point_id: u64,
+        all_points: &HashMap<u64, Point>,
+    ) {
+        self.b_spline_points
+            .insert(insertion.index, BezierC2BSplinePoint { id: point_id });
+        self.knot_intervals = insertion.knot_intervals.clone();
+        self.selected_bernstein_point = None;
+
+        let b_spline_points = self
+            .b_spline_points
+            .iter()
+            .map(|p| all_points[&p.id].clone())
+            .collect::<Vec<_>>();
+
+        self.bernstein_points =
+            Self::get_bernstein_points(&b_spline_points, self.closed, &self.knot_intervals);
+    }
+
+    pub fn split(&self, parameter: f64) -> Option<BezierC2Split> {
+        let count = self.b_spline_points.len();
+        if self.closed || count < 5 {
+            return None;
+        }
+
+        let knots = Self::knots(count, false, &self.knot_intervals);
+        let knot = knots[3] + parameter.clamp(0.0, 1.0) * (knots[count] - knots[3]);
+        let index = (4..count).min_by(|a, b| {
+            (knots[*a] - knot)
+                .abs()
+                .total_cmp(&(knots[*b] - knot).abs())
+        })?;
+
+        let ids = self
+            .b_spline_points
+            .iter()
+            .map(|p| p.id)
+            .collect::<Vec<_>>();
+        let knot_intervals = knots.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+
+        Some(BezierC2Split {
+            left_points: ids[..index].to_vec(),
+            left_knot_intervals: knot_intervals[..index + 3].to_vec(),
+            right_points: ids[index - 3..].to_vec(),
+            right_knot_intervals: knot_intervals[index - 3..].to_vec(),
+        })
     }
 
     pub fn set_draw_b_spline_polygon(&mut self, draw_b_spline_polygon: bool) {
@@ -135,36 +403,147 @@ impl BezierC2 {
         &self,
         transformer: LittleTransformer,
     ) -> Option<(u64, LittleTransformer)> {
-        if let Some(selected_bernstein_point) = self.selected_bernstein_point {
-            let delta = LittleTransformer {
-                position: (
-                    (transformer.position.0
-                        - self.bernstein_points[selected_bernstein_point]
-                            .transformer
-                            .position
-                            .0)
-                        * 1.5,
-                    (transformer.position.1
-                        - self.bernstein_points[selected_bernstein_point]
-                            .transformer
-                            .position
-                            .1)
-                        * 1.5,
-                    (transformer.position.2
-                        - self.bernstein_points[selected_bernstein_point]
-                            .transformer
-                            .position
-                            .2)
-                        * 1.5,
-                ),
-            };
-            return Some((
-                self.b_spline_points[(selected_bernstein_point + 1) / 3 + 1].id,
-                delta,
-            ));
+        let selected_bernstein_point = self.selected_bernstein_point?;
+        let (index, coefficient) = self.dominant_b_spline_point(selected_bernstein_point)?;
+        let position = self.bernstein_points[selected_bernstein_point]
+            .transformer
+            .position;
+        let delta = LittleTransformer {
+            position: (
+                (transformer.position.0 - position.0) / coefficient,
+                (transformer.position.1 - position.1) / coefficient,
+                (transformer.position.2 - position.2) / coefficient,
+            ),
+        };
+
+        Some((self.b_spline_points[index].id, delta))
+    }
+
+    fn dominant_b_spline_point(&self, bernstein_point: usize) -> Option<(usize, f64)> {
+        let count = self.b_spline_points.len();
+        let wrapped_count = if self.closed && count >= 3 {
+            count + 3
+        } else {
+            count
+        };
+        if wrapped_count < 4 {
+            return None;
+        }
+
+        let knots = Self::knots(count, self.closed, &self.knot_intervals);
+        let (span, arguments) = Self::bernstein_blossom(&knots, bernstein_point);
+        if span >= wrapped_count {
+            return None;
         }
 
-        return None;
+        let mut coefficients = vec![0.0; count];
+        for (j, coefficient) in Self::blossom_coefficients(&knots, span, arguments)
+            .iter()
+            .enumerate()
+        {
+            coefficients[(span - 3 + j) % count] += coefficient;
+        }
+
+        coefficients
+            .into_iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .filter(|(_, coefficient)| *coefficient > 0.0)
+    }
+
+    pub fn effective_knot_intervals(&self) -> Vec<f64> {
+        let count = self.b_spline_points.len();
+        let mut knot_intervals = Self::knots(count, self.closed, &self.knot_intervals)
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .collect::<Vec<_>>();
+        knot_intervals.truncate(if self.closed && count >= 3 {
+            count
+        } else {
+            count + 3
+        });
+        knot_intervals
+    }
+
+    pub fn is_uniform(&self) -> bool {
+        let knot_intervals = self.effective_knot_intervals();
+        knot_intervals
+            .iter()
+            .all(|interval| (interval - knot_intervals[0]).abs() < 1e-9)
+    }
+
+    pub fn b_spline_from_bernstein_points(
+        bernstein_points: &[Vector3<f64>],
+        knot_intervals: &[f64],
+    ) -> Option<Vec<Vector3<f64>>> {
+        if bernstein_points.len() < 4 || !(bernstein_points.len() - 1).is_multiple_of(3) {
+            return None;
+        }
+
+        let count = (bernstein_points.len() - 1) / 3 + 3;
+        if knot_intervals.len() != count + 3 {
+            return None;
+        }
+
+        let knots = Self::knots(count, false, knot_intervals);
+        let blossoms = (0..bernstein_points.len())
+            .map(|index| {
+                let (span, arguments) = Self::bernstein_blossom(&knots, index);
+                (span, Self::blossom_coefficients(&knots, span, arguments))
+            })
+            .collect::<Vec<_>>();
+
+        let mut diagonals = vec![vec![0.0; count]; 4];
+        let mut free_terms = vec![Vector3::zeros(); count];
+        for (point, (span, coefficients)) in bernstein_points.iter().zip(blossoms.iter()) {
+            for a in 0..4 {
+                for b in a..4 {
+                    diagonals[b - a][span - 3 + a] += coefficients[a] * coefficients[b];
+                }
+                free_terms[span - 3 + a] += point * coefficients[a];
+            }
+        }
+
+        let positions = solve_banded_symmetric(&diagonals, &free_terms)?;
+        let reproduced =
+            bernstein_points
+                .iter()
+                .zip(blossoms.iter())
+                .all(|(point, (span, coefficients))| {
+                    let fitted = (0..4)
+                        .map(|a| positions[span - 3 + a] * coefficients[a])
+                        .sum::<Vector3<f64>>();
+                    (fitted - point).norm() < REPRODUCTION_TOLERANCE
+                });
+        reproduced.then_some(positions)
+    }
+
+    fn bernstein_blossom(knots: &[f64], bernstein_point: usize) -> (usize, [f64; 3]) {
+        if bernstein_point == 0 {
+            return (3, [knots[3]; 3]);
+        }
+
+        let span = 3 + (bernstein_point - 1) / 3;
+        let (a, b) = (knots[span], knots[span + 1]);
+        let arguments = match (bernstein_point - 1) % 3 {
+            0 => [a, a, b],
+            1 => [a, b, b],
+            _ => [b, b, b],
+        };
+        (span, arguments)
+    }
+
+    fn blossom_coefficients(knots: &[f64], span: usize, arguments: [f64; 3]) -> [f64; 4] {
+        let mut coefficients = [Vector4::x(), Vector4::y(), Vector4::z(), Vector4::w()];
+        for (level, argument) in arguments.iter().enumerate() {
+            let r = level + 1;
+            for j in (r..4).rev() {
+                let k = span - 3 + j;
+                let alpha = (argument - knots[k]) / (knots[k + 4 - r] - knots[k]);
+                coefficients[j] = coefficients[j - 1] * (1.0 - alpha) + coefficients[j] * alpha;
+            }
+        }
+        coefficients[3].into()
     }
 
     pub fn replace_point(
@@ -185,6 +564,7 @@ impl BezierC2 {
             .map(|p| all_points[&p.id].clone())
             .collect::<Vec<_>>();
 
-        self.bernstein_points = Self::get_bernstein_points(&b_spline_points);
+        self.bernstein_points =
+            Self::get_bernstein_points(&b_spline_points, self.closed, &self.knot_intervals);
     }
 }
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
-use itertools::{multizip, Itertools};
+use math::linear_systems::{solve_cyclic_tridiagonal, solve_tridiagonal};
+use nalgebra::Vector3;
 
 use crate::domain::point::Point;
 use crate::domain::transformer::LittleTransformer;
@@ -10,6 +11,17 @@ pub struct BezierInt {
     pub name: String,
     pub points: Vec<BezierIntPoint>,
     pub bernstein_points: Vec<BezierIntBernsteinPoint>,
+    pub closed: bool,
+    pub clamped: bool,
+    pub end_tangents: [Vector3<f64>; 2],
+    pub parameterization: BezierIntParameterization,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BezierIntParameterization {
+    Uniform,
+    Centripetal,
+    ChordLength,
 }
 
 pub struct BezierIntPoint {
@@ -22,7 +34,12 @@ pub struct BezierIntBernsteinPoint {
 
 impl BezierInt {
     pub fn new(id: u64, points: Vec<Point>) -> Self {
-        let bernstein_points = Self::get_bernstein_points(&points);
+        let bernstein_points = Self::get_bernstein_points(
+            &points,
+            false,
+            None,
+            BezierIntParameterization::ChordLength,
+        );
 
         let points = points
             .iter()
@@ -34,11 +51,20 @@ impl BezierInt {
             name: format!("Bezier Int {}", id),
             points,
             bernstein_points,
+            closed: false,
+            clamped: false,
+            end_tangents: [Vector3::zeros(); 2],
+            parameterization: BezierIntParameterization::ChordLength,
         }
     }
 
     pub fn new_with_name(id: u64, name: String, points: Vec<Point>) -> Self {
-        let bernstein_points = Self::get_bernstein_points(&points);
+        let bernstein_points = Self::get_bernstein_points(
+            &points,
+            false,
+            None,
+            BezierIntParameterization::ChordLength,
+        );
 
         let points = points
             .iter()
@@ -50,6 +76,10 @@ impl BezierInt {
             name,
             points,
             bernstein_points,
+            closed: false,
+            clamped: false,
+            end_tangents: [Vector3::zeros(); 2],
+            parameterization: BezierIntParameterization::ChordLength,
         }
     }
 
@@ -62,192 +92,222 @@ impl BezierInt {
             .iter()
             .map(|point| BezierIntPoint { id: point.id })
             .collect();
-        self.bernstein_points = Self::get_bernstein_points(&points);
+        self.bernstein_points = Self::get_bernstein_points(
+            &points,
+            self.closed,
+            self.clamping(),
+            self.parameterization,
+        );
     }
 
-    fn get_bernstein_points(points: &[Point]) -> Vec<BezierIntBernsteinPoint> {
-        if points.len() < 2 {
-            vec![]
-        } else if points.len() == 2 {
-            vec![
-                BezierIntBernsteinPoint {
-                    transformer: LittleTransformer {
-                        position: points[0].transformer.position,
-                    },
-                },
-                BezierIntBernsteinPoint {
-                    transformer: LittleTransformer {
-                        position: points[0].transformer.position,
-                    },
-                },
-                BezierIntBernsteinPoint {
-                    transformer: LittleTransformer {
-                        position: points[1].transformer.position,
-                    },
-                },
-                BezierIntBernsteinPoint {
-                    transformer: LittleTransformer {
-                        position: points[1].transformer.position,
-                    },
-                },
-            ]
-        } else {
-            let n = points.len() - 1;
+    pub fn set_options(
+        &mut self,
+        closed: bool,
+        clamped: bool,
+        parameterization: BezierIntParameterization,
+        all_points: &HashMap<u64, Point>,
+    ) {
+        let points = self
+            .points
+            .iter()
+            .map(|p| all_points[&p.id].clone())
+            .collect::<Vec<_>>();
 
-            let lengths = points
-                .iter()
-                .tuple_windows()
-                .map(|(a, b)| {
-                    ((a.transformer.position.0 - b.transformer.position.0).powi(2)
-                        + (a.transformer.position.1 - b.transformer.position.1).powi(2)
-                        + (a.transformer.position.2 - b.transformer.position.2).powi(2))
-                    .sqrt()
-                })
-                .collect::<Vec<_>>();
-
-            let lower_diag = lengths
-                .iter()
-                .tuple_windows()
-                .map(|(a, b)| a / (a + b))
-                .skip(1)
-                .collect::<Vec<_>>();
+        if clamped && !self.clamped {
+            self.end_tangents = Self::estimated_end_tangents(&points, parameterization);
+        }
 
-            let upper_diag = lengths
-                .iter()
-                .tuple_windows()
-                .map(|(a, b)| b / (a + b))
-                .take(n - 2)
-                .collect::<Vec<_>>();
+        self.closed = closed;
+        self.clamped = clamped;
+        self.parameterization = parameterization;
 
-            let mut free_term = points
-                .iter()
-                .tuple_windows()
-                .zip(lengths.iter().tuple_windows())
-                .map(|((p1, p2, p3), (d1, d2))| {
-                    let lhs = (
-                        (p3.transformer.position.0 - p2.transformer.position.0) / d2,
-                        (p3.transformer.position.1 - p2.transformer.position.1) / d2,
-                        (p3.transformer.position.2 - p2.transformer.position.2) / d2,
-                    );
-                    let rhs = (
-                        (p2.transformer.position.0 - p1.transformer.position.0) / d1,
-                        (p2.transformer.position.1 - p1.transformer.position.1) / d1,
-                        (p2.transformer.position.2 - p1.transformer.position.2) / d1,
-                    );
-                    let divisor = d1 + d2;
-                    (
-                        (lhs.0 - rhs.0) / divisor * 3.0,
-                        (lhs.1 - rhs.1) / divisor * 3.0,
-                        (lhs.2 - rhs.2) / divisor * 3.0,
-                    )
-                })
-                .collect::<Vec<_>>();
-
-            let k = n - 1;
-            let mut diag = vec![2.0; k];
-
-            for i in 1..k {
-                let multiplier = lower_diag[i - 1] / diag[i - 1];
-                diag[i] -= multiplier * upper_diag[i - 1];
-
-                let term = free_term[i - 1];
-                free_term[i].0 -= term.0 * multiplier;
-                free_term[i].1 -= term.1 * multiplier;
-                free_term[i].2 -= term.2 * multiplier;
-            }
+        self.bernstein_points = Self::get_bernstein_points(
+            &points,
+            self.closed,
+            self.clamping(),
+            self.parameterization,
+        );
+    }
 
-            for i in (1..k).rev() {
-                let multiplier = upper_diag[i - 1] / diag[i];
-                let term = free_term[i];
-                free_term[i - 1].0 -= term.0 * multiplier;
-                free_term[i - 1].1 -= term.1 * multiplier;
-                free_term[i - 1].2 -= term.2 * multiplier;
-                free_term[i].0 /= diag[i];
-                free_term[i].1 /= diag[i];
-                free_term[i].2 /= diag[i];
-            }
+    pub fn set_end_tangents(
+        &mut self,
+        end_tangents: [Vector3<f64>; 2],
+        all_points: &HashMap<u64, Point>,
+    ) {
+        self.end_tangents = end_tangents;
+
+        let points = self
+            .points
+            .iter()
+            .map(|p| all_points[&p.id].clone())
+            .collect::<Vec<_>>();
+
+        self.bernstein_points = Self::get_bernstein_points(
+            &points,
+            self.closed,
+            self.clamping(),
+            self.parameterization,
+        );
+    }
 
-            free_term[0].0 /= diag[0];
-            free_term[0].1 /= diag[0];
-            free_term[0].2 /= diag[0];
+    fn clamping(&self) -> Option<[Vector3<f64>; 2]> {
+        self.clamped.then_some(self.end_tangents)
+    }
 
-            let c: Vec<_> = [&[(0.0, 0.0, 0.0)], free_term.as_slice(), &[(0.0, 0.0, 0.0)]].concat();
+    fn estimated_end_tangents(
+        points: &[Point],
+        parameterization: BezierIntParameterization,
+    ) -> [Vector3<f64>; 2] {
+        let positions = Self::positions(points);
+        let n = positions.len();
+        if n < 2 {
+            return [Vector3::zeros(); 2];
+        }
 
-            let d = c
-                .iter()
-                .tuple_windows()
-                .zip(lengths.iter())
-                .map(|((c1, c2), len)| {
-                    (
-                        (c2.0 - c1.0) / len / 3.0,
-                        (c2.1 - c1.1) / len / 3.0,
-                        (c2.2 - c1.2) / len / 3.0,
-                    )
-                })
-                .collect::<Vec<_>>();
-
-            let b: Vec<_> = multizip((
-                points.iter().tuple_windows(),
-                c.iter(),
-                d.iter(),
-                lengths.iter(),
-            ))
-            .map(|((p1, p2), c, d, len)| {
-                (
-                    (p2.transformer.position.0 - p1.transformer.position.0) / len
-                        - c.0 * len
-                        - d.0 * len * len,
-                    (p2.transformer.position.1 - p1.transformer.position.1) / len
-                        - c.1 * len
-                        - d.1 * len * len,
-                    (p2.transformer.position.2 - p1.transformer.position.2) / len
-                        - c.2 * len
-                        - d.2 * len * len,
+        let (intervals, slopes) = Self::intervals_and_slopes(&positions, n - 1, parameterization);
+        if n == 2 {
+            return [slopes[0], slopes[0]];
+        }
+
+        [
+            Self::end_tangent((slopes[0], intervals[0]), (slopes[1], intervals[1])),
+            Self::end_tangent(
+                (slopes[n - 2], intervals[n - 2]),
+                (slopes[n - 3], intervals[n - 3]),
+            ),
+        ]
+    }
+
+    fn positions(points: &[Point]) -> Vec<Vector3<f64>> {
+        points
+            .iter()
+            .map(|p| {
+                Vector3::new(
+                    p.transformer.position.0,
+                    p.transformer.position.1,
+                    p.transformer.position.2,
                 )
             })
-            .collect();
+            .collect()
+    }
+
+    fn intervals_and_slopes(
+        positions: &[Vector3<f64>],
+        segments: usize,
+        parameterization: BezierIntParameterization,
+    ) -> (Vec<f64>, Vec<Vector3<f64>>) {
+        let n = positions.len();
+        let next = |i: usize| positions[(i + 1) % n];
+
+        let intervals = (0..segments)
+            .map(|i| {
+                let length = (next(i) - positions[i]).norm();
+                let interval = match parameterization {
+                    BezierIntParameterization::Uniform => 1.0,
+                    BezierIntParameterization::Centripetal => length.sqrt(),
+                    BezierIntParameterization::ChordLength => length,
+                };
+                interval.max(1e-9)
+            })
+            .collect::<Vec<_>>();
+        let slopes = (0..segments)
+            .map(|i| (next(i) - positions[i]) / intervals[i])
+            .collect::<Vec<_>>();
+
+        (intervals, slopes)
+    }
+
+    fn get_bernstein_points(
+        points: &[Point],
+        closed: bool,
+        end_tangents: Option<[Vector3<f64>; 2]>,
+        parameterization: BezierIntParameterization,
+    ) -> Vec<BezierIntBernsteinPoint> {
+        let mut positions = Self::positions(points);
+
+        if closed && positions.len() > 3 && (positions[0] - positions.last().unwrap()).norm() < 1e-9
+        {
+            positions.pop();
+        }
+        let closed = closed && positions.len() > 2;
+
+        if positions.len() < 2 {
+            return vec![];
+        }
+        if positions.len() == 2 {
+            return [positions[0], positions[0], positions[1], positions[1]]
+                .iter()
+                .map(Self::to_bernstein_point)
+                .collect();
+        }
+
+        let n = positions.len();
+        let segments = if closed { n } else { n - 1 };
+        let next = |i: usize| positions[(i + 1) % n];
+        let (intervals, slopes) =
+            Self::intervals_and_slopes(&positions, segments, parameterization);
+
+        let mut lower = vec![0.0; n];
+        let mut diagonal = vec![0.0; n];
+        let mut upper = vec![0.0; n];
+        let mut free_terms = vec![Vector3::zeros(); n];
+
+        let interior = if closed { 0..n } else { 1..n - 1 };
+        for i in interior {
+            let previous = (i + segments - 1) % segments;
+            let current = i % segments;
+            lower[i] = intervals[current];
+            diagonal[i] = 2.0 * (intervals[previous] + intervals[current]);
+            upper[i] = intervals[previous];
+            free_terms[i] = (slopes[previous] * intervals[current]
+                + slopes[current] * intervals[previous])
+                * 3.0;
+        }
+
+        let tangents = if closed {
+            solve_cyclic_tridiagonal(&lower, &diagonal, &upper, &free_terms)
+        } else {
+            if let Some([start_tangent, end_tangent]) = end_tangents {
+                diagonal[0] = 1.0;
+                free_terms[0] = start_tangent;
+                diagonal[n - 1] = 1.0;
+                free_terms[n - 1] = end_tangent;
+            } else {
+                diagonal[0] = 2.0;
+                upper[0] = 1.0;
+                free_terms[0] = slopes[0] * 3.0;
+                lower[n - 1] = 1.0;
+                diagonal[n - 1] = 2.0;
+                free_terms[n - 1] = slopes[n - 2] * 3.0;
+            }
+            solve_tridiagonal(&lower, &diagonal, &upper, &free_terms)
+        };
+
+        (0..segments)
+            .flat_map(|i| {
+                [
+                    positions[i],
+                    positions[i] + tangents[i] * intervals[i] / 3.0,
+                    next(i) - tangents[(i + 1) % n] * intervals[i] / 3.0,
+                ]
+            })
+            .chain([positions[segments % n]])
+            .map(|p| Self::to_bernstein_point(&p))
+            .collect()
+    }
+
+    fn end_tangent(end: (Vector3<f64>, f64), neighbour: (Vector3<f64>, f64)) -> Vector3<f64> {
+        let (end_slope, end_interval) = end;
+        let (neighbour_slope, neighbour_interval) = neighbour;
+        (end_slope * (2.0 * end_interval + neighbour_interval) - neighbour_slope * end_interval)
+            / (end_interval + neighbour_interval)
+    }
 
-            multizip((points, b, c, d, lengths))
-                .map(|(a, b, c, d, len)| {
-                    (
-                        a,
-                        (b.0 * len, b.1 * len, b.2 * len),
-                        (c.0 * len * len, c.1 * len * len, c.2 * len * len),
-                        (
-                            d.0 * len * len * len,
-                            d.1 * len * len * len,
-                            d.2 * len * len * len,
-                        ),
-                    )
-                })
-                .flat_map(|(a, b, c, _)| {
-                    let p1 = BezierIntBernsteinPoint {
-                        transformer: a.transformer.clone(),
-                    };
-                    let p2 = BezierIntBernsteinPoint {
-                        transformer: LittleTransformer {
-                            position: (
-                                a.transformer.position.0 + b.0 / 3.0,
-                                a.transformer.position.1 + b.1 / 3.0,
-                                a.transformer.position.2 + b.2 / 3.0,
-                            ),
-                        },
-                    };
-                    let p3 = BezierIntBernsteinPoint {
-                        transformer: LittleTransformer {
-                            position: (
-                                a.transformer.position.0 + b.0 * 2.0 / 3.0 + c.0 / 3.0,
-                                a.transformer.position.1 + b.1 * 2.0 / 3.0 + c.1 / 3.0,
-                                a.transformer.position.2 + b.2 * 2.0 / 3.0 + c.2 / 3.0,
-                            ),
-                        },
-                    };
-                    [p1, p2, p3]
-                })
-                .chain([BezierIntBernsteinPoint {
-                    transformer: points.last().unwrap().transformer.clone(),
-                }])
-                .collect()
+    fn to_bernstein_point(position: &Vector3<f64>) -> BezierIntBernsteinPoint {
+        BezierIntBernsteinPoint {
+            transformer: LittleTransformer {
+                position: (position.x, position.y, position.z),
+            },
         }
     }
 
@@ -269,6 +329,11 @@ impl BezierInt {
             .map(|p| all_points[&p.id].clone())
             .collect::<Vec<_>>();
 
-        self.bernstein_points = Self::get_bernstein_points(&points);
+        self.bernstein_points = Self::get_bernstein_points(
+            &points,
+            self.closed,
+            self.clamping(),
+            self.parameterization,
+        );
     }
 }
@@ -0,0 +1,255 @@
+use nalgebra::Vector3;
+
+const DERIVATIVE_STEP: f64 = 1e-4;
+const SIDE_SAMPLES: usize = 16;
+const PROJECTION_ITERATIONS: usize = 40;
+
+pub enum PatchGeometry {
+    Bezier([[Vector3<f64>; 4]; 4]),
+    Gregory([Vector3<f64>; 20]),
+}
+
+pub struct ContinuityPatch {
+    pub name: String,
+    pub geometry: PatchGeometry,
+}
+
+pub struct BoundaryContinuity {
+    pub first: usize,
+    pub second: usize,
+    pub positions: Vec<Vector3<f64>>,
+    pub max_gap: f64,
+    pub max_angle: f64,
+}
+
+impl ContinuityPatch {
+    pub fn from_de_boor(name: String, de_boor: [[Vector3<f64>; 4]; 4]) -> Self {
+        let rows = de_boor.map(|row| de_boor_to_bernstein(&row));
+        let columns: [[Vector3<f64>; 4]; 4] = std::array::from_fn(|c| {
+            de_boor_to_bernstein(&[rows[0][c], rows[1][c], rows[2][c], rows[3][c]])
+        });
+
+        Self {
+            name,
+            geometry: PatchGeometry::Bezier(std::array::from_fn(|r| {
+                std::array::from_fn(|c| columns[c][r])
+            })),
+        }
+    }
+
+    pub fn value(&self, u: f64, v: f64) -> Vector3<f64> {
+        match &self.geometry {
+            PatchGeometry::Bezier(net) => bezier(&net.map(|row| bezier(&row, v)), u),
+            PatchGeometry::Gregory(p) => {
+                let inner = |a: f64, first: Vector3<f64>, b: f64, second: Vector3<f64>| {
+                    if a + b < f64::EPSILON {
+                        (first + second) / 2.0
+                    } else {
+                        (first * a + second * b) / (a + b)
+                    }
+                };
+                let rows = [
+                    [p[0], p[1], p[2], p[3]],
+                    [
+                        p[4],
+                        inner(u, p[12], v, p[16]),
+                        inner(u, p[13], 1.0 - v, p[17]),
+                        p[5],
+                    ],
+                    [
+                        p[6],
+                        inner(1.0 - u, p[14], v, p[18]),
+                        inner(1.0 - u, p[15], 1.0 - v, p[19]),
+                        p[7],
+                    ],
+                    [p[8], p[9], p[10], p[11]],
+                ];
+                bezier(&rows.map(|row| bezier(&row, v)), u)
+            }
+        }
+    }
+
+    pub fn normal(&self, u: f64, v: f64) -> Option<Vector3<f64>> {
+        let (u0, u1) = (
+            (u - DERIVATIVE_STEP).max(0.0),
+            (u + DERIVATIVE_STEP).min(1.0),
+        );
+        let (v0, v1) = (
+            (v - DERIVATIVE_STEP).max(0.0),
+            (v + DERIVATIVE_STEP).min(1.0),
+        );
+        let du = (self.value(u1, v) - self.value(u0, v)) / (u1 - u0);
+        let dv = (self.value(u, v1) - self.value(u, v0)) / (v1 - v0);
+        let normal = du.cross(&dv);
+
+        if normal.norm() < 1e-12 {
+            None
+        } else {
+            Some(normal.normalize())
+        }
+    }
+
+    fn side(side: usize, t: f64) -> (f64, f64) {
+        match side {
+            0 => (t, 0.0),
+            1 => (1.0, t),
+            2 => (t, 1.0),
+            _ => (0.0, t),
+        }
+    }
+
+    fn side_value(&self, side: usize, t: f64) -> Vector3<f64> {
+        let (u, v) = Self::side(side, t);
+        self.value(u, v)
+    }
+}
+
+struct Side {
+    patch: usize,
+    side: usize,
+    samples: Vec<Vector3<f64>>,
+    min: Vector3<f64>,
+    max: Vector3<f64>,
+}
+
+impl Side {
+    fn new(patches: &[ContinuityPatch], patch: usize, side: usize, tolerance: f64) -> Self {
+        let samples = (0..=SIDE_SAMPLES)
+            .map(|k| patches[patch].side_value(side, k as f64 / SIDE_SAMPLES as f64))
+            .collect::<Vec<_>>();
+        let margin = Vector3::repeat(tolerance);
+        let min = samples.iter().fold(samples[0], |min, p| min.inf(p)) - margin;
+        let max = samples.iter().fold(samples[0], |max, p| max.sup(p)) + margin;
+
+        Self {
+            patch,
+            side,
+            samples,
+            min,
+            max,
+        }
+    }
+
+    fn start(&self) -> Vector3<f64> {
+        self.samples[0]
+    }
+
+    fn end(&self) -> Vector3<f64> {
+        self.samples[SIDE_SAMPLES]
+    }
+
+    fn bounds(&self, point: &Vector3<f64>) -> bool {
+        (0..3).all(|i| point[i] >= self.min[i] && point[i] <= self.max[i])
+    }
+
+    fn project(&self, patches: &[ContinuityPatch], point: &Vector3<f64>) -> (f64, f64) {
+        let closest = (0..=SIDE_SAMPLES)
+            .min_by(|a, b| {
+                (self.samples[*a] - point)
+                    .norm()
+                    .total_cmp(&(self.samples[*b] - point).norm())
+            })
+            .unwrap();
+        let distance = |t: f64| (patches[self.patch].side_value(self.side, t) - point).norm();
+
+        let mut from = closest.saturating_sub(1) as f64 / SIDE_SAMPLES as f64;
+        let mut to = (closest + 1).min(SIDE_SAMPLES) as f64 / SIDE_SAMPLES as f64;
+        for _ in 0..PROJECTION_ITERATIONS {
+            let first = from + (to - from) / 3.0;
+            let second = to - (to - from) / 3.0;
+            if distance(first) < distance(second) {
+                to = second;
+            } else {
+                from = first;
+            }
+        }
+
+        let t = (from + to) / 2.0;
+        (t, distance(t))
+    }
+
+    fn lies_on(&self, other: &Side, patches: &[ContinuityPatch], tolerance: f64) -> bool {
+        other.bounds(&self.start())
+            && other.bounds(&self.end())
+            && other.project(patches, &self.start()).1 < tolerance
+            && other.project(patches, &self.end()).1 < tolerance
+    }
+}
+
+pub fn boundary_continuity(
+    patches: &[ContinuityPatch],
+    samples: usize,
+    tolerance: f64,
+) -> Vec<BoundaryContinuity> {
+    let samples = samples.max(1);
+    let sides = (0..patches.len())
+        .flat_map(|patch| (0..4).map(move |side| (patch, side)))
+        .map(|(patch, side)| Side::new(patches, patch, side, tolerance))
+        .filter(|side| (side.start() - side.end()).norm() >= tolerance)
+        .collect::<Vec<_>>();
+
+    let mut boundaries = vec![];
+    for first in sides.iter() {
+        for second in sides.iter().filter(|side| side.patch != first.patch) {
+            if !first.lies_on(second, patches, tolerance) {
+                continue;
+            }
+            if first.patch > second.patch && second.lies_on(first, patches, tolerance) {
+                continue;
+            }
+
+            boundaries.push(compare_sides(patches, first, second, samples));
+        }
+    }
+    boundaries
+}
+
+fn compare_sides(
+    patches: &[ContinuityPatch],
+    first: &Side,
+    second: &Side,
+    samples: usize,
+) -> BoundaryContinuity {
+    let mut max_gap: f64 = 0.0;
+    let mut max_angle: f64 = 0.0;
+    for k in 0..samples {
+        let t = (k + 1) as f64 / (samples + 1) as f64;
+        let (au, av) = ContinuityPatch::side(first.side, t);
+        let position = patches[first.patch].value(au, av);
+        let (t, gap) = second.project(patches, &position);
+        let (bu, bv) = ContinuityPatch::side(second.side, t);
+
+        max_gap = max_gap.max(gap);
+        if let (Some(na), Some(nb)) = (
+            patches[first.patch].normal(au, av),
+            patches[second.patch].normal(bu, bv),
+        ) {
+            max_angle = max_angle.max(na.dot(&nb).abs().min(1.0).acos().to_degrees());
+        }
+    }
+
+    BoundaryContinuity {
+        first: first.patch,
+        second: second.patch,
+        positions: first.samples.clone(),
+        max_gap,
+        max_angle,
+    }
+}
+
+fn bezier(points: &[Vector3<f64>; 4], t: f64) -> Vector3<f64> {
+    let s = 1.0 - t;
+    points[0] * (s * s * s)
+        + points[1] * (3.0 * s * s * t)
+        + points[2] * (3.0 * s * t * t)
+        + points[3] * (t * t * t)
+}
+
+fn de_boor_to_bernstein(d: &[Vector3<f64>; 4]) -> [Vector3<f64>; 4] {
+    [
+        (d[0] + d[1] * 4.0 + d[2]) / 6.0,
+        (d[1] * 4.0 + d[2] * 2.0) / 6.0,
+        (d[1] * 2.0 + d[2] * 4.0) / 6.0,
+        (d[1] + d[2] * 4.0 + d[3]) / 6.0,
+    ]
+}
@@ -0,0 +1,312 @@
+use nalgebra::{Vector3, Vector4};
+
+const GAUSS_NODES: [(f64, f64); 5] = [
+    (0.0, 0.5688888888888889),
+    (-0.5384693101056831, 0.47862867049936647),
+    (0.5384693101056831, 0.47862867049936647),
+    (-0.906179845938664, 0.23692688505618908),
+    (0.906179845938664, 0.23692688505618908),
+];
+const LENGTH_SUBINTERVALS: usize = 16;
+const NEWTON_ITERATIONS: usize = 8;
+
+pub struct CurveAnalysis {
+    segments: Vec<[Vector4<f64>; 4]>,
+}
+
+pub struct CurveFrame {
+    pub position: Vector3<f64>,
+    pub tangent: Vector3<f64>,
+    pub normal: Vector3<f64>,
+    pub curvature: f64,
+    pub torsion: f64,
+}
+
+pub struct RotationMinimizingFrame {
+    pub position: Vector3<f64>,
+    pub tangent: Vector3<f64>,
+    pub normal: Vector3<f64>,
+    pub binormal: Vector3<f64>,
+}
+
+pub struct CurveSample {
+    pub parameter: f64,
+    pub position: Vector3<f64>,
+}
+
+struct Derivatives {
+    position: Vector3<f64>,
+    first: Vector3<f64>,
+    second: Vector3<f64>,
+    third: Vector3<f64>,
+}
+
+impl CurveAnalysis {
+    pub fn from_bernstein_points(points: &[Vector3<f64>]) -> Self {
+        let weighted = points.iter().map(|p| (*p, 1.0)).collect::<Vec<_>>();
+        Self::from_weighted_bernstein_points(&weighted)
+    }
+
+    pub fn from_weighted_bernstein_points(points: &[(Vector3<f64>, f64)]) -> Self {
+        let homogeneous = points
+            .iter()
+            .map(|(p, w)| Vector4::new(p.x * w, p.y * w, p.z * w, *w))
+            .collect::<Vec<_>>();
+        let segments = if homogeneous.len() < 4 {
+            vec![]
+        } else {
+            (0..(homogeneous.len() - 1) / 3)
+                .map(|i| {
+                    [
+                        homogeneous[3 * i],
+                        homogeneous[3 * i + 1],
+                        homogeneous[3 * i + 2],
+                        homogeneous[3 * i + 3],
+                    ]
+                })
+                .collect()
+        };
+
+        Self { segments }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    pub fn frame(&self, parameter: f64) -> Option<CurveFrame> {
+        let (segment, t) = self.locate(parameter)?;
+        Some(Self::frame_at(&self.derivatives(segment, t)))
+    }
+
+    pub fn length(&self) -> f64 {
+        (0..self.segments.len())
+            .map(|segment| self.segment_length(segment, 0.0, 1.0))
+            .sum()
+    }
+
+    pub fn arc_length_samples(&self, count: usize) -> Vec<CurveSample> {
+        if self.segments.is_empty() || count == 0 {
+            return vec![];
+        }
+
+        let table = self.length_table();
+        let total = table.last().map(|(_, _, length)| *length).unwrap_or(0.0);
+        (0..count)
+            .map(|k| {
+                let target = if count == 1 {
+                    0.0
+                } else {
+                    total * k as f64 / (count - 1) as f64
+                };
+                let (segment, t) = self.invert_length(&table, target);
+                CurveSample {
+                    parameter: (segment as f64 + t) / self.segments.len() as f64,
+                    position: self.derivatives(segment, t).position,
+                }
+            })
+            .collect()
+    }
+
+    pub fn curvature_comb(
+        &self,
+        samples_per_segment: usize,
+        scale: f64,
+    ) -> Vec<(Vector3<f64>, Vector3<f64>)> {
+        let samples_per_segment = samples_per_segment.max(1);
+        let mut comb = vec![];
+        for segment in 0..self.segments.len() {
+            let first = if segment == 0 { 0 } else { 1 };
+            for k in first..=samples_per_segment {
+                let t = k as f64 / samples_per_segment as f64;
+                let frame = Self::frame_at(&self.derivatives(segment, t));
+                comb.push((
+                    frame.position,
+                    frame.position - frame.normal * frame.curvature * scale,
+                ));
+            }
+        }
+        comb
+    }
+
+    pub fn rotation_minimizing_frames(
+        &self,
+        count: usize,
+        reference: &Vector3<f64>,
+    ) -> Vec<RotationMinimizingFrame> {
+        let samples = self.arc_length_samples(count);
+        let mut frames: Vec<RotationMinimizingFrame> = vec![];
+        for sample in samples.iter() {
+            let (segment, t) = self.locate(sample.parameter).unwrap();
+            let first = self.derivatives(segment, t).first;
+            let tangent = if first.norm() > f64::EPSILON {
+                first.normalize()
+            } else {
+                frames
+                    .last()
+                    .map(|frame| frame.tangent)
+                    .unwrap_or(Vector3::x())
+            };
+
+            let normal = match frames.last() {
+                None => Self::perpendicular(reference, &tangent),
+                Some(previous) => {
+                    let v1 = sample.position - previous.position;
+                    let c1 = v1.dot(&v1);
+                    let (normal, previous_tangent) = if c1 > f64::EPSILON {
+                        (
+                            previous.normal - v1 * (2.0 / c1 * v1.dot(&previous.normal)),
+                            previous.tangent - v1 * (2.0 / c1 * v1.dot(&previous.tangent)),
+                        )
+                    } else {
+                        (previous.normal, previous.tangent)
+                    };
+                    let v2 = tangent - previous_tangent;
+                    let c2 = v2.dot(&v2);
+                    let normal = if c2 > f64::EPSILON {
+                        normal - v2 * (2.0 / c2 * v2.dot(&normal))
+                    } else {
+                        normal
+                    };
+                    Self::perpendicular(&normal, &tangent)
+                }
+            };
+
+            frames.push(RotationMinimizingFrame {
+                position: sample.position,
+                tangent,
+                normal,
+                binormal: tangent.cross(&normal),
+            });
+        }
+        frames
+    }
+
+    fn perpendicular(vector: &Vector3<f64>, tangent: &Vector3<f64>) -> Vector3<f64> {
+        let projected = vector - tangent * tangent.dot(vector);
+        if projected.norm() > 1e-9 {
+            return projected.normalize();
+        }
+
+        let axis = if tangent.x.abs() < 0.9 {
+            Vector3::x()
+        } else {
+            Vector3::y()
+        };
+        (axis - tangent * tangent.dot(&axis)).normalize()
+    }
+
+    fn locate(&self, parameter: f64) -> Option<(usize, f64)> {
+        if self.segments.is_empty() {
+            return None;
+        }
+
+        let u = parameter.clamp(0.0, 1.0) * self.segments.len() as f64;
+        let segment = (u.floor() as usize).min(self.segments.len() - 1);
+        Some((segment, u - segment as f64))
+    }
+
+    fn frame_at(derivatives: &Derivatives) -> CurveFrame {
+        let binormal = derivatives.first.cross(&derivatives.second);
+        let speed = derivatives.first.norm();
+        let binormal_norm = binormal.norm();
+
+        let tangent = if speed > f64::EPSILON {
+            derivatives.first / speed
+        } else {
+            Vector3::zeros()
+        };
+        let (normal, curvature, torsion) = if speed > f64::EPSILON && binormal_norm > 1e-12 {
+            (
+                binormal.cross(&derivatives.first).normalize(),
+                binormal_norm / speed.powi(3),
+                binormal.dot(&derivatives.third) / binormal_norm.powi(2),
+            )
+        } else {
+            (Vector3::zeros(), 0.0, 0.0)
+        };
+
+        CurveFrame {
+            position: derivatives.position,
+            tangent,
+            normal,
+            curvature,
+            torsion,
+        }
+    }
+
+    fn derivatives(&self, segment: usize, t: f64) -> Derivatives {
+        let [p0, p1, p2, p3] = self.segments[segment];
+        let s = 1.0 - t;
+
+        let a = p0 * s.powi(3) + p1 * (3.0 * s * s * t) + p2 * (3.0 * s * t * t) + p3 * t.powi(3);
+        let a1 = ((p1 - p0) * (s * s) + (p2 - p1) * (2.0 * s * t) + (p3 - p2) * (t * t)) * 3.0;
+        let a2 = ((p2 - p1 * 2.0 + p0) * s + (p3 - p2 * 2.0 + p1) * t) * 6.0;
+        let a3 = (p3 - p2 * 3.0 + p1 * 3.0 - p0) * 6.0;
+
+        let position = a.xyz() / a.w;
+        let first = (a1.xyz() - position * a1.w) / a.w;
+        let second = (a2.xyz() - first * (2.0 * a1.w) - position * a2.w) / a.w;
+        let third =
+            (a3.xyz() - first * (3.0 * a2.w) - second * (3.0 * a1.w) - position * a3.w) / a.w;
+
+        Derivatives {
+            position,
+            first,
+            second,
+            third,
+        }
+    }
+
+    fn segment_length(&self, segment: usize, from: f64, to: f64) -> f64 {
+        let (middle, half) = ((from + to) / 2.0, (to - from) / 2.0);
+        GAUSS_NODES
+            .iter()
+            .map(|(node, weight)| {
+                weight * self.derivatives(segment, middle + half * node).first.norm()
+            })
+            .sum::<f64>()
+            * half
+    }
+
+    fn length_table(&self) -> Vec<(usize, f64, f64)> {
+        let mut table = vec![(0, 0.0, 0.0)];
+        let mut length = 0.0;
+        for segment in 0..self.segments.len() {
+            for k in 0..LENGTH_SUBINTERVALS {
+                let from = k as f64 / LENGTH_SUBINTERVALS as f64;
+                let to = (k + 1) as f64 / LENGTH_SUBINTERVALS as f64;
+                length += self.segment_length(segment, from, to);
+                table.push((segment, to, length));
+            }
+        }
+        table
+    }
+
+    fn invert_length(&self, table: &[(usize, f64, f64)], target: f64) -> (usize, f64) {
+        let index = table
+            .iter()
+            .position(|(_, _, length)| *length >= target)
+            .unwrap_or(table.len() - 1)
+            .max(1);
+        let (segment, to, to_length) = table[index];
+        let from = to - 1.0 / LENGTH_SUBINTERVALS as f64;
+        let from_length = table[index - 1].2;
+
+        let span = to_length - from_length;
+        if span <= f64::EPSILON {
+            return (segment, from);
+        }
+
+        let mut t = from + (target - from_length) / span * (to - from);
+        for _ in 0..NEWTON_ITERATIONS {
+            let error = from_length + self.segment_length(segment, from, t) - target;
+            let speed = self.derivatives(segment, t).first.norm();
+            if error.abs() < 1e-12 || speed <= f64::EPSILON {
+                break;
+            }
+            t = (t - error / speed).clamp(from, to);
+        }
+        (segment, t)
+    }
+}
@@ -0,0 +1,186 @@
+use math::linear_systems::{solve_banded_symmetric, solve_cyclic_banded_symmetric};
+use nalgebra::Vector3;
+
+const SMOOTHING: f64 = 1e-8;
+
+pub struct BSplineFit {
+    pub control_points: Vec<Vector3<f64>>,
+    pub max_deviation: f64,
+}
+
+pub fn fit_b_spline(samples: &[Vector3<f64>], closed: bool, tolerance: f64) -> Option<BSplineFit> {
+    let min_count = 4;
+    let max_count = samples.len();
+    if max_count < min_count {
+        return None;
+    }
+
+    let parameters = chord_length_parameters(samples, closed);
+
+    let mut best: Option<(usize, BSplineFit)> = None;
+    let mut low = min_count;
+    let mut count = min_count;
+    while let Some(fit) = fit_with_count(samples, &parameters, closed, count) {
+        if fit.max_deviation <= tolerance {
+            best = Some((count, fit));
+            break;
+        }
+        low = count + 1;
+        best = Some((count, fit));
+        if count == max_count {
+            break;
+        }
+        count = (count * 2).min(max_count);
+    }
+
+    let (mut high, mut fit) = best?;
+    if fit.max_deviation > tolerance {
+        return Some(fit);
+    }
+
+    while low < high {
+        let middle = (low + high) / 2;
+        match fit_with_count(samples, &parameters, closed, middle) {
+            Some(candidate) if candidate.max_deviation <= tolerance => {
+                high = middle;
+                fit = candidate;
+            }
+            _ => low = middle + 1,
+        }
+    }
+
+    Some(fit)
+}
+
+pub fn b_spline_from_bezier_chain(
+    bernstein_points: &[Vector3<f64>],
+    tolerance: f64,
+) -> Option<Vec<Vector3<f64>>> {
+    let count = bernstein_points.len();
+    if count < 4 || !(count - 1).is_multiple_of(3) {
+        return None;
+    }
+
+    let segments = (count - 1) / 3;
+    let b = bernstein_points;
+    let mut control_points = vec![Vector3::zeros(); segments + 3];
+    for i in 0..segments {
+        control_points[i + 1] = b[3 * i + 1] * 2.0 - b[3 * i + 2];
+    }
+    control_points[segments + 1] = b[3 * segments - 1] * 2.0 - b[3 * segments - 2];
+    control_points[0] = b[0] * 6.0 - control_points[1] * 4.0 - control_points[2];
+    control_points[segments + 2] =
+        b[3 * segments] * 6.0 - control_points[segments + 1] * 4.0 - control_points[segments];
+
+    let p = &control_points;
+    let max_deviation = (0..segments)
+        .flat_map(|j| {
+            [
+                ((p[j] + p[j + 1] * 4.0 + p[j + 2]) / 6.0 - b[3 * j]).norm(),
+                ((p[j + 1] * 2.0 + p[j + 2]) / 3.0 - b[3 * j + 1]).norm(),
+                ((p[j + 1] + p[j + 2] * 2.0) / 3.0 - b[3 * j + 2]).norm(),
+            ]
+        })
+        .chain([
+            ((p[segments] + p[segments + 1] * 4.0 + p[segments + 2]) / 6.0 - b[3 * segments])
+                .norm(),
+        ])
+        .fold(0.0, f64::max);
+
+    (max_deviation <= tolerance).then_some(control_points)
+}
+
+pub fn fit_with_count(
+    samples: &[Vector3<f64>],
+    parameters: &[f64],
+    closed: bool,
+    count: usize,
+) -> Option<BSplineFit> {
+    let mut diagonals = vec![vec![0.0; count]; 4];
+    let mut free_terms = vec![Vector3::zeros(); count];
+
+    for (sample, parameter) in samples.iter().zip(parameters.iter()) {
+        let (indices, weights) = basis(*parameter, closed, count);
+        for i in 0..4 {
+            for j in i..4 {
+                diagonals[j - i][indices[i]] += weights[i] * weights[j];
+            }
+            free_terms[indices[i]] += sample * weights[i];
+        }
+    }
+
+    let second_differences = if closed { count } else { count - 2 };
+    for i in 0..second_differences {
+        let weights = [1.0, -2.0, 1.0];
+        for a in 0..3 {
+            for b in a..3 {
+                diagonals[b - a][(i + a) % count] += SMOOTHING * weights[a] * weights[b];
+            }
+        }
+    }
+
+    let control_points = if closed {
+        solve_cyclic_banded_symmetric(&diagonals, &free_terms)?
+    } else {
+        solve_banded_symmetric(&diagonals, &free_terms)?
+    };
+
+    let max_deviation = samples
+        .iter()
+        .zip(parameters.iter())
+        .map(|(sample, parameter)| (evaluate(&control_points, *parameter, closed) - sample).norm())
+        .fold(0.0, f64::max);
+
+    Some(BSplineFit {
+        control_points,
+        max_deviation,
+    })
+}
+
+pub fn chord_length_parameters(samples: &[Vector3<f64>], closed: bool) -> Vec<f64> {
+    let mut parameters = Vec::with_capacity(samples.len());
+    let mut length = 0.0;
+    parameters.push(0.0);
+    for i in 1..samples.len() {
+        length += (samples[i] - samples[i - 1]).norm();
+        parameters.push(length);
+    }
+    if closed {
+        length += (samples[0] - samples[samples.len() - 1]).norm();
+    }
+
+    if length == 0.0 {
+        return vec![0.0; samples.len()];
+    }
+
+    parameters.iter().map(|p| p / length).collect()
+}
+
+pub fn evaluate(control_points: &[Vector3<f64>], parameter: f64, closed: bool) -> Vector3<f64> {
+    let (indices, weights) = basis(parameter, closed, control_points.len());
+    (0..4).fold(Vector3::zeros(), |sum, i| {
+        sum + control_points[indices[i]] * weights[i]
+    })
+}
+
+fn basis(parameter: f64, closed: bool, count: usize) -> ([usize; 4], [f64; 4]) {
+    let segments = if closed { count } else { count - 3 };
+    let u = parameter.clamp(0.0, 1.0) * segments as f64;
+    let segment = (u.floor() as usize).min(segments - 1);
+    let t = u - segment as f64;
+
+    let indices = [
+        segment % count,
+        (segment + 1) % count,
+        (segment + 2) % count,
+        (segment + 3) % count,
+    ];
+    let weights = [
+        (1.0 - t).powi(3) / 6.0,
+        (3.0 * t.powi(3) - 6.0 * t.powi(2) + 4.0) / 6.0,
+        (-3.0 * t.powi(3) + 3.0 * t.powi(2) + 3.0 * t + 1.0) / 6.0,
+        t.powi(3) / 6.0,
+    ];
+
+    (indices, weights)
+}
@@ -0,0 +1,111 @@
+use std::collections::{HashMap, VecDeque};
+
+use nalgebra::Vector3;
+
+use crate::domain::point::Point;
+
+const JOIN_TOLERANCE: f64 = 1e-3;
+
+pub struct JoinedCurveCandidate {
+    pub points: Vec<u64>,
+    pub start: Vector3<f64>,
+    pub end: Vector3<f64>,
+}
+
+pub struct JoinedCurve {
+    pub points: Vec<u64>,
+    pub merged_points: Vec<(u64, u64)>,
+    pub chain: Vec<(usize, bool)>,
+}
+
+pub fn join_curves(
+    candidates: &[JoinedCurveCandidate],
+    all_points: &HashMap<u64, Point>,
+    merge_end_points: bool,
+) -> Option<JoinedCurve> {
+    let chain = chain_curves(candidates)?;
+
+    let mut points: Vec<u64> = vec![];
+    let mut merged_points = vec![];
+    for (index, reversed) in chain.iter().copied() {
+        let mut curve_points = candidates[index].points.clone();
+        if reversed {
+            curve_points.reverse();
+        }
+
+        if let (Some(last), Some(first)) = (points.last(), curve_points.first()) {
+            if *last == *first
+                || coincide(position(all_points, *last), position(all_points, *first))
+            {
+                if *last != *first && merge_end_points {
+                    merged_points.push((*last, *first));
+                }
+                curve_points.remove(0);
+            }
+        }
+
+        points.extend(curve_points);
+    }
+
+    Some(JoinedCurve {
+        points,
+        merged_points,
+        chain,
+    })
+}
+
+pub fn chain_curves(candidates: &[JoinedCurveCandidate]) -> Option<Vec<(usize, bool)>> {
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let mut chain = VecDeque::from([(0, false)]);
+    let mut used = vec![false; candidates.len()];
+    used[0] = true;
+    let mut head = candidates[0].start;
+    let mut tail = candidates[0].end;
+
+    while chain.len() < candidates.len() {
+        let (index, reversed, at_tail) =
+            (0..candidates.len()).filter(|i| !used[*i]).find_map(|i| {
+                let candidate = &candidates[i];
+                if coincide(candidate.start, tail) {
+                    Some((i, false, true))
+                } else if coincide(candidate.end, tail) {
+                    Some((i, true, true))
+                } else if coincide(candidate.end, head) {
+                    Some((i, false, false))
+                } else if coincide(candidate.start, head) {
+                    Some((i, true, false))
+                } else {
+                    None
+                }
+            })?;
+
+        used[index] = true;
+        let candidate = &candidates[index];
+        let (start, end) = if reversed {
+            (candidate.end, candidate.start)
+        } else {
+            (candidate.start, candidate.end)
+        };
+        if at_tail {
+            chain.push_back((index, reversed));
+            tail = end;
+        } else {
+            chain.push_front((index, reversed));
+            head = start;
+        }
+    }
+
+    Some(chain.into())
+}
+
+fn position(all_points: &HashMap<u64, Point>, id: u64) -> Vector3<f64> {
+    let position = all_points[&id].transformer.position;
+    Vector3::new(position.0, position.1, position.2)
+}
+
+fn coincide(a: Vector3<f64>, b: Vector3<f64>) -> bool {
+    (a - b).norm() < JOIN_TOLERANCE
+}
@@ -0,0 +1,49 @@
+pub const MILLIMETERS_PER_UNIT: f32 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CutterType {
+    Flat,
+    Spherical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cutter {
+    pub cutter_type: CutterType,
+    pub radius: f32,
+    pub cutting_length: f32,
+}
+
+impl Cutter {
+    pub fn new(cutter_type: CutterType, radius: f32, cutting_length: f32) -> Self {
+        Self {
+            cutter_type,
+            radius,
+            cutting_length,
+        }
+    }
+
+    pub fn height_at(&self, distance: f32) -> Option<f32> {
+        if distance > self.radius {
+            return None;
+        }
+
+        match self.cutter_type {
+            CutterType::Flat => Some(0.0),
+            CutterType::Spherical => {
+                Some(self.radius - (self.radius * self.radius - distance * distance).sqrt())
+            }
+        }
+    }
+
+    pub fn name(&self) -> String {
+        let prefix = match self.cutter_type {
+            CutterType::Flat => "f",
+            CutterType::Spherical => "k",
+        };
+        format!(
+            "{}{:02}",
+            prefix,
+            (2.0 * self.radius * MILLIMETERS_PER_UNIT).round() as u32
+        )
+    }
+}
@@ -1,8 +1,11 @@
 use derive_new::new;
 
+use crate::domain::surface_display_mode::SurfaceDisplayMode;
+
 #[derive(Debug, Clone, new)]
 pub struct GregorySettingsUpdated {
     pub gregory_id: u64,
     pub tess_level: u8,
     pub draw_vectors: bool,
+    pub display_mode: SurfaceDisplayMode,
 }
@@ -0,0 +1,9 @@
+pub struct NurbsCurveDeleted {
+    pub id: u64,
+}
+
+impl NurbsCurveDeleted {
+    pub fn new(id: u64) -> Self {
+        Self { id }
+    }
+}
@@ -0,0 +1,13 @@
+pub struct NurbsCurveDrawPolygonSet {
+    pub curve_id: u64,
+    pub draw_polygon: bool,
+}
+
+impl NurbsCurveDrawPolygonSet {
+    pub fn new(curve_id: u64, draw_polygon: bool) -> Self {
+        Self {
+            curve_id,
+            draw_polygon,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub struct NurbsCurvePointMoved {
+    pub curve_id: u64,
+}
+
+impl NurbsCurvePointMoved {
+    pub fn new(curve_id: u64) -> Self {
+        Self { curve_id }
+    }
+}
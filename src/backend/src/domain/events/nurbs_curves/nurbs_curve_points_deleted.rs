@@ -0,0 +1,10 @@
+pub struct NurbsCurvePointsDeleted {
+    pub id: u64,
+    pub deleted_points: Vec<u64>,
+}
+
+impl NurbsCurvePointsDeleted {
+    pub fn new(id: u64, deleted_points: Vec<u64>) -> Self {
+        Self { id, deleted_points }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::domain::nurbs_curve::NurbsCurve;
+
+pub struct NurbsCurveRenamed {
+    pub id: u64,
+    pub name: String,
+}
+
+impl NurbsCurveRenamed {
+    pub fn new(curve: &NurbsCurve) -> Self {
+        Self {
+            id: curve.id,
+            name: curve.name.clone(),
+        }
+    }
+}
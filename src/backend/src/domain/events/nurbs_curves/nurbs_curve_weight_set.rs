@@ -0,0 +1,15 @@
+pub struct NurbsCurveWeightSet {
+    pub curve_id: u64,
+    pub point_id: u64,
+    pub weight: f64,
+}
+
+impl NurbsCurveWeightSet {
+    pub fn new(curve_id: u64, point_id: u64, weight: f64) -> Self {
+        Self {
+            curve_id,
+            point_id,
+            weight,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub struct NurbsSurfacePointsSelected {
+    pub surface_id: u64,
+}
+
+impl NurbsSurfacePointsSelected {
+    pub fn new(surface_id: u64) -> Self {
+        Self { surface_id }
+    }
+}
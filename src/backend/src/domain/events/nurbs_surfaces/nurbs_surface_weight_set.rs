@@ -0,0 +1,15 @@
+pub struct NurbsSurfaceWeightSet {
+    pub surface_id: u64,
+    pub point_id: u64,
+    pub weight: f64,
+}
+
+impl NurbsSurfaceWeightSet {
+    pub fn new(surface_id: u64, point_id: u64, weight: f64) -> Self {
+        Self {
+            surface_id,
+            point_id,
+            weight,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+pub struct PointAddedToNurbsCurve {
+    pub point_id: u64,
+    pub curve_id: u64,
+    pub point_name: String,
+    pub index: usize,
+}
+
+impl PointAddedToNurbsCurve {
+    pub fn new(point_id: u64, curve_id: u64, point_name: String, index: usize) -> Self {
+        Self {
+            point_id,
+            curve_id,
+            point_name,
+            index,
+        }
+    }
+}
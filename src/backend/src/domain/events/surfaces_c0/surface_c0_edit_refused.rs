@@ -0,0 +1,10 @@
+pub struct SurfaceC0EditRefused {
+    pub id: u64,
+    pub reason: String,
+}
+
+impl SurfaceC0EditRefused {
+    pub fn new(id: u64, reason: String) -> Self {
+        Self { id, reason }
+    }
+}
@@ -1,4 +1,6 @@
 pub mod surface_c2_created;
 pub mod surface_c2_deleted;
+pub mod surface_c2_edit_refused;
 pub mod surface_c2_points_selected;
+pub mod surface_c2_resized;
 pub mod surface_c2_updated;
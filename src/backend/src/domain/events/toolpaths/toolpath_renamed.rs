@@ -0,0 +1,7 @@
+use derive_new::new;
+
+#[derive(Debug, Clone, new)]
+pub struct ToolpathRenamed {
+    pub toolpath_id: u64,
+    pub name: String,
+}
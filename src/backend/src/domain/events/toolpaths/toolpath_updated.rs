@@ -0,0 +1,9 @@
+use derive_new::new;
+use math::vector3::Vector3;
+
+#[derive(Debug, Clone, new)]
+pub struct ToolpathUpdated {
+    pub toolpath_id: u64,
+    pub points: Vec<Vector3>,
+    pub visible: bool,
+}
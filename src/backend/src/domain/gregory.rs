@@ -6,16 +6,35 @@ use itertools::Itertools;
 use math::vector3::Vector3;
 
 use super::point::Point;
+use super::surface_display_mode::SurfaceDisplayMode;
+
+const CORNER_TOLERANCE: f32 = 1e-4;
 
 #[derive(Debug, Clone)]
 pub struct Gregory {
     pub id: u64,
     pub name: String,
     pub patches: Vec<GregoryPatch>,
-    pub triangle: Triangle,
+    pub polygon: Polygon,
     pub tess_level: u8,
+    pub display_mode: SurfaceDisplayMode,
     pub vectors: Vec<GregoryVector>,
     pub draw_vectors: bool,
+    pub shape: GregoryShape,
+    pub coons: bool,
+}
+
+#[derive(Debug, Clone, Copy, new)]
+pub struct GregoryShape {
+    pub tangent_scale: f32,
+    pub center_offset: Vector3,
+    pub twist_blend: f32,
+}
+
+impl Default for GregoryShape {
+    fn default() -> Self {
+        Self::new(1.0, Vector3::zero(), 1.0)
+    }
 }
 
 #[derive(Debug, Clone, new)]
@@ -34,17 +53,39 @@ pub struct GregoryVector {
 }
 
 impl Gregory {
-    pub fn new(id: u64, triangle: Triangle, points: &HashMap<u64, Point>) -> Self {
-        let t = GregoryTriangle::from_triangle(&triangle, points);
+    pub fn new(id: u64, polygon: Polygon, points: &HashMap<u64, Point>) -> Self {
+        let shape = GregoryShape::default();
+        let t = GregoryPolygon::from_polygon(&polygon, &shape, points);
 
         Self {
             id,
             name: format!("Gregory {}", id),
             patches: t.patches.iter().cloned().collect(),
-            triangle,
+            polygon,
             tess_level: 4,
+            display_mode: SurfaceDisplayMode::default(),
             draw_vectors: false,
             vectors: Self::get_vectors(&t),
+            shape,
+            coons: false,
+        }
+    }
+
+    pub fn new_coons(id: u64, polygon: Polygon, points: &HashMap<u64, Point>) -> Self {
+        let shape = GregoryShape::default();
+        let t = GregoryPolygon::coons(&polygon, &shape, points);
+
+        Self {
+            id,
+            name: format!("Coons {}", id),
+            patches: t.patches.to_vec(),
+            polygon,
+            tess_level: 4,
+            display_mode: SurfaceDisplayMode::default(),
+            draw_vectors: false,
+            vectors: Self::get_vectors(&t),
+            shape,
+            coons: true,
         }
     }
 
@@ -53,13 +94,17 @@ impl Gregory {
     }
 
     pub fn recalculate_mesh(&mut self, points: &HashMap<u64, Point>) {
-        let t = GregoryTriangle::from_triangle(&self.triangle, points);
+        let t = if self.coons {
+            GregoryPolygon::coons(&self.polygon, &self.shape, points)
+        } else {
+            GregoryPolygon::from_polygon(&self.polygon, &self.shape, points)
+        };
         self.patches = t.patches.iter().cloned().collect();
         self.vectors = Self::get_vectors(&t);
     }
 
     pub fn related_points(&self) -> HashSet<u64> {
-        self.triangle
+        self.polygon
             .edges
             .iter()
             .flat_map(|e| e.patch_points.iter().flat_map(|p| p))
@@ -67,33 +112,52 @@ impl Gregory {
             .collect()
     }
 
+    pub fn replace_point(&mut self, old_point: u64, new_point: u64) {
+        for edge in self.polygon.edges.iter_mut() {
+            edge.edge_points
+                .iter_mut()
+                .chain(edge.patch_points.iter_mut().flatten())
+                .filter(|id| **id == old_point)
+                .for_each(|id| *id = new_point);
+        }
+    }
+
     pub fn update_settings(&mut self, tess_level: u8, draw_vectors: bool) {
         self.tess_level = tess_level;
         self.draw_vectors = draw_vectors;
     }
 
-    fn get_vectors(triangle: &GregoryTriangle) -> Vec<GregoryVector> {
-        triangle
+    pub fn set_display_mode(&mut self, display_mode: SurfaceDisplayMode) {
+        self.display_mode = display_mode;
+    }
+
+    pub fn update_shape(&mut self, shape: GregoryShape, points: &HashMap<u64, Point>) {
+        self.shape = shape;
+        self.recalculate_mesh(points);
+    }
+
+    fn get_vectors(polygon: &GregoryPolygon) -> Vec<GregoryVector> {
+        polygon
             .u_diff
             .iter()
             .flatten()
-            .zip(triangle.twist_u_p.iter().flatten())
+            .zip(polygon.twist_u_p.iter().flatten())
             .map(|(v, p)| GregoryVector::new([*p, *p - v.get_normalized()]))
             .chain(
-                triangle
+                polygon
                     .v_diff
                     .iter()
                     .flatten()
                     .flatten()
-                    .zip(triangle.v_diff_p.iter().flatten().flatten())
+                    .zip(polygon.v_diff_p.iter().flatten().flatten())
                     .map(|(v, p)| GregoryVector::new([*p, *p + v.get_normalized()])),
             )
             .chain(
-                triangle
+                polygon
                     .twist
                     .iter()
                     .flatten()
-                    .zip(triangle.twist_u_p.iter().flatten())
+                    .zip(polygon.twist_u_p.iter().flatten())
                     .map(|(v, p)| GregoryVector::new([*p, *p + v.get_normalized()])),
             )
             .collect()
@@ -104,6 +168,7 @@ impl Gregory {
 pub struct Edge {
     pub edge_points: [u64; 4],
     pub patch_points: [[u64; 4]; 4],
+    pub de_boor: bool,
 }
 
 impl Edge {
@@ -141,8 +206,40 @@ impl Edge {
                     self.patch_points[3][0],
                 ],
             ],
+            self.de_boor,
         )
     }
+
+    pub fn bernstein_net(&self, points: &HashMap<u64, Point>) -> [[Vector3; 4]; 4] {
+        let net = self
+            .patch_points
+            .map(|row| row.map(|id| points[&id].transformer.to_vec3()));
+        if !self.de_boor {
+            return net;
+        }
+
+        let rows = net.map(|row| Self::de_boor_to_bernstein(&row));
+        let columns: [[Vector3; 4]; 4] = std::array::from_fn(|c| {
+            Self::de_boor_to_bernstein(&[rows[0][c], rows[1][c], rows[2][c], rows[3][c]])
+        });
+        std::array::from_fn(|r| std::array::from_fn(|c| columns[c][r]))
+    }
+
+    fn de_boor_to_bernstein(d: &[Vector3; 4]) -> [Vector3; 4] {
+        [
+            (d[0] + 4.0 * d[1] + d[2]) / 6.0,
+            (4.0 * d[1] + 2.0 * d[2]) / 6.0,
+            (2.0 * d[1] + 4.0 * d[2]) / 6.0,
+            (d[1] + 4.0 * d[2] + d[3]) / 6.0,
+        ]
+    }
+
+    fn same_patch(&self, other: &Self) -> bool {
+        let s1: HashSet<u64> = HashSet::from_iter(self.patch_points.iter().flatten().copied());
+        let s2: HashSet<u64> = HashSet::from_iter(other.patch_points.iter().flatten().copied());
+
+        s1 == s2
+    }
 }
 
 impl PartialEq for Edge {
@@ -163,11 +260,11 @@ impl Hash for Edge {
 }
 
 #[derive(Debug, Clone, new)]
-pub struct Triangle {
-    pub edges: [Edge; 3],
+pub struct Polygon {
+    pub edges: Vec<Edge>,
 }
 
-impl PartialEq for Triangle {
+impl PartialEq for Polygon {
     fn eq(&self, other: &Self) -> bool {
         let s1: HashSet<Edge> = HashSet::from_iter(self.edges.iter().map(|x| x.clone()));
         let s2: HashSet<Edge> = HashSet::from_iter(other.edges.iter().map(|x| x.clone()));
@@ -176,9 +273,9 @@ impl PartialEq for Triangle {
     }
 }
 
-impl Eq for Triangle {}
+impl Eq for Polygon {}
 
-impl Hash for Triangle {
+impl Hash for Polygon {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.edges
             .iter()
@@ -188,6 +285,59 @@ impl Hash for Triangle {
     }
 }
 
+pub fn edge_loops(edges: &[Edge], length: usize, points: &HashMap<u64, Point>) -> Vec<Vec<Edge>> {
+    let oriented = edges
+        .iter()
+        .flat_map(|e| [e.clone(), e.inverse()])
+        .map(|e| {
+            let net = e.bernstein_net(points);
+            (e, net[0][0], net[0][3])
+        })
+        .collect::<Vec<_>>();
+
+    let mut loops = vec![];
+    for start in oriented.iter() {
+        extend_edge_loop(&oriented, &mut vec![start.clone()], length, &mut loops);
+    }
+
+    loops
+        .into_iter()
+        .map(|l| l.into_iter().map(|(e, _, _)| e).collect::<Vec<_>>())
+        .unique_by(|l: &Vec<Edge>| {
+            l.iter()
+                .map(|e| e.edge_points.iter().copied().sorted().collect::<Vec<_>>())
+                .sorted()
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn extend_edge_loop(
+    oriented: &[(Edge, Vector3, Vector3)],
+    path: &mut Vec<(Edge, Vector3, Vector3)>,
+    length: usize,
+    loops: &mut Vec<Vec<(Edge, Vector3, Vector3)>>,
+) {
+    let end = path[path.len() - 1].2;
+    if path.len() == length {
+        if (end - path[0].1).length() < CORNER_TOLERANCE {
+            loops.push(path.clone());
+        }
+        return;
+    }
+
+    for (e, start, end_point) in oriented.iter() {
+        if (*start - end).length() >= CORNER_TOLERANCE
+            || path.iter().any(|(p, _, _)| p == e || p.same_patch(e))
+        {
+            continue;
+        }
+        path.push((e.clone(), *start, *end_point));
+        extend_edge_loop(oriented, path, length, loops);
+        path.pop();
+    }
+}
+
 #[derive(Debug, Clone, new)]
 pub struct BorderPatch {
     base_points: [[Vector3; 4]; 4],
@@ -358,305 +508,193 @@ impl BorderPatch {
     }
 }
 
-pub struct GregoryTriangle {
-    patches: [GregoryPatch; 3],
-    v_diff: [[[Vector3; 4]; 2]; 3],
-    v_diff_p: [[[Vector3; 4]; 2]; 3],
-    u_diff: [[Vector3; 3]; 3],
-    twist: [[Vector3; 3]; 3],
-    twist_u_p: [[Vector3; 3]; 3],
+pub struct GregoryPolygon {
+    patches: Vec<GregoryPatch>,
+    v_diff: Vec<[[Vector3; 4]; 2]>,
+    v_diff_p: Vec<[[Vector3; 4]; 2]>,
+    u_diff: Vec<[Vector3; 3]>,
+    twist: Vec<[Vector3; 3]>,
+    twist_u_p: Vec<[Vector3; 3]>,
 }
 
-impl GregoryTriangle {
-    fn from_triangle(triangle: &Triangle, points: &HashMap<u64, Point>) -> Self {
-        let p0 = BorderPatch::new([
-            [
-                points[&triangle.edges[0].patch_points[0][0]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[0].patch_points[0][1]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[0].patch_points[0][2]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[0].patch_points[0][3]]
-                    .transformer
-                    .to_vec3(),
-            ],
-            [
-                points[&triangle.edges[0].patch_points[1][0]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[0].patch_points[1][1]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[0].patch_points[1][2]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[0].patch_points[1][3]]
-                    .transformer
-                    .to_vec3(),
-            ],
-            [
-                points[&triangle.edges[0].patch_points[2][0]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[0].patch_points[2][1]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[0].patch_points[2][2]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[0].patch_points[2][3]]
-                    .transformer
-                    .to_vec3(),
-            ],
-            [
-                points[&triangle.edges[0].patch_points[3][0]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[0].patch_points[3][1]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[0].patch_points[3][2]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[0].patch_points[3][3]]
-                    .transformer
-                    .to_vec3(),
-            ],
-        ]);
-
-        let p1 = BorderPatch::new([
-            [
-                points[&triangle.edges[1].patch_points[0][0]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[1].patch_points[0][1]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[1].patch_points[0][2]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[1].patch_points[0][3]]
-                    .transformer
-                    .to_vec3(),
-            ],
-            [
-                points[&triangle.edges[1].patch_points[1][0]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[1].patch_points[1][1]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[1].patch_points[1][2]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[1].patch_points[1][3]]
-                    .transformer
-                    .to_vec3(),
-            ],
-            [
-                points[&triangle.edges[1].patch_points[2][0]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[1].patch_points[2][1]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[1].patch_points[2][2]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[1].patch_points[2][3]]
-                    .transformer
-                    .to_vec3(),
-            ],
-            [
-                points[&triangle.edges[1].patch_points[3][0]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[1].patch_points[3][1]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[1].patch_points[3][2]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[1].patch_points[3][3]]
-                    .transformer
-                    .to_vec3(),
-            ],
-        ]);
-
-        let p2 = BorderPatch::new([
-            [
-                points[&triangle.edges[2].patch_points[0][0]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[2].patch_points[0][1]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[2].patch_points[0][2]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[2].patch_points[0][3]]
-                    .transformer
-                    .to_vec3(),
-            ],
-            [
-                points[&triangle.edges[2].patch_points[1][0]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[2].patch_points[1][1]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[2].patch_points[1][2]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[2].patch_points[1][3]]
-                    .transformer
-                    .to_vec3(),
-            ],
-            [
-                points[&triangle.edges[2].patch_points[2][0]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[2].patch_points[2][1]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[2].patch_points[2][2]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[2].patch_points[2][3]]
-                    .transformer
-                    .to_vec3(),
-            ],
-            [
-                points[&triangle.edges[2].patch_points[3][0]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[2].patch_points[3][1]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[2].patch_points[3][2]]
-                    .transformer
-                    .to_vec3(),
-                points[&triangle.edges[2].patch_points[3][3]]
-                    .transformer
-                    .to_vec3(),
-            ],
-        ]);
+impl GregoryPolygon {
+    fn from_polygon(polygon: &Polygon, shape: &GregoryShape, points: &HashMap<u64, Point>) -> Self {
+        let border_patches = polygon
+            .edges
+            .iter()
+            .map(|e| BorderPatch::new(e.bernstein_net(points)))
+            .collect::<Vec<_>>();
 
-        Self::new([p0, p1, p2])
+        Self::new(&border_patches, shape)
     }
 
-    fn new(border_patches: [BorderPatch; 3]) -> Self {
-        let border_points: Vec<_> = border_patches.iter().map(|p| p.points()).collect();
-        let border_tangents: Vec<_> = border_patches.iter().map(|p| p.diff_u()).collect();
-
-        let p30 = border_points[0][1][0];
-        let p31 = border_points[1][1][0];
-        let p32 = border_points[2][1][0];
+    fn coons(polygon: &Polygon, shape: &GregoryShape, points: &HashMap<u64, Point>) -> Self {
+        let nets = polygon
+            .edges
+            .iter()
+            .map(|e| e.bernstein_net(points))
+            .collect::<Vec<_>>();
+        let cell = |side: usize, k: usize, depth: usize| match side {
+            0 => (k, depth),
+            1 => (3 - depth, k),
+            2 => (3 - k, 3 - depth),
+            _ => (depth, 3 - k),
+        };
+
+        let mut grid = [[Vector3::zero(); 4]; 4];
+        for (side, net) in nets.iter().enumerate() {
+            for (k, point) in net[0].iter().enumerate() {
+                let (x, y) = cell(side, k, 0);
+                grid[x][y] = *point;
+            }
+        }
 
-        let p20 = p30 - border_tangents[0][1] / 3.0;
-        let p21 = p31 - border_tangents[1][1] / 3.0;
-        let p22 = p32 - border_tangents[2][1] / 3.0;
+        let coons = |x: usize, y: usize| {
+            let (u, v) = (x as f32 / 3.0, y as f32 / 3.0);
+            grid[0][y] * (1.0 - u) + grid[3][y] * u + grid[x][0] * (1.0 - v) + grid[x][3] * v
+                - grid[0][0] * ((1.0 - u) * (1.0 - v))
+                - grid[3][0] * (u * (1.0 - v))
+                - grid[0][3] * ((1.0 - u) * v)
+                - grid[3][3] * (u * v)
+        };
+        let dot = |a: Vector3, b: Vector3| a.x * b.x + a.y * b.y + a.z * b.z;
+
+        let mut u_inner = [(1, 1), (1, 2), (2, 1), (2, 2)].map(|(x, y)| coons(x, y));
+        let mut v_inner = u_inner;
+        for (side, (edge, net)) in polygon.edges.iter().zip(nets.iter()).enumerate() {
+            if edge.patch_points[1] == edge.edge_points {
+                continue;
+            }
 
-        let q0 = (3.0 * p20 - p30) / 2.0;
-        let q1 = (3.0 * p21 - p31) / 2.0;
-        let q2 = (3.0 * p22 - p32) / 2.0;
+            let outward = net[0]
+                .iter()
+                .zip(net[1].iter())
+                .map(|(border, neighbour)| *border - *neighbour)
+                .collect::<Vec<_>>();
+            let corners = [0, 3].map(|k| {
+                let (x, y) = cell(side, k, 1);
+                (grid[x][y] - net[0][k], outward[k])
+            });
+            let length = corners.iter().map(|(_, d)| dot(*d, *d)).sum::<f32>();
+            let scale = corners.iter().map(|(c, d)| dot(*c, *d)).sum::<f32>() / length;
+            let scale = if scale.is_finite() && scale > 0.0 {
+                scale
+            } else {
+                1.0
+            };
+
+            for k in 1..3 {
+                let (x, y) = cell(side, k, 1);
+                let inner = if side % 2 == 0 {
+                    &mut u_inner
+                } else {
+                    &mut v_inner
+                };
+                inner[(x - 1) * 2 + y - 1] = net[0][k] + outward[k] * (shape.tangent_scale * scale);
+            }
+        }
 
-        let p = Vector3::from((q0 + q1 + q2) / 3.0);
+        Self {
+            patches: vec![GregoryPatch {
+                top: grid[0],
+                top_sides: [grid[1][0], grid[1][3]],
+                bottom_sides: [grid[2][0], grid[2][3]],
+                bottom: grid[3],
+                u_inner,
+                v_inner,
+            }],
+            v_diff: vec![],
+            v_diff_p: vec![],
+            u_diff: vec![],
+            twist: vec![],
+            twist_u_p: vec![],
+        }
+    }
 
-        let p10 = (p + 2.0 * q0) / 3.0;
-        let p11 = (p + 2.0 * q1) / 3.0;
-        let p12 = (p + 2.0 * q2) / 3.0;
+    fn new(border_patches: &[BorderPatch], shape: &GregoryShape) -> Self {
+        let n = border_patches.len();
 
-        let [points00, points10] = border_patches[0].points();
-        let [points01, points11] = border_patches[1].points();
-        let [points02, points12] = border_patches[2].points();
+        let p3 = border_patches
+            .iter()
+            .map(|b| b.points()[1][0])
+            .collect::<Vec<_>>();
+        let p2 = border_patches
+            .iter()
+            .zip(p3.iter())
+            .map(|(b, p3)| *p3 - b.diff_u()[1] / 3.0)
+            .collect::<Vec<_>>();
+        let q = p2
+            .iter()
+            .zip(p3.iter())
+            .map(|(p2, p3)| (3.0 * *p2 - *p3) / 2.0)
+            .collect::<Vec<_>>();
 
-        let u0 = border_patches[0].diff_u();
-        let u1 = border_patches[1].diff_u();
-        let u2 = border_patches[2].diff_u();
+        let p = q.iter().fold(Vector3::zero(), |sum, q| sum + *q) / n as f32 + shape.center_offset;
 
-        let [v00, v10] = border_patches[0].diff_v();
-        let [v01, v11] = border_patches[1].diff_v();
-        let [v02, v12] = border_patches[2].diff_v();
+        let p1 = q
+            .iter()
+            .map(|q| p + shape.tangent_scale * 2.0 * (*q - p) / 3.0)
+            .collect::<Vec<_>>();
 
-        let w0 = border_patches[0].twist();
-        let w1 = border_patches[1].twist();
-        let w2 = border_patches[2].twist();
+        let points = border_patches
+            .iter()
+            .map(|b| b.points())
+            .collect::<Vec<_>>();
+        let u = border_patches
+            .iter()
+            .map(|b| b.diff_u())
+            .collect::<Vec<_>>();
+        let v = border_patches
+            .iter()
+            .map(|b| b.diff_v())
+            .collect::<Vec<_>>();
+        let w = border_patches.iter().map(|b| b.twist()).collect::<Vec<_>>();
+        let twist_scale = shape.twist_blend / 9.0;
+
+        let patches = (0..n)
+            .map(|i| {
+                let j = (i + n - 1) % n;
+                let [points0i, _] = points[i];
+                let [_, points1j] = points[j];
+                let [v0i, _] = v[i];
+                let [_, v1j] = v[j];
+
+                let inner = [
+                    p + (p1[j] - p) + (p1[i] - p),
+                    p3[i] - u[i][1] / 3.0 - v0i[3] / 3.0 + twist_scale * w[i][1],
+                    points1j[0] - u[j][1] / 3.0 + v1j[0] / 3.0 + twist_scale * w[j][1],
+                ];
 
-        Self {
-            twist: [w0, w1, w2],
-            twist_u_p: [
-                [points00[0], p30, points10[3]],
-                [points01[0], p31, points11[3]],
-                [points02[0], p32, points12[3]],
-            ],
-            u_diff: [u0, u1, u2],
-            v_diff: [[v00, v10], [v01, v11], [v02, v12]],
-            v_diff_p: [
-                border_patches[0].points_v(),
-                border_patches[1].points_v(),
-                border_patches[2].points_v(),
-            ],
-            patches: [
                 GregoryPatch {
-                    top: [p, p10, p20, p30],
-                    top_sides: [p12, points00[2]],
-                    bottom_sides: [p22, points00[1]],
-                    bottom: points12,
+                    top: [p, p1[i], p2[i], p3[i]],
+                    top_sides: [p1[j], points0i[2]],
+                    bottom_sides: [p2[j], points0i[1]],
+                    bottom: points1j,
                     u_inner: [
-                        p + (p12 - p) + (p10 - p),
-                        p30 - u0[1] / 3.0 - v00[3] / 3.0 + w0[1] / 9.0,
-                        points12[0] - u2[1] / 3.0 + v12[0] / 3.0 + w2[1] / 9.0,
-                        points12[3] - u2[2] / 3.0 - v12[3] / 3.0 + w2[2] / 9.0,
+                        inner[0],
+                        inner[1],
+                        inner[2],
+                        points1j[3] - u[j][2] / 3.0 - v1j[3] / 3.0 + twist_scale * w[j][2],
                     ],
                     v_inner: [
-                        p + (p12 - p) + (p10 - p),
-                        p30 - u0[1] / 3.0 - v00[3] / 3.0 + w0[1] / 9.0,
-                        points12[0] - u2[1] / 3.0 + v12[0] / 3.0 + w2[1] / 9.0,
-                        points00[0] - u0[0] / 3.0 + v00[0] / 3.0 + w0[0] / 9.0,
+                        inner[0],
+                        inner[1],
+                        inner[2],
+                        points0i[0] - u[i][0] / 3.0 + v0i[0] / 3.0 + twist_scale * w[i][0],
                     ],
-                },
-                GregoryPatch {
-                    top: [p, p11, p21, p31],
-                    top_sides: [p10, points01[2]],
-                    bottom_sides: [p20, points01[1]],
-                    bottom: points10,
-                    u_inner: [
-                        p + (p10 - p) + (p11 - p),
-                        p31 - u1[1] / 3.0 - v01[3] / 3.0 + w1[1] / 9.0,
-                        points10[0] - u0[1] / 3.0 + v10[0] / 3.0 + w0[1] / 9.0,
-                        points10[3] - u0[2] / 3.0 - v10[3] / 3.0 + w0[2] / 9.0,
-                    ],
-                    v_inner: [
-                        p + (p10 - p) + (p11 - p),
-                        p31 - u1[1] / 3.0 - v01[3] / 3.0 + w1[1] / 9.0,
-                        points10[0] - u0[1] / 3.0 + v10[0] / 3.0 + w0[1] / 9.0,
-                        points01[0] - u1[0] / 3.0 + v01[0] / 3.0 + w1[0] / 9.0,
-                    ],
-                },
-                GregoryPatch {
-                    top: [p, p12, p22, p32],
-                    top_sides: [p11, points02[2]],
-                    bottom_sides: [p21, points02[1]],
-                    bottom: points11,
-                    u_inner: [
-                        p + (p11 - p) + (p12 - p),
-                        p32 - u2[1] / 3.0 - v02[3] / 3.0 + w2[1] / 9.0,
-                        points11[0] - u1[1] / 3.0 + v11[0] / 3.0 + w1[1] / 9.0,
-                        points11[3] - u1[2] / 3.0 - v11[3] / 3.0 + w1[2] / 9.0,
-                    ],
-                    v_inner: [
-                        p + (p11 - p) + (p12 - p),
-                        p32 - u2[1] / 3.0 - v02[3] / 3.0 + w2[1] / 9.0,
-                        points11[0] - u1[1] / 3.0 + v11[0] / 3.0 + w1[1] / 9.0,
-                        points02[0] - u2[0] / 3.0 + v02[0] / 3.0 + w2[0] / 9.0,
-                    ],
-                },
-            ],
+                }
+            })
+            .collect();
+
+        Self {
+            twist_u_p: (0..n)
+                .map(|i| [points[i][0][0], p3[i], points[i][1][3]])
+                .collect(),
+            twist: w,
+            u_diff: u,
+            v_diff: v,
+            v_diff_p: border_patches.iter().map(|b| b.points_v()).collect(),
+            patches,
         }
     }
 }
@@ -32,6 +32,7 @@ pub enum IntersectionObjectId {
     Torus(u64),
     SurfaceC0(u64),
     SurfaceC2(u64),
+    NurbsSurface(u64),
 }
 
 bitflags! {
@@ -50,6 +51,7 @@ impl Display for IntersectionObjectId {
             IntersectionObjectId::Torus(id) => write!(f, "T{}", id),
             IntersectionObjectId::SurfaceC0(id) => write!(f, "SC0 {}", id),
             IntersectionObjectId::SurfaceC2(id) => write!(f, "SC2 {}", id),
+            IntersectionObjectId::NurbsSurface(id) => write!(f, "NS {}", id),
         }
     }
 }
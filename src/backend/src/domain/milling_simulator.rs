@@ -0,0 +1,278 @@
+use math::vector3::Vector3;
+
+use super::cutter::{Cutter, CutterType};
+
+#[derive(Debug, Clone)]
+pub struct MaterialBlock {
+    pub size: (f32, f32),
+    pub height: f32,
+    pub min_height: f32,
+    pub resolution: (usize, usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct HeightMap {
+    pub size: (f32, f32),
+    pub resolution: (usize, usize),
+    pub heights: Vec<f32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MillingErrorType {
+    TooDeep,
+    FlatCutterVerticalDescent,
+    ShankCollision,
+}
+
+#[derive(Debug, Clone)]
+pub struct MillingError {
+    pub move_index: usize,
+    pub error_type: MillingErrorType,
+    pub position: Vector3,
+}
+
+#[derive(Debug, Clone)]
+pub struct MillingMoveStats {
+    pub move_index: usize,
+    pub length: f32,
+    pub removed_volume: f32,
+    pub cut_cells: usize,
+    pub lowest_point: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct MillingReport {
+    pub moves: Vec<MillingMoveStats>,
+    pub errors: Vec<MillingError>,
+}
+
+pub struct MillingSimulator {
+    pub block: MaterialBlock,
+    pub cutter: Cutter,
+    pub height_map: HeightMap,
+}
+
+impl HeightMap {
+    pub fn new(size: (f32, f32), resolution: (usize, usize), height: f32) -> Self {
+        Self {
+            size,
+            resolution,
+            heights: vec![height; resolution.0 * resolution.1],
+        }
+    }
+
+    pub fn cell_size(&self) -> (f32, f32) {
+        (
+            self.size.0 / self.resolution.0 as f32,
+            self.size.1 / self.resolution.1 as f32,
+        )
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> f32 {
+        self.heights[y * self.resolution.0 + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, height: f32) {
+        self.heights[y * self.resolution.0 + x] = height;
+    }
+
+    pub fn cell_center(&self, x: usize, y: usize) -> (f32, f32) {
+        let cell_size = self.cell_size();
+        (
+            -self.size.0 / 2.0 + (x as f32 + 0.5) * cell_size.0,
+            -self.size.1 / 2.0 + (y as f32 + 0.5) * cell_size.1,
+        )
+    }
+
+    pub fn difference(&self, other: &HeightMap) -> Vec<f32> {
+        self.heights
+            .iter()
+            .zip(other.heights.iter())
+            .map(|(a, b)| a - b)
+            .collect()
+    }
+
+    fn cell_range(&self, center: (f32, f32), radius: f32) -> ((usize, usize), (usize, usize)) {
+        let cell_size = self.cell_size();
+        let to_cell = |value: f32, half_size: f32, cell: f32, max: usize| {
+            (((value + half_size) / cell).floor().max(0.0) as usize).min(max - 1)
+        };
+
+        (
+            (
+                to_cell(
+                    center.0 - radius,
+                    self.size.0 / 2.0,
+                    cell_size.0,
+                    self.resolution.0,
+                ),
+                to_cell(
+                    center.0 + radius,
+                    self.size.0 / 2.0,
+                    cell_size.0,
+                    self.resolution.0,
+                ),
+            ),
+            (
+                to_cell(
+                    center.1 - radius,
+                    self.size.1 / 2.0,
+                    cell_size.1,
+                    self.resolution.1,
+                ),
+                to_cell(
+                    center.1 + radius,
+                    self.size.1 / 2.0,
+                    cell_size.1,
+                    self.resolution.1,
+                ),
+            ),
+        )
+    }
+}
+
+impl MillingSimulator {
+    pub fn new(block: MaterialBlock, cutter: Cutter) -> Option<Self> {
+        if block.resolution.0 == 0
+            || block.resolution.1 == 0
+            || block.size.0 <= 0.0
+            || block.size.1 <= 0.0
+        {
+            return None;
+        }
+
+        let height_map = HeightMap::new(block.size, block.resolution, block.height);
+        Some(Self {
+            block,
+            cutter,
+            height_map,
+        })
+    }
+
+    pub fn simulate(&mut self, path: &[Vector3]) -> MillingReport {
+        let mut report = MillingReport {
+            moves: vec![],
+            errors: vec![],
+        };
+
+        for (move_index, (from, to)) in path.iter().zip(path.iter().skip(1)).enumerate() {
+            let (stats, errors) = self.simulate_move(move_index, *from, *to);
+            report.moves.push(stats);
+            report.errors.extend(errors);
+        }
+
+        report
+    }
+
+    fn simulate_move(
+        &mut self,
+        move_index: usize,
+        from: Vector3,
+        to: Vector3,
+    ) -> (MillingMoveStats, Vec<MillingError>) {
+        let delta = to - from;
+        let length = delta.length();
+        let horizontal_length = (delta.x * delta.x + delta.z * delta.z).sqrt();
+        let cell_size = self.height_map.cell_size();
+        let step = cell_size.0.min(cell_size.1) / 2.0;
+        let steps = ((length / step).ceil() as usize).max(1);
+
+        let mut stats = MillingMoveStats {
+            move_index,
+            length,
+            removed_volume: 0.0,
+            cut_cells: 0,
+            lowest_point: from.y.min(to.y),
+        };
+        let mut errors: Vec<MillingError> = vec![];
+
+        for i in 0..=steps {
+            let position = from + delta * (i as f32 / steps as f32);
+            let (removed_volume, cut_cells, shank_collision) = self.stamp(position);
+            stats.removed_volume += removed_volume;
+            stats.cut_cells += cut_cells;
+
+            if position.y < self.block.min_height {
+                Self::push_error(&mut errors, move_index, MillingErrorType::TooDeep, position);
+            }
+            if shank_collision {
+                Self::push_error(
+                    &mut errors,
+                    move_index,
+                    MillingErrorType::ShankCollision,
+                    position,
+                );
+            }
+            if cut_cells > 0
+                && self.cutter.cutter_type == CutterType::Flat
+                && delta.y < 0.0
+                && horizontal_length < step
+            {
+                Self::push_error(
+                    &mut errors,
+                    move_index,
+                    MillingErrorType::FlatCutterVerticalDescent,
+                    position,
+                );
+            }
+        }
+
+        (stats, errors)
+    }
+
+    fn stamp(&mut self, position: Vector3) -> (f32, usize, bool) {
+        let cell_size = self.height_map.cell_size();
+        let cell_area = cell_size.0 * cell_size.1;
+        let ((x_min, x_max), (y_min, y_max)) = self
+            .height_map
+            .cell_range((position.x, position.z), self.cutter.radius);
+
+        let mut removed_volume = 0.0;
+        let mut cut_cells = 0;
+        let mut shank_collision = false;
+
+        for x in x_min..=x_max {
+            for y in y_min..=y_max {
+                let center = self.height_map.cell_center(x, y);
+                let distance =
+                    ((center.0 - position.x).powi(2) + (center.1 - position.z).powi(2)).sqrt();
+                let Some(cutter_height) = self.cutter.height_at(distance) else {
+                    continue;
+                };
+
+                let cutter_height = position.y + cutter_height;
+                let height = self.height_map.get(x, y);
+                if height <= cutter_height {
+                    continue;
+                }
+
+                if height - position.y > self.cutter.cutting_length {
+                    shank_collision = true;
+                }
+
+                removed_volume += (height - cutter_height) * cell_area;
+                cut_cells += 1;
+                self.height_map.set(x, y, cutter_height);
+            }
+        }
+
+        (removed_volume, cut_cells, shank_collision)
+    }
+
+    fn push_error(
+        errors: &mut Vec<MillingError>,
+        move_index: usize,
+        error_type: MillingErrorType,
+        position: Vector3,
+    ) {
+        if errors.iter().any(|e| e.error_type == error_type) {
+            return;
+        }
+
+        errors.push(MillingError {
+            move_index,
+            error_type,
+            position,
+        });
+    }
+}
@@ -1,16 +1,26 @@
 pub mod bezier_c0;
 pub mod bezier_c2;
 pub mod bezier_int;
+pub mod continuity;
 pub mod cursor;
+pub mod curve_analysis;
+pub mod curve_fitting;
+pub mod curve_joining;
+pub mod cutter;
 pub mod events;
 pub mod gregory;
 pub mod intersection;
 pub mod intersection_object;
 pub mod mesh;
+pub mod milling_simulator;
+pub mod nurbs_curve;
+pub mod nurbs_surface;
 pub mod point;
 pub mod selected_object;
 pub mod surface_c0;
 pub mod surface_c2;
+pub mod surface_display_mode;
+pub mod toolpath;
 pub mod torus;
 pub mod transformer;
 pub mod vertex;
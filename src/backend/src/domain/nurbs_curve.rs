@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+
+use nalgebra::{Vector3, Vector4};
+
+use crate::domain::point::Point;
+use crate::domain::transformer::LittleTransformer;
+
+const DEGREE: usize = 3;
+
+pub struct NurbsCurve {
+    pub id: u64,
+    pub name: String,
+    pub draw_polygon: bool,
+    pub points: Vec<NurbsCurvePoint>,
+    pub bernstein_points: Vec<NurbsCurveBernsteinPoint>,
+    pub knots: Vec<f64>,
+}
+
+#[derive(Clone, Copy)]
+pub struct NurbsCurvePoint {
+    pub id: u64,
+    pub weight: f64,
+}
+
+pub struct NurbsCurveBernsteinPoint {
+    pub transformer: LittleTransformer,
+    pub weight: f64,
+}
+
+impl NurbsCurve {
+    pub fn new(id: u64, points: Vec<NurbsCurvePoint>, all_points: &HashMap<u64, Point>) -> Self {
+        Self::new_with_name(id, format!("NurbsCurve {}", id), points, all_points)
+    }
+
+    pub fn new_with_name(
+        id: u64,
+        name: String,
+        points: Vec<NurbsCurvePoint>,
+        all_points: &HashMap<u64, Point>,
+    ) -> Self {
+        let mut curve = Self {
+            id,
+            name,
+            draw_polygon: false,
+            points,
+            bernstein_points: vec![],
+            knots: vec![],
+        };
+        curve.update_points(all_points);
+        curve
+    }
+
+    pub fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    pub fn set_draw_polygon(&mut self, draw_polygon: bool) {
+        self.draw_polygon = draw_polygon;
+    }
+
+    pub fn add_point(&mut self, point_id: u64, all_points: &HashMap<u64, Point>) {
+        self.points.push(NurbsCurvePoint {
+            id: point_id,
+            weight: 1.0,
+        });
+        self.update_points(all_points);
+    }
+
+    pub fn delete_points(&mut self, points: &[u64], all_points: &HashMap<u64, Point>) {
+        self.points.retain(|point| !points.contains(&point.id));
+        self.update_points(all_points);
+    }
+
+    pub fn replace_point(
+        &mut self,
+        old_point: u64,
+        new_point: u64,
+        all_points: &HashMap<u64, Point>,
+    ) {
+        for point in self.points.iter_mut().filter(|p| p.id == old_point) {
+            point.id = new_point;
+        }
+        self.update_points(all_points);
+    }
+
+    pub fn set_weight(&mut self, point_id: u64, weight: f64, all_points: &HashMap<u64, Point>) {
+        for point in self.points.iter_mut().filter(|p| p.id == point_id) {
+            point.weight = weight.max(f64::EPSILON);
+        }
+        self.update_points(all_points);
+    }
+
+    pub fn set_knots(&mut self, knots: Vec<f64>, all_points: &HashMap<u64, Point>) {
+        self.knots = knots;
+        self.update_points(all_points);
+    }
+
+    pub fn update_points(&mut self, all_points: &HashMap<u64, Point>) {
+        if !Self::are_knots_valid(&self.knots, self.points.len()) {
+            self.knots.clear();
+        }
+
+        let positions = self.homogeneous_positions(all_points);
+        let knots = self.knot_vector();
+        self.bernstein_points = Self::get_bernstein_points(&positions, &knots);
+    }
+
+    pub fn knot_vector(&self) -> Vec<f64> {
+        if self.knots.is_empty() {
+            Self::clamped_knots(self.points.len())
+        } else {
+            self.knots.clone()
+        }
+    }
+
+    pub fn evaluate(
+        &self,
+        parameter: f64,
+        all_points: &HashMap<u64, Point>,
+    ) -> Option<Vector3<f64>> {
+        let positions = self.homogeneous_positions(all_points);
+        if positions.len() <= DEGREE {
+            return None;
+        }
+
+        let knots = self.knot_vector();
+        let last_span = positions.len() - 1;
+        let (start, end) = (knots[DEGREE], knots[last_span + 1]);
+        let knot = start + parameter.clamp(0.0, 1.0) * (end - start);
+        let span = (DEGREE..=last_span)
+            .rev()
+            .find(|i| knots[*i] <= knot && knots[*i] < knots[i + 1])?;
+
+        let point = Self::blossom(&positions, &knots, span, [knot; DEGREE]);
+        Some(point.xyz() / point.w)
+    }
+
+    fn homogeneous_positions(&self, all_points: &HashMap<u64, Point>) -> Vec<Vector4<f64>> {
+        self.points
+            .iter()
+            .map(|p| {
+                let position = all_points[&p.id].transformer.position;
+                Vector4::new(
+                    position.0 * p.weight,
+                    position.1 * p.weight,
+                    position.2 * p.weight,
+                    p.weight,
+                )
+            })
+            .collect()
+    }
+
+    fn clamped_knots(count: usize) -> Vec<f64> {
+        if count <= DEGREE {
+            return vec![];
+        }
+
+        let spans = count - DEGREE;
+        (0..count + DEGREE + 1)
+            .map(|i| i.saturating_sub(DEGREE).min(spans) as f64 / spans as f64)
+            .collect()
+    }
+
+    fn are_knots_valid(knots: &[f64], count: usize) -> bool {
+        knots.len() == count + DEGREE + 1
+            && knots.windows(2).all(|w| w[0] <= w[1])
+            && knots[DEGREE] < knots[count]
+    }
+
+    fn get_bernstein_points(
+        positions: &[Vector4<f64>],
+        knots: &[f64],
+    ) -> Vec<NurbsCurveBernsteinPoint> {
+        if positions.len() <= DEGREE {
+            return vec![];
+        }
+
+        let mut points = vec![];
+        for i in DEGREE..positions.len() {
+            let (a, b) = (knots[i], knots[i + 1]);
+            if a >= b {
+                continue;
+            }
+            if points.is_empty() {
+                points.push(Self::blossom(positions, knots, i, [a, a, a]));
+            }
+            points.push(Self::blossom(positions, knots, i, [a, a, b]));
+            points.push(Self::blossom(positions, knots, i, [a, b, b]));
+            points.push(Self::blossom(positions, knots, i, [b, b, b]));
+        }
+
+        points
+            .iter()
+            .map(|p| NurbsCurveBernsteinPoint {
+                transformer: LittleTransformer {
+                    position: (p.x / p.w, p.y / p.w, p.z / p.w),
+                },
+                weight: p.w,
+            })
+            .collect()
+    }
+
+    fn blossom(
+        positions: &[Vector4<f64>],
+        knots: &[f64],
+        span: usize,
+        arguments: [f64; DEGREE],
+    ) -> Vector4<f64> {
+        let mut points = positions[span - DEGREE..=span].to_vec();
+        for (level, argument) in arguments.iter().enumerate() {
+            let r = level + 1;
+            for j in (r..=DEGREE).rev() {
+                let k = span - DEGREE + j;
+                let alpha = (argument - knots[k]) / (knots[k + DEGREE + 1 - r] - knots[k]);
+                points[j] = points[j - 1] * (1.0 - alpha) + points[j] * alpha;
+            }
+        }
+        points[DEGREE]
+    }
+}
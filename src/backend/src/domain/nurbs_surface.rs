@@ -0,0 +1,142 @@
+use math::vector3::Vector3;
+
+use super::{
+    intersection::IntersectionObjectId, intersection_object::IntersectionObject, point::Point,
+};
+
+pub struct NurbsSurface {
+    pub id: u64,
+    pub name: String,
+    pub draw_polygon: bool,
+    pub tess_level: u8,
+    pub points: Vec<NurbsSurfacePoint>,
+    pub size: (u32, u32),
+    pub is_cylinder: bool,
+}
+
+#[derive(Clone, Copy)]
+pub struct NurbsSurfacePoint {
+    pub id: u64,
+    pub weight: f64,
+}
+
+impl NurbsSurface {
+    pub fn new(
+        id: u64,
+        points: Vec<NurbsSurfacePoint>,
+        size: (u32, u32),
+        is_cylinder: bool,
+    ) -> Self {
+        Self::new_with_name(
+            id,
+            format!("NurbsSurface {}", id),
+            points,
+            size,
+            is_cylinder,
+        )
+    }
+
+    pub fn new_with_name(
+        id: u64,
+        name: String,
+        points: Vec<NurbsSurfacePoint>,
+        size: (u32, u32),
+        is_cylinder: bool,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            draw_polygon: false,
+            tess_level: 4,
+            points,
+            size,
+            is_cylinder,
+        }
+    }
+
+    pub fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    pub fn set_draw_polygon(&mut self, draw_polygon: bool) {
+        self.draw_polygon = draw_polygon;
+    }
+
+    pub fn set_tess_level(&mut self, tess_level: u8) {
+        self.tess_level = tess_level;
+    }
+
+    pub fn set_weight(&mut self, point_id: u64, weight: f64) {
+        for point in self.points.iter_mut().filter(|p| p.id == point_id) {
+            point.weight = weight.max(f64::EPSILON);
+        }
+    }
+
+    pub fn replace_point(&mut self, old_point: u64, new_point: u64) {
+        for point in self.points.iter_mut().filter(|p| p.id == old_point) {
+            point.id = new_point;
+        }
+    }
+
+    pub fn is_closed_v(&self) -> bool {
+        let s1 = self.size.1 as usize + 3;
+        self.points
+            .chunks(s1)
+            .all(|column| (0..3).all(|y| column[y].id == column[s1 - 3 + y].id))
+    }
+
+    pub fn get_intersection_object(&self, points: &[Point]) -> IntersectionObject {
+        let points = self
+            .points
+            .iter()
+            .map(|p| {
+                let point = points.iter().find(|&point| point.id == p.id).unwrap();
+                (
+                    Vector3::new(
+                        point.transformer.position.0 as f32,
+                        point.transformer.position.1 as f32,
+                        point.transformer.position.2 as f32,
+                    ),
+                    p.weight as f32,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let size = self.size;
+        let basis = |t: f32| {
+            let t2 = t * t;
+            let t3 = t2 * t;
+            [
+                (-t3 + 3.0 * t2 - 3.0 * t + 1.0) / 6.0,
+                (3.0 * t3 - 6.0 * t2 + 4.0) / 6.0,
+                (-3.0 * t3 + 3.0 * t2 + 3.0 * t + 1.0) / 6.0,
+                t3 / 6.0,
+            ]
+        };
+
+        IntersectionObject::new(
+            IntersectionObjectId::NurbsSurface(self.id),
+            (self.size.0 as f32, self.size.1 as f32),
+            move |u, v| {
+                let ui = (u as usize).min(size.0 as usize - 1);
+                let vi = (v as usize).min(size.1 as usize - 1);
+                let bu = basis(u - ui as f32);
+                let bv = basis(v - vi as f32);
+
+                let mut position = Vector3::zero();
+                let mut weight = 0.0;
+                for (i, bu) in bu.iter().enumerate() {
+                    for (j, bv) in bv.iter().enumerate() {
+                        let (point, w) = points[(ui + i) * (size.1 as usize + 3) + vi + j];
+                        let b = bu * bv * w;
+                        position = position + b * point;
+                        weight += b;
+                    }
+                }
+                position / weight
+            },
+            self.is_cylinder,
+            self.is_closed_v(),
+        )
+    }
+}
@@ -4,10 +4,13 @@ pub struct SelectedObject {
     pub bezier_c0_id: Option<u64>,
     pub bezier_c2_id: Option<u64>,
     pub bezier_int_id: Option<u64>,
+    pub nurbs_curve_id: Option<u64>,
     pub surface_c0_id: Option<u64>,
     pub surface_c2_id: Option<u64>,
+    pub nurbs_surface_id: Option<u64>,
     pub gregory_id: Option<u64>,
     pub intersection_id: Option<u64>,
+    pub toolpath_id: Option<u64>,
 }
 
 impl SelectedObject {
@@ -18,10 +21,13 @@ impl SelectedObject {
             bezier_c0_id: None,
             bezier_c2_id: None,
             bezier_int_id: None,
+            nurbs_curve_id: None,
             surface_c0_id: None,
             surface_c2_id: None,
+            nurbs_surface_id: None,
             gregory_id: None,
             intersection_id: None,
+            toolpath_id: None,
         }
     }
 
@@ -32,10 +38,13 @@ impl SelectedObject {
             bezier_c0_id: None,
             bezier_c2_id: None,
             bezier_int_id: None,
+            nurbs_curve_id: None,
             surface_c0_id: None,
             surface_c2_id: None,
+            nurbs_surface_id: None,
             gregory_id: None,
             intersection_id: None,
+            toolpath_id: None,
         }
     }
 
@@ -46,10 +55,13 @@ impl SelectedObject {
             bezier_c0_id: Some(bezier_c0_id),
             bezier_c2_id: None,
             bezier_int_id: None,
+            nurbs_curve_id: None,
             surface_c0_id: None,
             surface_c2_id: None,
+            nurbs_surface_id: None,
             gregory_id: None,
             intersection_id: None,
+            toolpath_id: None,
         }
     }
 
@@ -60,10 +72,13 @@ impl SelectedObject {
             bezier_c0_id: None,
             bezier_c2_id: Some(bezier_c2_id),
             bezier_int_id: None,
+            nurbs_curve_id: None,
             surface_c0_id: None,
             surface_c2_id: None,
+            nurbs_surface_id: None,
             gregory_id: None,
             intersection_id: None,
+            toolpath_id: None,
         }
     }
 
@@ -74,10 +89,30 @@ impl SelectedObject {
             bezier_c0_id: None,
             bezier_c2_id: None,
             bezier_int_id: Some(bezier_int_id),
+            nurbs_curve_id: None,
             surface_c0_id: None,
             surface_c2_id: None,
+            nurbs_surface_id: None,
             gregory_id: None,
             intersection_id: None,
+            toolpath_id: None,
+        }
+    }
+
+    pub fn new_nurbs_curve(nurbs_curve_id: u64) -> Self {
+        Self {
+            torus_id: None,
+            point_id: None,
+            bezier_c0_id: None,
+            bezier_c2_id: None,
+            bezier_int_id: None,
+            nurbs_curve_id: Some(nurbs_curve_id),
+            surface_c0_id: None,
+            surface_c2_id: None,
+            nurbs_surface_id: None,
+            gregory_id: None,
+            intersection_id: None,
+            toolpath_id: None,
         }
     }
 
@@ -88,10 +123,13 @@ impl SelectedObject {
             bezier_c0_id: None,
             bezier_c2_id: None,
             bezier_int_id: None,
+            nurbs_curve_id: None,
             surface_c0_id: Some(surface_c0_id),
             surface_c2_id: None,
+            nurbs_surface_id: None,
             gregory_id: None,
             intersection_id: None,
+            toolpath_id: None,
         }
     }
 
@@ -102,10 +140,30 @@ impl SelectedObject {
             bezier_c0_id: None,
             bezier_c2_id: None,
             bezier_int_id: None,
+            nurbs_curve_id: None,
             surface_c0_id: None,
             surface_c2_id: Some(surface_c2_id),
+            nurbs_surface_id: None,
             gregory_id: None,
             intersection_id: None,
+            toolpath_id: None,
+        }
+    }
+
+    pub fn new_nurbs_surface(nurbs_surface_id: u64) -> Self {
+        Self {
+            torus_id: None,
+            point_id: None,
+            bezier_c0_id: None,
+            bezier_c2_id: None,
+            bezier_int_id: None,
+            nurbs_curve_id: None,
+            surface_c0_id: None,
+            surface_c2_id: None,
+            nurbs_surface_id: Some(nurbs_surface_id),
+            gregory_id: None,
+            intersection_id: None,
+            toolpath_id: None,
         }
     }
 
@@ -116,10 +174,13 @@ impl SelectedObject {
             bezier_c0_id: None,
             bezier_c2_id: None,
             bezier_int_id: None,
+            nurbs_curve_id: None,
             surface_c0_id: None,
             surface_c2_id: None,
+            nurbs_surface_id: None,
             gregory_id: Some(gregory_id),
             intersection_id: None,
+            toolpath_id: None,
         }
     }
 
@@ -130,10 +191,30 @@ impl SelectedObject {
             bezier_c0_id: None,
             bezier_c2_id: None,
             bezier_int_id: None,
+            nurbs_curve_id: None,
             surface_c0_id: None,
             surface_c2_id: None,
+            nurbs_surface_id: None,
             gregory_id: None,
             intersection_id: Some(intersection_id),
+            toolpath_id: None,
+        }
+    }
+
+    pub fn new_toolpath(toolpath_id: u64) -> Self {
+        Self {
+            torus_id: None,
+            point_id: None,
+            bezier_c0_id: None,
+            bezier_c2_id: None,
+            bezier_int_id: None,
+            nurbs_curve_id: None,
+            surface_c0_id: None,
+            surface_c2_id: None,
+            nurbs_surface_id: None,
+            gregory_id: None,
+            intersection_id: None,
+            toolpath_id: Some(toolpath_id),
         }
     }
 }
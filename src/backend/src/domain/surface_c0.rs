@@ -1,7 +1,8 @@
 use math::vector3::Vector3;
 
 use super::{
-    intersection::IntersectionObjectId, intersection_object::IntersectionObject, point::Point,
+    gregory::Edge, intersection::IntersectionObjectId, intersection_object::IntersectionObject,
+    point::Point, surface_display_mode::SurfaceDisplayMode,
 };
 
 pub struct SurfaceC0 {
@@ -9,6 +10,7 @@ pub struct SurfaceC0 {
     pub name: String,
     pub draw_polygon: bool,
     pub tess_level: u8,
+    pub display_mode: SurfaceDisplayMode,
     pub points: Vec<SurfaceC0Point>,
     pub size: (u32, u32),
     pub is_cylinder: bool,
@@ -26,6 +28,7 @@ impl SurfaceC0 {
             name: format!("SurfaceC0 {}", id),
             draw_polygon: false,
             tess_level: 4,
+            display_mode: SurfaceDisplayMode::default(),
             points,
             size,
             is_cylinder,
@@ -44,6 +47,7 @@ impl SurfaceC0 {
             name,
             draw_polygon: false,
             tess_level: 4,
+            display_mode: SurfaceDisplayMode::default(),
             points,
             size,
             is_cylinder,
@@ -62,6 +66,180 @@ impl SurfaceC0 {
         self.tess_level = tess_level;
     }
 
+    pub fn set_display_mode(&mut self, display_mode: SurfaceDisplayMode) {
+        self.display_mode = display_mode;
+    }
+
+    pub fn is_closed_u(&self) -> bool {
+        let s1 = self.size.1 as usize * 3 + 1;
+        let last = self.size.0 as usize * 3 * s1;
+        (0..s1).all(|y| self.points[y].id == self.points[last + y].id)
+    }
+
+    pub fn is_closed_v(&self) -> bool {
+        let s1 = self.size.1 as usize * 3 + 1;
+        self.points
+            .chunks(s1)
+            .all(|column| column[0].id == column[s1 - 1].id)
+    }
+
+    pub fn border_edges(&self) -> Vec<Edge> {
+        let s0 = self.size.0 as usize * 3 + 1;
+        let s1 = self.size.1 as usize * 3 + 1;
+        (0..(self.size.0 as usize))
+            .flat_map(move |x| {
+                let bx = x * 3;
+                [
+                    Edge::new(
+                        [
+                            self.points[bx * s1].id,
+                            self.points[(bx + 1) * s1].id,
+                            self.points[(bx + 2) * s1].id,
+                            self.points[(bx + 3) * s1].id,
+                        ],
+                        [
+                            [
+                                self.points[bx * s1].id,
+                                self.points[(bx + 1) * s1].id,
+                                self.points[(bx + 2) * s1].id,
+                                self.points[(bx + 3) * s1].id,
+                            ],
+                            [
+                                self.points[bx * s1 + 1].id,
+                                self.points[(bx + 1) * s1 + 1].id,
+                                self.points[(bx + 2) * s1 + 1].id,
+                                self.points[(bx + 3) * s1 + 1].id,
+                            ],
+                            [
+                                self.points[bx * s1 + 2].id,
+                                self.points[(bx + 1) * s1 + 2].id,
+                                self.points[(bx + 2) * s1 + 2].id,
+                                self.points[(bx + 3) * s1 + 2].id,
+                            ],
+                            [
+                                self.points[bx * s1 + 3].id,
+                                self.points[(bx + 1) * s1 + 3].id,
+                                self.points[(bx + 2) * s1 + 3].id,
+                                self.points[(bx + 3) * s1 + 3].id,
+                            ],
+                        ],
+                        false,
+                    ),
+                    Edge::new(
+                        [
+                            self.points[bx * s1 + s1 - 1].id,
+                            self.points[(bx + 1) * s1 + s1 - 1].id,
+                            self.points[(bx + 2) * s1 + s1 - 1].id,
+                            self.points[(bx + 3) * s1 + s1 - 1].id,
+                        ],
+                        [
+                            [
+                                self.points[bx * s1 + s1 - 1].id,
+                                self.points[(bx + 1) * s1 + s1 - 1].id,
+                                self.points[(bx + 2) * s1 + s1 - 1].id,
+                                self.points[(bx + 3) * s1 + s1 - 1].id,
+                            ],
+                            [
+                                self.points[bx * s1 + s1 - 2].id,
+                                self.points[(bx + 1) * s1 + s1 - 2].id,
+                                self.points[(bx + 2) * s1 + s1 - 2].id,
+                                self.points[(bx + 3) * s1 + s1 - 2].id,
+                            ],
+                            [
+                                self.points[bx * s1 + s1 - 3].id,
+                                self.points[(bx + 1) * s1 + s1 - 3].id,
+                                self.points[(bx + 2) * s1 + s1 - 3].id,
+                                self.points[(bx + 3) * s1 + s1 - 3].id,
+                            ],
+                            [
+                                self.points[bx * s1 + s1 - 4].id,
+                                self.points[(bx + 1) * s1 + s1 - 4].id,
+                                self.points[(bx + 2) * s1 + s1 - 4].id,
+                                self.points[(bx + 3) * s1 + s1 - 4].id,
+                            ],
+                        ],
+                        false,
+                    ),
+                ]
+            })
+            .chain((0..(self.size.1 as usize)).flat_map(move |y| {
+                let by = y * 3;
+                [
+                    Edge::new(
+                        [
+                            self.points[by].id,
+                            self.points[by + 1].id,
+                            self.points[by + 2].id,
+                            self.points[by + 3].id,
+                        ],
+                        [
+                            [
+                                self.points[by].id,
+                                self.points[by + 1].id,
+                                self.points[by + 2].id,
+                                self.points[by + 3].id,
+                            ],
+                            [
+                                self.points[s1 + by].id,
+                                self.points[s1 + by + 1].id,
+                                self.points[s1 + by + 2].id,
+                                self.points[s1 + by + 3].id,
+                            ],
+                            [
+                                self.points[2 * s1 + by].id,
+                                self.points[2 * s1 + by + 1].id,
+                                self.points[2 * s1 + by + 2].id,
+                                self.points[2 * s1 + by + 3].id,
+                            ],
+                            [
+                                self.points[3 * s1 + by].id,
+                                self.points[3 * s1 + by + 1].id,
+                                self.points[3 * s1 + by + 2].id,
+                                self.points[3 * s1 + by + 3].id,
+                            ],
+                        ],
+                        false,
+                    ),
+                    Edge::new(
+                        [
+                            self.points[(s0 - 1) * s1 + by].id,
+                            self.points[(s0 - 1) * s1 + by + 1].id,
+                            self.points[(s0 - 1) * s1 + by + 2].id,
+                            self.points[(s0 - 1) * s1 + by + 3].id,
+                        ],
+                        [
+                            [
+                                self.points[(s0 - 1) * s1 + by].id,
+                                self.points[(s0 - 1) * s1 + by + 1].id,
+                                self.points[(s0 - 1) * s1 + by + 2].id,
+                                self.points[(s0 - 1) * s1 + by + 3].id,
+                            ],
+                            [
+                                self.points[(s0 - 2) * s1 + by].id,
+                                self.points[(s0 - 2) * s1 + by + 1].id,
+                                self.points[(s0 - 2) * s1 + by + 2].id,
+                                self.points[(s0 - 2) * s1 + by + 3].id,
+                            ],
+                            [
+                                self.points[(s0 - 3) * s1 + by].id,
+                                self.points[(s0 - 3) * s1 + by + 1].id,
+                                self.points[(s0 - 3) * s1 + by + 2].id,
+                                self.points[(s0 - 3) * s1 + by + 3].id,
+                            ],
+                            [
+                                self.points[(s0 - 4) * s1 + by].id,
+                                self.points[(s0 - 4) * s1 + by + 1].id,
+                                self.points[(s0 - 4) * s1 + by + 2].id,
+                                self.points[(s0 - 4) * s1 + by + 3].id,
+                            ],
+                        ],
+                        false,
+                    ),
+                ]
+            }))
+            .collect()
+    }
+
     pub fn replace_point(&mut self, old_point: u64, new_point: u64) {
         for i in 0..self.points.len() {
             if self.points[i].id == old_point {
@@ -86,20 +264,8 @@ impl SurfaceC0 {
 
         let size = self.size;
 
-        let wrap_u = (0..self.size.1 as usize)
-            .flat_map(|x| [3 * x, 3 * x + 1, 3 * x + 2, 3 * x + 3])
-            .all(|v| {
-                self.points[v].id
-                    == self.points[3 * self.size.0 as usize * (3 * self.size.1 as usize + 1) + v].id
-            });
-        let wrap_v = (0..self.size.0 as usize)
-            .flat_map(|x| [3 * x, 3 * x + 1, 3 * x + 2, 3 * x + 3])
-            .all(|u| {
-                self.points[(3 * u + 3) * (3 * self.size.1 as usize + 1)].id
-                    == self.points
-                        [(3 * u + 3) * (3 * self.size.1 as usize + 1) + 3 * self.size.1 as usize]
-                        .id
-            });
+        let wrap_u = self.is_closed_u();
+        let wrap_v = self.is_closed_v();
 
         IntersectionObject::new(
             IntersectionObjectId::SurfaceC0(self.id),
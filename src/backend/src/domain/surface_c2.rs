@@ -1,7 +1,8 @@
 use math::vector3::Vector3;
 
 use super::{
-    intersection::IntersectionObjectId, intersection_object::IntersectionObject, point::Point,
+    gregory::Edge, intersection::IntersectionObjectId, intersection_object::IntersectionObject,
+    point::Point, surface_display_mode::SurfaceDisplayMode,
 };
 
 pub struct SurfaceC2 {
@@ -9,9 +10,13 @@ pub struct SurfaceC2 {
     pub name: String,
     pub draw_polygon: bool,
     pub tess_level: u8,
+    pub display_mode: SurfaceDisplayMode,
     pub points: Vec<SurfaceC2Point>,
     pub size: (u32, u32),
     pub is_cylinder: bool,
+    pub interpolation: SurfaceC2Interpolation,
+    pub interpolation_points: Vec<SurfaceC2Point>,
+    pub loft_sections: Vec<Vec<SurfaceC2Point>>,
 }
 
 #[derive(Clone)]
@@ -19,6 +24,14 @@ pub struct SurfaceC2Point {
     pub id: u64,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SurfaceC2Interpolation {
+    #[default]
+    None,
+    Grid,
+    Loft,
+}
+
 impl SurfaceC2 {
     pub fn new(id: u64, points: Vec<SurfaceC2Point>, size: (u32, u32), is_cylinder: bool) -> Self {
         Self {
@@ -26,9 +39,13 @@ impl SurfaceC2 {
             name: format!("SurfaceC2 {}", id),
             draw_polygon: false,
             tess_level: 4,
+            display_mode: SurfaceDisplayMode::default(),
             points,
             size,
             is_cylinder,
+            interpolation: SurfaceC2Interpolation::None,
+            interpolation_points: vec![],
+            loft_sections: vec![],
         }
     }
 
@@ -44,9 +61,13 @@ impl SurfaceC2 {
             name,
             draw_polygon: false,
             tess_level: 4,
+            display_mode: SurfaceDisplayMode::default(),
             points,
             size,
             is_cylinder,
+            interpolation: SurfaceC2Interpolation::None,
+            interpolation_points: vec![],
+            loft_sections: vec![],
         }
     }
 
@@ -62,12 +83,92 @@ impl SurfaceC2 {
         self.tess_level = tess_level;
     }
 
+    pub fn set_display_mode(&mut self, display_mode: SurfaceDisplayMode) {
+        self.display_mode = display_mode;
+    }
+
+    pub fn set_interpolation_points(&mut self, interpolation_points: Vec<SurfaceC2Point>) {
+        self.interpolation = SurfaceC2Interpolation::Grid;
+        self.interpolation_points = interpolation_points;
+    }
+
+    pub fn set_loft_sections(&mut self, loft_sections: Vec<Vec<SurfaceC2Point>>) {
+        self.interpolation = SurfaceC2Interpolation::Loft;
+        self.loft_sections = loft_sections;
+    }
+
+    pub fn clear_interpolation(&mut self) {
+        self.interpolation = SurfaceC2Interpolation::None;
+        self.interpolation_points.clear();
+        self.loft_sections.clear();
+    }
+
+    pub fn related_points(&self) -> Vec<u64> {
+        self.points
+            .iter()
+            .chain(self.interpolation_points.iter())
+            .chain(self.loft_sections.iter().flatten())
+            .map(|p| p.id)
+            .collect()
+    }
+
+    pub fn is_closed_u(&self) -> bool {
+        let s1 = self.size.1 as usize + 3;
+        let last = self.size.0 as usize * s1;
+        (0..3 * s1).all(|i| self.points[i].id == self.points[last + i].id)
+    }
+
+    pub fn is_closed_v(&self) -> bool {
+        let s1 = self.size.1 as usize + 3;
+        self.points
+            .chunks(s1)
+            .all(|column| (0..3).all(|y| column[y].id == column[s1 - 3 + y].id))
+    }
+
+    pub fn border_edges(&self) -> Vec<Edge> {
+        let s0 = self.size.0 as usize + 3;
+        let s1 = self.size.1 as usize + 3;
+        let edge = |position: &dyn Fn(usize, usize) -> (usize, usize)| {
+            let patch_points: [[u64; 4]; 4] = std::array::from_fn(|r| {
+                std::array::from_fn(|c| {
+                    let (x, y) = position(r, c);
+                    self.points[x * s1 + y].id
+                })
+            });
+            Edge::new(patch_points[0], patch_points, true)
+        };
+
+        let mut edges = vec![];
+        if !self.is_closed_v() {
+            edges.extend(
+                (0..self.size.0 as usize)
+                    .flat_map(|x| [edge(&|r, c| (x + c, r)), edge(&|r, c| (x + c, s1 - 1 - r))]),
+            );
+        }
+        if !self.is_cylinder {
+            edges.extend(
+                (0..self.size.1 as usize)
+                    .flat_map(|y| [edge(&|r, c| (r, y + c)), edge(&|r, c| (s0 - 1 - r, y + c))]),
+            );
+        }
+        edges
+    }
+
     pub fn replace_point(&mut self, old_point: u64, new_point: u64) {
         for i in 0..self.points.len() {
             if self.points[i].id == old_point {
                 self.points[i] = SurfaceC2Point { id: new_point };
             }
         }
+        for point in self
+            .interpolation_points
+            .iter_mut()
+            .chain(self.loft_sections.iter_mut().flatten())
+        {
+            if point.id == old_point {
+                point.id = new_point;
+            }
+        }
     }
 
     pub fn get_intersection_object(&self, points: &[Point]) -> IntersectionObject {
@@ -86,29 +187,8 @@ impl SurfaceC2 {
 
         let size = self.size;
 
-        let wrap_u = (0..self.size.1 as usize)
-            .flat_map(|x| [x, x + 1, x + 2, x + 3])
-            .all(|v| {
-                self.points[v].id
-                    == self.points[(self.size.0 as usize - 3) * (self.size.1 as usize + 3) + v].id
-                    && self.points[(self.size.1 as usize + 3) + v].id
-                        == self.points[(self.size.0 as usize - 2) * (self.size.1 as usize + 3) + v]
-                            .id
-                    && self.points[2 * (self.size.1 as usize + 3) + v].id
-                        == self.points[(self.size.0 as usize - 1) * (self.size.1 as usize + 3) + v]
-                            .id
-            });
-
-        let wrap_v = (0..self.size.0 as usize)
-            .flat_map(|x| [x, x + 1, x + 2, x + 3])
-            .all(|u| {
-                self.points[u * (self.size.1 as usize + 3) + 0].id
-                    == self.points[u * (self.size.1 as usize + 3) + self.size.0 as usize - 3].id
-                    && self.points[u * (self.size.1 as usize + 3) + 1].id
-                        == self.points[u * (self.size.1 as usize + 3) + self.size.0 as usize - 2].id
-                    && self.points[u * (self.size.1 as usize + 3) + 2].id
-                        == self.points[u * (self.size.1 as usize + 3) + self.size.0 as usize - 1].id
-            });
+        let wrap_u = self.is_closed_u();
+        let wrap_v = self.is_closed_v();
 
         IntersectionObject::new(
             IntersectionObjectId::SurfaceC2(self.id),
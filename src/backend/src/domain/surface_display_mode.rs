@@ -0,0 +1,9 @@
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SurfaceDisplayMode {
+    #[default]
+    Wireframe,
+    Normals,
+    GaussianCurvature,
+    MeanCurvature,
+    Zebra,
+}
@@ -0,0 +1,71 @@
+use math::vector3::Vector3;
+
+use super::cutter::Cutter;
+
+pub struct Toolpath {
+    pub id: u64,
+    pub name: String,
+    pub cutter: Cutter,
+    pub points: Vec<Vector3>,
+    pub visible: bool,
+}
+
+impl Toolpath {
+    pub fn new(id: u64, cutter: Cutter, points: Vec<Vector3>) -> Self {
+        Self {
+            id,
+            name: format!("Toolpath {}", id),
+            cutter,
+            points,
+            visible: true,
+        }
+    }
+
+    pub fn new_with_name(id: u64, name: String, cutter: Cutter, points: Vec<Vector3>) -> Self {
+        Self {
+            id,
+            name,
+            cutter,
+            points,
+            visible: true,
+        }
+    }
+
+    pub fn concatenate(id: u64, toolpaths: &[&Toolpath]) -> Option<Self> {
+        let cutter = toolpaths.first()?.cutter;
+        if toolpaths.iter().any(|t| t.cutter != cutter) {
+            return None;
+        }
+
+        Some(Self::new(
+            id,
+            cutter,
+            toolpaths
+                .iter()
+                .flat_map(|t| t.points.iter().cloned())
+                .collect(),
+        ))
+    }
+
+    pub fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    pub fn reverse(&mut self) {
+        self.points.reverse();
+    }
+
+    pub fn trim(&mut self, start: usize, end: usize) {
+        if self.points.is_empty() {
+            return;
+        }
+
+        let end = end.min(self.points.len() - 1);
+        let start = start.min(end);
+        self.points = self.points[start..=end].to_vec();
+    }
+}
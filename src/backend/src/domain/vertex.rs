@@ -31,3 +31,20 @@ impl VertexUV {
         }
     }
 }
+
+#[derive(Copy, Clone)]
+pub struct WeightedVertex {
+    pub position: [f32; 3],
+    pub weight: f32,
+}
+
+implement_vertex!(WeightedVertex, position, weight);
+
+#[derive(Copy, Clone)]
+pub struct WeightedVertexUV {
+    pub position: [f32; 3],
+    pub weight: f32,
+    pub uv: [f32; 2],
+}
+
+implement_vertex!(WeightedVertexUV, position, weight, uv);
@@ -23,6 +23,9 @@ impl Consumer<PointCreated> for AddPointToSelectedBeziersC2OnPointCreated {
             .for_each(|object| {
                 let id = object.bezier_c2_id.unwrap();
                 let bezier = storage.beziers_c2.get_mut(&id).unwrap();
+                if bezier.b_spline_points.iter().any(|p| p.id == event.id) {
+                    return;
+                }
                 let storage = unsafe { &mut (*backend).storage };
                 let points: Vec<_> = bezier
                     .b_spline_points
@@ -32,11 +35,16 @@ impl Consumer<PointCreated> for AddPointToSelectedBeziersC2OnPointCreated {
                 let mut points = points;
                 let point = storage.points.get(&event.id).unwrap();
                 points.push(point.clone());
+                let index = points.len() - 1;
                 bezier.update_points(points);
                 unsafe {
                     let backend = &*backend;
-                    let event =
-                        Rc::new(PointAddedToBezierC2::new(event.id, id, event.name.clone()));
+                    let event = Rc::new(PointAddedToBezierC2::new(
+                        event.id,
+                        id,
+                        event.name.clone(),
+                        index,
+                    ));
                     backend.services.event_publisher.publish(event);
                 }
             });
@@ -5,6 +5,7 @@ use std::rc::Rc;
 use infrastructure::consumer::{AnyConsumer, Consumer};
 
 use crate::backend::Backend;
+use crate::domain::events::beziers_c2::bezier_c2_closed_set::BezierC2ClosedSet;
 use crate::domain::events::beziers_c2::bezier_c2_created::BezierC2Created;
 use crate::domain::events::beziers_c2::bezier_c2_deleted::BezierC2Deleted;
 use crate::domain::events::beziers_c2::bezier_c2_draw_b_spline_polygon_set::BezierC2DrawBSplinePolygonSet;
@@ -40,6 +41,7 @@ impl Consumer<PointAddedToBezierC2> for PointAddedToBezierC2Publisher {
                 event.point_id,
                 event.bezier_id,
                 event.point_name.clone(),
+                event.index,
             ),
         );
         backend.services.event_publisher.publish(event);
@@ -97,6 +99,23 @@ impl Consumer<BezierC2DrawBSplinePolygonSet> for BezierC2DrawBSplinePolygonSetPu
     }
 }
 
+pub struct BezierC2ClosedSetPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<BezierC2ClosedSet> for BezierC2ClosedSetPublisher {
+    fn consume(&self, event: &BezierC2ClosedSet) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::beziers_c2::bezier_c2_closed_set::BezierC2ClosedSet::new(
+                event.bezier_id,
+                event.closed,
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
 pub struct BezierC2PointsDeletedPublisher {
     pub backend: Rc<RefCell<Backend>>,
 }
@@ -190,6 +209,12 @@ impl AnyConsumer for BezierC2DrawBSplinePolygonSetPublisher {
     }
 }
 
+impl AnyConsumer for BezierC2ClosedSetPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
 impl AnyConsumer for BezierC2PointsDeletedPublisher {
     fn consume_any(&self, message: Rc<dyn Any>) {
         self.consume_any_impl(message);
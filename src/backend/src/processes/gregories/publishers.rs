@@ -4,6 +4,7 @@ use infrastructure::consumer::{AnyConsumer, Consumer};
 
 use crate::{
     backend::Backend,
+    cqrs::common::surface_display_mode::display_mode_to_dto,
     domain::events::gregories::{
         gregory_created::GregoryCreated, gregory_deleted::GregoryDeleted,
         gregory_mesh_recalculated::GregoryMeshRecalculated, gregory_renamed::GregoryRenamed,
@@ -24,6 +25,7 @@ impl Consumer<GregoryCreated> for GregoryCreatedPublisher {
                 event.name.clone(),
                 event.tess_level,
                 event.draw_vectors,
+                event.coons,
             ),
         );
         backend.services.event_publisher.publish(event);
@@ -93,6 +95,7 @@ impl Consumer<GregorySettingsUpdated> for GregorySettingsUpdatedPublisher {
                 event.gregory_id,
                 event.tess_level,
                 event.draw_vectors,
+                display_mode_to_dto(event.display_mode),
             ),
         );
         backend.services.event_publisher.publish(event);
@@ -68,6 +68,7 @@ fn map_id(domain_id: &IntersectionObjectId) -> IntersectionObjectIdDTO {
         IntersectionObjectId::Torus(id) => IntersectionObjectIdDTO::Torus(*id),
         IntersectionObjectId::SurfaceC0(id) => IntersectionObjectIdDTO::SurfaceC0(*id),
         IntersectionObjectId::SurfaceC2(id) => IntersectionObjectIdDTO::SurfaceC2(*id),
+        IntersectionObjectId::NurbsSurface(id) => IntersectionObjectIdDTO::NurbsSurface(*id),
     }
 }
 
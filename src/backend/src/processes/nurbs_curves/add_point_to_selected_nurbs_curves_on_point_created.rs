@@ -0,0 +1,49 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::backend::Backend;
+use crate::domain::events::points::point_added_to_nurbs_curve::PointAddedToNurbsCurve;
+use crate::domain::events::points::point_created::PointCreated;
+
+pub struct AddPointToSelectedNurbsCurvesOnPointCreated {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<PointCreated> for AddPointToSelectedNurbsCurvesOnPointCreated {
+    fn consume(&self, event: &PointCreated) {
+        let backend = self.backend.as_ptr();
+        let storage = unsafe { &mut (*backend).storage };
+        storage
+            .selected_objects
+            .iter()
+            .filter_map(|object| object.nurbs_curve_id)
+            .for_each(|id| {
+                let storage = unsafe { &mut (*backend).storage };
+                let curve = storage.nurbs_curves.get_mut(&id).unwrap();
+                if curve.points.iter().any(|p| p.id == event.id) {
+                    return;
+                }
+                curve.add_point(event.id, &storage.points);
+                let index = curve.points.len() - 1;
+                unsafe {
+                    let backend = &*backend;
+                    let event = Rc::new(PointAddedToNurbsCurve::new(
+                        event.id,
+                        id,
+                        event.name.clone(),
+                        index,
+                    ));
+                    backend.services.event_publisher.publish(event);
+                }
+            });
+    }
+}
+
+impl AnyConsumer for AddPointToSelectedNurbsCurvesOnPointCreated {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
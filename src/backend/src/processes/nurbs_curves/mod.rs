@@ -0,0 +1,3 @@
+pub mod add_point_to_selected_nurbs_curves_on_point_created;
+pub mod move_nurbs_curve_points_on_point_moved;
+pub mod publishers;
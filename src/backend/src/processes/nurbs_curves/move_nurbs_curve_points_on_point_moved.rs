@@ -0,0 +1,34 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::backend::Backend;
+use crate::domain::events::nurbs_curves::nurbs_curve_point_moved::NurbsCurvePointMoved;
+use crate::domain::events::points::point_moved::PointMoved;
+
+pub struct MoveNurbsCurvePointsOnPointMoved {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<PointMoved> for MoveNurbsCurvePointsOnPointMoved {
+    fn consume(&self, event: &PointMoved) {
+        let backend = self.backend.as_ptr();
+        let storage = unsafe { &mut (*backend).storage };
+        let publisher = unsafe { &(*backend).services.event_publisher };
+        storage.nurbs_curves.values_mut().for_each(|curve| {
+            if curve.points.iter().any(|point| point.id == event.id) {
+                let storage = unsafe { &(*backend).storage };
+                curve.update_points(&storage.points);
+                publisher.publish(Rc::new(NurbsCurvePointMoved::new(curve.id)));
+            }
+        });
+    }
+}
+
+impl AnyConsumer for MoveNurbsCurvePointsOnPointMoved {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -0,0 +1,190 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::backend::Backend;
+use crate::domain::events::nurbs_curves::nurbs_curve_created::NurbsCurveCreated;
+use crate::domain::events::nurbs_curves::nurbs_curve_deleted::NurbsCurveDeleted;
+use crate::domain::events::nurbs_curves::nurbs_curve_draw_polygon_set::NurbsCurveDrawPolygonSet;
+use crate::domain::events::nurbs_curves::nurbs_curve_point_moved::NurbsCurvePointMoved;
+use crate::domain::events::nurbs_curves::nurbs_curve_points_deleted::NurbsCurvePointsDeleted;
+use crate::domain::events::nurbs_curves::nurbs_curve_renamed::NurbsCurveRenamed;
+use crate::domain::events::nurbs_curves::nurbs_curve_weight_set::NurbsCurveWeightSet;
+use crate::domain::events::points::point_added_to_nurbs_curve::PointAddedToNurbsCurve;
+
+pub struct NurbsCurveCreatedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<NurbsCurveCreated> for NurbsCurveCreatedPublisher {
+    fn consume(&self, event: &NurbsCurveCreated) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::nurbs_curves::nurbs_curve_created::NurbsCurveCreated::new(event.id),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+pub struct NurbsCurveDeletedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<NurbsCurveDeleted> for NurbsCurveDeletedPublisher {
+    fn consume(&self, event: &NurbsCurveDeleted) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::nurbs_curves::nurbs_curve_deleted::NurbsCurveDeleted::new(event.id),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+pub struct NurbsCurveRenamedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<NurbsCurveRenamed> for NurbsCurveRenamedPublisher {
+    fn consume(&self, event: &NurbsCurveRenamed) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::nurbs_curves::nurbs_curve_renamed::NurbsCurveRenamed::new(
+                event.id,
+                event.name.clone(),
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+pub struct NurbsCurvePointsDeletedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<NurbsCurvePointsDeleted> for NurbsCurvePointsDeletedPublisher {
+    fn consume(&self, event: &NurbsCurvePointsDeleted) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::nurbs_curves::nurbs_curve_points_deleted::NurbsCurvePointsDeleted::new(
+                event.id,
+                event.deleted_points.clone(),
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+pub struct NurbsCurvePointMovedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<NurbsCurvePointMoved> for NurbsCurvePointMovedPublisher {
+    fn consume(&self, event: &NurbsCurvePointMoved) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::nurbs_curves::nurbs_curve_point_moved::NurbsCurvePointMoved::new(
+                event.curve_id,
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+pub struct NurbsCurveWeightSetPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<NurbsCurveWeightSet> for NurbsCurveWeightSetPublisher {
+    fn consume(&self, event: &NurbsCurveWeightSet) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::nurbs_curves::nurbs_curve_weight_set::NurbsCurveWeightSet::new(
+                event.curve_id,
+                event.point_id,
+                event.weight,
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+pub struct NurbsCurveDrawPolygonSetPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<NurbsCurveDrawPolygonSet> for NurbsCurveDrawPolygonSetPublisher {
+    fn consume(&self, event: &NurbsCurveDrawPolygonSet) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(backend_events::nurbs_curves::nurbs_curve_draw_polygon_set::NurbsCurveDrawPolygonSet::new(event.curve_id, event.draw_polygon));
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+pub struct PointAddedToNurbsCurvePublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<PointAddedToNurbsCurve> for PointAddedToNurbsCurvePublisher {
+    fn consume(&self, event: &PointAddedToNurbsCurve) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::points::point_added_to_nurbs_curve::PointAddedToNurbsCurve::new(
+                event.point_id,
+                event.curve_id,
+                event.point_name.clone(),
+                event.index,
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+impl AnyConsumer for NurbsCurveCreatedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+impl AnyConsumer for NurbsCurveDeletedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+impl AnyConsumer for NurbsCurveRenamedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+impl AnyConsumer for NurbsCurvePointsDeletedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+impl AnyConsumer for NurbsCurvePointMovedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+impl AnyConsumer for NurbsCurveWeightSetPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+impl AnyConsumer for NurbsCurveDrawPolygonSetPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+impl AnyConsumer for PointAddedToNurbsCurvePublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
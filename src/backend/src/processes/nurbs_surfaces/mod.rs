@@ -0,0 +1,2 @@
+pub mod move_nurbs_surface_point_on_point_moved;
+pub mod publishers;
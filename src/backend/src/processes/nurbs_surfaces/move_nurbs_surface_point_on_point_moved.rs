@@ -0,0 +1,32 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend_events::nurbs_surfaces::nurbs_surface_point_moved::NurbsSurfacePointMoved;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::backend::Backend;
+use crate::domain::events::points::point_moved::PointMoved;
+
+pub struct MoveNurbsSurfacePointOnPointMoved {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<PointMoved> for MoveNurbsSurfacePointOnPointMoved {
+    fn consume(&self, event: &PointMoved) {
+        let backend = self.backend.as_ptr();
+        let storage = unsafe { &mut (*backend).storage };
+        let publisher = unsafe { &(*backend).services.event_publisher };
+        storage.nurbs_surfaces.values().for_each(|surface| {
+            if surface.points.iter().any(|point| point.id == event.id) {
+                publisher.publish(Rc::new(NurbsSurfacePointMoved::new(surface.id)));
+            }
+        });
+    }
+}
+
+impl AnyConsumer for MoveNurbsSurfacePointOnPointMoved {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
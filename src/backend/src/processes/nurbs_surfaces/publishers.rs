@@ -0,0 +1,126 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::backend::Backend;
+use crate::domain::events::nurbs_surfaces::nurbs_surface_created::NurbsSurfaceCreated;
+use crate::domain::events::nurbs_surfaces::nurbs_surface_deleted::NurbsSurfaceDeleted;
+use crate::domain::events::nurbs_surfaces::nurbs_surface_points_selected::NurbsSurfacePointsSelected;
+use crate::domain::events::nurbs_surfaces::nurbs_surface_updated::NurbsSurfaceUpdated;
+use crate::domain::events::nurbs_surfaces::nurbs_surface_weight_set::NurbsSurfaceWeightSet;
+
+pub struct NurbsSurfaceCreatedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<NurbsSurfaceCreated> for NurbsSurfaceCreatedPublisher {
+    fn consume(&self, message: &NurbsSurfaceCreated) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::nurbs_surfaces::nurbs_surface_created::NurbsSurfaceCreated::new(
+                message.id,
+                message.size,
+                message.is_cylinder,
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+impl AnyConsumer for NurbsSurfaceCreatedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+pub struct NurbsSurfacePointsSelectedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<NurbsSurfacePointsSelected> for NurbsSurfacePointsSelectedPublisher {
+    fn consume(&self, message: &NurbsSurfacePointsSelected) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::nurbs_surfaces::nurbs_surface_points_selected::NurbsSurfacePointsSelected::new(message.surface_id),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+impl AnyConsumer for NurbsSurfacePointsSelectedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+pub struct NurbsSurfaceUpdatedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<NurbsSurfaceUpdated> for NurbsSurfaceUpdatedPublisher {
+    fn consume(&self, message: &NurbsSurfaceUpdated) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::nurbs_surfaces::nurbs_surface_updated::NurbsSurfaceUpdated::new(
+                message.id,
+                message.draw_polygon,
+                message.tess_level,
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+impl AnyConsumer for NurbsSurfaceUpdatedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+pub struct NurbsSurfaceDeletedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<NurbsSurfaceDeleted> for NurbsSurfaceDeletedPublisher {
+    fn consume(&self, message: &NurbsSurfaceDeleted) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::nurbs_surfaces::nurbs_surface_deleted::NurbsSurfaceDeleted::new(
+                message.id,
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+impl AnyConsumer for NurbsSurfaceDeletedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+pub struct NurbsSurfaceWeightSetPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<NurbsSurfaceWeightSet> for NurbsSurfaceWeightSetPublisher {
+    fn consume(&self, message: &NurbsSurfaceWeightSet) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::nurbs_surfaces::nurbs_surface_weight_set::NurbsSurfaceWeightSet::new(
+                message.surface_id,
+                message.point_id,
+                message.weight,
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+impl AnyConsumer for NurbsSurfaceWeightSetPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -5,9 +5,12 @@ use std::rc::Rc;
 use infrastructure::consumer::{AnyConsumer, Consumer};
 
 use crate::backend::Backend;
+use crate::cqrs::common::surface_display_mode::display_mode_to_dto;
 use crate::domain::events::surfaces_c0::surface_c0_created::SurfaceC0Created;
 use crate::domain::events::surfaces_c0::surface_c0_deleted::SurfaceC0Deleted;
+use crate::domain::events::surfaces_c0::surface_c0_edit_refused::SurfaceC0EditRefused;
 use crate::domain::events::surfaces_c0::surface_c0_points_selected::SurfaceC0PointsSelected;
+use crate::domain::events::surfaces_c0::surface_c0_resized::SurfaceC0Resized;
 use crate::domain::events::surfaces_c0::surface_c0_updated::SurfaceC0Updated;
 
 pub struct SurfaceC0CreatedPublisher {
@@ -68,6 +71,7 @@ impl Consumer<SurfaceC0Updated> for SurfaceC0UpdatedPublisher {
                 message.id,
                 message.draw_polygon,
                 message.tess_level,
+                display_mode_to_dto(message.display_mode),
             ),
         );
         backend.services.event_publisher.publish(event);
@@ -99,3 +103,49 @@ impl AnyConsumer for SurfaceC0DeletedPublisher {
         self.consume_any_impl(message);
     }
 }
+
+pub struct SurfaceC0ResizedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<SurfaceC0Resized> for SurfaceC0ResizedPublisher {
+    fn consume(&self, message: &SurfaceC0Resized) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::surfaces_c0::surface_c0_resized::SurfaceC0Resized::new(
+                message.id,
+                message.size,
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+impl AnyConsumer for SurfaceC0ResizedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+pub struct SurfaceC0EditRefusedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<SurfaceC0EditRefused> for SurfaceC0EditRefusedPublisher {
+    fn consume(&self, message: &SurfaceC0EditRefused) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::surfaces_c0::surface_c0_edit_refused::SurfaceC0EditRefused::new(
+                message.id,
+                message.reason.clone(),
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+impl AnyConsumer for SurfaceC0EditRefusedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -0,0 +1,63 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use infrastructure::consumer::{AnyConsumer, Consumer};
+use nalgebra::Vector3;
+
+use crate::backend::Backend;
+use crate::domain::events::points::point_moved::PointMoved;
+use crate::domain::surface_c2::SurfaceC2Interpolation;
+use crate::services::create_surface::interpolate_b_spline;
+
+pub struct InterpolateLoftSurfaceC2OnPointMoved {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<PointMoved> for InterpolateLoftSurfaceC2OnPointMoved {
+    fn consume(&self, event: &PointMoved) {
+        let backend = self.backend.as_ptr();
+        let storage = unsafe { &mut (*backend).storage };
+        let publisher = unsafe { &(*backend).services.event_publisher };
+
+        let mut events = vec![];
+        for surface in storage.surfaces_c2.values().filter(|s| {
+            s.interpolation == SurfaceC2Interpolation::Loft
+                && s.loft_sections.iter().flatten().any(|p| p.id == event.id)
+        }) {
+            let count = surface.loft_sections.len();
+            let size_y = count + 2;
+            for x in 0..surface.loft_sections[0].len() {
+                let column = surface
+                    .loft_sections
+                    .iter()
+                    .map(|section| {
+                        let position = storage.points[&section[x].id].transformer.position;
+                        Vector3::new(position.0, position.1, position.2)
+                    })
+                    .collect::<Vec<_>>();
+
+                for (y, position) in interpolate_b_spline(&column).iter().enumerate() {
+                    if y == 1 || y == count {
+                        continue;
+                    }
+
+                    let id = surface.points[x * size_y + y].id;
+                    let position = (position.x, position.y, position.z);
+                    storage.points.get_mut(&id).unwrap().transform(position);
+                    events.push(Rc::new(PointMoved::new(id, position)));
+                }
+            }
+        }
+
+        for event in events {
+            publisher.publish(event);
+        }
+    }
+}
+
+impl AnyConsumer for InterpolateLoftSurfaceC2OnPointMoved {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -0,0 +1,67 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use infrastructure::consumer::{AnyConsumer, Consumer};
+use nalgebra::Vector3;
+
+use crate::backend::Backend;
+use crate::domain::events::points::point_moved::PointMoved;
+use crate::domain::surface_c2::SurfaceC2Interpolation;
+use crate::services::create_surface::interpolate_surface_c2;
+
+pub struct InterpolateSurfaceC2OnPointMoved {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<PointMoved> for InterpolateSurfaceC2OnPointMoved {
+    fn consume(&self, event: &PointMoved) {
+        let backend = self.backend.as_ptr();
+        let storage = unsafe { &mut (*backend).storage };
+        let publisher = unsafe { &(*backend).services.event_publisher };
+
+        let mut events = vec![];
+        for surface in storage.surfaces_c2.values().filter(|s| {
+            s.interpolation == SurfaceC2Interpolation::Grid
+                && s.interpolation_points.iter().any(|p| p.id == event.id)
+        }) {
+            let grid = surface
+                .interpolation_points
+                .chunks(surface.size.1 as usize + 1)
+                .map(|column| {
+                    column
+                        .iter()
+                        .map(|p| {
+                            let position = storage.points[&p.id].transformer.position;
+                            Vector3::new(position.0, position.1, position.2)
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            for (point, position) in surface
+                .points
+                .iter()
+                .zip(interpolate_surface_c2(&grid).iter().flatten())
+            {
+                let position = (position.x, position.y, position.z);
+                storage
+                    .points
+                    .get_mut(&point.id)
+                    .unwrap()
+                    .transform(position);
+                events.push(Rc::new(PointMoved::new(point.id, position)));
+            }
+        }
+
+        for event in events {
+            publisher.publish(event);
+        }
+    }
+}
+
+impl AnyConsumer for InterpolateSurfaceC2OnPointMoved {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -1,2 +1,4 @@
+pub mod interpolate_loft_surface_c2_on_point_moved;
+pub mod interpolate_surface_c2_on_point_moved;
 pub mod move_surface_c2_point_on_point_moved;
 pub mod publishers;
@@ -5,9 +5,12 @@ use std::rc::Rc;
 use infrastructure::consumer::{AnyConsumer, Consumer};
 
 use crate::backend::Backend;
+use crate::cqrs::common::surface_display_mode::display_mode_to_dto;
 use crate::domain::events::surfaces_c2::surface_c2_created::SurfaceC2Created;
 use crate::domain::events::surfaces_c2::surface_c2_deleted::SurfaceC2Deleted;
+use crate::domain::events::surfaces_c2::surface_c2_edit_refused::SurfaceC2EditRefused;
 use crate::domain::events::surfaces_c2::surface_c2_points_selected::SurfaceC2PointsSelected;
+use crate::domain::events::surfaces_c2::surface_c2_resized::SurfaceC2Resized;
 use crate::domain::events::surfaces_c2::surface_c2_updated::SurfaceC2Updated;
 
 pub struct SurfaceC2CreatedPublisher {
@@ -68,6 +71,7 @@ impl Consumer<SurfaceC2Updated> for SurfaceC2UpdatedPublisher {
                 message.id,
                 message.draw_polygon,
                 message.tess_level,
+                display_mode_to_dto(message.display_mode),
             ),
         );
         backend.services.event_publisher.publish(event);
@@ -99,3 +103,49 @@ impl AnyConsumer for SurfaceC2DeletedPublisher {
         self.consume_any_impl(message);
     }
 }
+
+pub struct SurfaceC2ResizedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<SurfaceC2Resized> for SurfaceC2ResizedPublisher {
+    fn consume(&self, message: &SurfaceC2Resized) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::surfaces_c2::surface_c2_resized::SurfaceC2Resized::new(
+                message.id,
+                message.size,
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+impl AnyConsumer for SurfaceC2ResizedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+pub struct SurfaceC2EditRefusedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<SurfaceC2EditRefused> for SurfaceC2EditRefusedPublisher {
+    fn consume(&self, message: &SurfaceC2EditRefused) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::surfaces_c2::surface_c2_edit_refused::SurfaceC2EditRefused::new(
+                message.id,
+                message.reason.clone(),
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+impl AnyConsumer for SurfaceC2EditRefusedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
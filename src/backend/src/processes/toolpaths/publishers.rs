@@ -0,0 +1,104 @@
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::{
+    backend::Backend,
+    domain::events::toolpaths::{
+        toolpath_created::ToolpathCreated, toolpath_deleted::ToolpathDeleted,
+        toolpath_renamed::ToolpathRenamed, toolpath_updated::ToolpathUpdated,
+    },
+};
+
+pub struct ToolpathCreatedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<ToolpathCreated> for ToolpathCreatedPublisher {
+    fn consume(&self, event: &ToolpathCreated) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::toolpaths::toolpath_created::ToolpathCreated::new(
+                event.toolpath_id,
+                event.name.clone(),
+                event.cutter_name.clone(),
+                event.points.clone(),
+                event.visible,
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+impl AnyConsumer for ToolpathCreatedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+pub struct ToolpathRenamedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<ToolpathRenamed> for ToolpathRenamedPublisher {
+    fn consume(&self, event: &ToolpathRenamed) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::toolpaths::toolpath_renamed::ToolpathRenamed::new(
+                event.toolpath_id,
+                event.name.clone(),
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+impl AnyConsumer for ToolpathRenamedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+pub struct ToolpathUpdatedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<ToolpathUpdated> for ToolpathUpdatedPublisher {
+    fn consume(&self, event: &ToolpathUpdated) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::toolpaths::toolpath_updated::ToolpathUpdated::new(
+                event.toolpath_id,
+                event.points.clone(),
+                event.visible,
+            ),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+impl AnyConsumer for ToolpathUpdatedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+pub struct ToolpathDeletedPublisher {
+    pub backend: Rc<RefCell<Backend>>,
+}
+
+impl Consumer<ToolpathDeleted> for ToolpathDeletedPublisher {
+    fn consume(&self, event: &ToolpathDeleted) {
+        let backend = self.backend.borrow();
+        let event = Rc::new(
+            backend_events::toolpaths::toolpath_deleted::ToolpathDeleted::new(event.toolpath_id),
+        );
+        backend.services.event_publisher.publish(event);
+    }
+}
+
+impl AnyConsumer for ToolpathDeletedPublisher {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::domain::bezier_c0::{BezierC0, BezierC0Point};
+use crate::domain::point::Point;
+use crate::domain::transformer::LittleTransformer;
+use crate::services::id_generator::IdGenerator;
+
+pub fn bezier_c0_from_bernstein_points(
+    mut bernstein_points: Vec<LittleTransformer>,
+    closed: bool,
+    id_generator: &mut IdGenerator,
+) -> Option<(BezierC0, Vec<Point>)> {
+    if bernstein_points.is_empty() {
+        return None;
+    }
+    let (first, last) = (
+        bernstein_points[0].position,
+        bernstein_points[bernstein_points.len() - 1].position,
+    );
+    let closed = closed
+        && bernstein_points.len() > 4
+        && (first.0 - last.0).abs() + (first.1 - last.1).abs() + (first.2 - last.2).abs() < 1e-6;
+    if closed {
+        bernstein_points.pop();
+    }
+
+    let points = bernstein_points
+        .into_iter()
+        .map(|transformer| Point::new(id_generator.next(), transformer))
+        .collect::<Vec<_>>();
+    let mut bezier_c0_points = points
+        .iter()
+        .map(|p| BezierC0Point { id: p.id })
+        .collect::<Vec<_>>();
+    if closed {
+        bezier_c0_points.push(BezierC0Point { id: points[0].id });
+    }
+    let bezier_c0 = BezierC0::new(id_generator.next(), bezier_c0_points);
+
+    Some((bezier_c0, points))
+}
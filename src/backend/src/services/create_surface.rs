@@ -1,4 +1,15 @@
-use crate::cqrs::surfaces_c0::create_surface_c0::CreateSurfaceInfoDTO;
+use std::collections::HashSet;
+use std::f64::consts::PI;
+use std::rc::Rc;
+
+use math::linear_systems::solve_tridiagonal;
+use nalgebra::{Matrix3, Vector3};
+
+use crate::cqrs::surfaces_c0::create_surface_c0::{CreateSurfaceInfoDTO, SurfaceShapeDTO};
+use crate::data_access::storage::Storage;
+use crate::domain::curve_analysis::CurveAnalysis;
+use crate::domain::events::points::point_created::PointCreated;
+use crate::domain::nurbs_surface::{NurbsSurface, NurbsSurfacePoint};
 use crate::domain::point::Point;
 use crate::domain::surface_c0::{SurfaceC0, SurfaceC0Point};
 use crate::domain::surface_c2::{SurfaceC2, SurfaceC2Point};
@@ -11,69 +22,73 @@ pub fn create_surface_c0(
     id_generator: &mut IdGenerator,
     cursor_position: &LittleTransformer,
 ) -> (SurfaceC0, Vec<Point>) {
-    if create_surface_info.is_cylinder {
-        let radius = create_surface_info.radius.unwrap();
-        let height = create_surface_info.height.unwrap();
-        let size = create_surface_info.size;
-        let size_x = size.0 * 3;
-        let size_y = size.1 * 3 + 1;
-        let points = (0..size_x)
-            .flat_map(|x| (0..size_y).map(move |y| (x, y)))
-            .map(|(x, y)| {
-                let x = x as f64;
-                let y = y as f64;
-                let angle = 2.0 * std::f64::consts::PI * x / size_x as f64;
-                let height = height * y / (size_y - 1) as f64;
-                let position = (radius * angle.cos(), radius * angle.sin(), height);
-                let position = LittleTransformer {
-                    position: (
-                        position.0 + cursor_position.position.0,
-                        position.1 + cursor_position.position.1,
-                        position.2 + cursor_position.position.2,
-                    ),
-                };
-                Point::new(id_generator.next(), position)
-            })
-            .collect::<Vec<_>>();
-        let surface_points = points
-            .iter()
-            .chain(points.iter().take(size_y as usize))
-            .map(|point| SurfaceC0Point { id: point.id })
-            .collect();
-        let surface = SurfaceC0::new(id, surface_points, create_surface_info.size, true);
-        (surface, points)
-    } else {
-        let width = create_surface_info.width.unwrap();
-        let length = create_surface_info.length.unwrap();
-        let size = create_surface_info.size;
-        let size_x = size.0 * 3 + 1;
-        let size_y = size.1 * 3 + 1;
-        let points = (0..size_x)
-            .flat_map(|x| (0..size_y).map(move |y| (x, y)))
-            .map(|(x, y)| {
-                let x = x as f64;
-                let y = y as f64;
-                let position = (
-                    width * x / (size_x - 1) as f64,
-                    0.0,
-                    length * y / (size_y - 1) as f64,
-                );
-                let position = LittleTransformer {
-                    position: (
-                        position.0 + cursor_position.position.0,
-                        position.1 + cursor_position.position.1,
-                        position.2 + cursor_position.position.2,
-                    ),
-                };
-                Point::new(id_generator.next(), position)
-            })
-            .collect::<Vec<_>>();
-        let surface_points = points
-            .iter()
-            .map(|point| SurfaceC0Point { id: point.id })
-            .collect();
-        let surface = SurfaceC0::new(id, surface_points, create_surface_info.size, false);
-        (surface, points)
+    match create_surface_info.shape {
+        SurfaceShapeDTO::Cylinder => {
+            let radius = create_surface_info.radius.unwrap();
+            let height = create_surface_info.height.unwrap();
+            let size = create_surface_info.size;
+            let size_x = size.0 * 3;
+            let size_y = size.1 * 3 + 1;
+            let points = (0..size_x)
+                .flat_map(|x| (0..size_y).map(move |y| (x, y)))
+                .map(|(x, y)| {
+                    let x = x as f64;
+                    let y = y as f64;
+                    let angle = 2.0 * std::f64::consts::PI * x / size_x as f64;
+                    let height = height * y / (size_y - 1) as f64;
+                    let position = (radius * angle.cos(), radius * angle.sin(), height);
+                    let position = LittleTransformer {
+                        position: (
+                            position.0 + cursor_position.position.0,
+                            position.1 + cursor_position.position.1,
+                            position.2 + cursor_position.position.2,
+                        ),
+                    };
+                    Point::new(id_generator.next(), position)
+                })
+                .collect::<Vec<_>>();
+            let surface_points = points
+                .iter()
+                .chain(points.iter().take(size_y as usize))
+                .map(|point| SurfaceC0Point { id: point.id })
+                .collect();
+            let surface = SurfaceC0::new(id, surface_points, create_surface_info.size, true);
+            (surface, points)
+        }
+        SurfaceShapeDTO::Sheet => {
+            let width = create_surface_info.width.unwrap();
+            let length = create_surface_info.length.unwrap();
+            let size = create_surface_info.size;
+            let size_x = size.0 * 3 + 1;
+            let size_y = size.1 * 3 + 1;
+            let points = (0..size_x)
+                .flat_map(|x| (0..size_y).map(move |y| (x, y)))
+                .map(|(x, y)| {
+                    let x = x as f64;
+                    let y = y as f64;
+                    let position = (
+                        width * x / (size_x - 1) as f64,
+                        0.0,
+                        length * y / (size_y - 1) as f64,
+                    );
+                    let position = LittleTransformer {
+                        position: (
+                            position.0 + cursor_position.position.0,
+                            position.1 + cursor_position.position.1,
+                            position.2 + cursor_position.position.2,
+                        ),
+                    };
+                    Point::new(id_generator.next(), position)
+                })
+                .collect::<Vec<_>>();
+            let surface_points = points
+                .iter()
+                .map(|point| SurfaceC0Point { id: point.id })
+                .collect();
+            let surface = SurfaceC0::new(id, surface_points, create_surface_info.size, false);
+            (surface, points)
+        }
+        _ => create_surface_c0_preset(id, create_surface_info, id_generator, cursor_position),
     }
 }
 
@@ -83,68 +98,697 @@ pub fn create_surface_c2(
     id_generator: &mut IdGenerator,
     cursor_position: &LittleTransformer,
 ) -> (SurfaceC2, Vec<Point>) {
-    if create_surface_info.is_cylinder {
-        let radius = create_surface_info.radius.unwrap();
-        let height = create_surface_info.height.unwrap();
-        let size = create_surface_info.size;
-        let size_x = size.0;
-        let size_y = size.1 + 3;
-        let points = (0..size_x)
-            .flat_map(|x| (0..size_y).map(move |y| (x, y)))
-            .map(|(x, y)| {
-                let x = x as f64;
-                let y = y as f64;
-                let angle = 2.0 * std::f64::consts::PI * x / size_x as f64;
-                let height = height * y / (size_y - 1) as f64;
-                let position = (radius * angle.cos(), radius * angle.sin(), height);
-                let position = LittleTransformer {
-                    position: (
-                        position.0 + cursor_position.position.0,
-                        position.1 + cursor_position.position.1,
-                        position.2 + cursor_position.position.2,
-                    ),
-                };
-                Point::new(id_generator.next(), position)
+    match create_surface_info.shape {
+        SurfaceShapeDTO::Cylinder => {
+            let radius = create_surface_info.radius.unwrap();
+            let height = create_surface_info.height.unwrap();
+            let size = create_surface_info.size;
+            let size_x = size.0;
+            let size_y = size.1 + 3;
+            let points = (0..size_x)
+                .flat_map(|x| (0..size_y).map(move |y| (x, y)))
+                .map(|(x, y)| {
+                    let x = x as f64;
+                    let y = y as f64;
+                    let angle = 2.0 * std::f64::consts::PI * x / size_x as f64;
+                    let height = height * y / (size_y - 1) as f64;
+                    let position = (radius * angle.cos(), radius * angle.sin(), height);
+                    let position = LittleTransformer {
+                        position: (
+                            position.0 + cursor_position.position.0,
+                            position.1 + cursor_position.position.1,
+                            position.2 + cursor_position.position.2,
+                        ),
+                    };
+                    Point::new(id_generator.next(), position)
+                })
+                .collect::<Vec<_>>();
+            let surface_points = points
+                .iter()
+                .chain(points.iter().take(3 * size_y as usize))
+                .map(|point| SurfaceC2Point { id: point.id })
+                .collect();
+            let surface = SurfaceC2::new(id, surface_points, create_surface_info.size, true);
+            (surface, points)
+        }
+        SurfaceShapeDTO::Sheet => {
+            let width = create_surface_info.width.unwrap();
+            let length = create_surface_info.length.unwrap();
+            let size = create_surface_info.size;
+            let size_x = size.0 + 3;
+            let size_y = size.1 + 3;
+            let points = (0..size_x)
+                .flat_map(|x| (0..size_y).map(move |y| (x, y)))
+                .map(|(x, y)| {
+                    let x = x as f64;
+                    let y = y as f64;
+                    let position = (
+                        width * x / (size_x - 1) as f64,
+                        0.0,
+                        length * y / (size_y - 1) as f64,
+                    );
+                    let position = LittleTransformer {
+                        position: (
+                            position.0 + cursor_position.position.0,
+                            position.1 + cursor_position.position.1,
+                            position.2 + cursor_position.position.2,
+                        ),
+                    };
+                    Point::new(id_generator.next(), position)
+                })
+                .collect::<Vec<_>>();
+            let surface_points = points
+                .iter()
+                .map(|point| SurfaceC2Point { id: point.id })
+                .collect();
+            let surface = SurfaceC2::new(id, surface_points, create_surface_info.size, false);
+            (surface, points)
+        }
+        _ => create_surface_c2_preset(id, create_surface_info, id_generator, cursor_position),
+    }
+}
+
+fn create_surface_c0_preset(
+    id: u64,
+    create_surface_info: &CreateSurfaceInfoDTO,
+    id_generator: &mut IdGenerator,
+    cursor_position: &LittleTransformer,
+) -> (SurfaceC0, Vec<Point>) {
+    let radius = create_surface_info.radius.unwrap();
+    let segments = create_surface_info.size.1 as usize;
+    let profile = match create_surface_info.shape {
+        SurfaceShapeDTO::Torus => arc_profile(
+            (radius, 0.0),
+            create_surface_info.minor_radius.unwrap(),
+            0.0,
+            2.0 * PI,
+            segments,
+        ),
+        SurfaceShapeDTO::Sphere => arc_profile((0.0, 0.0), radius, -PI / 2.0, PI, segments),
+        _ => {
+            let height = create_surface_info.height.unwrap();
+            (0..=3 * segments)
+                .map(|i| {
+                    let t = i as f64 / (3 * segments) as f64;
+                    (radius * (1.0 - t), height * t)
+                })
+                .collect()
+        }
+    };
+
+    let (mut surface, points) = create_surface_c0_of_revolution(
+        id,
+        &profile_positions(&profile, cursor_position),
+        create_surface_info.size.0,
+        cursor_position,
+        &Vector3::z(),
+        id_generator,
+    );
+    let is_torus = create_surface_info.shape == SurfaceShapeDTO::Torus;
+    let ids = share_points(
+        surface.points.iter().map(|p| p.id).collect(),
+        profile.len(),
+        |x, y| {
+            if is_torus && y == 3 * segments {
+                (x, 0)
+            } else if profile[y].0.abs() < 1e-9 {
+                (0, y)
+            } else {
+                (x, y)
+            }
+        },
+    );
+    surface.points = ids.iter().map(|&id| SurfaceC0Point { id }).collect();
+    (surface, retain_points(points, &ids))
+}
+
+fn create_surface_c2_preset(
+    id: u64,
+    create_surface_info: &CreateSurfaceInfoDTO,
+    id_generator: &mut IdGenerator,
+    cursor_position: &LittleTransformer,
+) -> (SurfaceC2, Vec<Point>) {
+    let radius = create_surface_info.radius.unwrap();
+    let segments = create_surface_info.size.1 as usize;
+    let de_boor = |center: (f64, f64), radius: f64, angle: f64| {
+        let radius = radius * 3.0 / (2.0 + angle.cos());
+        move |k: f64| {
+            (
+                center.0 + radius * (angle * k).cos(),
+                center.1 + radius * (angle * k).sin(),
+            )
+        }
+    };
+    let profile = match create_surface_info.shape {
+        SurfaceShapeDTO::Torus => {
+            let angle = 2.0 * PI / segments as f64;
+            let circle = de_boor(
+                (radius, 0.0),
+                create_surface_info.minor_radius.unwrap(),
+                angle,
+            );
+            (0..segments + 3)
+                .map(|k| circle(k as f64))
+                .collect::<Vec<_>>()
+        }
+        SurfaceShapeDTO::Sphere => {
+            let angle = PI / segments as f64;
+            let circle = de_boor((0.0, 0.0), radius, angle);
+            (0..segments + 3)
+                .map(|k| circle(k as f64 - 1.0 - segments as f64 / 2.0))
+                .map(|(x, z)| (if x.abs() < 1e-9 { 0.0 } else { x }, z))
+                .collect()
+        }
+        _ => {
+            let height = create_surface_info.height.unwrap();
+            (0..segments + 3)
+                .map(|k| {
+                    let t = (k as f64 - 1.0) / segments as f64;
+                    (radius * (1.0 - t), height * t)
+                })
+                .collect()
+        }
+    };
+
+    let (mut surface, points) = create_surface_c2_of_revolution(
+        id,
+        &profile_positions(&profile, cursor_position),
+        create_surface_info.size.0,
+        cursor_position,
+        &Vector3::z(),
+        id_generator,
+    );
+    let is_torus = create_surface_info.shape == SurfaceShapeDTO::Torus;
+    let ids = share_points(
+        surface.points.iter().map(|p| p.id).collect(),
+        profile.len(),
+        |x, y| {
+            if is_torus && y >= segments {
+                (x, y - segments)
+            } else if profile[y].0.abs() < 1e-9 {
+                (0, y)
+            } else {
+                (x, y)
+            }
+        },
+    );
+    surface.points = ids.iter().map(|&id| SurfaceC2Point { id }).collect();
+    (surface, retain_points(points, &ids))
+}
+
+pub fn create_nurbs_surface(
+    id: u64,
+    create_surface_info: &CreateSurfaceInfoDTO,
+    id_generator: &mut IdGenerator,
+    cursor_position: &LittleTransformer,
+) -> (NurbsSurface, Vec<Point>) {
+    let (surface, points) =
+        create_surface_c2(id, create_surface_info, id_generator, cursor_position);
+    let surface_points = surface
+        .points
+        .iter()
+        .map(|point| NurbsSurfacePoint {
+            id: point.id,
+            weight: 1.0,
+        })
+        .collect();
+    let surface = NurbsSurface::new(id, surface_points, surface.size, surface.is_cylinder);
+    (surface, points)
+}
+
+pub fn create_surface_c0_of_revolution(
+    id: u64,
+    profile: &[Vector3<f64>],
+    segments: u32,
+    axis_origin: &LittleTransformer,
+    axis_direction: &Vector3<f64>,
+    id_generator: &mut IdGenerator,
+) -> (SurfaceC0, Vec<Point>) {
+    let origin = Vector3::new(
+        axis_origin.position.0,
+        axis_origin.position.1,
+        axis_origin.position.2,
+    );
+    let axis = axis_direction.normalize();
+    let angle = 2.0 * PI / segments as f64;
+    let handle = 4.0 / 3.0 * (angle / 4.0).tan();
+    let size_y = profile.len();
+
+    let points = (0..segments)
+        .flat_map(|segment| {
+            let start = angle * segment as f64;
+            let end = angle * (segment + 1) as f64;
+            [(start, 0.0), (start, handle), (end, -handle)]
+        })
+        .flat_map(|(angle, handle)| {
+            profile.iter().map(move |position| {
+                let (center, radial, tangent) = revolve(position, &origin, &axis, angle);
+                center + radial + tangent * handle
+            })
+        })
+        .map(|position| Point::new(id_generator.next(), to_transformer(&position)))
+        .collect::<Vec<_>>();
+    let surface_points = points
+        .iter()
+        .chain(points.iter().take(size_y))
+        .map(|point| SurfaceC0Point { id: point.id })
+        .collect();
+    let surface = SurfaceC0::new(
+        id,
+        surface_points,
+        (segments, (size_y as u32 - 1) / 3),
+        true,
+    );
+    (surface, points)
+}
+
+pub fn create_surface_c2_of_revolution(
+    id: u64,
+    profile: &[Vector3<f64>],
+    segments: u32,
+    axis_origin: &LittleTransformer,
+    axis_direction: &Vector3<f64>,
+    id_generator: &mut IdGenerator,
+) -> (SurfaceC2, Vec<Point>) {
+    let origin = Vector3::new(
+        axis_origin.position.0,
+        axis_origin.position.1,
+        axis_origin.position.2,
+    );
+    let axis = axis_direction.normalize();
+    let angle = 2.0 * PI / segments as f64;
+    let radius_scale = 3.0 / (2.0 + angle.cos());
+    let size_y = profile.len();
+
+    let points = (0..segments)
+        .flat_map(|segment| {
+            profile.iter().map(move |position| {
+                let (center, radial, _) = revolve(position, &origin, &axis, angle * segment as f64);
+                center + radial * radius_scale
             })
+        })
+        .map(|position| Point::new(id_generator.next(), to_transformer(&position)))
+        .collect::<Vec<_>>();
+    let surface_points = points
+        .iter()
+        .chain(points.iter().take(3 * size_y))
+        .map(|point| SurfaceC2Point { id: point.id })
+        .collect();
+    let surface = SurfaceC2::new(id, surface_points, (segments, size_y as u32 - 3), true);
+    (surface, points)
+}
+
+pub fn create_loft_surface_c2(
+    id: u64,
+    sections: &[Vec<(u64, Vector3<f64>)>],
+    closed: bool,
+    interpolate: bool,
+    id_generator: &mut IdGenerator,
+) -> Option<(SurfaceC2, Vec<Point>)> {
+    let size_x = sections.first()?.len();
+    if sections.iter().any(|section| section.len() != size_x)
+        || size_x < if closed { 3 } else { 4 }
+        || sections.len() < if interpolate { 2 } else { 4 }
+    {
+        return None;
+    }
+
+    let mut points = vec![];
+    let rows = if interpolate {
+        let mut new_row = |positions: Vec<Vector3<f64>>| {
+            positions
+                .iter()
+                .map(|position| {
+                    let point = Point::new(id_generator.next(), to_transformer(position));
+                    let id = point.id;
+                    points.push(point);
+                    id
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let count = sections.len();
+        let columns = (0..size_x)
+            .map(|x| interpolate_b_spline(&sections.iter().map(|s| s[x].1).collect::<Vec<_>>()))
             .collect::<Vec<_>>();
-        let surface_points = points
+        (0..count + 2)
+            .map(|y| match y {
+                1 => sections[0].iter().map(|p| p.0).collect(),
+                y if y == count => sections[count - 1].iter().map(|p| p.0).collect(),
+                y => new_row(columns.iter().map(|column| column[y]).collect()),
+            })
+            .collect::<Vec<_>>()
+    } else {
+        sections
             .iter()
-            .chain(points.iter().take(3 * size_y as usize))
-            .map(|point| SurfaceC2Point { id: point.id })
-            .collect();
-        let surface = SurfaceC2::new(id, surface_points, create_surface_info.size, true);
-        (surface, points)
+            .map(|section| section.iter().map(|p| p.0).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    };
+
+    let size_y = rows.len();
+    let columns = (0..size_x)
+        .map(|x| rows.iter().map(|row| row[x]).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let surface_points = columns
+        .iter()
+        .chain(columns.iter().take(if closed { 3 } else { 0 }))
+        .flatten()
+        .map(|id| SurfaceC2Point { id: *id })
+        .collect();
+    let size = if closed {
+        (size_x as u32, size_y as u32 - 3)
     } else {
-        let width = create_surface_info.width.unwrap();
-        let length = create_surface_info.length.unwrap();
-        let size = create_surface_info.size;
-        let size_x = size.0 + 3;
-        let size_y = size.1 + 3;
-        let points = (0..size_x)
-            .flat_map(|x| (0..size_y).map(move |y| (x, y)))
-            .map(|(x, y)| {
-                let x = x as f64;
-                let y = y as f64;
-                let position = (
-                    width * x / (size_x - 1) as f64,
-                    0.0,
-                    length * y / (size_y - 1) as f64,
-                );
-                let position = LittleTransformer {
-                    position: (
-                        position.0 + cursor_position.position.0,
-                        position.1 + cursor_position.position.1,
-                        position.2 + cursor_position.position.2,
-                    ),
-                };
-                Point::new(id_generator.next(), position)
+        (size_x as u32 - 3, size_y as u32 - 3)
+    };
+    let mut surface = SurfaceC2::new(id, surface_points, size, closed);
+    if interpolate {
+        surface.set_loft_sections(
+            sections
+                .iter()
+                .map(|section| section.iter().map(|p| SurfaceC2Point { id: p.0 }).collect())
+                .collect(),
+        );
+    }
+    Some((surface, points))
+}
+
+pub fn create_interpolating_surface_c2(
+    id: u64,
+    grid: &[Vec<(u64, Vector3<f64>)>],
+    id_generator: &mut IdGenerator,
+) -> Option<(SurfaceC2, Vec<Point>)> {
+    let size_y = grid.first()?.len();
+    if grid.len() < 2 || size_y < 2 || grid.iter().any(|column| column.len() != size_y) {
+        return None;
+    }
+
+    let positions = grid
+        .iter()
+        .map(|column| column.iter().map(|p| p.1).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let points = interpolate_surface_c2(&positions)
+        .iter()
+        .flatten()
+        .map(|position| Point::new(id_generator.next(), to_transformer(position)))
+        .collect::<Vec<_>>();
+    let surface_points = points
+        .iter()
+        .map(|point| SurfaceC2Point { id: point.id })
+        .collect();
+    let size = (grid.len() as u32 - 1, size_y as u32 - 1);
+
+    let mut surface = SurfaceC2::new(id, surface_points, size, false);
+    surface.set_interpolation_points(
+        grid.iter()
+            .flatten()
+            .map(|p| SurfaceC2Point { id: p.0 })
+            .collect(),
+    );
+    Some((surface, points))
+}
+
+pub fn interpolate_surface_c2(grid: &[Vec<Vector3<f64>>]) -> Vec<Vec<Vector3<f64>>> {
+    let columns = grid
+        .iter()
+        .map(|column| interpolate_b_spline(column))
+        .collect::<Vec<_>>();
+    let rows = (0..columns[0].len())
+        .map(|y| interpolate_b_spline(&columns.iter().map(|c| c[y]).collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    (0..rows[0].len())
+        .map(|x| rows.iter().map(|row| row[x]).collect())
+        .collect()
+}
+
+pub fn create_sweep_surface_c0(
+    id: u64,
+    profile: &[Vector3<f64>],
+    closed: bool,
+    path: &CurveAnalysis,
+    segments: u32,
+    id_generator: &mut IdGenerator,
+) -> Option<(SurfaceC0, Vec<Point>)> {
+    if profile.len() < 4 || !(profile.len() - 1).is_multiple_of(3) || segments < 1 {
+        return None;
+    }
+
+    let profile = if closed {
+        &profile[..profile.len() - 1]
+    } else {
+        profile
+    };
+    let size_y = 3 * segments as usize + 1;
+    let points = sweep(profile, path, size_y)
+        .iter()
+        .map(|position| Point::new(id_generator.next(), to_transformer(position)))
+        .collect::<Vec<_>>();
+    let surface_points = points
+        .iter()
+        .chain(points.iter().take(if closed { size_y } else { 0 }))
+        .map(|point| SurfaceC0Point { id: point.id })
+        .collect();
+    let size = (
+        (profile.len() as u32 - if closed { 0 } else { 1 }) / 3,
+        segments,
+    );
+    Some((SurfaceC0::new(id, surface_points, size, closed), points))
+}
+
+pub fn create_sweep_surface_c2(
+    id: u64,
+    profile: &[Vector3<f64>],
+    closed: bool,
+    path: &CurveAnalysis,
+    segments: u32,
+    id_generator: &mut IdGenerator,
+) -> Option<(SurfaceC2, Vec<Point>)> {
+    if profile.len() < if closed { 3 } else { 4 } || segments < 1 {
+        return None;
+    }
+
+    let size_y = segments as usize + 3;
+    let points = sweep(profile, path, segments as usize + 1)
+        .chunks(segments as usize + 1)
+        .flat_map(interpolate_b_spline)
+        .map(|position| Point::new(id_generator.next(), to_transformer(&position)))
+        .collect::<Vec<_>>();
+    let surface_points = points
+        .iter()
+        .chain(points.iter().take(if closed { 3 * size_y } else { 0 }))
+        .map(|point| SurfaceC2Point { id: point.id })
+        .collect();
+    let size = if closed {
+        (profile.len() as u32, segments)
+    } else {
+        (profile.len() as u32 - 3, segments)
+    };
+    Some((SurfaceC2::new(id, surface_points, size, closed), points))
+}
+
+pub fn create_coons_surface_c0(
+    id: u64,
+    boundaries: &[[(u64, Vector3<f64>); 4]; 4],
+    id_generator: &mut IdGenerator,
+) -> (SurfaceC0, Vec<Point>) {
+    let mut grid = [[None; 4]; 4];
+    for k in 0..4 {
+        grid[k][0].get_or_insert(boundaries[0][k]);
+        grid[3][k].get_or_insert(boundaries[1][k]);
+        grid[3 - k][3].get_or_insert(boundaries[2][k]);
+        grid[0][3 - k].get_or_insert(boundaries[3][k]);
+    }
+    let position = |x: usize, y: usize| grid[x][y].unwrap().1;
+
+    let interior = (1..3)
+        .flat_map(|x| (1..3).map(move |y| (x, y)))
+        .map(|(x, y)| {
+            let (u, v) = (x as f64 / 3.0, y as f64 / 3.0);
+            let coons = position(0, y) * (1.0 - u)
+                + position(3, y) * u
+                + position(x, 0) * (1.0 - v)
+                + position(x, 3) * v
+                - position(0, 0) * ((1.0 - u) * (1.0 - v))
+                - position(3, 0) * (u * (1.0 - v))
+                - position(0, 3) * ((1.0 - u) * v)
+                - position(3, 3) * (u * v);
+
+            (x, y, coons)
+        })
+        .collect::<Vec<_>>();
+
+    let mut points = vec![];
+    for (x, y, position) in interior {
+        let point = Point::new(id_generator.next(), to_transformer(&position));
+        grid[x][y] = Some((point.id, position));
+        points.push(point);
+    }
+
+    let surface_points = grid
+        .iter()
+        .flatten()
+        .map(|point| SurfaceC0Point {
+            id: point.unwrap().0,
+        })
+        .collect();
+    (SurfaceC0::new(id, surface_points, (1, 1), false), points)
+}
+
+pub fn insert_points(storage: &mut Storage, points: Vec<Point>) -> Vec<Rc<PointCreated>> {
+    let events = points
+        .iter()
+        .map(|point| Rc::new(PointCreated::new(point.id, point.name.clone())))
+        .collect::<Vec<_>>();
+    for point in points {
+        storage.points.insert(point.id, point);
+    }
+    events
+}
+
+fn sweep(profile: &[Vector3<f64>], path: &CurveAnalysis, rows: usize) -> Vec<Vector3<f64>> {
+    let center = profile.iter().sum::<Vector3<f64>>() / profile.len() as f64;
+    let covariance = profile.iter().fold(Matrix3::zeros(), |sum, p| {
+        sum + (p - center) * (p - center).transpose()
+    });
+    let eigen = covariance.symmetric_eigen();
+    let order = {
+        let mut order = [0, 1, 2];
+        order.sort_by(|a, b| eigen.eigenvalues[*a].total_cmp(&eigen.eigenvalues[*b]));
+        order
+    };
+    let first_axis: Vector3<f64> = eigen.eigenvectors.column(order[2]).into();
+    let mut normal: Vector3<f64> = eigen.eigenvectors.column(order[0]).into();
+
+    let frames = path.rotation_minimizing_frames(rows, &first_axis);
+    if frames[0].tangent.dot(&normal) < 0.0 {
+        normal = -normal;
+    }
+    let second_axis = normal.cross(&first_axis);
+
+    profile
+        .iter()
+        .flat_map(|p| {
+            let offset = p - center;
+            let (x, y, z) = (
+                offset.dot(&first_axis),
+                offset.dot(&second_axis),
+                offset.dot(&normal),
+            );
+            frames.iter().map(move |frame| {
+                frame.position + frame.normal * x + frame.binormal * y + frame.tangent * z
             })
-            .collect::<Vec<_>>();
-        let surface_points = points
-            .iter()
-            .map(|point| SurfaceC2Point { id: point.id })
-            .collect();
-        let surface = SurfaceC2::new(id, surface_points, create_surface_info.size, false);
-        (surface, points)
+        })
+        .collect()
+}
+
+fn revolve(
+    position: &Vector3<f64>,
+    origin: &Vector3<f64>,
+    axis: &Vector3<f64>,
+    angle: f64,
+) -> (Vector3<f64>, Vector3<f64>, Vector3<f64>) {
+    let offset = position - origin;
+    let center = origin + axis * axis.dot(&offset);
+    let radial = offset - axis * axis.dot(&offset);
+    let binormal = axis.cross(&radial);
+    (
+        center,
+        radial * angle.cos() + binormal * angle.sin(),
+        binormal * angle.cos() - radial * angle.sin(),
+    )
+}
+
+pub fn interpolate_b_spline(points: &[Vector3<f64>]) -> Vec<Vector3<f64>> {
+    let count = points.len();
+    let free_terms = (1..count - 1)
+        .map(|i| {
+            let mut term = points[i] * 6.0;
+            if i == 1 {
+                term -= points[0];
+            }
+            if i == count - 2 {
+                term -= points[count - 1];
+            }
+            term
+        })
+        .collect::<Vec<_>>();
+    let n = free_terms.len();
+    let solved = solve_tridiagonal(&vec![1.0; n], &vec![4.0; n], &vec![1.0; n], &free_terms);
+    let control = |i: usize| match i {
+        0 => points[0],
+        i if i == count - 1 => points[count - 1],
+        i => solved[i - 1],
+    };
+
+    std::iter::once(control(0) * 2.0 - control(1))
+        .chain((0..count).map(control))
+        .chain(std::iter::once(
+            control(count - 1) * 2.0 - control(count - 2),
+        ))
+        .collect()
+}
+
+fn arc_profile(
+    center: (f64, f64),
+    radius: f64,
+    start: f64,
+    sweep: f64,
+    segments: usize,
+) -> Vec<(f64, f64)> {
+    let angle = sweep / segments as f64;
+    let handle = 4.0 / 3.0 * (angle / 4.0).tan() * radius;
+    let point = |angle: f64, handle: f64| {
+        (
+            center.0 + radius * angle.cos() - handle * angle.sin(),
+            center.1 + radius * angle.sin() + handle * angle.cos(),
+        )
+    };
+    let mut profile = vec![point(start, 0.0)];
+    for segment in 0..segments {
+        let from = start + angle * segment as f64;
+        let to = from + angle;
+        profile.extend([point(from, handle), point(to, -handle), point(to, 0.0)]);
+    }
+    profile
+        .into_iter()
+        .map(|(x, z)| (if x.abs() < 1e-9 { 0.0 } else { x }, z))
+        .collect()
+}
+
+fn profile_positions(profile: &[(f64, f64)], origin: &LittleTransformer) -> Vec<Vector3<f64>> {
+    profile
+        .iter()
+        .map(|(x, z)| {
+            Vector3::new(
+                origin.position.0 + x,
+                origin.position.1,
+                origin.position.2 + z,
+            )
+        })
+        .collect()
+}
+
+fn share_points(
+    mut ids: Vec<u64>,
+    size_y: usize,
+    shared: impl Fn(usize, usize) -> (usize, usize),
+) -> Vec<u64> {
+    for i in 0..ids.len() {
+        let (x, y) = shared(i / size_y, i % size_y);
+        ids[i] = ids[x * size_y + y];
+    }
+    ids
+}
+
+fn retain_points(points: Vec<Point>, ids: &[u64]) -> Vec<Point> {
+    let ids = ids.iter().collect::<HashSet<_>>();
+    points
+        .into_iter()
+        .filter(|point| ids.contains(&point.id))
+        .collect()
+}
+
+fn to_transformer(position: &Vector3<f64>) -> LittleTransformer {
+    LittleTransformer {
+        position: (position.x, position.y, position.z),
     }
 }
@@ -0,0 +1,628 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use nalgebra::Vector3;
+
+use crate::data_access::storage::Storage;
+use crate::domain::intersection::IntersectionObjectId;
+use crate::domain::point::Point;
+use crate::domain::surface_c0::SurfaceC0Point;
+use crate::domain::surface_c2::SurfaceC2Point;
+use crate::domain::transformer::LittleTransformer;
+use crate::services::id_generator::IdGenerator;
+
+const POINT_TOLERANCE: f64 = 1e-9;
+
+pub type SurfaceGrid = Vec<Vec<(Option<u64>, Vector3<f64>)>>;
+pub type SurfaceSizes = Vec<(u64, (u32, u32))>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SurfaceSide {
+    StartX,
+    EndX,
+    StartY,
+    EndY,
+}
+
+pub fn surface_grid(ids: &[u64], size_y: usize, points: &HashMap<u64, Point>) -> SurfaceGrid {
+    ids.chunks(size_y)
+        .map(|column| {
+            column
+                .iter()
+                .map(|id| {
+                    let position = points[id].transformer.position;
+                    (Some(*id), Vector3::new(position.0, position.1, position.2))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+struct SurfaceRebuild {
+    id: u64,
+    size: (u32, u32),
+    ids: Vec<u64>,
+    points: Vec<Point>,
+}
+
+pub fn rebuild_surface_c0(
+    storage: &mut Storage,
+    id: u64,
+    grid: &SurfaceGrid,
+    id_generator: &mut IdGenerator,
+) -> Result<(u32, u32), String> {
+    let rebuild = surface_c0_rebuild(storage, id, grid, &[], id_generator);
+    let size = rebuild.size;
+    rebuild_surfaces_c0(storage, vec![rebuild])?;
+    Ok(size)
+}
+
+pub fn rebuild_surface_c2(
+    storage: &mut Storage,
+    id: u64,
+    grid: &SurfaceGrid,
+    id_generator: &mut IdGenerator,
+) -> Result<(u32, u32), String> {
+    let rebuild = surface_c2_rebuild(storage, id, grid, &[], id_generator);
+    let size = rebuild.size;
+    rebuild_surfaces_c2(storage, vec![rebuild])?;
+    Ok(size)
+}
+
+pub fn refine_surface_c0(
+    storage: &mut Storage,
+    id: u64,
+    id_generator: &mut IdGenerator,
+) -> Result<SurfaceSizes, String> {
+    let surface = &storage.surfaces_c0[&id];
+    let grid = surface_grid(
+        &surface.points.iter().map(|p| p.id).collect::<Vec<_>>(),
+        3 * surface.size.1 as usize + 1,
+        &storage.points,
+    );
+    let refined = refine_c0(grid, (true, true), surface.is_cylinder);
+    let rebuild = surface_c0_rebuild(storage, id, &refined, &[], id_generator);
+    let dropped = dropped_points(
+        &surface.points.iter().map(|p| p.id).collect::<Vec<_>>(),
+        &rebuild,
+    );
+
+    let mut rebuilds = vec![];
+    for neighbour in storage
+        .surfaces_c0
+        .values()
+        .filter(|s| s.id != id && s.points.iter().any(|p| dropped.contains(&p.id)))
+    {
+        if storage.is_intersected(IntersectionObjectId::SurfaceC0(neighbour.id)) {
+            return Err(format!(
+                "Neighbouring surface {} is intersected",
+                neighbour.name
+            ));
+        }
+
+        let size_y = 3 * neighbour.size.1 as usize + 1;
+        let ids = neighbour.points.iter().map(|p| p.id).collect::<Vec<_>>();
+        let shared = ids
+            .iter()
+            .enumerate()
+            .filter(|(_, id)| dropped.contains(id))
+            .map(|(i, _)| (i / size_y, i % size_y))
+            .collect::<Vec<_>>();
+        let along = if shared.iter().all(|(x, _)| *x == 0)
+            || shared.iter().all(|(x, _)| *x == ids.len() / size_y - 1)
+        {
+            (false, true)
+        } else if shared.iter().all(|(_, y)| *y == 0)
+            || shared.iter().all(|(_, y)| *y == size_y - 1)
+        {
+            (true, false)
+        } else {
+            return Err(format!(
+                "Neighbouring surface {} shares points away from its border",
+                neighbour.name
+            ));
+        };
+
+        let grid = surface_grid(&ids, size_y, &storage.points);
+        let refined = refine_c0(grid, along, neighbour.is_cylinder);
+        rebuilds.push(surface_c0_rebuild(
+            storage,
+            neighbour.id,
+            &refined,
+            &rebuild.points,
+            id_generator,
+        ));
+    }
+
+    connect_neighbours(&rebuild, &rebuilds, |id| {
+        storage.surfaces_c0[&id].name.clone()
+    })?;
+    rebuilds.insert(0, rebuild);
+    rebuild_surfaces_c0(storage, rebuilds)
+}
+
+pub fn refine_surface_c2(
+    storage: &mut Storage,
+    id: u64,
+    id_generator: &mut IdGenerator,
+) -> Result<SurfaceSizes, String> {
+    let refined_grid = |storage: &Storage, id: u64| {
+        let surface = &storage.surfaces_c2[&id];
+        let grid = surface_grid(
+            &surface.points.iter().map(|p| p.id).collect::<Vec<_>>(),
+            surface.size.1 as usize + 3,
+            &storage.points,
+        );
+        refine_c2(grid, (surface.is_closed_u(), surface.is_closed_v()))
+    };
+
+    let rebuild = surface_c2_rebuild(storage, id, &refined_grid(storage, id), &[], id_generator);
+    let dropped = dropped_points(
+        &storage.surfaces_c2[&id]
+            .points
+            .iter()
+            .map(|p| p.id)
+            .collect::<Vec<_>>(),
+        &rebuild,
+    );
+
+    let mut rebuilds = vec![];
+    for neighbour in storage
+        .surfaces_c2
+        .values()
+        .filter(|s| s.id != id && s.points.iter().any(|p| dropped.contains(&p.id)))
+    {
+        if storage.is_intersected(IntersectionObjectId::SurfaceC2(neighbour.id)) {
+            return Err(format!(
+                "Neighbouring surface {} is intersected",
+                neighbour.name
+            ));
+        }
+
+        rebuilds.push(surface_c2_rebuild(
+            storage,
+            neighbour.id,
+            &refined_grid(storage, neighbour.id),
+            &rebuild.points,
+            id_generator,
+        ));
+    }
+
+    connect_neighbours(&rebuild, &rebuilds, |id| {
+        storage.surfaces_c2[&id].name.clone()
+    })?;
+    rebuilds.insert(0, rebuild);
+    rebuild_surfaces_c2(storage, rebuilds)
+}
+
+fn surface_c0_rebuild(
+    storage: &Storage,
+    id: u64,
+    grid: &SurfaceGrid,
+    known: &[Point],
+    id_generator: &mut IdGenerator,
+) -> SurfaceRebuild {
+    let surface = &storage.surfaces_c0[&id];
+    let closed = (surface.is_closed_u(), surface.is_closed_v());
+    let known = known
+        .iter()
+        .cloned()
+        .chain(surface.points.iter().map(|p| storage.points[&p.id].clone()))
+        .collect::<Vec<_>>();
+    let (ids, points) = grid_points(grid, 1, closed, &known, id_generator);
+
+    SurfaceRebuild {
+        id,
+        size: ((grid.len() as u32 - 1) / 3, (grid[0].len() as u32 - 1) / 3),
+        ids,
+        points,
+    }
+}
+
+fn surface_c2_rebuild(
+    storage: &Storage,
+    id: u64,
+    grid: &SurfaceGrid,
+    known: &[Point],
+    id_generator: &mut IdGenerator,
+) -> SurfaceRebuild {
+    let surface = &storage.surfaces_c2[&id];
+    let closed = (surface.is_closed_u(), surface.is_closed_v());
+    let known = known
+        .iter()
+        .cloned()
+        .chain(surface.points.iter().map(|p| storage.points[&p.id].clone()))
+        .collect::<Vec<_>>();
+    let (ids, points) = grid_points(grid, 3, closed, &known, id_generator);
+
+    SurfaceRebuild {
+        id,
+        size: (grid.len() as u32 - 3, grid[0].len() as u32 - 3),
+        ids,
+        points,
+    }
+}
+
+fn rebuild_surfaces_c0(
+    storage: &mut Storage,
+    rebuilds: Vec<SurfaceRebuild>,
+) -> Result<SurfaceSizes, String> {
+    let old_points = rebuilds
+        .iter()
+        .map(|rebuild| {
+            let surface = storage.surfaces_c0.get_mut(&rebuild.id).unwrap();
+            let points = rebuild
+                .ids
+                .iter()
+                .map(|&id| SurfaceC0Point { id })
+                .collect();
+            (rebuild.id, std::mem::replace(&mut surface.points, points))
+        })
+        .collect::<Vec<_>>();
+    let old_ids = old_points
+        .iter()
+        .flat_map(|(_, points)| points.iter().map(|p| p.id))
+        .collect::<Vec<_>>();
+    if let Err(reason) = check_dropped_points(storage, &old_ids, &rebuilds) {
+        for (id, points) in old_points {
+            storage.surfaces_c0.get_mut(&id).unwrap().points = points;
+        }
+        return Err(reason);
+    }
+
+    let mut sizes = vec![];
+    for rebuild in rebuilds {
+        storage.surfaces_c0.get_mut(&rebuild.id).unwrap().size = rebuild.size;
+        for point in rebuild.points {
+            storage.points.insert(point.id, point);
+        }
+        sizes.push((rebuild.id, rebuild.size));
+    }
+    storage.remove_unused_points(&old_ids);
+    Ok(sizes)
+}
+
+fn rebuild_surfaces_c2(
+    storage: &mut Storage,
+    rebuilds: Vec<SurfaceRebuild>,
+) -> Result<SurfaceSizes, String> {
+    let old_points = rebuilds
+        .iter()
+        .map(|rebuild| {
+            let surface = storage.surfaces_c2.get_mut(&rebuild.id).unwrap();
+            let points = rebuild
+                .ids
+                .iter()
+                .map(|&id| SurfaceC2Point { id })
+                .collect();
+            (rebuild.id, std::mem::replace(&mut surface.points, points))
+        })
+        .collect::<Vec<_>>();
+    let old_ids = old_points
+        .iter()
+        .flat_map(|(_, points)| points.iter().map(|p| p.id))
+        .collect::<Vec<_>>();
+    if let Err(reason) = check_dropped_points(storage, &old_ids, &rebuilds) {
+        for (id, points) in old_points {
+            storage.surfaces_c2.get_mut(&id).unwrap().points = points;
+        }
+        return Err(reason);
+    }
+
+    let mut sizes = vec![];
+    for rebuild in rebuilds {
+        let surface = storage.surfaces_c2.get_mut(&rebuild.id).unwrap();
+        surface.size = rebuild.size;
+        surface.clear_interpolation();
+        for point in rebuild.points {
+            storage.points.insert(point.id, point);
+        }
+        sizes.push((rebuild.id, rebuild.size));
+    }
+    storage.remove_unused_points(&old_ids);
+    Ok(sizes)
+}
+
+fn dropped_points(old_ids: &[u64], rebuild: &SurfaceRebuild) -> HashSet<u64> {
+    let kept = rebuild.ids.iter().collect::<HashSet<_>>();
+    old_ids
+        .iter()
+        .filter(|id| !kept.contains(id))
+        .copied()
+        .collect()
+}
+
+fn check_dropped_points(
+    storage: &Storage,
+    old_ids: &[u64],
+    rebuilds: &[SurfaceRebuild],
+) -> Result<(), String> {
+    let kept = rebuilds
+        .iter()
+        .flat_map(|rebuild| rebuild.ids.iter())
+        .collect::<HashSet<_>>();
+    let used = old_ids
+        .iter()
+        .filter(|id| !kept.contains(id))
+        .unique()
+        .filter(|id| storage.is_point_used(**id))
+        .count();
+    if used > 0 {
+        return Err(format!(
+            "{} removed points are still used by other objects",
+            used
+        ));
+    }
+    Ok(())
+}
+
+fn connect_neighbours(
+    rebuild: &SurfaceRebuild,
+    neighbours: &[SurfaceRebuild],
+    name: impl Fn(u64) -> String,
+) -> Result<(), String> {
+    let created = rebuild.points.iter().map(|p| p.id).collect::<HashSet<_>>();
+    match neighbours
+        .iter()
+        .find(|neighbour| !neighbour.ids.iter().any(|id| created.contains(id)))
+    {
+        Some(neighbour) => Err(format!(
+            "Neighbouring surface {} cannot be refined along the shared border",
+            name(neighbour.id)
+        )),
+        None => Ok(()),
+    }
+}
+
+pub fn append_patches_c0(grid: SurfaceGrid, side: SurfaceSide) -> SurfaceGrid {
+    on_side(grid, side, |mut grid| {
+        let last = grid[grid.len() - 1].clone();
+        let step = last
+            .iter()
+            .zip(grid[grid.len() - 2].iter())
+            .map(|(a, b)| a.1 - b.1)
+            .collect::<Vec<_>>();
+        for k in 1..=3 {
+            grid.push(
+                last.iter()
+                    .zip(step.iter())
+                    .map(|(a, step)| (None, a.1 + step * k as f64))
+                    .collect(),
+            );
+        }
+        grid
+    })
+}
+
+pub fn remove_patches_c0(grid: SurfaceGrid, side: SurfaceSide) -> SurfaceGrid {
+    on_side(grid, side, |mut grid| {
+        grid.truncate(grid.len() - 3);
+        grid
+    })
+}
+
+pub fn append_patches_c2(grid: SurfaceGrid, side: SurfaceSide) -> SurfaceGrid {
+    on_side(grid, side, |mut grid| {
+        let column = grid[grid.len() - 1]
+            .iter()
+            .zip(grid[grid.len() - 2].iter())
+            .map(|(a, b)| (None, a.1 * 2.0 - b.1))
+            .collect();
+        grid.push(column);
+        grid
+    })
+}
+
+pub fn remove_patches_c2(grid: SurfaceGrid, side: SurfaceSide) -> SurfaceGrid {
+    on_side(grid, side, |mut grid| {
+        grid.truncate(grid.len() - 1);
+        grid
+    })
+}
+
+fn refine_c0(grid: SurfaceGrid, along: (bool, bool), is_cylinder: bool) -> SurfaceGrid {
+    let mut grid = grid;
+    if along.1 {
+        grid = grid.iter().map(|column| subdivide_bezier(column)).collect();
+    }
+    if along.0 {
+        grid = transpose(
+            transpose(grid)
+                .iter()
+                .map(|row| subdivide_bezier(row))
+                .collect(),
+        );
+        if is_cylinder {
+            let last = grid.len() - 1;
+            grid[last] = grid[0].clone();
+        }
+    }
+    grid
+}
+
+fn refine_c2(grid: SurfaceGrid, closed: (bool, bool)) -> SurfaceGrid {
+    let grid = grid
+        .iter()
+        .map(|column| subdivide_b_spline(column, closed.1))
+        .collect::<SurfaceGrid>();
+    transpose(
+        transpose(grid)
+            .iter()
+            .map(|row| subdivide_b_spline(row, closed.0))
+            .collect(),
+    )
+}
+
+fn grid_points(
+    grid: &SurfaceGrid,
+    overlap: usize,
+    closed: (bool, bool),
+    known: &[Point],
+    id_generator: &mut IdGenerator,
+) -> (Vec<u64>, Vec<Point>) {
+    let (size_x, size_y) = (grid.len(), grid[0].len());
+    let mut used = grid
+        .iter()
+        .flatten()
+        .filter_map(|(id, _)| *id)
+        .collect::<HashSet<_>>();
+    let mut known_id = |x: usize, y: usize, position: &Vector3<f64>| {
+        let border = x < overlap || x + overlap >= size_x || y < overlap || y + overlap >= size_y;
+        let id = known
+            .iter()
+            .filter(|_| border)
+            .filter(|point| !used.contains(&point.id))
+            .find(|point| {
+                let known = point.transformer.position;
+                (Vector3::new(known.0, known.1, known.2) - position).norm() < POINT_TOLERANCE
+            })
+            .map(|point| point.id)?;
+        used.insert(id);
+        Some(id)
+    };
+    let shared = |i: usize, size: usize, closed: bool| {
+        if closed && i >= size - overlap {
+            i + overlap - size
+        } else {
+            i
+        }
+    };
+
+    let is_pole = |line: Vec<Vector3<f64>>| {
+        line.iter()
+            .all(|position| (position - line[0]).norm() < 1e-9)
+    };
+    let pole_rows = (0..size_y)
+        .map(|y| is_pole(grid.iter().map(|column| column[y].1).collect()))
+        .collect::<Vec<_>>();
+    let pole_columns = grid
+        .iter()
+        .map(|column| is_pole(column.iter().map(|p| p.1).collect()))
+        .collect::<Vec<_>>();
+    let mut pole_ids = HashMap::new();
+    for (x, column) in grid.iter().enumerate() {
+        for (y, (id, _)) in column.iter().enumerate() {
+            if let Some(id) = id {
+                if pole_rows[y] {
+                    pole_ids.entry((true, y)).or_insert(*id);
+                }
+                if pole_columns[x] {
+                    pole_ids.entry((false, x)).or_insert(*id);
+                }
+            }
+        }
+    }
+
+    let mut ids = vec![];
+    let mut points = vec![];
+    for (x, column) in grid.iter().enumerate() {
+        for (y, (id, position)) in column.iter().enumerate() {
+            let (sx, sy) = (shared(x, size_x, closed.0), shared(y, size_y, closed.1));
+            if (sx, sy) != (x, y) {
+                ids.push(ids[sx * size_y + sy]);
+                continue;
+            }
+            let pole = if pole_rows[y] {
+                Some((true, y))
+            } else if pole_columns[x] {
+                Some((false, x))
+            } else {
+                None
+            };
+            let id = id.or_else(|| known_id(x, y, position));
+            match (id, pole.and_then(|pole| pole_ids.get(&pole))) {
+                (Some(id), _) | (None, Some(&id)) => ids.push(id),
+                (None, None) => {
+                    let point = Point::new(
+                        id_generator.next(),
+                        LittleTransformer {
+                            position: (position.x, position.y, position.z),
+                        },
+                    );
+                    if let Some(pole) = pole {
+                        pole_ids.insert(pole, point.id);
+                    }
+                    ids.push(point.id);
+                    points.push(point);
+                }
+            }
+        }
+    }
+    (ids, points)
+}
+
+fn on_side(
+    grid: SurfaceGrid,
+    side: SurfaceSide,
+    edit: impl Fn(SurfaceGrid) -> SurfaceGrid,
+) -> SurfaceGrid {
+    match side {
+        SurfaceSide::EndX => edit(grid),
+        SurfaceSide::StartX => reverse(edit(reverse(grid))),
+        SurfaceSide::EndY => transpose(edit(transpose(grid))),
+        SurfaceSide::StartY => transpose(reverse(edit(reverse(transpose(grid))))),
+    }
+}
+
+fn reverse(grid: SurfaceGrid) -> SurfaceGrid {
+    grid.into_iter().rev().collect()
+}
+
+fn transpose(grid: SurfaceGrid) -> SurfaceGrid {
+    (0..grid[0].len())
+        .map(|y| grid.iter().map(|column| column[y]).collect())
+        .collect()
+}
+
+fn subdivide_bezier(line: &[(Option<u64>, Vector3<f64>)]) -> Vec<(Option<u64>, Vector3<f64>)> {
+    let mut result = vec![line[0]];
+    for segment in line.windows(4).step_by(3) {
+        let [p0, p1, p2, p3] = [segment[0].1, segment[1].1, segment[2].1, segment[3].1];
+        let (q0, q1, q2) = ((p0 + p1) / 2.0, (p1 + p2) / 2.0, (p2 + p3) / 2.0);
+        let (r0, r1) = ((q0 + q1) / 2.0, (q1 + q2) / 2.0);
+        let s = (r0 + r1) / 2.0;
+        result.extend([
+            (None, q0),
+            (None, r0),
+            (None, s),
+            (None, r1),
+            (None, q2),
+            segment[3],
+        ]);
+    }
+    result
+}
+
+fn subdivide_b_spline(
+    line: &[(Option<u64>, Vector3<f64>)],
+    closed: bool,
+) -> Vec<(Option<u64>, Vector3<f64>)> {
+    if closed {
+        let mut result = subdivide_closed_b_spline(&line[..line.len() - 3]);
+        result.extend_from_within(..3);
+        return result;
+    }
+
+    let d = line.iter().map(|p| p.1).collect::<Vec<_>>();
+    let mut result = vec![(None, (d[0] + d[1]) / 2.0)];
+    for i in 1..d.len() - 1 {
+        result.push((None, (d[i - 1] + d[i] * 6.0 + d[i + 1]) / 8.0));
+        result.push((None, (d[i] + d[i + 1]) / 2.0));
+    }
+    result
+}
+
+fn subdivide_closed_b_spline(
+    line: &[(Option<u64>, Vector3<f64>)],
+) -> Vec<(Option<u64>, Vector3<f64>)> {
+    let n = line.len();
+    let d = |i: usize| line[i % n].1;
+    (0..n)
+        .flat_map(|i| {
+            [
+                (None, (d(i) + d(i + 1)) / 2.0),
+                (None, (d(i) + d(i + 1) * 6.0 + d(i + 2)) / 8.0),
+            ]
+        })
+        .collect()
+}
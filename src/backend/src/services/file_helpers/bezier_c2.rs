@@ -6,6 +6,10 @@ pub struct BezierC2 {
     pub id: u64,
     pub name: String,
     pub de_boor_points: Vec<BezierC2Point>,
+    #[serde(default)]
+    pub closed: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub knot_intervals: Vec<f64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1,11 +1,33 @@
 use serde::{Deserialize, Serialize};
 
+use crate::domain::bezier_int as domain;
+use crate::services::file_helpers::xyz::Xyz;
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BezierInt {
     pub id: u64,
     pub name: String,
     pub control_points: Vec<BezierIntPoint>,
+    #[serde(default)]
+    pub closed: bool,
+    #[serde(default)]
+    pub clamped: bool,
+    #[serde(default)]
+    pub start_tangent: Option<Xyz>,
+    #[serde(default)]
+    pub end_tangent: Option<Xyz>,
+    #[serde(default)]
+    pub parameterization: BezierIntParameterization,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BezierIntParameterization {
+    Uniform,
+    Centripetal,
+    #[default]
+    ChordLength,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -13,3 +35,21 @@ pub struct BezierInt {
 pub struct BezierIntPoint {
     pub id: u64,
 }
+
+impl BezierIntParameterization {
+    pub fn from_domain(parameterization: domain::BezierIntParameterization) -> Self {
+        match parameterization {
+            domain::BezierIntParameterization::Uniform => Self::Uniform,
+            domain::BezierIntParameterization::Centripetal => Self::Centripetal,
+            domain::BezierIntParameterization::ChordLength => Self::ChordLength,
+        }
+    }
+
+    pub fn to_domain(self) -> domain::BezierIntParameterization {
+        match self {
+            Self::Uniform => domain::BezierIntParameterization::Uniform,
+            Self::Centripetal => domain::BezierIntParameterization::Centripetal,
+            Self::ChordLength => domain::BezierIntParameterization::ChordLength,
+        }
+    }
+}
@@ -3,8 +3,12 @@ use serde::{Deserialize, Serialize};
 use crate::services::file_helpers::bezier_c0::BezierC0;
 use crate::services::file_helpers::bezier_c2::BezierC2;
 use crate::services::file_helpers::bezier_int::BezierInt;
+use crate::services::file_helpers::gregory::Gregory;
+use crate::services::file_helpers::nurbs_curve::NurbsCurve;
+use crate::services::file_helpers::nurbs_surface::NurbsSurface;
 use crate::services::file_helpers::surface_c0::SurfaceC0;
 use crate::services::file_helpers::surface_c2::SurfaceC2;
+use crate::services::file_helpers::toolpath::Toolpath;
 use crate::services::file_helpers::torus::Torus;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -14,6 +18,10 @@ pub enum GeometryObj {
     BezierC0(BezierC0),
     BezierC2(BezierC2),
     InterpolatedC2(BezierInt),
+    NurbsCurve(NurbsCurve),
     BezierSurfaceC0(SurfaceC0),
     BezierSurfaceC2(SurfaceC2),
+    NurbsSurface(NurbsSurface),
+    Gregory(Gregory),
+    Toolpath(Toolpath),
 }
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::services::file_helpers::xyz::Xyz;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Gregory {
+    pub id: u64,
+    pub name: String,
+    pub edges: Vec<GregoryEdge>,
+    pub tess_level: u8,
+    pub draw_vectors: bool,
+    #[serde(default = "default_scale")]
+    pub tangent_scale: f32,
+    #[serde(default = "default_offset")]
+    pub center_offset: Xyz,
+    #[serde(default = "default_scale")]
+    pub twist_blend: f32,
+    #[serde(default)]
+    pub coons: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GregoryEdge {
+    pub edge_points: Vec<u64>,
+    pub patch_points: Vec<u64>,
+    #[serde(default)]
+    pub de_boor: bool,
+}
+
+fn default_scale() -> f32 {
+    1.0
+}
+
+fn default_offset() -> Xyz {
+    Xyz::from_tuple((0.0, 0.0, 0.0))
+}
@@ -1,16 +1,23 @@
 use math::operations::euler_to_quaternion;
+use math::vector3::Vector3;
 
 use crate::data_access::storage::Storage;
 use crate::domain::bezier_c0::{BezierC0, BezierC0Point};
 use crate::domain::bezier_c2::BezierC2;
 use crate::domain::bezier_int::BezierInt;
+use crate::domain::cutter::{Cutter, CutterType};
+use crate::domain::gregory::{Edge, Gregory, GregoryShape, Polygon};
+use crate::domain::nurbs_curve::{NurbsCurve, NurbsCurvePoint};
+use crate::domain::nurbs_surface::{NurbsSurface, NurbsSurfacePoint};
 use crate::domain::point::Point;
 use crate::domain::surface_c0::{SurfaceC0, SurfaceC0Point};
 use crate::domain::surface_c2::{SurfaceC2, SurfaceC2Point};
+use crate::domain::toolpath::Toolpath;
 use crate::domain::torus::Torus;
 use crate::domain::transformer::{LittleTransformer, Transformer};
 use crate::services::file_helpers::geometry_obj::GeometryObj;
 use crate::services::file_helpers::scene::Scene;
+use crate::services::file_helpers::surface_c2::SurfaceC2Interpolation;
 
 pub fn load_scene(storage: &mut Storage, file_path: &str) {
     let serialized = std::fs::read_to_string(file_path).unwrap();
@@ -20,8 +27,12 @@ pub fn load_scene(storage: &mut Storage, file_path: &str) {
     storage.beziers_c0.clear();
     storage.beziers_c2.clear();
     storage.beziers_int.clear();
+    storage.nurbs_curves.clear();
     storage.surfaces_c0.clear();
     storage.surfaces_c2.clear();
+    storage.nurbs_surfaces.clear();
+    storage.gregories.clear();
+    storage.toolpaths.clear();
     storage.selected_objects.clear();
     for point in scene.points {
         storage.points.insert(
@@ -92,10 +103,10 @@ pub fn load_scene(storage: &mut Storage, file_path: &str) {
             .iter()
             .map(|p| storage.points.get(&p.id).unwrap().clone())
             .collect();
-        storage.beziers_c2.insert(
-            bezier_c2.id,
-            BezierC2::new_with_name(bezier_c2.id, bezier_c2.name.clone(), points),
-        );
+        let mut bezier = BezierC2::new_with_name(bezier_c2.id, bezier_c2.name.clone(), points);
+        bezier.set_closed(bezier_c2.closed, &storage.points);
+        bezier.set_knot_intervals(bezier_c2.knot_intervals.clone(), &storage.points);
+        storage.beziers_c2.insert(bezier_c2.id, bezier);
     }
     for bezier_int in scene.geometry.iter().filter_map(|g| {
         if let GeometryObj::InterpolatedC2(bezier) = g {
@@ -109,10 +120,44 @@ pub fn load_scene(storage: &mut Storage, file_path: &str) {
             .iter()
             .map(|p| storage.points.get(&p.id).unwrap().clone())
             .collect();
-        storage.beziers_int.insert(
-            bezier_int.id,
-            BezierInt::new_with_name(bezier_int.id, bezier_int.name.clone(), points),
+        let mut bezier = BezierInt::new_with_name(bezier_int.id, bezier_int.name.clone(), points);
+        bezier.set_options(
+            bezier_int.closed,
+            bezier_int.clamped,
+            bezier_int.parameterization.to_domain(),
+            &storage.points,
+        );
+        if let (Some(start), Some(end)) = (&bezier_int.start_tangent, &bezier_int.end_tangent) {
+            bezier.set_end_tangents(
+                [start, end].map(|t| nalgebra::Vector3::new(t.x, t.y, t.z)),
+                &storage.points,
+            );
+        }
+        storage.beziers_int.insert(bezier_int.id, bezier);
+    }
+    for nurbs_curve in scene.geometry.iter().filter_map(|g| {
+        if let GeometryObj::NurbsCurve(curve) = g {
+            Some(curve)
+        } else {
+            None
+        }
+    }) {
+        let points = nurbs_curve
+            .control_points
+            .iter()
+            .map(|p| NurbsCurvePoint {
+                id: p.id,
+                weight: p.weight,
+            })
+            .collect();
+        let mut curve = NurbsCurve::new_with_name(
+            nurbs_curve.id,
+            nurbs_curve.name.clone(),
+            points,
+            &storage.points,
         );
+        curve.set_knots(nurbs_curve.knots.clone(), &storage.points);
+        storage.nurbs_curves.insert(nurbs_curve.id, curve);
     }
     for surface_c0 in scene.geometry.iter().filter_map(|g| {
         if let GeometryObj::BezierSurfaceC0(surface) = g {
@@ -187,15 +232,126 @@ pub fn load_scene(storage: &mut Storage, file_path: &str) {
             }
         }
 
-        storage.surfaces_c2.insert(
+        let mut surface = SurfaceC2::new_with_name(
             surface_c2.id,
-            SurfaceC2::new_with_name(
-                surface_c2.id,
-                surface_c2.name.clone(),
+            surface_c2.name.clone(),
+            points,
+            (surface_c2.size.x, surface_c2.size.y),
+            false,
+        );
+        match surface_c2.interpolation {
+            SurfaceC2Interpolation::None => {}
+            SurfaceC2Interpolation::Grid => surface.set_interpolation_points(
+                surface_c2
+                    .interpolation_points
+                    .iter()
+                    .map(|p| SurfaceC2Point { id: p.id })
+                    .collect(),
+            ),
+            SurfaceC2Interpolation::Loft => surface.set_loft_sections(
+                surface_c2
+                    .loft_sections
+                    .iter()
+                    .map(|section| {
+                        section
+                            .iter()
+                            .map(|p| SurfaceC2Point { id: p.id })
+                            .collect()
+                    })
+                    .collect(),
+            ),
+        }
+        storage.surfaces_c2.insert(surface_c2.id, surface);
+    }
+    for nurbs_surface in scene.geometry.iter().filter_map(|g| {
+        if let GeometryObj::NurbsSurface(surface) = g {
+            Some(surface)
+        } else {
+            None
+        }
+    }) {
+        let points = nurbs_surface
+            .control_points
+            .iter()
+            .map(|p| NurbsSurfacePoint {
+                id: p.id,
+                weight: p.weight,
+            })
+            .collect();
+        storage.nurbs_surfaces.insert(
+            nurbs_surface.id,
+            NurbsSurface::new_with_name(
+                nurbs_surface.id,
+                nurbs_surface.name.clone(),
                 points,
-                (surface_c2.size.x, surface_c2.size.y),
-                false,
+                (nurbs_surface.size.x, nurbs_surface.size.y),
+                nurbs_surface.cylinder,
+            ),
+        );
+    }
+    for gregory in scene.geometry.iter().filter_map(|g| {
+        if let GeometryObj::Gregory(gregory) = g {
+            Some(gregory)
+        } else {
+            None
+        }
+    }) {
+        let edges = gregory
+            .edges
+            .iter()
+            .map(|e| {
+                Edge::new(
+                    std::array::from_fn(|i| e.edge_points[i]),
+                    std::array::from_fn(|i| std::array::from_fn(|j| e.patch_points[4 * i + j])),
+                    e.de_boor,
+                )
+            })
+            .collect();
+        let mut result = if gregory.coons {
+            Gregory::new_coons(gregory.id, Polygon::new(edges), &storage.points)
+        } else {
+            Gregory::new(gregory.id, Polygon::new(edges), &storage.points)
+        };
+        result.rename(&gregory.name);
+        result.update_settings(gregory.tess_level, gregory.draw_vectors);
+        result.update_shape(
+            GregoryShape::new(
+                gregory.tangent_scale,
+                Vector3::new(
+                    gregory.center_offset.x as f32,
+                    gregory.center_offset.y as f32,
+                    -gregory.center_offset.z as f32,
+                ),
+                gregory.twist_blend,
             ),
+            &storage.points,
         );
+        storage.gregories.insert(gregory.id, result);
+    }
+    for toolpath in scene.geometry.iter().filter_map(|g| {
+        if let GeometryObj::Toolpath(toolpath) = g {
+            Some(toolpath)
+        } else {
+            None
+        }
+    }) {
+        let cutter = Cutter::new(
+            if toolpath.cutter.spherical {
+                CutterType::Spherical
+            } else {
+                CutterType::Flat
+            },
+            toolpath.cutter.radius,
+            toolpath.cutter.cutting_length,
+        );
+        let points = toolpath
+            .points
+            .iter()
+            .map(|p| Vector3::new(p.x as f32, p.y as f32, -p.z as f32))
+            .collect();
+        let mut result =
+            Toolpath::new_with_name(toolpath.id, toolpath.name.clone(), cutter, points);
+        result.set_visible(toolpath.visible);
+        storage.toolpaths.insert(toolpath.id, result);
     }
 }
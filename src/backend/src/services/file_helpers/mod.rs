@@ -2,11 +2,15 @@ mod bezier_c0;
 mod bezier_c2;
 mod bezier_int;
 mod geometry_obj;
+mod gregory;
 pub mod load_scene;
+mod nurbs_curve;
+mod nurbs_surface;
 mod point;
 pub mod save_scene;
 mod scene;
 mod surface_c0;
 mod surface_c2;
+mod toolpath;
 mod torus;
 mod xyz;
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::services::file_helpers::xyz::Xyu32;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NurbsSurface {
+    pub id: u64,
+    pub name: String,
+    pub control_points: Vec<NurbsSurfacePoint>,
+    pub size: Xyu32,
+    #[serde(default)]
+    pub cylinder: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NurbsSurfacePoint {
+    pub id: u64,
+    #[serde(default = "default_weight")]
+    pub weight: f64,
+}
+
+fn default_weight() -> f64 {
+    1.0
+}
@@ -4,14 +4,21 @@ use std::rc::Rc;
 use math::operations::quaternion_to_euler;
 
 use crate::data_access::storage::Storage;
+use crate::domain::cutter::CutterType;
 use crate::services::file_helpers::bezier_c0::{BezierC0, BezierC0Point};
 use crate::services::file_helpers::bezier_c2::{BezierC2, BezierC2Point};
-use crate::services::file_helpers::bezier_int::{BezierInt, BezierIntPoint};
+use crate::services::file_helpers::bezier_int::{
+    BezierInt, BezierIntParameterization, BezierIntPoint,
+};
 use crate::services::file_helpers::geometry_obj::GeometryObj;
+use crate::services::file_helpers::gregory::{Gregory, GregoryEdge};
+use crate::services::file_helpers::nurbs_curve::{NurbsCurve, NurbsCurvePoint};
+use crate::services::file_helpers::nurbs_surface::{NurbsSurface, NurbsSurfacePoint};
 use crate::services::file_helpers::point::Point;
 use crate::services::file_helpers::scene::Scene;
 use crate::services::file_helpers::surface_c0::{SurfaceC0, SurfaceC0ControlPoint, SurfaceC0Patch};
 use crate::services::file_helpers::surface_c2::{SurfaceC2, SurfaceC2ControlPoint, SurfaceC2Patch};
+use crate::services::file_helpers::toolpath::{Toolpath, ToolpathCutter};
 use crate::services::file_helpers::torus::Torus;
 use crate::services::file_helpers::xyz::{Xyu32, Xyz};
 use crate::services::id_generator::IdGenerator;
@@ -78,6 +85,8 @@ pub fn save_scene(storage: &Storage, file_path: &str, id_generator: &mut IdGener
                         .iter()
                         .map(|p| BezierC2Point { id: p.id })
                         .collect(),
+                    closed: b.closed,
+                    knot_intervals: b.knot_intervals.clone(),
                 })
             }))
             .chain(storage.beziers_int.values().map(|b| {
@@ -89,6 +98,32 @@ pub fn save_scene(storage: &Storage, file_path: &str, id_generator: &mut IdGener
                         .iter()
                         .map(|p| BezierIntPoint { id: p.id })
                         .collect(),
+                    closed: b.closed,
+                    clamped: b.clamped,
+                    start_tangent: b.clamped.then(|| {
+                        let t = b.end_tangents[0];
+                        Xyz::from_tuple((t.x, t.y, t.z))
+                    }),
+                    end_tangent: b.clamped.then(|| {
+                        let t = b.end_tangents[1];
+                        Xyz::from_tuple((t.x, t.y, t.z))
+                    }),
+                    parameterization: BezierIntParameterization::from_domain(b.parameterization),
+                })
+            }))
+            .chain(storage.nurbs_curves.values().map(|c| {
+                GeometryObj::NurbsCurve(NurbsCurve {
+                    id: c.id,
+                    name: c.name.clone(),
+                    control_points: c
+                        .points
+                        .iter()
+                        .map(|p| NurbsCurvePoint {
+                            id: p.id,
+                            weight: p.weight,
+                        })
+                        .collect(),
+                    knots: c.knots.clone(),
                 })
             }))
             .chain(storage.surfaces_c0.values().map(|s| {
@@ -179,6 +214,88 @@ pub fn save_scene(storage: &Storage, file_path: &str, id_generator: &mut IdGener
                             samples: Xyu32 { x: 4, y: 4 },
                         })
                         .collect::<Vec<_>>(),
+                    interpolation: s.interpolation.into(),
+                    interpolation_points: s
+                        .interpolation_points
+                        .iter()
+                        .map(|p| SurfaceC2ControlPoint { id: p.id })
+                        .collect(),
+                    loft_sections: s
+                        .loft_sections
+                        .iter()
+                        .map(|section| {
+                            section
+                                .iter()
+                                .map(|p| SurfaceC2ControlPoint { id: p.id })
+                                .collect()
+                        })
+                        .collect(),
+                })
+            }))
+            .chain(storage.nurbs_surfaces.values().map(|s| {
+                GeometryObj::NurbsSurface(NurbsSurface {
+                    id: s.id,
+                    name: s.name.clone(),
+                    control_points: s
+                        .points
+                        .iter()
+                        .map(|p| NurbsSurfacePoint {
+                            id: p.id,
+                            weight: p.weight,
+                        })
+                        .collect(),
+                    size: Xyu32 {
+                        x: s.size.0,
+                        y: s.size.1,
+                    },
+                    cylinder: s.is_cylinder,
+                })
+            }))
+            .chain(storage.gregories.values().map(|g| {
+                GeometryObj::Gregory(Gregory {
+                    id: g.id,
+                    name: g.name.clone(),
+                    edges: g
+                        .polygon
+                        .edges
+                        .iter()
+                        .map(|e| GregoryEdge {
+                            edge_points: e.edge_points.to_vec(),
+                            patch_points: e.patch_points.iter().flatten().copied().collect(),
+                            de_boor: e.de_boor,
+                        })
+                        .collect(),
+                    tess_level: g.tess_level,
+                    draw_vectors: g.draw_vectors,
+                    tangent_scale: g.shape.tangent_scale,
+                    center_offset: Xyz {
+                        x: g.shape.center_offset.x as f64,
+                        y: g.shape.center_offset.y as f64,
+                        z: -g.shape.center_offset.z as f64,
+                    },
+                    twist_blend: g.shape.twist_blend,
+                    coons: g.coons,
+                })
+            }))
+            .chain(storage.toolpaths.values().map(|t| {
+                GeometryObj::Toolpath(Toolpath {
+                    id: t.id,
+                    name: t.name.clone(),
+                    cutter: ToolpathCutter {
+                        spherical: t.cutter.cutter_type == CutterType::Spherical,
+                        radius: t.cutter.radius,
+                        cutting_length: t.cutter.cutting_length,
+                    },
+                    points: t
+                        .points
+                        .iter()
+                        .map(|p| Xyz {
+                            x: p.x as f64,
+                            y: p.y as f64,
+                            z: -p.z as f64,
+                        })
+                        .collect(),
+                    visible: t.visible,
                 })
             }))
             .collect(),
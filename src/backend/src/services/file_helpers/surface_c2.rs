@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::domain::surface_c2::SurfaceC2Interpolation as DomainSurfaceC2Interpolation;
 use crate::services::file_helpers::xyz::Xyu32;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -9,6 +10,12 @@ pub struct SurfaceC2 {
     pub name: String,
     pub patches: Vec<SurfaceC2Patch>,
     pub size: Xyu32,
+    #[serde(default, skip_serializing_if = "SurfaceC2Interpolation::is_none")]
+    pub interpolation: SurfaceC2Interpolation,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub interpolation_points: Vec<SurfaceC2ControlPoint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub loft_sections: Vec<Vec<SurfaceC2ControlPoint>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -26,3 +33,28 @@ pub struct SurfaceC2Patch {
 pub struct SurfaceC2ControlPoint {
     pub id: u64,
 }
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SurfaceC2Interpolation {
+    #[default]
+    None,
+    Grid,
+    Loft,
+}
+
+impl SurfaceC2Interpolation {
+    fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+}
+
+impl From<DomainSurfaceC2Interpolation> for SurfaceC2Interpolation {
+    fn from(interpolation: DomainSurfaceC2Interpolation) -> Self {
+        match interpolation {
+            DomainSurfaceC2Interpolation::None => Self::None,
+            DomainSurfaceC2Interpolation::Grid => Self::Grid,
+            DomainSurfaceC2Interpolation::Loft => Self::Loft,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use crate::services::file_helpers::xyz::Xyz;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Toolpath {
+    pub id: u64,
+    pub name: String,
+    pub cutter: ToolpathCutter,
+    pub points: Vec<Xyz>,
+    pub visible: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolpathCutter {
+    pub spherical: bool,
+    pub radius: f32,
+    pub cutting_length: f32,
+}
@@ -1,5 +1,8 @@
+pub mod convert_curve;
 pub mod create_surface;
+pub mod edit_surface;
 mod event_publisher;
 pub mod file_helpers;
 pub mod id_generator;
 pub mod services;
+pub mod toolpath_file;
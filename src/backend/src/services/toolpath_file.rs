@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use math::vector3::Vector3;
+
+use crate::domain::cutter::{Cutter, CutterType, MILLIMETERS_PER_UNIT};
+
+pub fn load_toolpath(file_path: &str) -> Option<(Cutter, Vec<Vector3>)> {
+    let cutter = cutter_from_extension(file_path)?;
+    let serialized = std::fs::read_to_string(file_path).ok()?;
+
+    let mut position = Vector3::zero();
+    let mut points = vec![];
+    for line in serialized.lines() {
+        let line = line.trim();
+        if !line.contains(['X', 'Y', 'Z']) {
+            continue;
+        }
+
+        position.x = coordinate(line, 'X').unwrap_or(position.x);
+        position.y = coordinate(line, 'Y').unwrap_or(position.y);
+        position.z = coordinate(line, 'Z').unwrap_or(position.z);
+        points.push(from_machine(&position));
+    }
+
+    Some((cutter, points))
+}
+
+pub fn save_toolpath(file_path: &str, points: &[Vector3]) {
+    let serialized = points
+        .iter()
+        .map(to_machine)
+        .enumerate()
+        .map(|(i, p)| format!("N{}G01X{:.3}Y{:.3}Z{:.3}", i + 3, p.x, p.y, p.z))
+        .collect::<Vec<_>>()
+        .join("\n");
+    std::fs::write(file_path, serialized + "\n").unwrap();
+}
+
+pub fn to_machine(point: &Vector3) -> Vector3 {
+    Vector3::new(point.x, -point.z, point.y) * MILLIMETERS_PER_UNIT
+}
+
+pub fn from_machine(point: &Vector3) -> Vector3 {
+    Vector3::new(point.x, point.z, -point.y) / MILLIMETERS_PER_UNIT
+}
+
+fn cutter_from_extension(file_path: &str) -> Option<Cutter> {
+    let extension = Path::new(file_path).extension()?.to_str()?.to_lowercase();
+    let cutter_type = match extension.chars().next()? {
+        'k' => CutterType::Spherical,
+        'f' => CutterType::Flat,
+        _ => return None,
+    };
+    let diameter = extension[1..].parse::<f32>().ok()? / MILLIMETERS_PER_UNIT;
+
+    Some(Cutter::new(cutter_type, diameter / 2.0, 2.0 * diameter))
+}
+
+fn coordinate(line: &str, axis: char) -> Option<f32> {
+    let start = line.find(axis)? + 1;
+    let value = line[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.' || *c == '-')
+        .collect::<String>();
+    value.parse().ok()
+}
@@ -0,0 +1,10 @@
+pub struct BezierC2ClosedSet {
+    pub bezier_id: u64,
+    pub closed: bool,
+}
+
+impl BezierC2ClosedSet {
+    pub fn new(bezier_id: u64, closed: bool) -> Self {
+        Self { bezier_id, closed }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod bezier_c2_closed_set;
 pub mod bezier_c2_created;
 pub mod bezier_c2_deleted;
 pub mod bezier_c2_draw_b_spline_polygon_set;
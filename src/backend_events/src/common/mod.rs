@@ -1 +1,2 @@
 pub mod scene_loaded;
+pub mod surface_display_mode;
@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SurfaceDisplayModeDTO {
+    Wireframe,
+    Normals,
+    GaussianCurvature,
+    MeanCurvature,
+    Zebra,
+}
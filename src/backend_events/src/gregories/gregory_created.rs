@@ -6,4 +6,5 @@ pub struct GregoryCreated {
     pub name: String,
     pub tess_level: u8,
     pub draw_vectors: bool,
+    pub coons: bool,
 }
@@ -25,4 +25,5 @@ pub enum IntersectionObjectIdDTO {
     Torus(u64),
     SurfaceC0(u64),
     SurfaceC2(u64),
+    NurbsSurface(u64),
 }
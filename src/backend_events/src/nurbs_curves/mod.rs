@@ -0,0 +1,7 @@
+pub mod nurbs_curve_created;
+pub mod nurbs_curve_deleted;
+pub mod nurbs_curve_draw_polygon_set;
+pub mod nurbs_curve_point_moved;
+pub mod nurbs_curve_points_deleted;
+pub mod nurbs_curve_renamed;
+pub mod nurbs_curve_weight_set;
@@ -0,0 +1,9 @@
+pub struct NurbsCurveCreated {
+    pub id: u64,
+}
+
+impl NurbsCurveCreated {
+    pub fn new(id: u64) -> Self {
+        Self { id }
+    }
+}
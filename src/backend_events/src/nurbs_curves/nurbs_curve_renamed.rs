@@ -0,0 +1,10 @@
+pub struct NurbsCurveRenamed {
+    pub id: u64,
+    pub name: String,
+}
+
+impl NurbsCurveRenamed {
+    pub fn new(id: u64, name: String) -> Self {
+        Self { id, name }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod nurbs_surface_created;
+pub mod nurbs_surface_deleted;
+pub mod nurbs_surface_point_moved;
+pub mod nurbs_surface_points_selected;
+pub mod nurbs_surface_updated;
+pub mod nurbs_surface_weight_set;
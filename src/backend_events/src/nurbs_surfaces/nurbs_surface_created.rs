@@ -0,0 +1,15 @@
+pub struct NurbsSurfaceCreated {
+    pub id: u64,
+    pub size: (u32, u32),
+    pub is_cylinder: bool,
+}
+
+impl NurbsSurfaceCreated {
+    pub fn new(id: u64, size: (u32, u32), is_cylinder: bool) -> Self {
+        NurbsSurfaceCreated {
+            id,
+            size,
+            is_cylinder,
+        }
+    }
+}
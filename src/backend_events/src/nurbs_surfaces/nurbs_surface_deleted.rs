@@ -0,0 +1,9 @@
+pub struct NurbsSurfaceDeleted {
+    pub id: u64,
+}
+
+impl NurbsSurfaceDeleted {
+    pub fn new(id: u64) -> Self {
+        Self { id }
+    }
+}
@@ -0,0 +1,9 @@
+pub struct NurbsSurfacePointMoved {
+    pub id: u64,
+}
+
+impl NurbsSurfacePointMoved {
+    pub fn new(id: u64) -> Self {
+        Self { id }
+    }
+}
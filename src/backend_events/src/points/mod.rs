@@ -1,6 +1,7 @@
 pub mod point_added_to_bezier_c0;
 pub mod point_added_to_bezier_c2;
 pub mod point_added_to_bezier_int;
+pub mod point_added_to_nurbs_curve;
 pub mod point_created;
 pub mod point_moved;
 pub mod selected_points_merged;
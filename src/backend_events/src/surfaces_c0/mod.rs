@@ -1,5 +1,7 @@
 pub mod surface_c0_created;
 pub mod surface_c0_deleted;
+pub mod surface_c0_edit_refused;
 pub mod surface_c0_point_moved;
 pub mod surface_c0_points_selected;
+pub mod surface_c0_resized;
 pub mod surface_c0_updated;
@@ -0,0 +1,10 @@
+pub struct SurfaceC0Resized {
+    pub id: u64,
+    pub size: (u32, u32),
+}
+
+impl SurfaceC0Resized {
+    pub fn new(id: u64, size: (u32, u32)) -> Self {
+        Self { id, size }
+    }
+}
@@ -1,15 +1,24 @@
+use crate::common::surface_display_mode::SurfaceDisplayModeDTO;
+
 pub struct SurfaceC2Updated {
     pub id: u64,
     pub draw_polygon: bool,
     pub tess_level: u8,
+    pub display_mode: SurfaceDisplayModeDTO,
 }
 
 impl SurfaceC2Updated {
-    pub fn new(id: u64, draw_polygon: bool, tess_level: u8) -> Self {
+    pub fn new(
+        id: u64,
+        draw_polygon: bool,
+        tess_level: u8,
+        display_mode: SurfaceDisplayModeDTO,
+    ) -> Self {
         Self {
             id,
             draw_polygon,
             tess_level,
+            display_mode,
         }
     }
 }
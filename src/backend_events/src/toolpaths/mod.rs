@@ -0,0 +1,4 @@
+pub mod toolpath_created;
+pub mod toolpath_deleted;
+pub mod toolpath_renamed;
+pub mod toolpath_updated;
@@ -0,0 +1,11 @@
+use derive_new::new;
+use math::vector3::Vector3;
+
+#[derive(Debug, Clone, new)]
+pub struct ToolpathCreated {
+    pub toolpath_id: u64,
+    pub name: String,
+    pub cutter_name: String,
+    pub points: Vec<Vector3>,
+    pub visible: bool,
+}
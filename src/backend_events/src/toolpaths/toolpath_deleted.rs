@@ -0,0 +1,6 @@
+use derive_new::new;
+
+#[derive(Debug, Clone, new)]
+pub struct ToolpathDeleted {
+    pub toolpath_id: u64,
+}
@@ -20,6 +20,7 @@ pub struct BezierC2 {
     pub draw_bernstein_polygon: bool,
     pub draw_b_spline_polygon: bool,
     pub selected_bernstein_point: Option<usize>,
+    pub closed: bool,
 }
 
 impl BezierC2 {
@@ -58,7 +59,7 @@ impl BezierC2 {
             bernstein_points_index_buffer,
             bernstein_polygon_index_buffer,
             b_spline_polygon_index_buffer,
-        ) = Self::get_buffers(&bernstein_points, &b_spline_points, &display);
+        ) = Self::get_buffers(&bernstein_points, &b_spline_points, false, &display);
 
         Self {
             id,
@@ -74,6 +75,7 @@ impl BezierC2 {
             draw_bernstein_polygon: false,
             draw_b_spline_polygon: false,
             selected_bernstein_point: None,
+            closed: false,
         }
     }
 
@@ -112,12 +114,35 @@ impl BezierC2 {
             self.bernstein_points_index_buffer,
             self.bernstein_polygon_index_buffer,
             self.b_spline_polygon_index_buffer,
-        ) = Self::get_buffers(&self.bernstein_points, &self.b_spline_points, &display);
+        ) = Self::get_buffers(
+            &self.bernstein_points,
+            &self.b_spline_points,
+            self.closed,
+            &display,
+        );
+    }
+
+    pub fn set_closed(&mut self, closed: bool, display: &Display<WindowSurface>) {
+        self.closed = closed;
+        (
+            self.bernstein_vertex_buffer,
+            self.b_spline_vertex_buffer,
+            self.curve_index_buffer,
+            self.bernstein_points_index_buffer,
+            self.bernstein_polygon_index_buffer,
+            self.b_spline_polygon_index_buffer,
+        ) = Self::get_buffers(
+            &self.bernstein_points,
+            &self.b_spline_points,
+            self.closed,
+            display,
+        );
     }
 
     fn get_buffers(
         bernstein_points: &Vec<Vertex>,
         b_spline_points: &Vec<Vertex>,
+        closed: bool,
         display: &Display<WindowSurface>,
     ) -> (
         Option<VertexBuffer<Vertex>>,
@@ -176,7 +201,9 @@ impl BezierC2 {
                     IndexBuffer::new(
                         display,
                         PrimitiveType::LineStrip,
-                        &(0..b_spline_points.len() as u16).collect::<Vec<u16>>(),
+                        &(0..b_spline_points.len() as u16)
+                            .chain(if closed { Some(0) } else { None })
+                            .collect::<Vec<u16>>(),
                     )
                     .unwrap(),
                 ),
@@ -1,5 +1,6 @@
 use backend::cqrs::gregories::gregory_details::GregoryVectorDTO;
 use backend::domain::vertex::Vertex;
+use backend_events::common::surface_display_mode::SurfaceDisplayModeDTO;
 use glium::glutin::surface::WindowSurface;
 use glium::index::PrimitiveType;
 use glium::{Display, IndexBuffer, VertexBuffer};
@@ -14,6 +15,7 @@ pub struct Gregory {
     pub vectors_vertex_buffer: VertexBuffer<Vertex>,
     pub vectors_index_buffer: IndexBuffer<u16>,
     pub draw_vectors: bool,
+    pub display_mode: SurfaceDisplayModeDTO,
 }
 
 impl Gregory {
@@ -74,6 +76,7 @@ impl Gregory {
             vectors_vertex_buffer,
             vectors_index_buffer,
             draw_vectors,
+            display_mode: SurfaceDisplayModeDTO::Wireframe,
         }
     }
 
@@ -131,8 +134,14 @@ impl Gregory {
         self.vectors_index_buffer = vectors_index_buffer;
     }
 
-    pub fn update_settings(&mut self, tess_level: u8, draw_vectors: bool) {
+    pub fn update_settings(
+        &mut self,
+        tess_level: u8,
+        draw_vectors: bool,
+        display_mode: SurfaceDisplayModeDTO,
+    ) {
         self.tess_level = tess_level;
         self.draw_vectors = draw_vectors;
+        self.display_mode = display_mode;
     }
 }
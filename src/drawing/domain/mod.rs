@@ -3,6 +3,9 @@ pub mod bezier_c2;
 pub mod bezier_int;
 pub mod gregory;
 pub mod intersection;
+pub mod nurbs_curve;
+pub mod nurbs_surface;
 pub mod surface_c0;
 pub mod surface_c2;
+pub mod toolpath;
 pub mod torus;
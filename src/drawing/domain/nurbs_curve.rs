@@ -0,0 +1,107 @@
+use glium::glutin::surface::WindowSurface;
+use glium::index::PrimitiveType;
+use glium::{Display, IndexBuffer, VertexBuffer};
+
+use backend::cqrs::nurbs_curves::nurbs_curve_bernstein_points::NurbsCurveBernsteinPointDTO;
+use backend::cqrs::points::point_details::PointDTO;
+use backend::domain::vertex::{Vertex, WeightedVertex};
+
+pub struct NurbsCurve {
+    pub id: u64,
+    pub bernstein_points: Vec<WeightedVertex>,
+    pub control_points: Vec<Vertex>,
+    pub bernstein_vertex_buffer: Option<VertexBuffer<WeightedVertex>>,
+    pub control_vertex_buffer: Option<VertexBuffer<Vertex>>,
+    pub curve_index_buffer: Option<IndexBuffer<u16>>,
+    pub polygon_index_buffer: Option<IndexBuffer<u16>>,
+    pub draw_polygon: bool,
+}
+
+impl NurbsCurve {
+    pub fn new(
+        id: u64,
+        bernstein_points: &[NurbsCurveBernsteinPointDTO],
+        control_points: &[PointDTO],
+        display: &Display<WindowSurface>,
+    ) -> Self {
+        let mut curve = Self {
+            id,
+            bernstein_points: vec![],
+            control_points: vec![],
+            bernstein_vertex_buffer: None,
+            control_vertex_buffer: None,
+            curve_index_buffer: None,
+            polygon_index_buffer: None,
+            draw_polygon: false,
+        };
+        curve.update_points(bernstein_points, control_points, display);
+        curve
+    }
+
+    pub fn update_points(
+        &mut self,
+        bernstein_points: &[NurbsCurveBernsteinPointDTO],
+        control_points: &[PointDTO],
+        display: &Display<WindowSurface>,
+    ) {
+        self.bernstein_points = bernstein_points
+            .iter()
+            .map(|p| WeightedVertex {
+                position: [
+                    p.transformer.position.0 as f32,
+                    p.transformer.position.1 as f32,
+                    p.transformer.position.2 as f32,
+                ],
+                weight: p.weight as f32,
+            })
+            .collect();
+
+        self.control_points = control_points
+            .iter()
+            .map(|p| Vertex {
+                position: [
+                    p.transformer.position.0 as f32,
+                    p.transformer.position.1 as f32,
+                    p.transformer.position.2 as f32,
+                ],
+            })
+            .collect();
+
+        (self.bernstein_vertex_buffer, self.curve_index_buffer) = if self.bernstein_points.len() < 4
+        {
+            (None, None)
+        } else {
+            (
+                Some(VertexBuffer::new(display, &self.bernstein_points).unwrap()),
+                Some(
+                    IndexBuffer::new(
+                        display,
+                        PrimitiveType::LinesListAdjacency,
+                        &(0..(self.bernstein_points.len() as u16 - 3))
+                            .step_by(3)
+                            .flat_map(|f| [f, f + 1, f + 2, f + 3])
+                            .collect::<Vec<u16>>(),
+                    )
+                    .unwrap(),
+                ),
+            )
+        };
+
+        (self.control_vertex_buffer, self.polygon_index_buffer) = if self.control_points.is_empty()
+        {
+            (None, None)
+        } else {
+            (
+                Some(VertexBuffer::new(display, &self.control_points).unwrap()),
+                Some(
+                    IndexBuffer::new(
+                        display,
+                        PrimitiveType::LineStrip,
+                        &(0..self.control_points.len() as u16).collect::<Vec<u16>>(),
+                    )
+                    .unwrap(),
+                ),
+            )
+        };
+    }
+}
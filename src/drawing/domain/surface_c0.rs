@@ -4,11 +4,13 @@ use glium::{Display, IndexBuffer, Rect, Texture2d, VertexBuffer};
 
 use backend::cqrs::points::point_details::PointDTO;
 use backend::domain::vertex::VertexUV;
+use backend_events::common::surface_display_mode::SurfaceDisplayModeDTO;
 
 pub struct SurfaceC0 {
     pub id: u64,
     pub draw_polygon: bool,
     pub tess_level: u8,
+    pub display_mode: SurfaceDisplayModeDTO,
     pub is_cylinder: bool,
     pub vertex_buffer: VertexBuffer<VertexUV>,
     pub surface_index_buffer: IndexBuffer<u32>,
@@ -132,6 +134,7 @@ impl SurfaceC0 {
             id,
             draw_polygon: false,
             tess_level: 4,
+            display_mode: SurfaceDisplayModeDTO::Wireframe,
             is_cylinder,
             vertex_buffer,
             surface_index_buffer,
@@ -149,6 +152,10 @@ impl SurfaceC0 {
         self.tess_level = tess_level;
     }
 
+    pub fn set_display_mode(&mut self, display_mode: SurfaceDisplayModeDTO) {
+        self.display_mode = display_mode;
+    }
+
     pub fn update_points(&mut self, points: &[PointDTO], display: &Display<WindowSurface>) {
         self.vertex_buffer = VertexBuffer::new(
             display,
@@ -4,11 +4,13 @@ use glium::{Display, IndexBuffer, Rect, Texture2d, VertexBuffer};
 
 use backend::cqrs::points::point_details::PointDTO;
 use backend::domain::vertex::VertexUV;
+use backend_events::common::surface_display_mode::SurfaceDisplayModeDTO;
 
 pub struct SurfaceC2 {
     pub id: u64,
     pub draw_polygon: bool,
     pub tess_level: u8,
+    pub display_mode: SurfaceDisplayModeDTO,
     pub is_cylinder: bool,
     pub vertex_buffer: VertexBuffer<VertexUV>,
     pub surface_index_buffer: IndexBuffer<u32>,
@@ -134,6 +136,7 @@ impl SurfaceC2 {
             id,
             draw_polygon: false,
             tess_level: 4,
+            display_mode: SurfaceDisplayModeDTO::Wireframe,
             is_cylinder,
             vertex_buffer,
             surface_index_buffer,
@@ -151,6 +154,10 @@ impl SurfaceC2 {
         self.tess_level = tess_level;
     }
 
+    pub fn set_display_mode(&mut self, display_mode: SurfaceDisplayModeDTO) {
+        self.display_mode = display_mode;
+    }
+
     pub fn update_points(&mut self, points: &[PointDTO], display: &Display<WindowSurface>) {
         self.vertex_buffer = VertexBuffer::new(
             display,
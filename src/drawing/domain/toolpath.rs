@@ -0,0 +1,62 @@
+use backend::domain::vertex::Vertex;
+use glium::glutin::surface::WindowSurface;
+use glium::index::PrimitiveType;
+use glium::{Display, IndexBuffer, VertexBuffer};
+use math::vector3::Vector3;
+
+pub struct Toolpath {
+    pub id: u64,
+    pub visible: bool,
+    pub vertex_buffer: VertexBuffer<Vertex>,
+    pub index_buffer: IndexBuffer<u32>,
+}
+
+impl Toolpath {
+    pub fn new(
+        id: u64,
+        points: &[Vector3],
+        visible: bool,
+        display: &Display<WindowSurface>,
+    ) -> Self {
+        let (vertex_buffer, index_buffer) = Self::get_buffers(points, display);
+
+        Self {
+            id,
+            visible,
+            vertex_buffer,
+            index_buffer,
+        }
+    }
+
+    pub fn update(&mut self, points: &[Vector3], visible: bool, display: &Display<WindowSurface>) {
+        let (vertex_buffer, index_buffer) = Self::get_buffers(points, display);
+        self.vertex_buffer = vertex_buffer;
+        self.index_buffer = index_buffer;
+        self.visible = visible;
+    }
+
+    fn get_buffers(
+        points: &[Vector3],
+        display: &Display<WindowSurface>,
+    ) -> (VertexBuffer<Vertex>, IndexBuffer<u32>) {
+        let vertex_buffer = VertexBuffer::new(
+            display,
+            &points
+                .iter()
+                .map(|p| Vertex {
+                    position: [p.x, p.y, p.z],
+                })
+                .collect::<Vec<Vertex>>(),
+        )
+        .unwrap();
+
+        let index_buffer = IndexBuffer::new(
+            display,
+            PrimitiveType::LineStrip,
+            &(0..(points.len() as u32)).collect::<Vec<_>>(),
+        )
+        .unwrap();
+
+        (vertex_buffer, index_buffer)
+    }
+}
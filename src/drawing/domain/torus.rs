@@ -9,6 +9,8 @@ pub struct Torus {
     pub id: u64,
     pub vertex_buffer: VertexBuffer<VertexUV>,
     pub index_buffer: IndexBuffer<u32>,
+    pub triangle_index_buffer: IndexBuffer<u32>,
+    pub major_radius: f32,
     pub model_matrix: Matrix4,
     pub texture: Texture2d,
 }
@@ -27,6 +29,7 @@ impl Torus {
     ) -> Self {
         let mut vertices = Vec::new();
         let mut indices = Vec::new();
+        let mut triangle_indices = Vec::new();
 
         for i in 0..(major_segments + 1) {
             for j in 0..(minor_segments + 1) {
@@ -50,6 +53,13 @@ impl Torus {
                     indices.push((j + 1) + i * (minor_segments + 1));
                     indices.push(j + i * (minor_segments + 1));
                     indices.push(j + (i + 1) * (minor_segments + 1));
+
+                    triangle_indices.push(j + i * (minor_segments + 1));
+                    triangle_indices.push((j + 1) + i * (minor_segments + 1));
+                    triangle_indices.push((j + 1) + (i + 1) * (minor_segments + 1));
+                    triangle_indices.push(j + i * (minor_segments + 1));
+                    triangle_indices.push((j + 1) + (i + 1) * (minor_segments + 1));
+                    triangle_indices.push(j + (i + 1) * (minor_segments + 1));
                 }
             }
         }
@@ -58,6 +68,9 @@ impl Torus {
 
         let index_buffer = IndexBuffer::new(display, PrimitiveType::LinesList, &indices).unwrap();
 
+        let triangle_index_buffer =
+            IndexBuffer::new(display, PrimitiveType::TrianglesList, &triangle_indices).unwrap();
+
         let model_matrix =
             Matrix4::translation(position.0 as f32, position.1 as f32, position.2 as f32)
                 * Matrix4::rotation_quaternion(Vector4::new(
@@ -93,6 +106,8 @@ impl Torus {
             id,
             vertex_buffer,
             index_buffer,
+            triangle_index_buffer,
+            major_radius: major_radius as f32,
             model_matrix,
             texture,
         }
@@ -108,6 +123,7 @@ impl Torus {
     ) {
         let mut vertices = Vec::new();
         let mut indices = Vec::new();
+        let mut triangle_indices = Vec::new();
 
         for i in 0..(major_segments + 1) {
             for j in 0..(minor_segments + 1) {
@@ -131,6 +147,13 @@ impl Torus {
                     indices.push((j + 1) + i * (minor_segments + 1));
                     indices.push(j + i * (minor_segments + 1));
                     indices.push(j + (i + 1) * (minor_segments + 1));
+
+                    triangle_indices.push(j + i * (minor_segments + 1));
+                    triangle_indices.push((j + 1) + i * (minor_segments + 1));
+                    triangle_indices.push((j + 1) + (i + 1) * (minor_segments + 1));
+                    triangle_indices.push(j + i * (minor_segments + 1));
+                    triangle_indices.push((j + 1) + (i + 1) * (minor_segments + 1));
+                    triangle_indices.push(j + (i + 1) * (minor_segments + 1));
                 }
             }
         }
@@ -139,8 +162,13 @@ impl Torus {
 
         let index_buffer = IndexBuffer::new(display, PrimitiveType::LinesList, &indices).unwrap();
 
+        let triangle_index_buffer =
+            IndexBuffer::new(display, PrimitiveType::TrianglesList, &triangle_indices).unwrap();
+
         self.vertex_buffer = vertex_buffer;
         self.index_buffer = index_buffer;
+        self.triangle_index_buffer = triangle_index_buffer;
+        self.major_radius = major_radius as f32;
     }
 
     pub fn transform(
@@ -0,0 +1,97 @@
+use glium::glutin::surface::WindowSurface;
+use glium::index::PrimitiveType;
+use glium::{Display, DrawParameters, Frame, IndexBuffer, Program, Surface, VertexBuffer};
+
+use backend::cqrs::curves::curve_curvature_comb::CurvatureCombToothDTO;
+use backend::domain::vertex::Vertex;
+
+pub struct CurvatureCombDrawer {
+    program: Program,
+}
+
+impl CurvatureCombDrawer {
+    pub fn new(display: &Display<WindowSurface>) -> Self {
+        let vertex_shader_src = r#"
+            #version 410 core
+
+            in vec3 position;
+
+            uniform mat4 perspective;
+            uniform mat4 view;
+
+            void main() {
+                gl_Position = perspective * view * vec4(position, 1.0);
+            }
+        "#;
+
+        let fragment_shader_src = r#"
+            #version 410 core
+
+            out vec4 color;
+
+            uniform vec4 obj_color;
+
+            void main() {
+                color = obj_color;
+            }
+        "#;
+
+        let program =
+            Program::from_source(display, vertex_shader_src, fragment_shader_src, None).unwrap();
+
+        Self { program }
+    }
+
+    pub fn draw(
+        &self,
+        target: &mut Frame,
+        display: &Display<WindowSurface>,
+        comb: &[CurvatureCombToothDTO],
+        perspective: &math::matrix4::Matrix4,
+        view_matrix: &math::matrix4::Matrix4,
+        color: [f32; 4],
+        drawing_parameters: &DrawParameters,
+    ) {
+        if comb.len() < 2 {
+            return;
+        }
+
+        let vertices = comb
+            .iter()
+            .flat_map(|tooth| [&tooth.base, &tooth.tip])
+            .map(|transformer| Vertex {
+                position: [
+                    transformer.position.0 as f32,
+                    transformer.position.1 as f32,
+                    transformer.position.2 as f32,
+                ],
+            })
+            .collect::<Vec<_>>();
+        let teeth_indices = (0..vertices.len() as u32).collect::<Vec<_>>();
+        let envelope_indices = (0..comb.len() as u32)
+            .map(|i| 2 * i + 1)
+            .collect::<Vec<_>>();
+
+        let vertex_buffer = VertexBuffer::new(display, &vertices).unwrap();
+        let teeth_index_buffer =
+            IndexBuffer::new(display, PrimitiveType::LinesList, &teeth_indices).unwrap();
+        let envelope_index_buffer =
+            IndexBuffer::new(display, PrimitiveType::LineStrip, &envelope_indices).unwrap();
+
+        for index_buffer in [&teeth_index_buffer, &envelope_index_buffer] {
+            target
+                .draw(
+                    &vertex_buffer,
+                    index_buffer,
+                    &self.program,
+                    &uniform! {
+                        perspective: perspective.data,
+                        view: view_matrix.data,
+                        obj_color: color,
+                    },
+                    drawing_parameters,
+                )
+                .unwrap();
+        }
+    }
+}
@@ -1,12 +1,16 @@
 use glium::glutin::surface::WindowSurface;
 use glium::program::SourceCode;
-use glium::{Display, DrawParameters, Frame, Program, Surface};
+use glium::{Display, DrawParameters, Frame, PolygonMode, Program, Surface};
 
 use crate::drawing::domain::gregory::Gregory;
+use crate::drawing::drawers::surface_display_drawer::{
+    SurfaceDisplayDrawer, NORMAL_LENGTH, ZEBRA_STRIPES,
+};
 
 pub struct GregoryDrawer {
     surface_program: Program,
     vectors_program: Program,
+    display_drawer: SurfaceDisplayDrawer,
 }
 
 impl GregoryDrawer {
@@ -33,30 +37,7 @@ impl GregoryDrawer {
             }
         "#;
 
-        let surface_tessellation_control_shader = r#"
-            #version 410 core
-
-            layout(vertices = 20) out;
-
-            uniform int tess_level;
-
-            void main() {
-                gl_out[gl_InvocationID].gl_Position = gl_in[gl_InvocationID].gl_Position;
-
-                gl_TessLevelOuter[0] = tess_level;
-                gl_TessLevelOuter[1] = tess_level;
-            }
-        "#;
-
-        let surface_tessellation_evaluation_shader = r#"
-            #version 410 core
-
-            layout(isolines, equal_spacing) in;
-
-            uniform mat4 perspective;
-            uniform mat4 view;
-            uniform bool swap_xy;
-            
+        let gregory_functions = r#"
             vec3 p(uint idx) {
                 return gl_in[idx].gl_Position.xyz;
             }
@@ -89,8 +70,35 @@ impl GregoryDrawer {
 
                 return bezier3(p0, p1, p2, p3, u);
             }
+        "#;
 
+        let surface_tessellation_control_shader = r#"
+            #version 410 core
+
+            layout(vertices = 20) out;
+
+            uniform int tess_level;
+
+            void main() {
+                gl_out[gl_InvocationID].gl_Position = gl_in[gl_InvocationID].gl_Position;
+
+                gl_TessLevelOuter[0] = tess_level;
+                gl_TessLevelOuter[1] = tess_level;
+            }
+        "#;
+
+        let surface_tessellation_evaluation_shader = [
+            r#"
+            #version 410 core
 
+            layout(isolines, equal_spacing) in;
+
+            uniform mat4 perspective;
+            uniform mat4 view;
+            uniform bool swap_xy;
+            "#,
+            gregory_functions,
+            r#"
             void main() {
                 float u = gl_TessCoord.x;
                 float v = gl_TessCoord.y;
@@ -105,7 +113,9 @@ impl GregoryDrawer {
 
                 gl_Position = perspective * view * position;
             }
-        "#;
+            "#,
+        ]
+        .concat();
 
         let surface_program = Program::new(
             display,
@@ -113,12 +123,69 @@ impl GregoryDrawer {
                 vertex_shader: surface_vertex_shader,
                 fragment_shader: surface_fragment_shader,
                 tessellation_control_shader: Some(surface_tessellation_control_shader),
-                tessellation_evaluation_shader: Some(surface_tessellation_evaluation_shader),
+                tessellation_evaluation_shader: Some(&surface_tessellation_evaluation_shader),
                 geometry_shader: None,
             },
         )
         .unwrap();
 
+        let display_tessellation_control_shader = r#"
+            #version 410 core
+
+            layout(vertices = 20) out;
+
+            uniform int tess_level;
+
+            void main() {
+                gl_out[gl_InvocationID].gl_Position = gl_in[gl_InvocationID].gl_Position;
+
+                gl_TessLevelOuter[0] = tess_level;
+                gl_TessLevelOuter[1] = tess_level;
+                gl_TessLevelOuter[2] = tess_level;
+                gl_TessLevelOuter[3] = tess_level;
+                gl_TessLevelInner[0] = tess_level;
+                gl_TessLevelInner[1] = tess_level;
+            }
+        "#;
+
+        let display_evaluation = [
+            gregory_functions,
+            r#"
+            const float h = 1e-2;
+
+            void evaluate(float u, float v, out vec3 s, out vec3 du, out vec3 dv, out vec3 duu, out vec3 duv, out vec3 dvv) {
+                float cu = clamp(u, h, 1.0 - h);
+                float cv = clamp(v, h, 1.0 - h);
+
+                vec3 c = gregory(cu, cv);
+                vec3 pu = gregory(cu + h, cv);
+                vec3 mu = gregory(cu - h, cv);
+                vec3 pv = gregory(cu, cv + h);
+                vec3 mv = gregory(cu, cv - h);
+
+                s = gregory(u, v);
+                du = (pu - mu) / (2.0 * h);
+                dv = (pv - mv) / (2.0 * h);
+                duu = (pu - 2.0 * c + mu) / (h * h);
+                dvv = (pv - 2.0 * c + mv) / (h * h);
+                duv = (gregory(cu + h, cv + h) - gregory(cu + h, cv - h)
+                    - gregory(cu - h, cv + h) + gregory(cu - h, cv - h)) / (4.0 * h * h);
+            }
+
+            vec2 evaluate_uv(float u, float v) {
+                return vec2(0.0);
+            }
+            "#,
+        ]
+        .concat();
+
+        let display_drawer = SurfaceDisplayDrawer::new(
+            display,
+            surface_vertex_shader,
+            display_tessellation_control_shader,
+            &display_evaluation,
+        );
+
         let vectors_vertex_shader_src = r#"
             #version 140
 
@@ -155,6 +222,7 @@ impl GregoryDrawer {
         Self {
             surface_program,
             vectors_program,
+            display_drawer,
         }
     }
 
@@ -166,6 +234,52 @@ impl GregoryDrawer {
         view_matrix: &math::matrix4::Matrix4,
         color: [f32; 4],
         drawing_parameters: &DrawParameters,
+    ) {
+        if SurfaceDisplayDrawer::draws_wireframe(gregory.display_mode) {
+            self.draw_wireframe(
+                target,
+                gregory,
+                perspective,
+                view_matrix,
+                color,
+                drawing_parameters,
+            );
+        }
+
+        let fill_parameters = DrawParameters {
+            polygon_mode: PolygonMode::Fill,
+            ..drawing_parameters.clone()
+        };
+        if let Some(program) = self.display_drawer.program(gregory.display_mode) {
+            target
+                .draw(
+                    &gregory.vertex_buffer,
+                    &gregory.index_buffer,
+                    program,
+                    &uniform! {
+                        perspective: perspective.data,
+                        view: view_matrix.data,
+                        obj_color: color,
+                        tess_level: gregory.tess_level as i32,
+                        display_mode: SurfaceDisplayDrawer::shaded_mode(gregory.display_mode),
+                        zebra_stripes: ZEBRA_STRIPES,
+                        normal_length: NORMAL_LENGTH,
+                        trimmed: false,
+                    },
+                    &fill_parameters,
+                )
+                .unwrap();
+        }
+    }
+
+    pub fn draw_wireframe(
+        &self,
+        target: &mut Frame,
+        gregory: &Gregory,
+        perspective: &math::matrix4::Matrix4,
+        view_matrix: &math::matrix4::Matrix4,
+        color: [f32; 4],
+        drawing_parameters: &DrawParameters,
     ) {
         target
             .draw(
@@ -200,6 +314,49 @@ impl GregoryDrawer {
             .unwrap();
     }
 
+    pub fn draw_shaded(
+        &self,
+        target: &mut Frame,
+        gregory: &Gregory,
+        perspective: &math::matrix4::Matrix4,
+        view_matrix: &math::matrix4::Matrix4,
+        color: [f32; 4],
+        drawing_parameters: &DrawParameters,
+    ) {
+        let uniforms = uniform! {
+            perspective: perspective.data,
+            view: view_matrix.data,
+            obj_color: color,
+            tess_level: gregory.tess_level as i32,
+            display_mode: SurfaceDisplayDrawer::shaded_mode(gregory.display_mode),
+            zebra_stripes: ZEBRA_STRIPES,
+            normal_length: NORMAL_LENGTH,
+            trimmed: false,
+        };
+
+        target
+            .draw(
+                &gregory.vertex_buffer,
+                &gregory.index_buffer,
+                self.display_drawer.fill_program(gregory.display_mode),
+                &uniforms,
+                drawing_parameters,
+            )
+            .unwrap();
+
+        if let Some(program) = self.display_drawer.normals_program(gregory.display_mode) {
+            target
+                .draw(
+                    &gregory.vertex_buffer,
+                    &gregory.index_buffer,
+                    program,
+                    &uniforms,
+                    drawing_parameters,
+                )
+                .unwrap();
+        }
+    }
+
     pub fn draw_vectors(
         &self,
         target: &mut Frame,
@@ -1,13 +1,19 @@
 pub mod bezier_c0_drawer;
 pub mod bezier_c2_drawer;
 pub mod bezier_int_drawer;
+pub mod boundary_drawer;
 pub mod cursor_drawer;
+pub mod curvature_comb_drawer;
 pub mod gregory_drawer;
 pub mod infinite_grid_drawer;
 pub mod intersection_drawer;
+pub mod nurbs_curve_drawer;
+pub mod nurbs_surface_drawer;
 pub mod point_drawer;
 pub mod points_drawer;
 pub mod polygon_drawer;
 pub mod surface_c0_drawer;
 pub mod surface_c2_drawer;
+pub mod surface_display_drawer;
+pub mod toolpath_drawer;
 pub mod torus_drawer;
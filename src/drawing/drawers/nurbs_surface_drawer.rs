@@ -0,0 +1,375 @@
+use glium::glutin::surface::WindowSurface;
+use glium::program::SourceCode;
+use glium::{Display, DrawParameters, Frame, Program, Surface};
+
+use backend_events::common::surface_display_mode::SurfaceDisplayModeDTO;
+
+use crate::drawing::domain::nurbs_surface::NurbsSurface;
+use crate::drawing::drawers::surface_display_drawer::{
+    SurfaceDisplayDrawer, B_SPLINE_BASIS, TENSOR_PRODUCT_UV,
+};
+
+pub struct NurbsSurfaceDrawer {
+    program: Program,
+    display_drawer: SurfaceDisplayDrawer,
+}
+
+impl NurbsSurfaceDrawer {
+    pub fn new(display: &Display<WindowSurface>) -> Self {
+        let vertex_shader = r#"
+            #version 410 core
+    
+            in vec3 position;
+            in float weight;
+            in vec2 uv;
+
+            out vec2 out_uv;
+            out float out_weight;
+            
+            uniform mat4 perspective;
+            uniform mat4 view;
+    
+            void main() {
+                gl_Position = perspective * view * vec4(position, 1.0);
+                out_uv = uv;
+                out_weight = weight;
+            }
+        "#;
+
+        let fragment_shader = r#"
+            #version 410 core
+
+            in vec2 uv;
+    
+            out vec4 color;
+            
+            uniform vec4 obj_color;
+
+            uniform sampler2D tex;
+    
+            void main() {
+                float value = texture(tex, uv).x;
+                if (value == 1.0) {
+                    color = obj_color;
+                } else {
+                    discard;
+                }
+            }
+        "#;
+
+        let tessellation_control_shader = r#"
+            #version 410 core
+
+            in vec2 out_uv[];
+            in float out_weight[];
+
+            out vec2 out_uvs[];
+            out float out_weights[];
+
+            layout(vertices = 16) out;
+
+            uniform int tess_level;
+
+            void main() {
+                gl_out[gl_InvocationID].gl_Position = gl_in[gl_InvocationID].gl_Position;
+                out_uvs[gl_InvocationID] = out_uv[gl_InvocationID];
+                out_weights[gl_InvocationID] = out_weight[gl_InvocationID];
+
+                gl_TessLevelOuter[0] = tess_level;
+                gl_TessLevelOuter[1] = tess_level;
+            }
+        "#;
+
+        let tessellation_evaluation_shader = r#"
+            #version 410 core
+
+            layout(isolines, equal_spacing) in;
+
+            in vec2 out_uvs[];
+            in float out_weights[];
+
+            out vec2 uv;
+
+            uniform bool swap_xy;
+            uniform bool is_cylinder;
+
+            float[4] get_basis(float t) {
+                float t2 = t * t;
+                float t3 = t2 * t;
+                return float[4](
+                    (-t3 + 3 * t2 - 3 * t + 1) / 6,
+                    (3 * t3 - 6 * t2 + 4) / 6,
+                    (-3 * t3 + 3 * t2 + 3 * t + 1) / 6,
+                    t3 / 6
+                );
+            }
+
+            vec4 get_rational_value(float x, float y, vec4 positions[16], float weights[16]) {
+                float bx[4] = get_basis(x);
+                float by[4] = get_basis(y);
+
+                vec4 p = vec4(0.0);
+                float w = 0.0;
+                for (int i = 0; i < 4; i++) {
+                    for (int j = 0; j < 4; j++) {
+                        float b = bx[i] * by[j] * weights[j * 4 + i];
+                        p += b * positions[j * 4 + i];
+                        w += b;
+                    }
+                }
+
+                return p / w;
+            }
+
+            vec2 get_uv_value(float x, float y, vec2 uvs[16]) {
+                vec2 v11 = uvs[0];
+                vec2 v21 = uvs[1];
+                vec2 v31 = uvs[2];
+                vec2 v41 = uvs[3];
+                vec2 v12 = uvs[4];
+                vec2 v22 = uvs[5];
+                vec2 v32 = uvs[6];
+                vec2 v42 = uvs[7];
+                vec2 v13 = uvs[8];
+                vec2 v23 = uvs[9];
+                vec2 v33 = uvs[10];
+                vec2 v43 = uvs[11];
+                vec2 v14 = uvs[12];
+                vec2 v24 = uvs[13];
+                vec2 v34 = uvs[14];
+                vec2 v44 = uvs[15];
+
+                float x2 = x * x;
+                float x3 = x2 * x;
+                float b0x = (-x3 + 3 * x2 - 3 * x + 1) / 6;
+                float b1x = (3 * x3 - 6 * x2 + 4) / 6;
+                float b2x = (-3 * x3 + 3 * x2 + 3 * x + 1) / 6;
+                float b3x = (x3) / 6;
+
+                float y2 = y * y;
+                float y3 = y2 * y;
+                float b0y = (-y3 + 3 * y2 - 3 * y + 1) / 6;
+                float b1y = (3 * y3 - 6 * y2 + 4) / 6;
+                float b2y = (-3 * y3 + 3 * y2 + 3 * y + 1) / 6;
+                float b3y = (y3) / 6;
+
+                vec2 p = b0x * (b0y * v11 + b1y * v12 + b2y * v13 + b3y * v14) +
+                         b1x * (b0y * v21 + b1y * v22 + b2y * v23 + b3y * v24) +
+                         b2x * (b0y * v31 + b1y * v32 + b2y * v33 + b3y * v34) +
+                         b3x * (b0y * v41 + b1y * v42 + b2y * v43 + b3y * v44);
+            
+                return p;
+            }
+
+            void main() {
+                vec4 positions[16];
+                vec2 uvs[16];
+                float weights[16];
+                for (int i = 0; i < 16; i++) {
+                    positions[i] = gl_in[i].gl_Position;
+                    uvs[i] = out_uvs[i];
+                    weights[i] = out_weights[i];
+                }
+
+                float x = gl_TessCoord.x;
+                float y = gl_TessCoord.y * float(gl_TessLevelOuter[0]) / float(gl_TessLevelOuter[0] - 1);
+
+                if (swap_xy && !is_cylinder) {
+                    float temp = x;
+                    x = y;
+                    y = temp;
+                } else if (swap_xy && is_cylinder) {
+                    y = gl_TessCoord.x;
+                    x = gl_TessCoord.y;
+                }
+
+                gl_Position = get_rational_value(x, y, positions, weights);
+                uv = get_uv_value(x, y, uvs);
+            }
+        "#;
+
+        let program = Program::new(
+            display,
+            SourceCode {
+                vertex_shader,
+                fragment_shader,
+                tessellation_control_shader: Some(tessellation_control_shader),
+                tessellation_evaluation_shader: Some(tessellation_evaluation_shader),
+                geometry_shader: None,
+            },
+        )
+        .unwrap();
+
+        let display_vertex_shader = r#"
+            #version 410 core
+
+            in vec3 position;
+            in float weight;
+            in vec2 uv;
+
+            out vec2 out_uv;
+            out float out_weight;
+
+            void main() {
+                gl_Position = vec4(position, 1.0);
+                out_uv = uv;
+                out_weight = weight;
+            }
+        "#;
+
+        let display_tessellation_control_shader = r#"
+            #version 410 core
+
+            in vec2 out_uv[];
+            in float out_weight[];
+
+            out vec2 out_uvs[];
+            out float out_weights[];
+
+            layout(vertices = 16) out;
+
+            uniform int tess_level;
+
+            void main() {
+                gl_out[gl_InvocationID].gl_Position = gl_in[gl_InvocationID].gl_Position;
+                out_uvs[gl_InvocationID] = out_uv[gl_InvocationID];
+                out_weights[gl_InvocationID] = out_weight[gl_InvocationID];
+
+                gl_TessLevelOuter[0] = tess_level;
+                gl_TessLevelOuter[1] = tess_level;
+                gl_TessLevelOuter[2] = tess_level;
+                gl_TessLevelOuter[3] = tess_level;
+                gl_TessLevelInner[0] = tess_level;
+                gl_TessLevelInner[1] = tess_level;
+            }
+        "#;
+
+        let rational_evaluation = r#"
+            in float out_weights[];
+
+            void evaluate(float x, float y, out vec3 p, out vec3 dx, out vec3 dy, out vec3 dxx, out vec3 dxy, out vec3 dyy) {
+                float bx[4], dbx[4], ddbx[4];
+                float by[4], dby[4], ddby[4];
+                basis(x, bx, dbx, ddbx);
+                basis(y, by, dby, ddby);
+
+                vec4 a = vec4(0.0);
+                vec4 ax = vec4(0.0);
+                vec4 ay = vec4(0.0);
+                vec4 axx = vec4(0.0);
+                vec4 axy = vec4(0.0);
+                vec4 ayy = vec4(0.0);
+                for (int j = 0; j < 4; j++) {
+                    for (int i = 0; i < 4; i++) {
+                        vec4 c = out_weights[4 * j + i] * vec4(gl_in[4 * j + i].gl_Position.xyz, 1.0);
+                        a += bx[i] * by[j] * c;
+                        ax += dbx[i] * by[j] * c;
+                        ay += bx[i] * dby[j] * c;
+                        axx += ddbx[i] * by[j] * c;
+                        axy += dbx[i] * dby[j] * c;
+                        ayy += bx[i] * ddby[j] * c;
+                    }
+                }
+
+                p = a.xyz / a.w;
+                dx = (ax.xyz - ax.w * p) / a.w;
+                dy = (ay.xyz - ay.w * p) / a.w;
+                dxx = (axx.xyz - 2.0 * ax.w * dx - axx.w * p) / a.w;
+                dxy = (axy.xyz - ax.w * dy - ay.w * dx - axy.w * p) / a.w;
+                dyy = (ayy.xyz - 2.0 * ay.w * dy - ayy.w * p) / a.w;
+            }
+        "#;
+
+        let display_drawer = SurfaceDisplayDrawer::new(
+            display,
+            display_vertex_shader,
+            display_tessellation_control_shader,
+            &[B_SPLINE_BASIS, TENSOR_PRODUCT_UV, rational_evaluation].concat(),
+        );
+
+        Self {
+            program,
+            display_drawer,
+        }
+    }
+
+    pub fn draw(
+        &self,
+        target: &mut Frame,
+        surface: &NurbsSurface,
+        perspective: &math::matrix4::Matrix4,
+        view_matrix: &math::matrix4::Matrix4,
+        color: [f32; 4],
+        tess_level: u8,
+        drawing_parameters: &DrawParameters,
+    ) {
+        target
+            .draw(
+                &surface.vertex_buffer,
+                &surface.surface_index_buffer,
+                &self.program,
+                &uniform! {
+                    perspective: perspective.data,
+                    view: view_matrix.data,
+                    obj_color: color,
+                    tess_level: tess_level as i32,
+                    swap_xy: false,
+                    is_cylinder: surface.is_cylinder,
+                    tex: surface.texture.sampled()
+                        .minify_filter(glium::uniforms::MinifySamplerFilter::Nearest)
+                        .magnify_filter(glium::uniforms::MagnifySamplerFilter::Nearest),
+                },
+                &drawing_parameters,
+            )
+            .unwrap();
+        target
+            .draw(
+                &surface.vertex_buffer,
+                &surface.surface_index_buffer,
+                &self.program,
+                &uniform! {
+                    perspective: perspective.data,
+                    view: view_matrix.data,
+                    obj_color: color,
+                    tess_level: tess_level as i32,
+                    swap_xy: true,
+                    is_cylinder: surface.is_cylinder,
+                    tex: surface.texture.sampled()
+                        .minify_filter(glium::uniforms::MinifySamplerFilter::Nearest)
+                        .magnify_filter(glium::uniforms::MagnifySamplerFilter::Nearest),
+                },
+                &drawing_parameters,
+            )
+            .unwrap();
+    }
+
+    pub fn draw_shaded(
+        &self,
+        target: &mut Frame,
+        surface: &NurbsSurface,
+        perspective: &math::matrix4::Matrix4,
+        view_matrix: &math::matrix4::Matrix4,
+        color: [f32; 4],
+        drawing_parameters: &DrawParameters,
+    ) {
+        target
+            .draw(
+                &surface.vertex_buffer,
+                &surface.surface_index_buffer,
+                self.display_drawer
+                    .fill_program(SurfaceDisplayModeDTO::Wireframe),
+                &uniform! {
+                    perspective: perspective.data,
+                    view: view_matrix.data,
+                    obj_color: color,
+                    tess_level: surface.tess_level as i32,
+                    trimmed: true,
+                    tex: surface.texture.sampled()
+                        .minify_filter(glium::uniforms::MinifySamplerFilter::Nearest)
+                        .magnify_filter(glium::uniforms::MagnifySamplerFilter::Nearest),
+                },
+                drawing_parameters,
+            )
+            .unwrap();
+    }
+}
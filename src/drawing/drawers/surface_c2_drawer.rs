@@ -1,11 +1,16 @@
 use glium::glutin::surface::WindowSurface;
 use glium::program::SourceCode;
-use glium::{Display, DrawParameters, Frame, Program, Surface};
+use glium::{Display, DrawParameters, Frame, PolygonMode, Program, Surface};
 
 use crate::drawing::domain::surface_c2::SurfaceC2;
+use crate::drawing::drawers::surface_display_drawer::{
+    SurfaceDisplayDrawer, B_SPLINE_BASIS, NORMAL_LENGTH, TENSOR_PRODUCT_CONTROL_SHADER,
+    TENSOR_PRODUCT_EVALUATION, TENSOR_PRODUCT_UV, TENSOR_PRODUCT_VERTEX_SHADER, ZEBRA_STRIPES,
+};
 
 pub struct SurfaceC2Drawer {
     program: Program,
+    display_drawer: SurfaceDisplayDrawer,
 }
 
 impl SurfaceC2Drawer {
@@ -197,7 +202,17 @@ impl SurfaceC2Drawer {
         )
         .unwrap();
 
-        Self { program }
+        let display_drawer = SurfaceDisplayDrawer::new(
+            display,
+            TENSOR_PRODUCT_VERTEX_SHADER,
+            TENSOR_PRODUCT_CONTROL_SHADER,
+            &[B_SPLINE_BASIS, TENSOR_PRODUCT_UV, TENSOR_PRODUCT_EVALUATION].concat(),
+        );
+
+        Self {
+            program,
+            display_drawer,
+        }
     }
 
     pub fn draw(
@@ -209,6 +224,55 @@ impl SurfaceC2Drawer {
         color: [f32; 4],
         tess_level: u8,
         drawing_parameters: &DrawParameters,
+    ) {
+        if SurfaceDisplayDrawer::draws_wireframe(surface.display_mode) {
+            self.draw_wireframe(
+                target,
+                surface,
+                perspective,
+                view_matrix,
+                color,
+                drawing_parameters,
+            );
+        }
+
+        let fill_parameters = DrawParameters {
+            polygon_mode: PolygonMode::Fill,
+            ..drawing_parameters.clone()
+        };
+        if let Some(program) = self.display_drawer.program(surface.display_mode) {
+            target
+                .draw(
+                    &surface.vertex_buffer,
+                    &surface.surface_index_buffer,
+                    program,
+                    &uniform! {
+                        perspective: perspective.data,
+                        view: view_matrix.data,
+                        obj_color: color,
+                        tess_level: tess_level as i32,
+                        display_mode: SurfaceDisplayDrawer::shaded_mode(surface.display_mode),
+                        zebra_stripes: ZEBRA_STRIPES,
+                        normal_length: NORMAL_LENGTH,
+                        trimmed: true,
+                        tex: surface.texture.sampled()
+                            .minify_filter(glium::uniforms::MinifySamplerFilter::Nearest)
+                            .magnify_filter(glium::uniforms::MagnifySamplerFilter::Nearest),
+                    },
+                    &fill_parameters,
+                )
+                .unwrap();
+        }
+    }
+
+    pub fn draw_wireframe(
+        &self,
+        target: &mut Frame,
+        surface: &SurfaceC2,
+        perspective: &math::matrix4::Matrix4,
+        view_matrix: &math::matrix4::Matrix4,
+        color: [f32; 4],
+        drawing_parameters: &DrawParameters,
     ) {
         target
             .draw(
@@ -219,7 +283,7 @@ impl SurfaceC2Drawer {
                     perspective: perspective.data,
                     view: view_matrix.data,
                     obj_color: color,
-                    tess_level: tess_level as i32,
+                    tess_level: surface.tess_level as i32,
                     swap_xy: false,
                     is_cylinder: surface.is_cylinder,
                     tex: surface.texture.sampled()
@@ -238,7 +302,7 @@ impl SurfaceC2Drawer {
                     perspective: perspective.data,
                     view: view_matrix.data,
                     obj_color: color,
-                    tess_level: tess_level as i32,
+                    tess_level: surface.tess_level as i32,
                     swap_xy: true,
                     is_cylinder: surface.is_cylinder,
                     tex: surface.texture.sampled()
@@ -249,4 +313,50 @@ impl SurfaceC2Drawer {
             )
             .unwrap();
     }
+
+    pub fn draw_shaded(
+        &self,
+        target: &mut Frame,
+        surface: &SurfaceC2,
+        perspective: &math::matrix4::Matrix4,
+        view_matrix: &math::matrix4::Matrix4,
+        color: [f32; 4],
+        drawing_parameters: &DrawParameters,
+    ) {
+        let uniforms = uniform! {
+            perspective: perspective.data,
+            view: view_matrix.data,
+            obj_color: color,
+            tess_level: surface.tess_level as i32,
+            display_mode: SurfaceDisplayDrawer::shaded_mode(surface.display_mode),
+            zebra_stripes: ZEBRA_STRIPES,
+            normal_length: NORMAL_LENGTH,
+            trimmed: true,
+            tex: surface.texture.sampled()
+                .minify_filter(glium::uniforms::MinifySamplerFilter::Nearest)
+                .magnify_filter(glium::uniforms::MagnifySamplerFilter::Nearest),
+        };
+
+        target
+            .draw(
+                &surface.vertex_buffer,
+                &surface.surface_index_buffer,
+                self.display_drawer.fill_program(surface.display_mode),
+                &uniforms,
+                drawing_parameters,
+            )
+            .unwrap();
+
+        if let Some(program) = self.display_drawer.normals_program(surface.display_mode) {
+            target
+                .draw(
+                    &surface.vertex_buffer,
+                    &surface.surface_index_buffer,
+                    program,
+                    &uniforms,
+                    drawing_parameters,
+                )
+                .unwrap();
+        }
+    }
 }
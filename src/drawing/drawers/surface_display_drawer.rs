@@ -0,0 +1,395 @@
+use backend_events::common::surface_display_mode::SurfaceDisplayModeDTO;
+use glium::glutin::surface::WindowSurface;
+use glium::program::SourceCode;
+use glium::{Display, Program};
+
+pub const NORMAL_LENGTH: f32 = 0.1;
+pub const ZEBRA_STRIPES: f32 = 8.0;
+
+pub const TENSOR_PRODUCT_VERTEX_SHADER: &str = r#"
+    #version 410 core
+
+    in vec3 position;
+    in vec2 uv;
+
+    out vec2 out_uv;
+
+    void main() {
+        gl_Position = vec4(position, 1.0);
+        out_uv = uv;
+    }
+"#;
+
+pub const TENSOR_PRODUCT_CONTROL_SHADER: &str = r#"
+    #version 410 core
+
+    in vec2 out_uv[];
+
+    out vec2 out_uvs[];
+
+    layout(vertices = 16) out;
+
+    uniform int tess_level;
+
+    void main() {
+        gl_out[gl_InvocationID].gl_Position = gl_in[gl_InvocationID].gl_Position;
+        out_uvs[gl_InvocationID] = out_uv[gl_InvocationID];
+
+        gl_TessLevelOuter[0] = tess_level;
+        gl_TessLevelOuter[1] = tess_level;
+        gl_TessLevelOuter[2] = tess_level;
+        gl_TessLevelOuter[3] = tess_level;
+        gl_TessLevelInner[0] = tess_level;
+        gl_TessLevelInner[1] = tess_level;
+    }
+"#;
+
+pub const BERNSTEIN_BASIS: &str = r#"
+    void basis(float t, out float b[4], out float db[4], out float ddb[4]) {
+        float it = 1.0 - t;
+        b[0] = it * it * it;
+        b[1] = 3.0 * it * it * t;
+        b[2] = 3.0 * it * t * t;
+        b[3] = t * t * t;
+
+        db[0] = -3.0 * it * it;
+        db[1] = 3.0 * it * (it - 2.0 * t);
+        db[2] = 3.0 * t * (2.0 * it - t);
+        db[3] = 3.0 * t * t;
+
+        ddb[0] = 6.0 * it;
+        ddb[1] = 18.0 * t - 12.0;
+        ddb[2] = 6.0 - 18.0 * t;
+        ddb[3] = 6.0 * t;
+    }
+"#;
+
+pub const B_SPLINE_BASIS: &str = r#"
+    void basis(float t, out float b[4], out float db[4], out float ddb[4]) {
+        float it = 1.0 - t;
+        b[0] = it * it * it / 6.0;
+        b[1] = (3.0 * t * t * t - 6.0 * t * t + 4.0) / 6.0;
+        b[2] = (-3.0 * t * t * t + 3.0 * t * t + 3.0 * t + 1.0) / 6.0;
+        b[3] = t * t * t / 6.0;
+
+        db[0] = -it * it / 2.0;
+        db[1] = (3.0 * t * t - 4.0 * t) / 2.0;
+        db[2] = (-3.0 * t * t + 2.0 * t + 1.0) / 2.0;
+        db[3] = t * t / 2.0;
+
+        ddb[0] = it;
+        ddb[1] = 3.0 * t - 2.0;
+        ddb[2] = 1.0 - 3.0 * t;
+        ddb[3] = t;
+    }
+"#;
+
+pub const TENSOR_PRODUCT_UV: &str = r#"
+    in vec2 out_uvs[];
+
+    vec2 evaluate_uv(float x, float y) {
+        float bx[4], dbx[4], ddbx[4];
+        float by[4], dby[4], ddby[4];
+        basis(x, bx, dbx, ddbx);
+        basis(y, by, dby, ddby);
+
+        vec2 uv = vec2(0.0);
+        for (int j = 0; j < 4; j++) {
+            for (int i = 0; i < 4; i++) {
+                uv += bx[i] * by[j] * out_uvs[4 * j + i];
+            }
+        }
+        return uv;
+    }
+"#;
+
+pub const TENSOR_PRODUCT_EVALUATION: &str = r#"
+    void evaluate(float x, float y, out vec3 p, out vec3 dx, out vec3 dy, out vec3 dxx, out vec3 dxy, out vec3 dyy) {
+        float bx[4], dbx[4], ddbx[4];
+        float by[4], dby[4], ddby[4];
+        basis(x, bx, dbx, ddbx);
+        basis(y, by, dby, ddby);
+
+        p = vec3(0.0);
+        dx = vec3(0.0);
+        dy = vec3(0.0);
+        dxx = vec3(0.0);
+        dxy = vec3(0.0);
+        dyy = vec3(0.0);
+        for (int j = 0; j < 4; j++) {
+            for (int i = 0; i < 4; i++) {
+                vec3 c = gl_in[4 * j + i].gl_Position.xyz;
+                p += bx[i] * by[j] * c;
+                dx += dbx[i] * by[j] * c;
+                dy += bx[i] * dby[j] * c;
+                dxx += ddbx[i] * by[j] * c;
+                dxy += dbx[i] * dby[j] * c;
+                dyy += bx[i] * ddby[j] * c;
+            }
+        }
+    }
+"#;
+
+const TESSELLATION_EVALUATION_MAIN: &str = r#"
+    out vec3 world_position;
+    out vec3 normal;
+    out float gaussian_curvature;
+    out float mean_curvature;
+    out vec2 trim_uv;
+
+    uniform mat4 perspective;
+    uniform mat4 view;
+
+    void main() {
+        vec3 position, dx, dy, dxx, dxy, dyy;
+        evaluate(gl_TessCoord.x, gl_TessCoord.y, position, dx, dy, dxx, dxy, dyy);
+
+        vec3 n = cross(dx, dy);
+        float n_length = length(n);
+        n = n_length > 1e-12 ? n / n_length : vec3(0.0);
+
+        float e = dot(dx, dx);
+        float f = dot(dx, dy);
+        float g = dot(dy, dy);
+        float l = dot(dxx, n);
+        float m = dot(dxy, n);
+        float k = dot(dyy, n);
+        float det = max(e * g - f * f, 1e-12);
+
+        world_position = position;
+        normal = n;
+        gaussian_curvature = (l * k - m * m) / det;
+        mean_curvature = (e * k - 2.0 * f * m + g * l) / (2.0 * det);
+        trim_uv = evaluate_uv(gl_TessCoord.x, gl_TessCoord.y);
+
+        gl_Position = perspective * view * vec4(position, 1.0);
+    }
+"#;
+
+const SHADED_FRAGMENT_SHADER: &str = r#"
+    #version 410 core
+
+    in vec3 world_position;
+    in vec3 normal;
+    in float gaussian_curvature;
+    in float mean_curvature;
+    in vec2 trim_uv;
+
+    out vec4 color;
+
+    uniform mat4 view;
+    uniform int display_mode;
+    uniform float zebra_stripes;
+    uniform bool trimmed;
+    uniform sampler2D tex;
+
+    vec3 curvature_color(float value) {
+        float t = value / (1.0 + abs(value));
+        vec3 flat_color = vec3(0.2, 0.9, 0.3);
+        if (t < 0.0) {
+            return mix(flat_color, vec3(0.1, 0.3, 1.0), -t);
+        }
+        return mix(flat_color, vec3(1.0, 0.2, 0.1), t);
+    }
+
+    void main() {
+        if (trimmed && texture(tex, trim_uv).x != 1.0) {
+            discard;
+        }
+
+        if (display_mode == 0) {
+            color = vec4(curvature_color(gaussian_curvature), 1.0);
+        } else if (display_mode == 1) {
+            color = vec4(curvature_color(mean_curvature), 1.0);
+        } else {
+            vec3 eye = inverse(view)[3].xyz;
+            vec3 r = reflect(normalize(world_position - eye), normalize(normal));
+            float stripe = step(0.5, fract(0.5 * zebra_stripes * (r.y + 1.0)));
+            color = vec4(vec3(mix(0.05, 1.0, stripe)), 1.0);
+        }
+    }
+"#;
+
+pub const PHONG_FRAGMENT_SHADER: &str = r#"
+    #version 410 core
+
+    in vec3 world_position;
+    in vec3 normal;
+    in vec2 trim_uv;
+
+    out vec4 color;
+
+    uniform mat4 view;
+    uniform vec4 obj_color;
+    uniform bool trimmed;
+    uniform sampler2D tex;
+
+    const float ambient = 0.15;
+    const float diffuse = 0.75;
+    const float specular = 0.35;
+    const float shininess = 32.0;
+
+    void main() {
+        if (trimmed && texture(tex, trim_uv).x != 1.0) {
+            discard;
+        }
+
+        vec3 eye = inverse(view)[3].xyz;
+        vec3 to_eye = normalize(eye - world_position);
+        vec3 n = length(normal) > 1e-6 ? normalize(normal) : to_eye;
+        if (dot(n, to_eye) < 0.0) {
+            n = -n;
+        }
+
+        float lambert = max(dot(n, to_eye), 0.0);
+        float highlight = pow(max(dot(reflect(-to_eye, n), to_eye), 0.0), shininess);
+
+        color = vec4(obj_color.rgb * (ambient + diffuse * lambert) + vec3(specular * highlight), obj_color.a);
+    }
+"#;
+
+const NORMALS_GEOMETRY_SHADER: &str = r#"
+    #version 410 core
+
+    layout(points) in;
+    layout(line_strip, max_vertices = 2) out;
+
+    in vec3 world_position[];
+    in vec3 normal[];
+    in vec2 trim_uv[];
+
+    uniform mat4 perspective;
+    uniform mat4 view;
+    uniform float normal_length;
+    uniform bool trimmed;
+    uniform sampler2D tex;
+
+    void main() {
+        if (trimmed && texture(tex, trim_uv[0]).x != 1.0) {
+            return;
+        }
+
+        gl_Position = perspective * view * vec4(world_position[0], 1.0);
+        EmitVertex();
+        gl_Position = perspective * view * vec4(world_position[0] + normal_length * normal[0], 1.0);
+        EmitVertex();
+        EndPrimitive();
+    }
+"#;
+
+const NORMALS_FRAGMENT_SHADER: &str = r#"
+    #version 410 core
+
+    out vec4 color;
+
+    uniform vec4 obj_color;
+
+    void main() {
+        color = obj_color;
+    }
+"#;
+
+pub struct SurfaceDisplayDrawer {
+    shaded_program: Program,
+    normals_program: Program,
+    phong_program: Program,
+}
+
+impl SurfaceDisplayDrawer {
+    pub fn new(
+        display: &Display<WindowSurface>,
+        vertex_shader: &str,
+        tessellation_control_shader: &str,
+        surface_evaluation: &str,
+    ) -> Self {
+        let shaded_evaluation_shader = format!(
+            "#version 410 core\nlayout(quads, equal_spacing, ccw) in;\n{}{}",
+            surface_evaluation, TESSELLATION_EVALUATION_MAIN
+        );
+        let normals_evaluation_shader = format!(
+            "#version 410 core\nlayout(quads, equal_spacing, point_mode) in;\n{}{}",
+            surface_evaluation, TESSELLATION_EVALUATION_MAIN
+        );
+
+        let shaded_program = Program::new(
+            display,
+            SourceCode {
+                vertex_shader,
+                fragment_shader: SHADED_FRAGMENT_SHADER,
+                tessellation_control_shader: Some(tessellation_control_shader),
+                tessellation_evaluation_shader: Some(&shaded_evaluation_shader),
+                geometry_shader: None,
+            },
+        )
+        .unwrap();
+
+        let normals_program = Program::new(
+            display,
+            SourceCode {
+                vertex_shader,
+                fragment_shader: NORMALS_FRAGMENT_SHADER,
+                tessellation_control_shader: Some(tessellation_control_shader),
+                tessellation_evaluation_shader: Some(&normals_evaluation_shader),
+                geometry_shader: Some(NORMALS_GEOMETRY_SHADER),
+            },
+        )
+        .unwrap();
+
+        let phong_program = Program::new(
+            display,
+            SourceCode {
+                vertex_shader,
+                fragment_shader: PHONG_FRAGMENT_SHADER,
+                tessellation_control_shader: Some(tessellation_control_shader),
+                tessellation_evaluation_shader: Some(&shaded_evaluation_shader),
+                geometry_shader: None,
+            },
+        )
+        .unwrap();
+
+        Self {
+            shaded_program,
+            normals_program,
+            phong_program,
+        }
+    }
+
+    pub fn draws_wireframe(display_mode: SurfaceDisplayModeDTO) -> bool {
+        matches!(
+            display_mode,
+            SurfaceDisplayModeDTO::Wireframe | SurfaceDisplayModeDTO::Normals
+        )
+    }
+
+    pub fn program(&self, display_mode: SurfaceDisplayModeDTO) -> Option<&Program> {
+        match display_mode {
+            SurfaceDisplayModeDTO::Wireframe => None,
+            SurfaceDisplayModeDTO::Normals => Some(&self.normals_program),
+            _ => Some(&self.shaded_program),
+        }
+    }
+
+    pub fn fill_program(&self, display_mode: SurfaceDisplayModeDTO) -> &Program {
+        match display_mode {
+            SurfaceDisplayModeDTO::Wireframe | SurfaceDisplayModeDTO::Normals => {
+                &self.phong_program
+            }
+            _ => &self.shaded_program,
+        }
+    }
+
+    pub fn normals_program(&self, display_mode: SurfaceDisplayModeDTO) -> Option<&Program> {
+        match display_mode {
+            SurfaceDisplayModeDTO::Normals => Some(&self.normals_program),
+            _ => None,
+        }
+    }
+
+    pub fn shaded_mode(display_mode: SurfaceDisplayModeDTO) -> i32 {
+        match display_mode {
+            SurfaceDisplayModeDTO::GaussianCurvature => 0,
+            SurfaceDisplayModeDTO::MeanCurvature => 1,
+            _ => 2,
+        }
+    }
+}
@@ -2,9 +2,11 @@ use glium::glutin::surface::WindowSurface;
 use glium::{Display, DrawParameters, Frame, Program, Surface};
 
 use crate::drawing::domain::torus::Torus;
+use crate::drawing::drawers::surface_display_drawer::PHONG_FRAGMENT_SHADER;
 
 pub struct TorusDrawer {
     program: Program,
+    shaded_program: Program,
 }
 
 impl TorusDrawer {
@@ -51,7 +53,42 @@ impl TorusDrawer {
         let program =
             Program::from_source(display, vertex_shader_src, fragment_shader_src, None).unwrap();
 
-        Self { program }
+        let shaded_vertex_shader_src = r#"
+            #version 410 core
+
+            in vec3 position;
+            in vec2 uv;
+
+            out vec3 world_position;
+            out vec3 normal;
+            out vec2 trim_uv;
+
+            uniform mat4 perspective;
+            uniform mat4 model_matrix;
+            uniform mat4 view;
+            uniform float major_radius;
+
+            void main() {
+                vec3 center = major_radius * normalize(vec3(position.x, 0.0, position.z));
+                world_position = (model_matrix * vec4(position, 1.0)).xyz;
+                normal = mat3(transpose(inverse(model_matrix))) * (position - center);
+                trim_uv = uv;
+                gl_Position = perspective * view * vec4(world_position, 1.0);
+            }
+        "#;
+
+        let shaded_program = Program::from_source(
+            display,
+            shaded_vertex_shader_src,
+            PHONG_FRAGMENT_SHADER,
+            None,
+        )
+        .unwrap();
+
+        Self {
+            program,
+            shaded_program,
+        }
     }
 
     pub fn draw(
@@ -81,4 +118,34 @@ impl TorusDrawer {
             )
             .unwrap();
     }
+
+    pub fn draw_shaded(
+        &self,
+        target: &mut Frame,
+        torus: &Torus,
+        perspective: &math::matrix4::Matrix4,
+        view_matrix: &math::matrix4::Matrix4,
+        color: [f32; 4],
+        drawing_parameters: &DrawParameters,
+    ) {
+        target
+            .draw(
+                &torus.vertex_buffer,
+                &torus.triangle_index_buffer,
+                &self.shaded_program,
+                &uniform! {
+                    perspective: perspective.data,
+                    model_matrix: torus.model_matrix.data,
+                    view: view_matrix.data,
+                    major_radius: torus.major_radius,
+                    obj_color: color,
+                    trimmed: true,
+                    tex: torus.texture.sampled()
+                        .minify_filter(glium::uniforms::MinifySamplerFilter::Nearest)
+                        .magnify_filter(glium::uniforms::MagnifySamplerFilter::Nearest),
+                },
+                drawing_parameters,
+            )
+            .unwrap();
+    }
 }
@@ -3,11 +3,14 @@ use std::collections::HashMap;
 use crate::drawing::domain::bezier_c0::BezierC0;
 use crate::drawing::domain::bezier_c2::BezierC2;
 use crate::drawing::domain::bezier_int::BezierInt;
+use crate::drawing::domain::nurbs_curve::NurbsCurve;
+use crate::drawing::domain::nurbs_surface::NurbsSurface;
 use crate::drawing::domain::surface_c0::SurfaceC0;
 use crate::drawing::domain::surface_c2::SurfaceC2;
 
 use super::domain::gregory::Gregory;
 use super::domain::intersection::Intersection;
+use super::domain::toolpath::Toolpath;
 use super::domain::torus::Torus;
 
 pub struct DrawingStorage {
@@ -15,10 +18,13 @@ pub struct DrawingStorage {
     pub beziers_c0: HashMap<u64, BezierC0>,
     pub beziers_c2: HashMap<u64, BezierC2>,
     pub beziers_int: HashMap<u64, BezierInt>,
+    pub nurbs_curves: HashMap<u64, NurbsCurve>,
     pub surfaces_c0: HashMap<u64, SurfaceC0>,
     pub surfaces_c2: HashMap<u64, SurfaceC2>,
+    pub nurbs_surfaces: HashMap<u64, NurbsSurface>,
     pub gregories: HashMap<u64, Gregory>,
     pub intersections: HashMap<u64, Intersection>,
+    pub toolpaths: HashMap<u64, Toolpath>,
 }
 
 impl DrawingStorage {
@@ -28,10 +34,13 @@ impl DrawingStorage {
             beziers_c0: HashMap::new(),
             beziers_c2: HashMap::new(),
             beziers_int: HashMap::new(),
+            nurbs_curves: HashMap::new(),
             surfaces_c0: HashMap::new(),
             surfaces_c2: HashMap::new(),
+            nurbs_surfaces: HashMap::new(),
             gregories: HashMap::new(),
             intersections: HashMap::new(),
+            toolpaths: HashMap::new(),
         }
     }
 }
@@ -6,4 +6,5 @@ pub mod set_draw_b_spline_polygon_on_bezier_c2_draw_b_spline_polygon_set;
 pub mod set_draw_bernstein_points_on_bezier_c2_draw_bernstein_points_set;
 pub mod set_draw_bernstein_polygon_on_bezier_c2_draw_bernstein_polygon_set;
 pub mod set_selected_bernstein_point_on_bezier_c2_selected_bernstein_point_set;
+pub mod update_bezier_c2_points_on_bezier_c2_closed_set;
 pub mod update_bezier_c2_points_on_bezier_c2_point_moved;
@@ -0,0 +1,45 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use glium::glutin::surface::WindowSurface;
+use glium::Display;
+
+use backend::cqrs::beziers_c2::bezier_c2_b_spline_points::BezierC2BSplinePoints;
+use backend::cqrs::beziers_c2::bezier_c2_bernstein_points::BezierC2BernsteinPoints;
+use backend::cqrs::cqrs::CQRS;
+use backend_events::beziers_c2::bezier_c2_closed_set::BezierC2ClosedSet;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::drawing::drawing_storage::DrawingStorage;
+
+pub struct UpdateBezierC2PointsOnBezierC2ClosedSet {
+    pub drawing_storage: Rc<RefCell<DrawingStorage>>,
+    pub cqrs: CQRS,
+    pub display: Rc<Display<WindowSurface>>,
+}
+
+impl Consumer<BezierC2ClosedSet> for UpdateBezierC2PointsOnBezierC2ClosedSet {
+    fn consume(&self, event: &BezierC2ClosedSet) {
+        let mut drawing_storage = self.drawing_storage.borrow_mut();
+        let bernstein_points = self.cqrs.get(&BezierC2BernsteinPoints {
+            id: event.bezier_id,
+        });
+        let b_spline_points = self.cqrs.get(&BezierC2BSplinePoints {
+            id: event.bezier_id,
+        });
+        let bezier_c2 = drawing_storage
+            .beziers_c2
+            .get_mut(&event.bezier_id)
+            .unwrap();
+        bezier_c2.selected_bernstein_point = None;
+        bezier_c2.update_points(&bernstein_points, &b_spline_points, &self.display);
+        bezier_c2.set_closed(event.closed, &self.display);
+    }
+}
+
+impl AnyConsumer for UpdateBezierC2PointsOnBezierC2ClosedSet {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -4,6 +4,7 @@ use std::rc::Rc;
 
 use backend::cqrs::gregories::all_gregories::AllGregories;
 use backend::cqrs::intersections::all_intersections::AllIntersections;
+use backend::cqrs::toolpaths::all_toolpaths::AllToolpaths;
 use backend::cqrs::toruses::all_toruses::AllToruses;
 use backend_events::points::selected_points_merged::SelectedPointsMerged;
 use glium::glutin::surface::WindowSurface;
@@ -17,6 +18,11 @@ use backend::cqrs::beziers_c2::bezier_c2_bernstein_points::BezierC2BernsteinPoin
 use backend::cqrs::beziers_int::all_beziers_int::AllBeziersInt;
 use backend::cqrs::beziers_int::bezier_int_bernstein_points::BezierIntBernsteinPoints;
 use backend::cqrs::cqrs::CQRS;
+use backend::cqrs::nurbs_curves::all_nurbs_curves::AllNurbsCurves;
+use backend::cqrs::nurbs_curves::nurbs_curve_bernstein_points::NurbsCurveBernsteinPoints;
+use backend::cqrs::nurbs_curves::nurbs_curve_control_points::NurbsCurveControlPoints;
+use backend::cqrs::nurbs_surfaces::all_nurbs_surfaces::AllNurbsSurfaces;
+use backend::cqrs::nurbs_surfaces::nurbs_surface_points::NurbsSurfacePoints;
 use backend::cqrs::surfaces_c0::all_surfaces_c0::AllSurfacesC0;
 use backend::cqrs::surfaces_c0::surface_c0_points::SurfaceC0Points;
 use backend::cqrs::surfaces_c2::all_surfaces_c2::AllSurfacesC2;
@@ -28,8 +34,11 @@ use crate::drawing::domain::bezier_c2::BezierC2;
 use crate::drawing::domain::bezier_int::BezierInt;
 use crate::drawing::domain::gregory::Gregory;
 use crate::drawing::domain::intersection::Intersection;
+use crate::drawing::domain::nurbs_curve::NurbsCurve;
+use crate::drawing::domain::nurbs_surface::NurbsSurface;
 use crate::drawing::domain::surface_c0::SurfaceC0;
 use crate::drawing::domain::surface_c2::SurfaceC2;
+use crate::drawing::domain::toolpath::Toolpath;
 use crate::drawing::domain::torus::Torus;
 use crate::drawing::drawing_storage::DrawingStorage;
 
@@ -46,8 +55,10 @@ impl Consumer<SelectedPointsMerged> for RebuildStorageOnSelectedPointsMerged {
         drawing_storage.beziers_c0.clear();
         drawing_storage.beziers_c2.clear();
         drawing_storage.beziers_int.clear();
+        drawing_storage.nurbs_curves.clear();
         drawing_storage.surfaces_c0.clear();
         drawing_storage.surfaces_c2.clear();
+        drawing_storage.nurbs_surfaces.clear();
 
         for torus in self.cqrs.get(&AllToruses {}) {
             drawing_storage.toruses.insert(
@@ -77,15 +88,18 @@ impl Consumer<SelectedPointsMerged> for RebuildStorageOnSelectedPointsMerged {
         for bezier_c2 in self.cqrs.get(&AllBeziersC2 {}) {
             let bernstein_points = self.cqrs.get(&BezierC2BernsteinPoints { id: bezier_c2.id });
             let b_spline_points = self.cqrs.get(&BezierC2BSplinePoints { id: bezier_c2.id });
-            drawing_storage.beziers_c2.insert(
+            let mut drawing_bezier_c2 = BezierC2::new(
                 bezier_c2.id,
-                BezierC2::new(
-                    bezier_c2.id,
-                    &bernstein_points,
-                    &b_spline_points,
-                    &self.display,
-                ),
+                &bernstein_points,
+                &b_spline_points,
+                &self.display,
             );
+            if bezier_c2.closed {
+                drawing_bezier_c2.set_closed(true, &self.display);
+            }
+            drawing_storage
+                .beziers_c2
+                .insert(bezier_c2.id, drawing_bezier_c2);
         }
 
         for bezier_int in self.cqrs.get(&AllBeziersInt {}) {
@@ -98,6 +112,25 @@ impl Consumer<SelectedPointsMerged> for RebuildStorageOnSelectedPointsMerged {
             );
         }
 
+        for nurbs_curve in self.cqrs.get(&AllNurbsCurves {}) {
+            let bernstein_points = self
+                .cqrs
+                .get(&NurbsCurveBernsteinPoints { id: nurbs_curve.id });
+            let control_points = self
+                .cqrs
+                .get(&NurbsCurveControlPoints { id: nurbs_curve.id });
+            let mut drawing_nurbs_curve = NurbsCurve::new(
+                nurbs_curve.id,
+                &bernstein_points,
+                &control_points,
+                &self.display,
+            );
+            drawing_nurbs_curve.draw_polygon = nurbs_curve.draw_polygon;
+            drawing_storage
+                .nurbs_curves
+                .insert(nurbs_curve.id, drawing_nurbs_curve);
+        }
+
         for surface_c0 in self.cqrs.get(&AllSurfacesC0 {}) {
             let points = self.cqrs.get(&SurfaceC0Points { id: surface_c0.id });
             drawing_storage.surfaces_c0.insert(
@@ -126,6 +159,22 @@ impl Consumer<SelectedPointsMerged> for RebuildStorageOnSelectedPointsMerged {
             );
         }
 
+        for nurbs_surface in self.cqrs.get(&AllNurbsSurfaces {}) {
+            let points = self.cqrs.get(&NurbsSurfacePoints {
+                id: nurbs_surface.id,
+            });
+            drawing_storage.nurbs_surfaces.insert(
+                nurbs_surface.id,
+                NurbsSurface::new(
+                    nurbs_surface.id,
+                    &points,
+                    nurbs_surface.size,
+                    &self.display,
+                    nurbs_surface.is_cylinder,
+                ),
+            );
+        }
+
         for gregory in self.cqrs.get(&AllGregories {}) {
             drawing_storage.gregories.insert(
                 gregory.id,
@@ -151,6 +200,18 @@ impl Consumer<SelectedPointsMerged> for RebuildStorageOnSelectedPointsMerged {
                 ),
             );
         }
+
+        for toolpath in self.cqrs.get(&AllToolpaths {}) {
+            drawing_storage.toolpaths.insert(
+                toolpath.id,
+                Toolpath::new(
+                    toolpath.id,
+                    &toolpath.points,
+                    toolpath.visible,
+                    &self.display,
+                ),
+            );
+        }
     }
 }
 
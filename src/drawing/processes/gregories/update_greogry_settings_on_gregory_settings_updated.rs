@@ -17,7 +17,11 @@ impl Consumer<GregorySettingsUpdated> for UpdateGreogrySettingsOnGregorySettings
             .iter_mut()
             .filter(|g| g.0 == &message.gregory_id)
             .for_each(|g| {
-                g.1.update_settings(message.tess_level, message.draw_vectors)
+                g.1.update_settings(
+                    message.tess_level,
+                    message.draw_vectors,
+                    message.display_mode,
+                )
             });
     }
 }
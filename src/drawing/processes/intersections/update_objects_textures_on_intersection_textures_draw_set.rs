@@ -9,6 +9,7 @@ use infrastructure::{
 };
 
 use crate::drawing::processes::{
+    nurbs_surfaces::update_nurbs_surface_texture::UpdateNurbsSurfaceTexture,
     surfaces_c0::update_surface_c0_texture::UpdateSurfaceC0Texture,
     surfaces_c2::update_surface_c2_texture::UpdateSurfaceC2Texture,
     toruses::update_torus_texture::UpdateTorusTexture,
@@ -33,6 +34,10 @@ impl Consumer<IntersectionTexturesDrawSet> for UpdateObjectsTexturesOnIntersecti
                 .bus
                 .borrow()
                 .publish(Rc::new(UpdateSurfaceC2Texture { id })),
+            IntersectionObjectIdDTO::NurbsSurface(id) => self
+                .bus
+                .borrow()
+                .publish(Rc::new(UpdateNurbsSurfaceTexture { id })),
         }
         match event.id2 {
             IntersectionObjectIdDTO::Torus(id) => self
@@ -47,6 +52,10 @@ impl Consumer<IntersectionTexturesDrawSet> for UpdateObjectsTexturesOnIntersecti
                 .bus
                 .borrow()
                 .publish(Rc::new(UpdateSurfaceC2Texture { id })),
+            IntersectionObjectIdDTO::NurbsSurface(id) => self
+                .bus
+                .borrow()
+                .publish(Rc::new(UpdateNurbsSurfaceTexture { id })),
         }
     }
 }
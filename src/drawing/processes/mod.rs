@@ -4,6 +4,9 @@ pub mod beziers_int;
 pub mod common;
 pub mod gregories;
 pub mod intersections;
+pub mod nurbs_curves;
+pub mod nurbs_surfaces;
 pub mod surfaces_c0;
 pub mod surfaces_c2;
+pub mod toolpaths;
 pub mod toruses;
@@ -0,0 +1,25 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend_events::nurbs_curves::nurbs_curve_deleted::NurbsCurveDeleted;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::drawing::drawing_storage::DrawingStorage;
+
+pub struct DeleteNurbsCurveOnNurbsCurveDeleted {
+    pub drawing_storage: Rc<RefCell<DrawingStorage>>,
+}
+
+impl Consumer<NurbsCurveDeleted> for DeleteNurbsCurveOnNurbsCurveDeleted {
+    fn consume(&self, event: &NurbsCurveDeleted) {
+        let mut drawing_storage = self.drawing_storage.borrow_mut();
+        drawing_storage.nurbs_curves.remove(&event.id);
+    }
+}
+
+impl AnyConsumer for DeleteNurbsCurveOnNurbsCurveDeleted {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
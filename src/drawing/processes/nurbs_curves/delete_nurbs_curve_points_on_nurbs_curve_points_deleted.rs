@@ -0,0 +1,36 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use glium::glutin::surface::WindowSurface;
+use glium::Display;
+
+use backend::cqrs::cqrs::CQRS;
+use backend::cqrs::nurbs_curves::nurbs_curve_bernstein_points::NurbsCurveBernsteinPoints;
+use backend::cqrs::nurbs_curves::nurbs_curve_control_points::NurbsCurveControlPoints;
+use backend_events::nurbs_curves::nurbs_curve_points_deleted::NurbsCurvePointsDeleted;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::drawing::drawing_storage::DrawingStorage;
+
+pub struct DeleteNurbsCurvePointsOnNurbsCurvePointsDeleted {
+    pub drawing_storage: Rc<RefCell<DrawingStorage>>,
+    pub cqrs: CQRS,
+    pub display: Rc<Display<WindowSurface>>,
+}
+
+impl Consumer<NurbsCurvePointsDeleted> for DeleteNurbsCurvePointsOnNurbsCurvePointsDeleted {
+    fn consume(&self, event: &NurbsCurvePointsDeleted) {
+        let mut drawing_storage = self.drawing_storage.borrow_mut();
+        let bernstein_points = self.cqrs.get(&NurbsCurveBernsteinPoints { id: event.id });
+        let control_points = self.cqrs.get(&NurbsCurveControlPoints { id: event.id });
+        let curve = drawing_storage.nurbs_curves.get_mut(&event.id).unwrap();
+        curve.update_points(&bernstein_points, &control_points, &self.display);
+    }
+}
+
+impl AnyConsumer for DeleteNurbsCurvePointsOnNurbsCurvePointsDeleted {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
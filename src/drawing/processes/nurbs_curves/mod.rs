@@ -0,0 +1,7 @@
+pub mod add_nurbs_curve_on_nurbs_curve_created;
+pub mod add_point_to_nurbs_curve_on_point_added_to_nurbs_curve;
+pub mod delete_nurbs_curve_on_nurbs_curve_deleted;
+pub mod delete_nurbs_curve_points_on_nurbs_curve_points_deleted;
+pub mod set_draw_polygon_on_nurbs_curve_draw_polygon_set;
+pub mod update_nurbs_curve_points_on_nurbs_curve_point_moved;
+pub mod update_nurbs_curve_points_on_nurbs_curve_weight_set;
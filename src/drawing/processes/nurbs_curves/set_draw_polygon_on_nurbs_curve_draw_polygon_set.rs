@@ -0,0 +1,29 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend_events::nurbs_curves::nurbs_curve_draw_polygon_set::NurbsCurveDrawPolygonSet;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::drawing::drawing_storage::DrawingStorage;
+
+pub struct SetDrawPolygonOnNurbsCurveDrawPolygonSet {
+    pub drawing_storage: Rc<RefCell<DrawingStorage>>,
+}
+
+impl Consumer<NurbsCurveDrawPolygonSet> for SetDrawPolygonOnNurbsCurveDrawPolygonSet {
+    fn consume(&self, event: &NurbsCurveDrawPolygonSet) {
+        let mut drawing_storage = self.drawing_storage.borrow_mut();
+        let curve = drawing_storage
+            .nurbs_curves
+            .get_mut(&event.curve_id)
+            .unwrap();
+        curve.draw_polygon = event.draw_polygon;
+    }
+}
+
+impl AnyConsumer for SetDrawPolygonOnNurbsCurveDrawPolygonSet {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -0,0 +1,43 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use glium::glutin::surface::WindowSurface;
+use glium::Display;
+
+use backend::cqrs::cqrs::CQRS;
+use backend::cqrs::nurbs_surfaces::nurbs_surface_points::NurbsSurfacePoints;
+use backend_events::nurbs_surfaces::nurbs_surface_created::NurbsSurfaceCreated;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::drawing::domain::nurbs_surface::NurbsSurface;
+use crate::drawing::drawing_storage::DrawingStorage;
+
+pub struct AddNurbsSurfaceOnNurbsSurfaceCreated {
+    pub drawing_storage: Rc<RefCell<DrawingStorage>>,
+    pub cqrs: CQRS,
+    pub display: Rc<Display<WindowSurface>>,
+}
+
+impl Consumer<NurbsSurfaceCreated> for AddNurbsSurfaceOnNurbsSurfaceCreated {
+    fn consume(&self, event: &NurbsSurfaceCreated) {
+        let mut drawing_storage = self.drawing_storage.borrow_mut();
+        let points = self.cqrs.get(&NurbsSurfacePoints { id: event.id });
+        drawing_storage.nurbs_surfaces.insert(
+            event.id,
+            NurbsSurface::new(
+                event.id,
+                &points,
+                event.size,
+                &self.display,
+                event.is_cylinder,
+            ),
+        );
+    }
+}
+
+impl AnyConsumer for AddNurbsSurfaceOnNurbsSurfaceCreated {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
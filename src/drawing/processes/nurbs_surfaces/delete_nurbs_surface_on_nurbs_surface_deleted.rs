@@ -0,0 +1,25 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend_events::nurbs_surfaces::nurbs_surface_deleted::NurbsSurfaceDeleted;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::drawing::drawing_storage::DrawingStorage;
+
+pub struct DeleteNurbsSurfaceOnNurbsSurfaceDeleted {
+    pub drawing_storage: Rc<RefCell<DrawingStorage>>,
+}
+
+impl Consumer<NurbsSurfaceDeleted> for DeleteNurbsSurfaceOnNurbsSurfaceDeleted {
+    fn consume(&self, event: &NurbsSurfaceDeleted) {
+        let mut drawing_storage = self.drawing_storage.borrow_mut();
+        drawing_storage.nurbs_surfaces.remove(&event.id);
+    }
+}
+
+impl AnyConsumer for DeleteNurbsSurfaceOnNurbsSurfaceDeleted {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod add_nurbs_surface_on_nurbs_surface_created;
+pub mod delete_nurbs_surface_on_nurbs_surface_deleted;
+pub mod update_nurbs_surface_on_nurbs_surface_updated;
+pub mod update_nurbs_surface_points_on_nurbs_surface_point_moved;
+pub mod update_nurbs_surface_points_on_nurbs_surface_weight_set;
+pub mod update_nurbs_surface_texture;
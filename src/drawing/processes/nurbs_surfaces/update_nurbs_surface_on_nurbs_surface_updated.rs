@@ -0,0 +1,27 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend_events::nurbs_surfaces::nurbs_surface_updated::NurbsSurfaceUpdated;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::drawing::drawing_storage::DrawingStorage;
+
+pub struct UpdateNurbsSurfaceOnNurbsSurfaceUpdated {
+    pub drawing_storage: Rc<RefCell<DrawingStorage>>,
+}
+
+impl Consumer<NurbsSurfaceUpdated> for UpdateNurbsSurfaceOnNurbsSurfaceUpdated {
+    fn consume(&self, event: &NurbsSurfaceUpdated) {
+        let mut drawing_storage = self.drawing_storage.borrow_mut();
+        let nurbs_surface = drawing_storage.nurbs_surfaces.get_mut(&event.id).unwrap();
+        nurbs_surface.set_draw_polygon(event.draw_polygon);
+        nurbs_surface.set_tess_level(event.tess_level);
+    }
+}
+
+impl AnyConsumer for UpdateNurbsSurfaceOnNurbsSurfaceUpdated {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -0,0 +1,34 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use glium::glutin::surface::WindowSurface;
+use glium::Display;
+
+use backend::cqrs::cqrs::CQRS;
+use backend::cqrs::nurbs_surfaces::nurbs_surface_points::NurbsSurfacePoints;
+use backend_events::nurbs_surfaces::nurbs_surface_point_moved::NurbsSurfacePointMoved;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::drawing::drawing_storage::DrawingStorage;
+
+pub struct UpdateNurbsSurfacePointsOnNurbsSurfacePointMoved {
+    pub drawing_storage: Rc<RefCell<DrawingStorage>>,
+    pub cqrs: CQRS,
+    pub display: Rc<Display<WindowSurface>>,
+}
+
+impl Consumer<NurbsSurfacePointMoved> for UpdateNurbsSurfacePointsOnNurbsSurfacePointMoved {
+    fn consume(&self, event: &NurbsSurfacePointMoved) {
+        let mut drawing_storage = self.drawing_storage.borrow_mut();
+        let surface = drawing_storage.nurbs_surfaces.get_mut(&event.id).unwrap();
+        let points = self.cqrs.get(&NurbsSurfacePoints { id: event.id });
+        surface.update_points(&points, &self.display);
+    }
+}
+
+impl AnyConsumer for UpdateNurbsSurfacePointsOnNurbsSurfacePointMoved {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -0,0 +1,39 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use glium::glutin::surface::WindowSurface;
+use glium::Display;
+
+use backend::cqrs::cqrs::CQRS;
+use backend::cqrs::nurbs_surfaces::nurbs_surface_points::NurbsSurfacePoints;
+use backend_events::nurbs_surfaces::nurbs_surface_weight_set::NurbsSurfaceWeightSet;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::drawing::drawing_storage::DrawingStorage;
+
+pub struct UpdateNurbsSurfacePointsOnNurbsSurfaceWeightSet {
+    pub drawing_storage: Rc<RefCell<DrawingStorage>>,
+    pub cqrs: CQRS,
+    pub display: Rc<Display<WindowSurface>>,
+}
+
+impl Consumer<NurbsSurfaceWeightSet> for UpdateNurbsSurfacePointsOnNurbsSurfaceWeightSet {
+    fn consume(&self, event: &NurbsSurfaceWeightSet) {
+        let mut drawing_storage = self.drawing_storage.borrow_mut();
+        let surface = drawing_storage
+            .nurbs_surfaces
+            .get_mut(&event.surface_id)
+            .unwrap();
+        let points = self.cqrs.get(&NurbsSurfacePoints {
+            id: event.surface_id,
+        });
+        surface.update_points(&points, &self.display);
+    }
+}
+
+impl AnyConsumer for UpdateNurbsSurfacePointsOnNurbsSurfaceWeightSet {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -1,5 +1,6 @@
 pub mod add_surface_c0_on_surface_c0_created;
 pub mod delete_surface_c0_on_surface_c0_deleted;
+pub mod rebuild_surface_c0_on_surface_c0_resized;
 pub mod update_surface_c0_on_surface_c0_updated;
 pub mod update_surface_c0_points_on_surface_c0_point_moved;
 pub mod update_surface_c0_texture;
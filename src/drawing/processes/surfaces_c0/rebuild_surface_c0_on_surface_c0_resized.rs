@@ -0,0 +1,48 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use glium::glutin::surface::WindowSurface;
+use glium::Display;
+
+use backend::cqrs::cqrs::CQRS;
+use backend::cqrs::surfaces_c0::surface_c0_details::SurfaceC0Details;
+use backend::cqrs::surfaces_c0::surface_c0_points::SurfaceC0Points;
+use backend_events::surfaces_c0::surface_c0_resized::SurfaceC0Resized;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::drawing::domain::surface_c0::SurfaceC0;
+use crate::drawing::drawing_storage::DrawingStorage;
+
+pub struct RebuildSurfaceC0OnSurfaceC0Resized {
+    pub drawing_storage: Rc<RefCell<DrawingStorage>>,
+    pub cqrs: CQRS,
+    pub display: Rc<Display<WindowSurface>>,
+}
+
+impl Consumer<SurfaceC0Resized> for RebuildSurfaceC0OnSurfaceC0Resized {
+    fn consume(&self, event: &SurfaceC0Resized) {
+        let mut drawing_storage = self.drawing_storage.borrow_mut();
+        let surface = self.cqrs.get(&SurfaceC0Details { id: event.id });
+        let points = self.cqrs.get(&SurfaceC0Points { id: event.id });
+        let mut drawing_surface = SurfaceC0::new(
+            event.id,
+            &points,
+            event.size,
+            &self.display,
+            surface.is_cylinder,
+        );
+        drawing_surface.set_draw_polygon(surface.draw_polygon);
+        drawing_surface.set_tess_level(surface.tess_level);
+        drawing_surface.set_display_mode(surface.display_mode);
+        drawing_storage
+            .surfaces_c0
+            .insert(event.id, drawing_surface);
+    }
+}
+
+impl AnyConsumer for RebuildSurfaceC0OnSurfaceC0Resized {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
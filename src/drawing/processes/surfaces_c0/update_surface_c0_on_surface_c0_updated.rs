@@ -17,6 +17,7 @@ impl Consumer<SurfaceC0Updated> for UpdateSurfaceC0OnSurfaceC0Updated {
         let surface_c0 = drawing_storage.surfaces_c0.get_mut(&event.id).unwrap();
         surface_c0.set_draw_polygon(event.draw_polygon);
         surface_c0.set_tess_level(event.tess_level);
+        surface_c0.set_display_mode(event.display_mode);
     }
 }
 
@@ -1,5 +1,6 @@
 pub mod add_surface_c2_on_surface_c2_created;
 pub mod delete_surface_c2_on_surface_c2_deleted;
+pub mod rebuild_surface_c2_on_surface_c2_resized;
 pub mod update_surface_c2_on_surface_c2_updated;
 pub mod update_surface_c2_points_on_surface_c2_point_moved;
 pub mod update_surface_c2_texture;
@@ -0,0 +1,48 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use glium::glutin::surface::WindowSurface;
+use glium::Display;
+
+use backend::cqrs::cqrs::CQRS;
+use backend::cqrs::surfaces_c2::surface_c2_details::SurfaceC2Details;
+use backend::cqrs::surfaces_c2::surface_c2_points::SurfaceC2Points;
+use backend_events::surfaces_c2::surface_c2_resized::SurfaceC2Resized;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::drawing::domain::surface_c2::SurfaceC2;
+use crate::drawing::drawing_storage::DrawingStorage;
+
+pub struct RebuildSurfaceC2OnSurfaceC2Resized {
+    pub drawing_storage: Rc<RefCell<DrawingStorage>>,
+    pub cqrs: CQRS,
+    pub display: Rc<Display<WindowSurface>>,
+}
+
+impl Consumer<SurfaceC2Resized> for RebuildSurfaceC2OnSurfaceC2Resized {
+    fn consume(&self, event: &SurfaceC2Resized) {
+        let mut drawing_storage = self.drawing_storage.borrow_mut();
+        let surface = self.cqrs.get(&SurfaceC2Details { id: event.id });
+        let points = self.cqrs.get(&SurfaceC2Points { id: event.id });
+        let mut drawing_surface = SurfaceC2::new(
+            event.id,
+            &points,
+            event.size,
+            &self.display,
+            surface.is_cylinder,
+        );
+        drawing_surface.set_draw_polygon(surface.draw_polygon);
+        drawing_surface.set_tess_level(surface.tess_level);
+        drawing_surface.set_display_mode(surface.display_mode);
+        drawing_storage
+            .surfaces_c2
+            .insert(event.id, drawing_surface);
+    }
+}
+
+impl AnyConsumer for RebuildSurfaceC2OnSurfaceC2Resized {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
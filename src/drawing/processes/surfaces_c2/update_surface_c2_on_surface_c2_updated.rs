@@ -17,6 +17,7 @@ impl Consumer<SurfaceC2Updated> for UpdateSurfaceC2OnSurfaceC2Updated {
         let surface_c2 = drawing_storage.surfaces_c2.get_mut(&event.id).unwrap();
         surface_c2.set_draw_polygon(event.draw_polygon);
         surface_c2.set_tess_level(event.tess_level);
+        surface_c2.set_display_mode(event.display_mode);
     }
 }
 
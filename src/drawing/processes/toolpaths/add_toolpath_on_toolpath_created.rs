@@ -0,0 +1,38 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend_events::toolpaths::toolpath_created::ToolpathCreated;
+use glium::glutin::surface::WindowSurface;
+use glium::Display;
+
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::drawing::domain::toolpath::Toolpath;
+use crate::drawing::drawing_storage::DrawingStorage;
+
+pub struct AddToolpathOnToolpathCreated {
+    pub drawing_storage: Rc<RefCell<DrawingStorage>>,
+    pub display: Rc<Display<WindowSurface>>,
+}
+
+impl Consumer<ToolpathCreated> for AddToolpathOnToolpathCreated {
+    fn consume(&self, event: &ToolpathCreated) {
+        let mut drawing_storage = self.drawing_storage.borrow_mut();
+        drawing_storage.toolpaths.insert(
+            event.toolpath_id,
+            Toolpath::new(
+                event.toolpath_id,
+                &event.points,
+                event.visible,
+                &self.display,
+            ),
+        );
+    }
+}
+
+impl AnyConsumer for AddToolpathOnToolpathCreated {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
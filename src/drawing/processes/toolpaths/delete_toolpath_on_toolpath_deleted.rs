@@ -0,0 +1,26 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend_events::toolpaths::toolpath_deleted::ToolpathDeleted;
+
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::drawing::drawing_storage::DrawingStorage;
+
+pub struct DeleteToolpathOnToolpathDeleted {
+    pub drawing_storage: Rc<RefCell<DrawingStorage>>,
+}
+
+impl Consumer<ToolpathDeleted> for DeleteToolpathOnToolpathDeleted {
+    fn consume(&self, event: &ToolpathDeleted) {
+        let mut drawing_storage = self.drawing_storage.borrow_mut();
+        drawing_storage.toolpaths.remove(&event.toolpath_id);
+    }
+}
+
+impl AnyConsumer for DeleteToolpathOnToolpathDeleted {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
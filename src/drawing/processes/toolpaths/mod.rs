@@ -0,0 +1,3 @@
+pub mod add_toolpath_on_toolpath_created;
+pub mod delete_toolpath_on_toolpath_deleted;
+pub mod update_toolpath_on_toolpath_updated;
@@ -0,0 +1,31 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend_events::toolpaths::toolpath_updated::ToolpathUpdated;
+use glium::glutin::surface::WindowSurface;
+use glium::Display;
+
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::drawing::drawing_storage::DrawingStorage;
+
+pub struct UpdateToolpathOnToolpathUpdated {
+    pub drawing_storage: Rc<RefCell<DrawingStorage>>,
+    pub display: Rc<Display<WindowSurface>>,
+}
+
+impl Consumer<ToolpathUpdated> for UpdateToolpathOnToolpathUpdated {
+    fn consume(&self, event: &ToolpathUpdated) {
+        let mut drawing_storage = self.drawing_storage.borrow_mut();
+        if let Some(toolpath) = drawing_storage.toolpaths.get_mut(&event.toolpath_id) {
+            toolpath.update(&event.points, event.visible, &self.display);
+        }
+    }
+}
+
+impl AnyConsumer for UpdateToolpathOnToolpathUpdated {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
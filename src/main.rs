@@ -14,11 +14,16 @@ use backend::processes::intersections::publishers::{
     IntersectionCreatedPublisher, IntersectionDeletedPublisher,
     IntersectionTexturesDrawSetPublisher,
 };
+use backend::processes::toolpaths::publishers::{
+    ToolpathCreatedPublisher, ToolpathDeletedPublisher, ToolpathRenamedPublisher,
+    ToolpathUpdatedPublisher,
+};
 use backend::processes::toruses::publishers::{
     TorusCreatedPublisher, TorusDeletedPublisher, TorusTransformedPublisher, TorusUpdatedPublisher,
 };
 use drawing::drawers::gregory_drawer::GregoryDrawer;
 use drawing::drawers::intersection_drawer::IntersectionDrawer;
+use drawing::drawers::toolpath_drawer::ToolpathDrawer;
 use drawing::processes::common::rebuild_storage_on_selected_points_merged::RebuildStorageOnSelectedPointsMerged;
 use drawing::processes::gregories::add_gregory_on_gregory_created::AddGregoryOnGregoryCreated;
 use drawing::processes::gregories::delete_gregory_on_gregory_deleted::DeleteGregoryOnGregoryDeleted;
@@ -27,8 +32,12 @@ use drawing::processes::gregories::update_greogry_settings_on_gregory_settings_u
 use drawing::processes::intersections::add_intersection_on_intersection_created::AddIntersectionOnIntersectionCreated;
 use drawing::processes::intersections::delete_intersection_on_intersection_deleted::DeleteIntersectionOnIntersectionDeleted;
 use drawing::processes::intersections::update_objects_textures_on_intersection_textures_draw_set::UpdateObjectsTexturesOnIntersectionTexturesDrawSet;
+use drawing::processes::nurbs_surfaces::update_nurbs_surface_texture::UpdateNurbsSurfaceTextureConsumer;
 use drawing::processes::surfaces_c0::update_surface_c0_texture::UpdateSurfaceC0TextureConsumer;
 use drawing::processes::surfaces_c2::update_surface_c2_texture::UpdateSurfaceC2TextureConsumer;
+use drawing::processes::toolpaths::add_toolpath_on_toolpath_created::AddToolpathOnToolpathCreated;
+use drawing::processes::toolpaths::delete_toolpath_on_toolpath_deleted::DeleteToolpathOnToolpathDeleted;
+use drawing::processes::toolpaths::update_toolpath_on_toolpath_updated::UpdateToolpathOnToolpathUpdated;
 use drawing::processes::toruses::add_torus_on_torus_created::AddTorusOnTorusCreated;
 use drawing::processes::toruses::delete_torus_on_torus_deleted::DeleteTorusOnTorusDeleted;
 use drawing::processes::toruses::transform_torus_on_torus_transformed::TransformTorusOnTorusTransformed;
@@ -37,6 +46,12 @@ use drawing::processes::toruses::update_torus_texture::UpdateTorusTextureConsume
 use egui::Color32;
 use glium::{Blend, BlendingFunction, LinearBlendingFactor, PolygonMode, Surface};
 use user_interface::processes::fetch_objects_on_selected_points_merged::FetchObjectsOnSelectedPointsMerged;
+use user_interface::processes::fetch_objects_on_surface_resized::{
+    FetchObjectsOnSurfaceC0Resized, FetchObjectsOnSurfaceC2Resized,
+};
+use user_interface::processes::report_surface_edit_refusal::{
+    ReportSurfaceC0EditRefusal, ReportSurfaceC2EditRefusal,
+};
 use user_interface::processes::sync_greogry_with_backend::{SyncGregoryCreation, SyncGregoryName};
 use user_interface::processes::sync_intersection_with_backend::{
     SyncIntersectionCreation, SyncIntersectionDeletion,
@@ -49,6 +64,7 @@ use backend::backend::Backend;
 use backend::cqrs::common::selected_objects_center::SelectedObjectsCenter;
 use backend::cqrs::cqrs::CQRS;
 use backend::cqrs::cursors::transform_cursor::TransformCursor;
+use backend::cqrs::curves::curve_curvature_comb::CurveCurvatureComb;
 use backend::cqrs::points::all_points::AllPoints;
 use backend::cqrs::points::point_details::LittleTransformerDTO;
 use backend::domain::point::Point;
@@ -63,10 +79,11 @@ use backend::processes::beziers_c0::publishers::{
 use backend::processes::beziers_c2::add_point_to_selected_beziers_c2_on_point_created::AddPointToSelectedBeziersC2OnPointCreated;
 use backend::processes::beziers_c2::move_bezier_c2_points_on_point_moved::MoveBezierC2PointsOnPointMoved;
 use backend::processes::beziers_c2::publishers::{
-    BezierC2CreatedPublisher, BezierC2DeletedPublisher, BezierC2DrawBSplinePolygonSetPublisher,
-    BezierC2DrawBernsteinPointsSetPublisher, BezierC2DrawBernsteinPolygonSetPublisher,
-    BezierC2PointMovedPublisher, BezierC2PointsDeletedPublisher,
-    BezierC2SelectedBernsteinPointSetPublisher, PointAddedToBezierC2Publisher,
+    BezierC2ClosedSetPublisher, BezierC2CreatedPublisher, BezierC2DeletedPublisher,
+    BezierC2DrawBSplinePolygonSetPublisher, BezierC2DrawBernsteinPointsSetPublisher,
+    BezierC2DrawBernsteinPolygonSetPublisher, BezierC2PointMovedPublisher,
+    BezierC2PointsDeletedPublisher, BezierC2SelectedBernsteinPointSetPublisher,
+    PointAddedToBezierC2Publisher,
 };
 use backend::processes::beziers_int::add_point_to_selected_bezier_int_on_point_created::AddPointToSelectedBezierIntOnPointCreated;
 use backend::processes::beziers_int::publishers::{
@@ -75,37 +92,63 @@ use backend::processes::beziers_int::publishers::{
 };
 use backend::processes::beziers_int::update_bezier_int_points_on_point_moved::UpdateBezierIntPointsOnPointMoved;
 use backend::processes::common::publishers::SceneLoadedPublisher;
+use backend::processes::nurbs_curves::add_point_to_selected_nurbs_curves_on_point_created::AddPointToSelectedNurbsCurvesOnPointCreated;
+use backend::processes::nurbs_curves::move_nurbs_curve_points_on_point_moved::MoveNurbsCurvePointsOnPointMoved;
+use backend::processes::nurbs_curves::publishers::{
+    NurbsCurveCreatedPublisher, NurbsCurveDeletedPublisher, NurbsCurveDrawPolygonSetPublisher,
+    NurbsCurvePointMovedPublisher, NurbsCurvePointsDeletedPublisher, NurbsCurveRenamedPublisher,
+    NurbsCurveWeightSetPublisher, PointAddedToNurbsCurvePublisher,
+};
+use backend::processes::nurbs_surfaces::move_nurbs_surface_point_on_point_moved::MoveNurbsSurfacePointOnPointMoved;
+use backend::processes::nurbs_surfaces::publishers::{
+    NurbsSurfaceCreatedPublisher, NurbsSurfaceDeletedPublisher,
+    NurbsSurfacePointsSelectedPublisher, NurbsSurfaceUpdatedPublisher,
+    NurbsSurfaceWeightSetPublisher,
+};
 use backend::processes::points::publishers::{PointCreatedPublisher, PointMovedPublisher};
 use backend::processes::surfaces_c0::move_surface_c0_point_on_point_moved::MoveSurfaceC0PointOnPointMoved;
 use backend::processes::surfaces_c0::publishers::{
-    SurfaceC0CreatedPublisher, SurfaceC0DeletedPublisher, SurfaceC0PointsSelectedPublisher,
-    SurfaceC0UpdatedPublisher,
+    SurfaceC0CreatedPublisher, SurfaceC0DeletedPublisher, SurfaceC0EditRefusedPublisher,
+    SurfaceC0PointsSelectedPublisher, SurfaceC0ResizedPublisher, SurfaceC0UpdatedPublisher,
 };
+use backend::processes::surfaces_c2::interpolate_loft_surface_c2_on_point_moved::InterpolateLoftSurfaceC2OnPointMoved;
+use backend::processes::surfaces_c2::interpolate_surface_c2_on_point_moved::InterpolateSurfaceC2OnPointMoved;
 use backend::processes::surfaces_c2::move_surface_c2_point_on_point_moved::MoveSurfaceC2PointOnPointMoved;
 use backend::processes::surfaces_c2::publishers::{
-    SurfaceC2CreatedPublisher, SurfaceC2DeletedPublisher, SurfaceC2PointsSelectedPublisher,
-    SurfaceC2UpdatedPublisher,
+    SurfaceC2CreatedPublisher, SurfaceC2DeletedPublisher, SurfaceC2EditRefusedPublisher,
+    SurfaceC2PointsSelectedPublisher, SurfaceC2ResizedPublisher, SurfaceC2UpdatedPublisher,
 };
 use infrastructure::event_bus::EventBus;
 use math::vector4::Vector4;
 use user_interface::processes::fetch_objects_on_scene_loaded::FetchObjectsOnSceneLoaded;
+use user_interface::processes::selected_nurbs_surface_points_on_nurbs_surface_points_selected::SelectedNurbsSurfacePointsOnNurbsSurfacePointsSelected;
 use user_interface::processes::selected_surface_c0_points_on_surface_c0_points_selected::SelectedSurfaceC0PointsOnSurfaceC0PointsSelected;
 use user_interface::processes::selected_surface_c2_points_on_surface_c2_points_selected::SelectedSurfaceC2PointsOnSurfaceC2PointsSelected;
 use user_interface::processes::sync_bezier_c0_with_backend::{
-    SyncBezierC0AddedPointsWithBackend, SyncBezierC0DeletedPointsWithBackend,
+    SyncBezierC0AddedPointsWithBackend, SyncBezierC0CreationWithBackend,
+    SyncBezierC0DeletedPointsWithBackend, SyncBezierC0DeletionWithBackend,
     SyncBezierC0NameWithBackend,
 };
 use user_interface::processes::sync_bezier_c2_with_backend::{
-    SyncBezierC2AddedPointsWithBackend, SyncBezierC2DeletedPointsWithBackend,
-    SyncBezierC2PointPositionsWithBackend,
+    SyncBezierC2AddedPointsWithBackend, SyncBezierC2ClosedWithBackend,
+    SyncBezierC2CreationWithBackend, SyncBezierC2DeletedPointsWithBackend,
+    SyncBezierC2DeletionWithBackend, SyncBezierC2PointPositionsWithBackend,
 };
 use user_interface::processes::sync_bezier_int_with_backend::{
     SyncBezierIntAddedPointWithBackend, SyncBezierIntCreationWithBackend,
-    SyncBezierIntPointsDeletedWithBackend,
+    SyncBezierIntDeletionWithBackend, SyncBezierIntPointsDeletedWithBackend,
+};
+use user_interface::processes::sync_nurbs_curve_with_backend::{
+    SyncNurbsCurveAddedPointsWithBackend, SyncNurbsCurveCreationWithBackend,
+    SyncNurbsCurveDeletedPointsWithBackend, SyncNurbsCurveDeletionWithBackend,
+    SyncNurbsCurveNameWithBackend,
 };
 use user_interface::processes::sync_point_with_backend::{
     SyncPointCreationWithBackend, SyncPointPositionWithBackend,
 };
+use user_interface::processes::sync_toolpath_with_backend::{
+    SyncToolpathCreation, SyncToolpathDeletion, SyncToolpathName, SyncToolpathUpdate,
+};
 use user_interface::ui::Ui;
 
 use crate::drawing::drawers::bezier_c0_drawer::BezierC0Drawer;
@@ -113,6 +156,10 @@ use crate::drawing::drawers::bezier_c2_drawer::BezierC2Drawer;
 use crate::drawing::drawers::bezier_int_drawer::BezierIntDrawer;
 use crate::drawing::drawers::cursor_drawer::CursorDrawer;
 use crate::drawing::drawers::infinite_grid_drawer::InfiniteGridDrawer;
+use crate::drawing::drawers::boundary_drawer::BoundaryDrawer;
+use crate::drawing::drawers::curvature_comb_drawer::CurvatureCombDrawer;
+use crate::drawing::drawers::nurbs_curve_drawer::NurbsCurveDrawer;
+use crate::drawing::drawers::nurbs_surface_drawer::NurbsSurfaceDrawer;
 use crate::drawing::drawers::point_drawer::PointDrawer;
 use crate::drawing::drawers::points_drawer::PointsDrawer;
 use crate::drawing::drawers::polygon_drawer::PolygonDrawer;
@@ -134,6 +181,7 @@ use crate::drawing::processes::beziers_c2::set_draw_b_spline_polygon_on_bezier_c
 use crate::drawing::processes::beziers_c2::set_draw_bernstein_points_on_bezier_c2_draw_bernstein_points_set::SetDrawBernsteinPointsOnBezierC2DrawBernsteinPointsSet;
 use crate::drawing::processes::beziers_c2::set_draw_bernstein_polygon_on_bezier_c2_draw_bernstein_polygon_set::SetDrawBernsteinPolygonOnBezierC2DrawBernsteinPolygonSet;
 use crate::drawing::processes::beziers_c2::set_selected_bernstein_point_on_bezier_c2_selected_bernstein_point_set::SetSelectedBernsteinPointOnBezierC2SelectedBernsteinPointSet;
+use crate::drawing::processes::beziers_c2::update_bezier_c2_points_on_bezier_c2_closed_set::UpdateBezierC2PointsOnBezierC2ClosedSet;
 use crate::drawing::processes::beziers_c2::update_bezier_c2_points_on_bezier_c2_point_moved::UpdateBezierC2PointsOnBezierC2PointMoved;
 use crate::drawing::processes::beziers_int::add_bezier_int_on_bezier_int_created::AddBezierIntOnBezierIntCreated;
 use crate::drawing::processes::beziers_int::add_point_to_bezier_int_on_point_added_to_bezier_int::AddPointToBezierIntOnPointAddedToBezierInt;
@@ -141,12 +189,26 @@ use crate::drawing::processes::beziers_int::delete_bezier_int_on_bezier_int_dele
 use crate::drawing::processes::beziers_int::delete_bezier_int_points_on_bezier_int_points_deleted::DeleteBezierIntPointsOnBezierIntPointsDeleted;
 use crate::drawing::processes::beziers_int::update_bezier_int_points_on_bezier_int_bernstein_point_moved::UpdateBezierIntPointsOnBezierIntBernsteinPointMoved;
 use crate::drawing::processes::common::rebuild_storage_on_scene_loaded::RebuildStorageOnSceneLoaded;
+use crate::drawing::processes::nurbs_curves::add_nurbs_curve_on_nurbs_curve_created::AddNurbsCurveOnNurbsCurveCreated;
+use crate::drawing::processes::nurbs_curves::add_point_to_nurbs_curve_on_point_added_to_nurbs_curve::AddPointToNurbsCurveOnPointAddedToNurbsCurve;
+use crate::drawing::processes::nurbs_curves::delete_nurbs_curve_on_nurbs_curve_deleted::DeleteNurbsCurveOnNurbsCurveDeleted;
+use crate::drawing::processes::nurbs_curves::delete_nurbs_curve_points_on_nurbs_curve_points_deleted::DeleteNurbsCurvePointsOnNurbsCurvePointsDeleted;
+use crate::drawing::processes::nurbs_curves::set_draw_polygon_on_nurbs_curve_draw_polygon_set::SetDrawPolygonOnNurbsCurveDrawPolygonSet;
+use crate::drawing::processes::nurbs_curves::update_nurbs_curve_points_on_nurbs_curve_point_moved::UpdateNurbsCurvePointsOnNurbsCurvePointMoved;
+use crate::drawing::processes::nurbs_curves::update_nurbs_curve_points_on_nurbs_curve_weight_set::UpdateNurbsCurvePointsOnNurbsCurveWeightSet;
+use crate::drawing::processes::nurbs_surfaces::add_nurbs_surface_on_nurbs_surface_created::AddNurbsSurfaceOnNurbsSurfaceCreated;
+use crate::drawing::processes::nurbs_surfaces::delete_nurbs_surface_on_nurbs_surface_deleted::DeleteNurbsSurfaceOnNurbsSurfaceDeleted;
+use crate::drawing::processes::nurbs_surfaces::update_nurbs_surface_on_nurbs_surface_updated::UpdateNurbsSurfaceOnNurbsSurfaceUpdated;
+use crate::drawing::processes::nurbs_surfaces::update_nurbs_surface_points_on_nurbs_surface_point_moved::UpdateNurbsSurfacePointsOnNurbsSurfacePointMoved;
+use crate::drawing::processes::nurbs_surfaces::update_nurbs_surface_points_on_nurbs_surface_weight_set::UpdateNurbsSurfacePointsOnNurbsSurfaceWeightSet;
 use crate::drawing::processes::surfaces_c0::add_surface_c0_on_surface_c0_created::AddSurfaceC0OnSurfaceC0Created;
 use crate::drawing::processes::surfaces_c0::delete_surface_c0_on_surface_c0_deleted::DeleteSurfaceC0OnSurfaceC0Deleted;
+use crate::drawing::processes::surfaces_c0::rebuild_surface_c0_on_surface_c0_resized::RebuildSurfaceC0OnSurfaceC0Resized;
 use crate::drawing::processes::surfaces_c0::update_surface_c0_on_surface_c0_updated::UpdateSurfaceC0OnSurfaceC0Updated;
 use crate::drawing::processes::surfaces_c0::update_surface_c0_points_on_surface_c0_point_moved::UpdateSurfaceC0PointsOnSurfaceC0PointMoved;
 use crate::drawing::processes::surfaces_c2::add_surface_c2_on_surface_c2_created::AddSurfaceC2OnSurfaceC2Created;
 use crate::drawing::processes::surfaces_c2::delete_surface_c2_on_surface_c2_deleted::DeleteSurfaceC2OnSurfaceC2Deleted;
+use crate::drawing::processes::surfaces_c2::rebuild_surface_c2_on_surface_c2_resized::RebuildSurfaceC2OnSurfaceC2Resized;
 use crate::drawing::processes::surfaces_c2::update_surface_c2_on_surface_c2_updated::UpdateSurfaceC2OnSurfaceC2Updated;
 use crate::drawing::processes::surfaces_c2::update_surface_c2_points_on_surface_c2_point_moved::UpdateSurfaceC2PointsOnSurfaceC2PointMoved;
 
@@ -243,6 +305,11 @@ fn main() {
         .add_consumer(BezierC2PointMovedPublisher {
             backend: app_state.clone(),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(BezierC2ClosedSetPublisher {
+            backend: app_state.clone(),
+        });
     event_bus.borrow_mut().add_consumer(PointMovedPublisher {
         backend: app_state.clone(),
     });
@@ -311,6 +378,56 @@ fn main() {
         .add_consumer(BezierIntDeletedPublisher {
             backend: app_state.clone(),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(NurbsCurveCreatedPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(PointAddedToNurbsCurvePublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(AddPointToSelectedNurbsCurvesOnPointCreated {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(NurbsCurvePointsDeletedPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(NurbsCurvePointMovedPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(MoveNurbsCurvePointsOnPointMoved {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(NurbsCurveWeightSetPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(NurbsCurveDrawPolygonSetPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(NurbsCurveRenamedPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(NurbsCurveDeletedPublisher {
+            backend: app_state.clone(),
+        });
     event_bus
         .borrow_mut()
         .add_consumer(SurfaceC0CreatedPublisher {
@@ -336,6 +453,16 @@ fn main() {
         .add_consumer(SurfaceC0DeletedPublisher {
             backend: app_state.clone(),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SurfaceC0ResizedPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SurfaceC0EditRefusedPublisher {
+            backend: app_state.clone(),
+        });
     event_bus
         .borrow_mut()
         .add_consumer(SurfaceC2CreatedPublisher {
@@ -346,6 +473,16 @@ fn main() {
         .add_consumer(MoveSurfaceC2PointOnPointMoved {
             backend: app_state.clone(),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(InterpolateSurfaceC2OnPointMoved {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(InterpolateLoftSurfaceC2OnPointMoved {
+            backend: app_state.clone(),
+        });
     event_bus
         .borrow_mut()
         .add_consumer(SurfaceC2PointsSelectedPublisher {
@@ -361,6 +498,46 @@ fn main() {
         .add_consumer(SurfaceC2DeletedPublisher {
             backend: app_state.clone(),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SurfaceC2ResizedPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SurfaceC2EditRefusedPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(NurbsSurfaceCreatedPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(MoveNurbsSurfacePointOnPointMoved {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(NurbsSurfacePointsSelectedPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(NurbsSurfaceUpdatedPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(NurbsSurfaceWeightSetPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(NurbsSurfaceDeletedPublisher {
+            backend: app_state.clone(),
+        });
     event_bus.borrow_mut().add_consumer(SceneLoadedPublisher {
         backend: app_state.clone(),
     });
@@ -426,6 +603,26 @@ fn main() {
     event_bus.borrow_mut().add_consumer(PointCreatedPublisher {
         backend: app_state.clone(),
     });
+    event_bus
+        .borrow_mut()
+        .add_consumer(ToolpathCreatedPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(ToolpathRenamedPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(ToolpathUpdatedPublisher {
+            backend: app_state.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(ToolpathDeletedPublisher {
+            backend: app_state.clone(),
+        });
 
     event_bus
         .borrow_mut()
@@ -454,6 +651,12 @@ fn main() {
             ui: ui.clone(),
             cqrs: CQRS::new(app_state.clone()),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncBezierC2ClosedWithBackend {
+            ui: ui.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+        });
     event_bus
         .borrow_mut()
         .add_consumer(SyncPointPositionWithBackend { ui: ui.clone() });
@@ -475,6 +678,18 @@ fn main() {
     event_bus
         .borrow_mut()
         .add_consumer(SyncIntersectionDeletion { ui: ui.clone() });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncToolpathCreation { ui: ui.clone() });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncToolpathName { ui: ui.clone() });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncToolpathUpdate { ui: ui.clone() });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncToolpathDeletion { ui: ui.clone() });
     event_bus
         .borrow_mut()
         .add_consumer(SelectedSurfaceC0PointsOnSurfaceC0PointsSelected {
@@ -487,6 +702,12 @@ fn main() {
             ui: ui.clone(),
             cqrs: CQRS::new(app_state.clone()),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SelectedNurbsSurfacePointsOnNurbsSurfacePointsSelected {
+            ui: ui.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+        });
     event_bus
         .borrow_mut()
         .add_consumer(FetchObjectsOnSceneLoaded {
@@ -499,6 +720,24 @@ fn main() {
             ui: ui.clone(),
             cqrs: CQRS::new(app_state.clone()),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(FetchObjectsOnSurfaceC0Resized {
+            ui: ui.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(FetchObjectsOnSurfaceC2Resized {
+            ui: ui.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(ReportSurfaceC0EditRefusal { ui: ui.clone() });
+    event_bus
+        .borrow_mut()
+        .add_consumer(ReportSurfaceC2EditRefusal { ui: ui.clone() });
     event_bus
         .borrow_mut()
         .add_consumer(SyncPointCreationWithBackend {
@@ -511,6 +750,45 @@ fn main() {
             ui: ui.clone(),
             cqrs: CQRS::new(app_state.clone()),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncBezierIntDeletionWithBackend { ui: ui.clone() });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncNurbsCurveNameWithBackend { ui: ui.clone() });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncNurbsCurveAddedPointsWithBackend { ui: ui.clone() });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncNurbsCurveDeletedPointsWithBackend { ui: ui.clone() });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncNurbsCurveCreationWithBackend {
+            ui: ui.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncNurbsCurveDeletionWithBackend { ui: ui.clone() });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncBezierC0CreationWithBackend {
+            ui: ui.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncBezierC0DeletionWithBackend { ui: ui.clone() });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncBezierC2CreationWithBackend {
+            ui: ui.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SyncBezierC2DeletionWithBackend { ui: ui.clone() });
 
     event_bus
         .borrow_mut()
@@ -593,6 +871,13 @@ fn main() {
             cqrs: CQRS::new(app_state.clone()),
             display: display.clone(),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(UpdateBezierC2PointsOnBezierC2ClosedSet {
+            drawing_storage: drawing_storage.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+            display: display.clone(),
+        });
     event_bus.borrow_mut().add_consumer(
         SetSelectedBernsteinPointOnBezierC2SelectedBernsteinPointSet {
             drawing_storage: drawing_storage.clone(),
@@ -636,6 +921,51 @@ fn main() {
         .add_consumer(DeleteBezierIntOnBezierIntDeleted {
             drawing_storage: drawing_storage.clone(),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(AddNurbsCurveOnNurbsCurveCreated {
+            drawing_storage: drawing_storage.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+            display: display.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(AddPointToNurbsCurveOnPointAddedToNurbsCurve {
+            drawing_storage: drawing_storage.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+            display: display.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(DeleteNurbsCurvePointsOnNurbsCurvePointsDeleted {
+            drawing_storage: drawing_storage.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+            display: display.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(UpdateNurbsCurvePointsOnNurbsCurvePointMoved {
+            drawing_storage: drawing_storage.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+            display: display.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(UpdateNurbsCurvePointsOnNurbsCurveWeightSet {
+            drawing_storage: drawing_storage.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+            display: display.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(SetDrawPolygonOnNurbsCurveDrawPolygonSet {
+            drawing_storage: drawing_storage.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(DeleteNurbsCurveOnNurbsCurveDeleted {
+            drawing_storage: drawing_storage.clone(),
+        });
     event_bus
         .borrow_mut()
         .add_consumer(AddSurfaceC0OnSurfaceC0Created {
@@ -660,6 +990,13 @@ fn main() {
         .add_consumer(DeleteSurfaceC0OnSurfaceC0Deleted {
             drawing_storage: drawing_storage.clone(),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(RebuildSurfaceC0OnSurfaceC0Resized {
+            drawing_storage: drawing_storage.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+            display: display.clone(),
+        });
     event_bus
         .borrow_mut()
         .add_consumer(AddSurfaceC2OnSurfaceC2Created {
@@ -684,6 +1021,44 @@ fn main() {
         .add_consumer(DeleteSurfaceC2OnSurfaceC2Deleted {
             drawing_storage: drawing_storage.clone(),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(RebuildSurfaceC2OnSurfaceC2Resized {
+            drawing_storage: drawing_storage.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+            display: display.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(AddNurbsSurfaceOnNurbsSurfaceCreated {
+            drawing_storage: drawing_storage.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+            display: display.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(UpdateNurbsSurfacePointsOnNurbsSurfacePointMoved {
+            drawing_storage: drawing_storage.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+            display: display.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(UpdateNurbsSurfacePointsOnNurbsSurfaceWeightSet {
+            drawing_storage: drawing_storage.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+            display: display.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(UpdateNurbsSurfaceOnNurbsSurfaceUpdated {
+            drawing_storage: drawing_storage.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(DeleteNurbsSurfaceOnNurbsSurfaceDeleted {
+            drawing_storage: drawing_storage.clone(),
+        });
     event_bus
         .borrow_mut()
         .add_consumer(RebuildStorageOnSceneLoaded {
@@ -747,6 +1122,13 @@ fn main() {
             cqrs: CQRS::new(app_state.clone()),
             display: display.clone(),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(UpdateNurbsSurfaceTextureConsumer {
+            drawing_storage: drawing_storage.clone(),
+            cqrs: CQRS::new(app_state.clone()),
+            display: display.clone(),
+        });
     event_bus.borrow_mut().add_consumer(AddTorusOnTorusCreated {
         drawing_storage: drawing_storage.clone(),
         display: display.clone(),
@@ -779,6 +1161,23 @@ fn main() {
         .add_consumer(DeleteIntersectionOnIntersectionDeleted {
             drawing_storage: drawing_storage.clone(),
         });
+    event_bus
+        .borrow_mut()
+        .add_consumer(AddToolpathOnToolpathCreated {
+            drawing_storage: drawing_storage.clone(),
+            display: display.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(UpdateToolpathOnToolpathUpdated {
+            drawing_storage: drawing_storage.clone(),
+            display: display.clone(),
+        });
+    event_bus
+        .borrow_mut()
+        .add_consumer(DeleteToolpathOnToolpathDeleted {
+            drawing_storage: drawing_storage.clone(),
+        });
 
     let torus_drawer = TorusDrawer::new(&display);
     let point_drawer = PointDrawer::new(&display);
@@ -787,12 +1186,17 @@ fn main() {
     let bezier_c0_drawer = BezierC0Drawer::new(&display);
     let bezier_c2_drawer = BezierC2Drawer::new(&display);
     let bezier_int_drawer = BezierIntDrawer::new(&display);
+    let nurbs_curve_drawer = NurbsCurveDrawer::new(&display);
+    let curvature_comb_drawer = CurvatureCombDrawer::new(&display);
+    let boundary_drawer = BoundaryDrawer::new(&display);
     let polygon_drawer = PolygonDrawer::new(&display);
     let points_drawer = PointsDrawer::new(&display);
     let surface_c0_drawer = SurfaceC0Drawer::new(&display);
     let surface_c2_drawer = SurfaceC2Drawer::new(&display);
+    let nurbs_surface_drawer = NurbsSurfaceDrawer::new(&display);
     let gregory_drawer = GregoryDrawer::new(&display);
     let intersection_drawer = IntersectionDrawer::new(&display);
+    let toolpath_drawer = ToolpathDrawer::new(&display);
 
     let mut mouse_position = (0.0, 0.0);
     let mut camera_direction = math::vector3::Vector3::new(0.0f32, 0.0, 1.0);
@@ -810,8 +1214,11 @@ fn main() {
     let selected_color = Color32::YELLOW.to_normalized_gamma_f32();
     let bernstein_color = Color32::DARK_RED.to_normalized_gamma_f32();
     let selected_bernstein_color = Color32::LIGHT_GREEN.to_normalized_gamma_f32();
+    let curvature_comb_color = Color32::LIGHT_RED.to_normalized_gamma_f32();
+    let offending_boundary_color = Color32::RED.to_normalized_gamma_f32();
     let right_eye_color = [1.0, 0.0, 0.0, 1.0];
     let left_eye_color = [0.0, 1.0, 1.0, 1.0];
+    let wireframe_overlay_color = Color32::DARK_GRAY.to_normalized_gamma_f32();
 
     let draw_params = glium::DrawParameters {
         depth: glium::Depth {
@@ -842,6 +1249,19 @@ fn main() {
 
         draw_params
     };
+    let draw_params_shaded = {
+        let mut draw_params = draw_params.clone();
+
+        draw_params.polygon_mode = PolygonMode::Fill;
+        draw_params.polygon_offset = glium::draw_parameters::PolygonOffset {
+            factor: 1.0,
+            units: 1.0,
+            fill: true,
+            ..Default::default()
+        };
+
+        draw_params
+    };
 
     event_loop.run(move |event, _window_target, control_flow| {
         let mut redraw = || {
@@ -872,6 +1292,22 @@ fn main() {
 
                 let app_state = app_state.borrow();
 
+                let curvature_combs = if ui.borrow().curvature_comb {
+                    let drawing_storage = drawing_storage.borrow();
+                    drawing_storage.beziers_c0.keys().chain(drawing_storage.beziers_c2.keys()).chain(drawing_storage.beziers_int.keys())
+                        .map(|id| cqrs.get(&CurveCurvatureComb { id: *id, samples_per_segment: ui.borrow().curvature_comb_samples, scale: ui.borrow().curvature_comb_scale as f64 }))
+                        .collect::<Vec<_>>()
+                } else {
+                    vec![]
+                };
+
+                let offending_boundaries = if ui.borrow().continuity_highlight {
+                    let ui = ui.borrow();
+                    ui.continuity_report.iter().filter(|b| ui.is_offending_boundary(b)).cloned().collect::<Vec<_>>()
+                } else {
+                    vec![]
+                };
+
                 if ui.borrow().stereoscopy {
                     let eye_distance = ui.borrow().stereoscopy_eye_distance;
                     let fov = ui.borrow().stereoscopy_fov;
@@ -905,6 +1341,22 @@ fn main() {
                         bezier_int_drawer.draw(&mut target, &bezier, &perspective, &view_matrix, right_eye_color, width, height, &draw_params_stereo);
                     }
 
+                    for curve in drawing_storage.borrow().nurbs_curves.values() {
+                        nurbs_curve_drawer.draw(&mut target, &curve, &perspective, &view_matrix, right_eye_color, width, height, &draw_params_stereo);
+                    }
+
+                    for curve in drawing_storage.borrow().nurbs_curves.values().filter(|c| c.draw_polygon && c.polygon_index_buffer.is_some()) {
+                        polygon_drawer.draw(&mut target, &curve.control_vertex_buffer.as_ref().unwrap(), &curve.polygon_index_buffer.as_ref().unwrap(), &perspective, &view_matrix, right_eye_color, &draw_params_stereo);
+                    }
+
+                    for comb in curvature_combs.iter() {
+                        curvature_comb_drawer.draw(&mut target, &display, comb, &perspective, &view_matrix, right_eye_color, &draw_params_stereo);
+                    }
+
+                    for boundary in offending_boundaries.iter() {
+                        boundary_drawer.draw(&mut target, &display, boundary, &perspective, &view_matrix, right_eye_color, &draw_params_stereo);
+                    }
+
                     for bezier in drawing_storage.borrow().beziers_c0.values().filter(|b| b.draw_polygon && b.polygon_index_buffer.is_some()) {
                         polygon_drawer.draw(&mut target, &bezier.vertex_buffer.as_ref().unwrap(), &bezier.polygon_index_buffer.as_ref().unwrap(), &perspective, &view_matrix, right_eye_color, &draw_params_stereo);
                     }
@@ -937,6 +1389,14 @@ fn main() {
                         polygon_drawer.draw(&mut target, &surface.vertex_buffer, &surface.polygon_index_buffer, &perspective, &view_matrix, right_eye_color, &draw_params_stereo);
                     }
 
+                    for surface in drawing_storage.borrow().nurbs_surfaces.values() {
+                        nurbs_surface_drawer.draw(&mut target, &surface, &perspective, &view_matrix, right_eye_color, surface.tess_level, &draw_params_stereo);
+                    }
+
+                    for surface in drawing_storage.borrow().nurbs_surfaces.values().filter(|s| s.draw_polygon) {
+                        polygon_drawer.draw(&mut target, &surface.vertex_buffer, &surface.polygon_index_buffer, &perspective, &view_matrix, right_eye_color, &draw_params_stereo);
+                    }
+
                     cursor_drawer.draw(&mut target, &display, &app_state.storage.cursor, &perspective, &view_matrix, right_eye_color, &draw_params_stereo);
                     infinite_grid_drawer.draw(&mut target, &perspective.data, &view_matrix.data, right_eye_color, &draw_params_stereo);
 
@@ -972,6 +1432,22 @@ fn main() {
                         bezier_int_drawer.draw(&mut target, &bezier, &perspective, &view_matrix, left_eye_color, width, height, &draw_params_stereo);
                     }
 
+                    for curve in drawing_storage.borrow().nurbs_curves.values() {
+                        nurbs_curve_drawer.draw(&mut target, &curve, &perspective, &view_matrix, left_eye_color, width, height, &draw_params_stereo);
+                    }
+
+                    for curve in drawing_storage.borrow().nurbs_curves.values().filter(|c| c.draw_polygon && c.polygon_index_buffer.is_some()) {
+                        polygon_drawer.draw(&mut target, &curve.control_vertex_buffer.as_ref().unwrap(), &curve.polygon_index_buffer.as_ref().unwrap(), &perspective, &view_matrix, left_eye_color, &draw_params_stereo);
+                    }
+
+                    for comb in curvature_combs.iter() {
+                        curvature_comb_drawer.draw(&mut target, &display, comb, &perspective, &view_matrix, left_eye_color, &draw_params_stereo);
+                    }
+
+                    for boundary in offending_boundaries.iter() {
+                        boundary_drawer.draw(&mut target, &display, boundary, &perspective, &view_matrix, left_eye_color, &draw_params_stereo);
+                    }
+
                     for bezier in drawing_storage.borrow().beziers_c0.values().filter(|b| b.draw_polygon && b.polygon_index_buffer.is_some()) {
                         polygon_drawer.draw(&mut target, &bezier.vertex_buffer.as_ref().unwrap(), &bezier.polygon_index_buffer.as_ref().unwrap(), &perspective, &view_matrix, left_eye_color, &draw_params_stereo);
                     }
@@ -1004,15 +1480,32 @@ fn main() {
                         polygon_drawer.draw(&mut target, &surface.vertex_buffer, &surface.polygon_index_buffer, &perspective, &view_matrix, left_eye_color, &draw_params_stereo);
                     }
 
+                    for surface in drawing_storage.borrow().nurbs_surfaces.values() {
+                        nurbs_surface_drawer.draw(&mut target, &surface, &perspective, &view_matrix, left_eye_color, surface.tess_level, &draw_params_stereo);
+                    }
+
+                    for surface in drawing_storage.borrow().nurbs_surfaces.values().filter(|s| s.draw_polygon) {
+                        polygon_drawer.draw(&mut target, &surface.vertex_buffer, &surface.polygon_index_buffer, &perspective, &view_matrix, left_eye_color, &draw_params_stereo);
+                    }
+
                     cursor_drawer.draw(&mut target, &display, &app_state.storage.cursor, &perspective, &view_matrix, left_eye_color, &draw_params_stereo);
 
                     infinite_grid_drawer.draw(&mut target, &perspective.data, &view_matrix.data, left_eye_color, &draw_params_stereo);
                 } else {
                     let perspective = math::matrix4::Matrix4::perspective(std::f32::consts::PI / 3.0, width as f32 / height as f32, 0.1, 1024.0);
+                    let shaded = ui.borrow().shaded;
+                    let wireframe_overlay = ui.borrow().wireframe_overlay;
 
                     for torus in drawing_storage.borrow().toruses.values() {
                         let color = if app_state.storage.selected_objects.iter().any(|so| so.torus_id == Some(torus.id)) { selected_color } else { color };
-                        torus_drawer.draw(&mut target, &torus, &perspective, &view_matrix, color, &draw_params);
+                        if shaded {
+                            torus_drawer.draw_shaded(&mut target, &torus, &perspective, &view_matrix, color, &draw_params_shaded);
+                            if wireframe_overlay {
+                                torus_drawer.draw(&mut target, &torus, &perspective, &view_matrix, wireframe_overlay_color, &draw_params);
+                            }
+                        } else {
+                            torus_drawer.draw(&mut target, &torus, &perspective, &view_matrix, color, &draw_params);
+                        }
                     }
 
                     for point in app_state.storage.points.iter() {
@@ -1022,7 +1515,14 @@ fn main() {
 
                     for gregory in drawing_storage.borrow().gregories.values() {
                         let color = if app_state.storage.selected_objects.iter().any(|so| so.gregory_id == Some(gregory.id)) { selected_color } else { color };
-                        gregory_drawer.draw_surface(&mut target, gregory, &perspective, &view_matrix, color, &draw_params);
+                        if shaded {
+                            gregory_drawer.draw_shaded(&mut target, gregory, &perspective, &view_matrix, color, &draw_params_shaded);
+                            if wireframe_overlay {
+                                gregory_drawer.draw_wireframe(&mut target, gregory, &perspective, &view_matrix, wireframe_overlay_color, &draw_params);
+                            }
+                        } else {
+                            gregory_drawer.draw_surface(&mut target, gregory, &perspective, &view_matrix, color, &draw_params);
+                        }
                     }
 
                     for gregory in drawing_storage.borrow().gregories.values().filter(|g| g.draw_vectors) {
@@ -1034,6 +1534,11 @@ fn main() {
                         intersection_drawer.draw(&mut target, &intersection, &perspective, &view_matrix, color, &draw_params);
                     }
 
+                    for toolpath in drawing_storage.borrow().toolpaths.values().filter(|t| t.visible) {
+                        let color = if app_state.storage.selected_objects.iter().any(|so| so.toolpath_id == Some(toolpath.id)) { selected_color } else { Color32::LIGHT_BLUE.to_normalized_gamma_f32() };
+                        toolpath_drawer.draw(&mut target, &toolpath, &perspective, &view_matrix, color, &draw_params);
+                    }
+
                     let center_point = cqrs.get(&SelectedObjectsCenter);
                     if let Some(center_point) = center_point {
                         let mut transformer = LittleTransformer::new();
@@ -1056,6 +1561,23 @@ fn main() {
                         bezier_int_drawer.draw(&mut target, &bezier, &perspective, &view_matrix, color, width, height, &draw_params);
                     }
 
+                    for curve in drawing_storage.borrow().nurbs_curves.values() {
+                        let color = if app_state.storage.selected_objects.iter().any(|so| so.nurbs_curve_id == Some(curve.id)) { selected_color } else { color };
+                        nurbs_curve_drawer.draw(&mut target, &curve, &perspective, &view_matrix, color, width, height, &draw_params);
+                    }
+
+                    for curve in drawing_storage.borrow().nurbs_curves.values().filter(|c| c.draw_polygon && c.polygon_index_buffer.is_some()) {
+                        polygon_drawer.draw(&mut target, &curve.control_vertex_buffer.as_ref().unwrap(), &curve.polygon_index_buffer.as_ref().unwrap(), &perspective, &view_matrix, color, &draw_params);
+                    }
+
+                    for comb in curvature_combs.iter() {
+                        curvature_comb_drawer.draw(&mut target, &display, comb, &perspective, &view_matrix, curvature_comb_color, &draw_params);
+                    }
+
+                    for boundary in offending_boundaries.iter() {
+                        boundary_drawer.draw(&mut target, &display, boundary, &perspective, &view_matrix, offending_boundary_color, &draw_params);
+                    }
+
                     for bezier in drawing_storage.borrow().beziers_c0.values().filter(|b| b.draw_polygon && b.polygon_index_buffer.is_some()) {
                         polygon_drawer.draw(&mut target, &bezier.vertex_buffer.as_ref().unwrap(), &bezier.polygon_index_buffer.as_ref().unwrap(), &perspective, &view_matrix, color, &draw_params);
                     }
@@ -1074,7 +1596,14 @@ fn main() {
 
                     for surface in drawing_storage.borrow().surfaces_c0.values() {
                         let color = if app_state.storage.selected_objects.iter().any(|so| so.surface_c0_id == Some(surface.id)) { selected_color } else { color };
-                        surface_c0_drawer.draw(&mut target, &surface, &perspective, &view_matrix, color, surface.tess_level, &draw_params);
+                        if shaded {
+                            surface_c0_drawer.draw_shaded(&mut target, &surface, &perspective, &view_matrix, color, &draw_params_shaded);
+                            if wireframe_overlay {
+                                surface_c0_drawer.draw_wireframe(&mut target, &surface, &perspective, &view_matrix, wireframe_overlay_color, &draw_params);
+                            }
+                        } else {
+                            surface_c0_drawer.draw(&mut target, &surface, &perspective, &view_matrix, color, surface.tess_level, &draw_params);
+                        }
                     }
 
                     for surface in drawing_storage.borrow().surfaces_c0.values().filter(|s| s.draw_polygon) {
@@ -1083,13 +1612,36 @@ fn main() {
 
                     for surface in drawing_storage.borrow().surfaces_c2.values() {
                         let color = if app_state.storage.selected_objects.iter().any(|so| so.surface_c2_id == Some(surface.id)) { selected_color } else { color };
-                        surface_c2_drawer.draw(&mut target, &surface, &perspective, &view_matrix, color, surface.tess_level, &draw_params);
+                        if shaded {
+                            surface_c2_drawer.draw_shaded(&mut target, &surface, &perspective, &view_matrix, color, &draw_params_shaded);
+                            if wireframe_overlay {
+                                surface_c2_drawer.draw_wireframe(&mut target, &surface, &perspective, &view_matrix, wireframe_overlay_color, &draw_params);
+                            }
+                        } else {
+                            surface_c2_drawer.draw(&mut target, &surface, &perspective, &view_matrix, color, surface.tess_level, &draw_params);
+                        }
                     }
 
                     for surface in drawing_storage.borrow().surfaces_c2.values().filter(|s| s.draw_polygon) {
                         polygon_drawer.draw(&mut target, &surface.vertex_buffer, &surface.polygon_index_buffer, &perspective, &view_matrix, color, &draw_params);
                     }
 
+                    for surface in drawing_storage.borrow().nurbs_surfaces.values() {
+                        let color = if app_state.storage.selected_objects.iter().any(|so| so.nurbs_surface_id == Some(surface.id)) { selected_color } else { color };
+                        if shaded {
+                            nurbs_surface_drawer.draw_shaded(&mut target, &surface, &perspective, &view_matrix, color, &draw_params_shaded);
+                            if wireframe_overlay {
+                                nurbs_surface_drawer.draw(&mut target, &surface, &perspective, &view_matrix, wireframe_overlay_color, surface.tess_level, &draw_params);
+                            }
+                        } else {
+                            nurbs_surface_drawer.draw(&mut target, &surface, &perspective, &view_matrix, color, surface.tess_level, &draw_params);
+                        }
+                    }
+
+                    for surface in drawing_storage.borrow().nurbs_surfaces.values().filter(|s| s.draw_polygon) {
+                        polygon_drawer.draw(&mut target, &surface.vertex_buffer, &surface.polygon_index_buffer, &perspective, &view_matrix, color, &draw_params);
+                    }
+
                     cursor_drawer.draw(&mut target, &display, &app_state.storage.cursor, &perspective, &view_matrix, [0.0, 1.0, 0.0, 1.0], &draw_params);
 
                     infinite_grid_drawer.draw(&mut target, &perspective.data, &view_matrix.data, color, &draw_params);
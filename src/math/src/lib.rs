@@ -1,3 +1,4 @@
+pub mod linear_systems;
 pub mod matrix4;
 pub mod operations;
 pub mod operators;
@@ -0,0 +1,234 @@
+use std::ops::{Div, Mul, Sub};
+
+use nalgebra::{DMatrix, Vector3};
+
+pub fn solve_tridiagonal(
+    lower: &[f64],
+    diagonal: &[f64],
+    upper: &[f64],
+    free_terms: &[Vector3<f64>],
+) -> Vec<Vector3<f64>> {
+    let n = diagonal.len();
+    if n == 0 {
+        return vec![];
+    }
+
+    let mut diagonal = diagonal.to_vec();
+    let mut result = free_terms.to_vec();
+
+    for i in 1..n {
+        let multiplier = lower[i] / diagonal[i - 1];
+        diagonal[i] -= multiplier * upper[i - 1];
+        let term = result[i - 1];
+        result[i] -= term * multiplier;
+    }
+
+    result[n - 1] /= diagonal[n - 1];
+    for i in (0..n - 1).rev() {
+        let term = result[i + 1];
+        result[i] = (result[i] - term * upper[i]) / diagonal[i];
+    }
+
+    result
+}
+
+pub fn solve_cyclic_tridiagonal(
+    lower: &[f64],
+    diagonal: &[f64],
+    upper: &[f64],
+    free_terms: &[Vector3<f64>],
+) -> Vec<Vector3<f64>> {
+    let n = diagonal.len();
+    if n < 3 {
+        return solve_tridiagonal(lower, diagonal, upper, free_terms);
+    }
+
+    let corner_lower = lower[0];
+    let corner_upper = upper[n - 1];
+    let gamma = -diagonal[0];
+
+    let mut modified_diagonal = diagonal.to_vec();
+    modified_diagonal[0] -= gamma;
+    modified_diagonal[n - 1] -= corner_lower * corner_upper / gamma;
+
+    let x = solve_tridiagonal(lower, &modified_diagonal, upper, free_terms);
+
+    let mut u = vec![Vector3::zeros(); n];
+    u[0] = Vector3::new(gamma, gamma, gamma);
+    u[n - 1] = Vector3::new(corner_upper, corner_upper, corner_upper);
+    let z = solve_tridiagonal(lower, &modified_diagonal, upper, &u);
+
+    let denominator = 1.0 + z[0].x + corner_lower * z[n - 1].x / gamma;
+    let factor = (x[0] + x[n - 1] * (corner_lower / gamma)) / denominator;
+
+    x.iter()
+        .zip(z.iter())
+        .map(|(x, z)| x - factor * z.x)
+        .collect()
+}
+
+pub fn solve_banded_symmetric(
+    diagonals: &[Vec<f64>],
+    free_terms: &[Vector3<f64>],
+) -> Option<Vec<Vector3<f64>>> {
+    let factor = cholesky_banded(diagonals, free_terms.len())?;
+    Some(substitute_banded(&factor, free_terms))
+}
+
+pub fn solve_cyclic_banded_symmetric(
+    diagonals: &[Vec<f64>],
+    free_terms: &[Vector3<f64>],
+) -> Option<Vec<Vector3<f64>>> {
+    let n = free_terms.len();
+    let width = diagonals.len() - 1;
+    if n <= 2 * width {
+        return solve_dense_cyclic(diagonals, free_terms);
+    }
+
+    let m = n - width;
+    let mut band = vec![vec![0.0; m]; width + 1];
+    let mut border = vec![vec![0.0; m]; width];
+    let mut corner = DMatrix::<f64>::zeros(width, width);
+    for (d, diagonal) in diagonals.iter().enumerate() {
+        for (i, value) in diagonal.iter().enumerate() {
+            let j = i + d;
+            if j < m {
+                band[d][i] += value;
+            } else if i < m {
+                border[j - m][i] += value;
+            } else if j < n {
+                corner[(i - m, j - m)] += value;
+                if d > 0 {
+                    corner[(j - m, i - m)] += value;
+                }
+            } else {
+                border[i - m][j - n] += value;
+            }
+        }
+    }
+
+    let factor = cholesky_banded(&band, m)?;
+    let interior = substitute_banded(&factor, &free_terms[..m]);
+    let border_solutions = border
+        .iter()
+        .map(|column| substitute_banded(&factor, column))
+        .collect::<Vec<_>>();
+
+    let mut schur = corner;
+    let mut schur_terms = DMatrix::<f64>::zeros(width, 3);
+    for a in 0..width {
+        for b in 0..width {
+            schur[(a, b)] -= dot(&border[a], &border_solutions[b]);
+        }
+        let term = free_terms[m + a]
+            - interior
+                .iter()
+                .zip(border[a].iter())
+                .fold(Vector3::zeros(), |sum, (x, e)| sum + x * *e);
+        for k in 0..3 {
+            schur_terms[(a, k)] = term[k];
+        }
+    }
+    let border_result = schur.lu().solve(&schur_terms)?;
+
+    let mut result = interior;
+    for (i, x) in result.iter_mut().enumerate() {
+        for (a, solution) in border_solutions.iter().enumerate() {
+            *x -= Vector3::new(
+                border_result[(a, 0)],
+                border_result[(a, 1)],
+                border_result[(a, 2)],
+            ) * solution[i];
+        }
+    }
+    result.extend((0..width).map(|a| {
+        Vector3::new(
+            border_result[(a, 0)],
+            border_result[(a, 1)],
+            border_result[(a, 2)],
+        )
+    }));
+
+    Some(result)
+}
+
+fn cholesky_banded(diagonals: &[Vec<f64>], n: usize) -> Option<Vec<Vec<f64>>> {
+    let width = diagonals.len() - 1;
+    let mut factor = vec![vec![0.0; width + 1]; n];
+
+    for i in 0..n {
+        let first = i.saturating_sub(width);
+        for j in first..i {
+            let mut sum = diagonals[i - j][j];
+            for k in first..j {
+                sum -= factor[i][i - k] * factor[j][j - k];
+            }
+            factor[i][i - j] = sum / factor[j][0];
+        }
+
+        let mut sum = diagonals[0][i];
+        for k in first..i {
+            sum -= factor[i][i - k] * factor[i][i - k];
+        }
+        if sum <= 0.0 {
+            return None;
+        }
+        factor[i][0] = sum.sqrt();
+    }
+
+    Some(factor)
+}
+
+fn substitute_banded<T>(factor: &[Vec<f64>], free_terms: &[T]) -> Vec<T>
+where
+    T: Copy + Sub<Output = T> + Mul<f64, Output = T> + Div<f64, Output = T>,
+{
+    let n = free_terms.len();
+    let width = factor.first().map_or(0, |row| row.len() - 1);
+    let mut result = free_terms.to_vec();
+
+    for i in 0..n {
+        for k in i.saturating_sub(width)..i {
+            result[i] = result[i] - result[k] * factor[i][i - k];
+        }
+        result[i] = result[i] / factor[i][0];
+    }
+
+    for i in (0..n).rev() {
+        for k in i + 1..n.min(i + width + 1) {
+            result[i] = result[i] - result[k] * factor[k][k - i];
+        }
+        result[i] = result[i] / factor[i][0];
+    }
+
+    result
+}
+
+fn solve_dense_cyclic(
+    diagonals: &[Vec<f64>],
+    free_terms: &[Vector3<f64>],
+) -> Option<Vec<Vector3<f64>>> {
+    let n = free_terms.len();
+    let mut matrix = DMatrix::<f64>::zeros(n, n);
+    for (d, diagonal) in diagonals.iter().enumerate() {
+        for (i, value) in diagonal.iter().enumerate() {
+            let j = (i + d) % n;
+            matrix[(i, j)] += value;
+            if d > 0 {
+                matrix[(j, i)] += value;
+            }
+        }
+    }
+    let terms = DMatrix::from_fn(n, 3, |i, k| free_terms[i][k]);
+    let solution = matrix.lu().solve(&terms)?;
+
+    Some(
+        (0..n)
+            .map(|i| Vector3::new(solution[(i, 0)], solution[(i, 1)], solution[(i, 2)]))
+            .collect(),
+    )
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+}
@@ -6,6 +6,7 @@ pub struct BezierC0 {
     pub points: Vec<BezierC0Point>,
     pub selected_point: Option<(u64, String)>,
     pub draw_polygon: bool,
+    pub split_parameter: f64,
 }
 
 pub struct BezierC0Point {
@@ -21,6 +22,7 @@ impl BezierC0 {
             name: dto.name.clone(),
             selected_point: None,
             draw_polygon: false,
+            split_parameter: 0.5,
             points: dto
                 .points
                 .iter()
@@ -11,6 +11,8 @@ pub struct BezierC2 {
     pub draw_b_spline_polygon: bool,
     pub draw_bernstein_polygon: bool,
     pub draw_bernstein_points: bool,
+    pub closed: bool,
+    pub knot_parameter: f64,
 }
 
 pub struct BezierC2BSplinePoint {
@@ -37,6 +39,8 @@ impl BezierC2 {
             b_spline_points: Self::b_spline_points_from_dto(&dto.b_spline_points),
             bernstein_points: Self::bernstein_points_from_dto(&dto.bernstein_points),
             selected_bernstein_point: None,
+            closed: dto.closed,
+            knot_parameter: 0.5,
         }
     }
 
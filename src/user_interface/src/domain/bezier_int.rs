@@ -1,10 +1,16 @@
 use backend::cqrs::beziers_int::bezier_int_details::BezierIntDTO;
+use backend::cqrs::beziers_int::set_bezier_int_options::BezierIntParameterizationDTO;
 
 pub struct BezierInt {
     pub id: u64,
     pub name: String,
     pub points: Vec<BezierIntPoint>,
     pub selected_point: Option<(u64, String)>,
+    pub closed: bool,
+    pub clamped: bool,
+    pub start_tangent: (f64, f64, f64),
+    pub end_tangent: (f64, f64, f64),
+    pub parameterization: BezierIntParameterizationDTO,
 }
 
 pub struct BezierIntPoint {
@@ -28,6 +34,11 @@ impl BezierInt {
                 })
                 .collect(),
             selected_point: None,
+            closed: dto.closed,
+            clamped: dto.clamped,
+            start_tangent: dto.start_tangent,
+            end_tangent: dto.end_tangent,
+            parameterization: dto.parameterization,
         }
     }
 }
@@ -1,6 +1,14 @@
+use backend_events::common::surface_display_mode::SurfaceDisplayModeDTO;
+use math::vector3::Vector3;
+
 pub struct Gregory {
     pub id: u64,
     pub name: String,
     pub tess_level: u8,
+    pub display_mode: SurfaceDisplayModeDTO,
     pub draw_vectors: bool,
+    pub tangent_scale: f32,
+    pub center_offset: Vector3,
+    pub twist_blend: f32,
+    pub coons: bool,
 }
@@ -3,3 +3,5 @@ pub mod bezier_c2;
 pub mod bezier_int;
 pub mod gregory;
 pub mod intersection;
+pub mod nurbs_curve;
+pub mod toolpath;
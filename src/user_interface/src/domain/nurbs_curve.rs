@@ -0,0 +1,37 @@
+use backend::cqrs::nurbs_curves::nurbs_curve_details::NurbsCurveDTO;
+
+pub struct NurbsCurve {
+    pub id: u64,
+    pub name: String,
+    pub points: Vec<NurbsCurvePoint>,
+    pub selected_point: Option<(u64, String)>,
+    pub draw_polygon: bool,
+}
+
+pub struct NurbsCurvePoint {
+    pub id: u64,
+    pub name: String,
+    pub weight: f64,
+    pub is_selected: bool,
+}
+
+impl NurbsCurve {
+    pub fn from_dto(dto: &NurbsCurveDTO) -> Self {
+        NurbsCurve {
+            id: dto.id,
+            name: dto.name.clone(),
+            selected_point: None,
+            draw_polygon: dto.draw_polygon,
+            points: dto
+                .points
+                .iter()
+                .map(|p| NurbsCurvePoint {
+                    id: p.id,
+                    name: p.name.clone(),
+                    weight: p.weight,
+                    is_selected: false,
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use backend::cqrs::milling::simulate_milling::{MaterialBlockDTO, MillingSimulationDTO};
+use backend::cqrs::toolpaths::toolpath_details::ToolpathDTO;
+
+pub struct Toolpath {
+    pub id: u64,
+    pub name: String,
+    pub cutter_name: String,
+    pub points_count: usize,
+    pub visible: bool,
+    pub trim_range: (usize, usize),
+    pub block: MaterialBlockDTO,
+    pub simulation: Option<MillingSimulationDTO>,
+}
+
+impl Toolpath {
+    pub fn new(
+        id: u64,
+        name: String,
+        cutter_name: String,
+        points_count: usize,
+        visible: bool,
+    ) -> Self {
+        Toolpath {
+            id,
+            name,
+            cutter_name,
+            points_count,
+            visible,
+            trim_range: (0, points_count.saturating_sub(1)),
+            block: MaterialBlockDTO {
+                size: (15.0, 15.0),
+                height: 5.0,
+                min_height: 1.6,
+                resolution: (300, 300),
+            },
+            simulation: None,
+        }
+    }
+
+    pub fn from_dto(dto: &ToolpathDTO) -> Self {
+        Self::new(
+            dto.id,
+            dto.name.clone(),
+            dto.cutter_name.clone(),
+            dto.points.len(),
+            dto.visible,
+        )
+    }
+
+    pub fn update_points_count(&mut self, points_count: usize) {
+        self.points_count = points_count;
+        self.trim_range = (0, points_count.saturating_sub(1));
+        self.simulation = None;
+    }
+}
@@ -1,4 +1,5 @@
 use backend::cqrs::common::select_objects::ObjectTypeDTO;
+use backend::cqrs::nurbs_surfaces::nurbs_surface_details::NurbsSurfaceDTO;
 use backend::cqrs::points::point_details::PointDTO;
 use backend::cqrs::surfaces_c0::surface_c0_details::SurfaceC0DTO;
 use backend::cqrs::surfaces_c2::surface_c2_details::SurfaceC2DTO;
@@ -9,6 +10,8 @@ use crate::domain::bezier_c2::BezierC2;
 use crate::domain::bezier_int::BezierInt;
 use crate::domain::gregory::Gregory;
 use crate::domain::intersection::Intersection;
+use crate::domain::nurbs_curve::NurbsCurve;
+use crate::domain::toolpath::Toolpath;
 
 pub enum Object {
     Torus(TorusDTO),
@@ -16,10 +19,13 @@ pub enum Object {
     BezierC0(BezierC0),
     BezierC2(BezierC2),
     BezierInt(BezierInt),
+    NurbsCurve(NurbsCurve),
     SurfaceC0(SurfaceC0DTO),
     SurfaceC2(SurfaceC2DTO),
+    NurbsSurface(NurbsSurfaceDTO),
     Gregory(Gregory),
     Intersection(Intersection),
+    Toolpath(Toolpath),
 }
 
 impl Object {
@@ -30,10 +36,13 @@ impl Object {
             Object::BezierC0(bezier_c0) => bezier_c0.id,
             Object::BezierC2(bezier_c2) => bezier_c2.id,
             Object::BezierInt(bezier_int) => bezier_int.id,
+            Object::NurbsCurve(nurbs_curve) => nurbs_curve.id,
             Object::SurfaceC0(surface_c0) => surface_c0.id,
             Object::SurfaceC2(surface_c2) => surface_c2.id,
+            Object::NurbsSurface(nurbs_surface) => nurbs_surface.id,
             Object::Gregory(gregory) => gregory.id,
             Object::Intersection(intersection) => intersection.id,
+            Object::Toolpath(toolpath) => toolpath.id,
         }
     }
 
@@ -44,10 +53,13 @@ impl Object {
             Object::BezierC0(bezier_c0) => bezier_c0.name.clone(),
             Object::BezierC2(bezier_c2) => bezier_c2.name.clone(),
             Object::BezierInt(bezier_int) => bezier_int.name.clone(),
+            Object::NurbsCurve(nurbs_curve) => nurbs_curve.name.clone(),
             Object::SurfaceC0(surface_c0) => surface_c0.name.clone(),
             Object::SurfaceC2(surface_c2) => surface_c2.name.clone(),
+            Object::NurbsSurface(nurbs_surface) => nurbs_surface.name.clone(),
             Object::Gregory(gregory) => gregory.name.clone(),
             Object::Intersection(intersection) => intersection.name.clone(),
+            Object::Toolpath(toolpath) => toolpath.name.clone(),
         }
     }
 
@@ -58,10 +70,13 @@ impl Object {
             Object::BezierC0(_) => ObjectTypeDTO::BezierC0,
             Object::BezierC2(_) => ObjectTypeDTO::BezierC2,
             Object::BezierInt(_) => ObjectTypeDTO::BezierInt,
+            Object::NurbsCurve(_) => ObjectTypeDTO::NurbsCurve,
             Object::SurfaceC0(_) => ObjectTypeDTO::SurfaceC0,
             Object::SurfaceC2(_) => ObjectTypeDTO::SurfaceC2,
+            Object::NurbsSurface(_) => ObjectTypeDTO::NurbsSurface,
             Object::Gregory(_) => ObjectTypeDTO::Gregory,
             Object::Intersection(_) => ObjectTypeDTO::Intersection,
+            Object::Toolpath(_) => ObjectTypeDTO::Toolpath,
         }
     }
 }
@@ -9,10 +9,13 @@ pub enum ObjectId {
     BezierC0(u64),
     BezierC2(u64),
     BezierInt(u64),
+    NurbsCurve(u64),
     SurfaceC0(u64),
     SurfaceC2(u64),
+    NurbsSurface(u64),
     Gregory(u64),
     Intersection(u64),
+    Toolpath(u64),
 }
 
 impl ObjectId {
@@ -23,10 +26,13 @@ impl ObjectId {
             ObjectId::BezierC0(id) => *id,
             ObjectId::BezierC2(id) => *id,
             ObjectId::BezierInt(id) => *id,
+            ObjectId::NurbsCurve(id) => *id,
             ObjectId::SurfaceC0(id) => *id,
             ObjectId::SurfaceC2(id) => *id,
+            ObjectId::NurbsSurface(id) => *id,
             ObjectId::Gregory(id) => *id,
             ObjectId::Intersection(id) => *id,
+            ObjectId::Toolpath(id) => *id,
         }
     }
 
@@ -37,10 +43,13 @@ impl ObjectId {
             ObjectId::BezierC0(_) => ObjectTypeDTO::BezierC0,
             ObjectId::BezierC2(_) => ObjectTypeDTO::BezierC2,
             ObjectId::BezierInt(_) => ObjectTypeDTO::BezierInt,
+            ObjectId::NurbsCurve(_) => ObjectTypeDTO::NurbsCurve,
             ObjectId::SurfaceC0(_) => ObjectTypeDTO::SurfaceC0,
             ObjectId::SurfaceC2(_) => ObjectTypeDTO::SurfaceC2,
+            ObjectId::NurbsSurface(_) => ObjectTypeDTO::NurbsSurface,
             ObjectId::Gregory(_) => ObjectTypeDTO::Gregory,
             ObjectId::Intersection(_) => ObjectTypeDTO::Intersection,
+            ObjectId::Toolpath(_) => ObjectTypeDTO::Toolpath,
         }
     }
 
@@ -51,10 +60,13 @@ impl ObjectId {
             ObjectId::BezierC0(_) => None,
             ObjectId::BezierC2(_) => None,
             ObjectId::BezierInt(_) => None,
+            ObjectId::NurbsCurve(_) => None,
             ObjectId::SurfaceC0(id) => Some(IntersectionObjectIdDTO::SurfaceC0(*id)),
             ObjectId::SurfaceC2(id) => Some(IntersectionObjectIdDTO::SurfaceC2(*id)),
+            ObjectId::NurbsSurface(id) => Some(IntersectionObjectIdDTO::NurbsSurface(*id)),
             ObjectId::Gregory(_) => None,
             ObjectId::Intersection(_) => None,
+            ObjectId::Toolpath(_) => None,
         }
     }
 }
@@ -1,9 +1,11 @@
-use egui::{Context, Widget};
+use egui::{ComboBox, Context, Widget};
 use itertools::Itertools;
 
 use backend::cqrs::common::new_id::NewId;
 use backend::cqrs::cqrs::CQRS;
-use backend::cqrs::surfaces_c0::create_surface_c0::{CreateSurfaceC0, CreateSurfaceInfoDTO};
+use backend::cqrs::surfaces_c0::create_surface_c0::{
+    CreateSurfaceC0, CreateSurfaceInfoDTO, SurfaceShapeDTO,
+};
 use backend::cqrs::surfaces_c0::surface_c0_details::SurfaceC0Details;
 use backend::cqrs::surfaces_c0::surface_c0_points::SurfaceC0Points;
 
@@ -12,11 +14,12 @@ use crate::popups::popup::Popup;
 
 pub struct AddSurfaceC0Popup {
     is_closed: bool,
-    pub is_cylinder: bool,
+    pub shape: SurfaceShapeDTO,
     pub length: f64,
     pub width: f64,
     pub height: f64,
     pub radius: f64,
+    pub minor_radius: f64,
     pub size: (u32, u32),
 }
 
@@ -24,14 +27,24 @@ impl AddSurfaceC0Popup {
     pub fn new() -> Self {
         Self {
             is_closed: false,
-            is_cylinder: false,
+            shape: SurfaceShapeDTO::Sheet,
             length: 1.0,
             width: 1.0,
             height: 1.0,
             radius: 1.0,
+            minor_radius: 0.25,
             size: (1, 1),
         }
     }
+
+    fn min_size(&self) -> (u32, u32) {
+        match self.shape {
+            SurfaceShapeDTO::Sheet | SurfaceShapeDTO::Cylinder => (1, 1),
+            SurfaceShapeDTO::Torus => (3, 3),
+            SurfaceShapeDTO::Sphere => (3, 2),
+            SurfaceShapeDTO::Cone => (3, 1),
+        }
+    }
 }
 
 impl Popup for AddSurfaceC0Popup {
@@ -39,50 +52,91 @@ impl Popup for AddSurfaceC0Popup {
         let mut result = vec![];
 
         egui::Window::new("Add Surface C0").show(context, |ui| {
-            ui.checkbox(&mut self.is_cylinder, "Cylinder");
-
-            if self.is_cylinder {
-                ui.horizontal(|ui| {
-                    egui::DragValue::new(&mut self.radius)
-                        .clamp_range(0.1..=100.0)
-                        .speed(0.1)
-                        .ui(ui);
-                    ui.label("Radius");
-                });
-                ui.horizontal(|ui| {
-                    egui::DragValue::new(&mut self.height)
-                        .clamp_range(0.1..=100.0)
-                        .speed(0.1)
-                        .ui(ui);
-                    ui.label("Height");
-                });
-            } else {
-                ui.horizontal(|ui| {
-                    egui::DragValue::new(&mut self.length)
-                        .clamp_range(0.1..=100.0)
-                        .speed(0.1)
-                        .ui(ui);
-                    ui.label("Length");
-                });
-                ui.horizontal(|ui| {
-                    egui::DragValue::new(&mut self.width)
-                        .clamp_range(0.1..=100.0)
-                        .speed(0.1)
-                        .ui(ui);
-                    ui.label("Width");
+            ComboBox::from_label("Shape")
+                .selected_text(format!("{:?}", self.shape))
+                .show_ui(ui, |ui| {
+                    for shape in [
+                        SurfaceShapeDTO::Sheet,
+                        SurfaceShapeDTO::Cylinder,
+                        SurfaceShapeDTO::Torus,
+                        SurfaceShapeDTO::Sphere,
+                        SurfaceShapeDTO::Cone,
+                    ] {
+                        ui.selectable_value(&mut self.shape, shape, format!("{:?}", shape));
+                    }
                 });
+
+            match self.shape {
+                SurfaceShapeDTO::Sheet => {
+                    ui.horizontal(|ui| {
+                        egui::DragValue::new(&mut self.length)
+                            .clamp_range(0.1..=100.0)
+                            .speed(0.1)
+                            .ui(ui);
+                        ui.label("Length");
+                    });
+                    ui.horizontal(|ui| {
+                        egui::DragValue::new(&mut self.width)
+                            .clamp_range(0.1..=100.0)
+                            .speed(0.1)
+                            .ui(ui);
+                        ui.label("Width");
+                    });
+                }
+                SurfaceShapeDTO::Cylinder | SurfaceShapeDTO::Cone => {
+                    ui.horizontal(|ui| {
+                        egui::DragValue::new(&mut self.radius)
+                            .clamp_range(0.1..=100.0)
+                            .speed(0.1)
+                            .ui(ui);
+                        ui.label("Radius");
+                    });
+                    ui.horizontal(|ui| {
+                        egui::DragValue::new(&mut self.height)
+                            .clamp_range(0.1..=100.0)
+                            .speed(0.1)
+                            .ui(ui);
+                        ui.label("Height");
+                    });
+                }
+                SurfaceShapeDTO::Torus => {
+                    ui.horizontal(|ui| {
+                        egui::DragValue::new(&mut self.radius)
+                            .clamp_range(0.1..=100.0)
+                            .speed(0.1)
+                            .ui(ui);
+                        ui.label("Radius");
+                    });
+                    ui.horizontal(|ui| {
+                        egui::DragValue::new(&mut self.minor_radius)
+                            .clamp_range(0.01..=100.0)
+                            .speed(0.1)
+                            .ui(ui);
+                        ui.label("Minor Radius");
+                    });
+                }
+                SurfaceShapeDTO::Sphere => {
+                    ui.horizontal(|ui| {
+                        egui::DragValue::new(&mut self.radius)
+                            .clamp_range(0.1..=100.0)
+                            .speed(0.1)
+                            .ui(ui);
+                        ui.label("Radius");
+                    });
+                }
             }
 
+            let min_size = self.min_size();
             ui.horizontal(|ui| {
                 egui::DragValue::new(&mut self.size.0)
-                    .clamp_range(1..=100)
+                    .clamp_range(min_size.0..=100)
                     .ui(ui);
                 ui.label("Size X");
             });
 
             ui.horizontal(|ui| {
                 egui::DragValue::new(&mut self.size.1)
-                    .clamp_range(1..=100)
+                    .clamp_range(min_size.1..=100)
                     .ui(ui);
                 ui.label("Size Y");
             });
@@ -93,12 +147,13 @@ impl Popup for AddSurfaceC0Popup {
                     cqrs.execute(&CreateSurfaceC0 {
                         id,
                         create_surface_info: CreateSurfaceInfoDTO {
-                            is_cylinder: self.is_cylinder,
+                            shape: self.shape,
                             length: Some(self.length),
                             width: Some(self.width),
                             height: Some(self.height),
                             radius: Some(self.radius),
-                            size: self.size,
+                            minor_radius: Some(self.minor_radius),
+                            size: (self.size.0.max(min_size.0), self.size.1.max(min_size.1)),
                         },
                     });
 
@@ -1,9 +1,12 @@
-use egui::{Context, Widget};
+use egui::{ComboBox, Context, Widget};
 use itertools::Itertools;
 
 use backend::cqrs::common::new_id::NewId;
 use backend::cqrs::cqrs::CQRS;
-use backend::cqrs::surfaces_c0::create_surface_c0::CreateSurfaceInfoDTO;
+use backend::cqrs::nurbs_surfaces::create_nurbs_surface::CreateNurbsSurface;
+use backend::cqrs::nurbs_surfaces::nurbs_surface_details::NurbsSurfaceDetails;
+use backend::cqrs::points::point_details::PointDetails;
+use backend::cqrs::surfaces_c0::create_surface_c0::{CreateSurfaceInfoDTO, SurfaceShapeDTO};
 use backend::cqrs::surfaces_c2::create_surface_c2::CreateSurfaceC2;
 use backend::cqrs::surfaces_c2::surface_c2_details::SurfaceC2Details;
 use backend::cqrs::surfaces_c2::surface_c2_points::SurfaceC2Points;
@@ -13,11 +16,13 @@ use crate::popups::popup::Popup;
 
 pub struct AddSurfaceC2Popup {
     is_closed: bool,
-    pub is_cylinder: bool,
+    pub shape: SurfaceShapeDTO,
+    pub rational: bool,
     pub length: f64,
     pub width: f64,
     pub height: f64,
     pub radius: f64,
+    pub minor_radius: f64,
     pub size: (u32, u32),
 }
 
@@ -25,14 +30,25 @@ impl AddSurfaceC2Popup {
     pub fn new() -> Self {
         Self {
             is_closed: false,
-            is_cylinder: false,
+            shape: SurfaceShapeDTO::Sheet,
+            rational: false,
             length: 1.0,
             width: 1.0,
             height: 1.0,
             radius: 1.0,
+            minor_radius: 0.25,
             size: (1, 1),
         }
     }
+
+    fn min_size(&self) -> (u32, u32) {
+        match self.shape {
+            SurfaceShapeDTO::Sheet => (1, 1),
+            SurfaceShapeDTO::Cylinder | SurfaceShapeDTO::Cone => (3, 1),
+            SurfaceShapeDTO::Torus => (3, 3),
+            SurfaceShapeDTO::Sphere => (3, 2),
+        }
+    }
 }
 
 impl Popup for AddSurfaceC2Popup {
@@ -40,55 +56,92 @@ impl Popup for AddSurfaceC2Popup {
         let mut result = vec![];
 
         egui::Window::new("Add Surface C2").show(context, |ui| {
-            ui.checkbox(&mut self.is_cylinder, "Cylinder");
-
-            if self.is_cylinder {
-                ui.horizontal(|ui| {
-                    egui::DragValue::new(&mut self.radius)
-                        .clamp_range(0.1..=100.0)
-                        .speed(0.1)
-                        .ui(ui);
-                    ui.label("Radius");
-                });
-                ui.horizontal(|ui| {
-                    egui::DragValue::new(&mut self.height)
-                        .clamp_range(0.1..=100.0)
-                        .speed(0.1)
-                        .ui(ui);
-                    ui.label("Height");
-                });
-                ui.horizontal(|ui| {
-                    egui::DragValue::new(&mut self.size.0)
-                        .clamp_range(3..=100)
-                        .ui(ui);
-                    ui.label("Size X");
-                });
-            } else {
-                ui.horizontal(|ui| {
-                    egui::DragValue::new(&mut self.length)
-                        .clamp_range(0.1..=100.0)
-                        .speed(0.1)
-                        .ui(ui);
-                    ui.label("Length");
-                });
-                ui.horizontal(|ui| {
-                    egui::DragValue::new(&mut self.width)
-                        .clamp_range(0.1..=100.0)
-                        .speed(0.1)
-                        .ui(ui);
-                    ui.label("Width");
-                });
-                ui.horizontal(|ui| {
-                    egui::DragValue::new(&mut self.size.0)
-                        .clamp_range(1..=100)
-                        .ui(ui);
-                    ui.label("Size X");
+            ComboBox::from_label("Shape")
+                .selected_text(format!("{:?}", self.shape))
+                .show_ui(ui, |ui| {
+                    for shape in [
+                        SurfaceShapeDTO::Sheet,
+                        SurfaceShapeDTO::Cylinder,
+                        SurfaceShapeDTO::Torus,
+                        SurfaceShapeDTO::Sphere,
+                        SurfaceShapeDTO::Cone,
+                    ] {
+                        ui.selectable_value(&mut self.shape, shape, format!("{:?}", shape));
+                    }
                 });
+            ui.checkbox(&mut self.rational, "Rational (NURBS)");
+
+            match self.shape {
+                SurfaceShapeDTO::Sheet => {
+                    ui.horizontal(|ui| {
+                        egui::DragValue::new(&mut self.length)
+                            .clamp_range(0.1..=100.0)
+                            .speed(0.1)
+                            .ui(ui);
+                        ui.label("Length");
+                    });
+                    ui.horizontal(|ui| {
+                        egui::DragValue::new(&mut self.width)
+                            .clamp_range(0.1..=100.0)
+                            .speed(0.1)
+                            .ui(ui);
+                        ui.label("Width");
+                    });
+                }
+                SurfaceShapeDTO::Cylinder | SurfaceShapeDTO::Cone => {
+                    ui.horizontal(|ui| {
+                        egui::DragValue::new(&mut self.radius)
+                            .clamp_range(0.1..=100.0)
+                            .speed(0.1)
+                            .ui(ui);
+                        ui.label("Radius");
+                    });
+                    ui.horizontal(|ui| {
+                        egui::DragValue::new(&mut self.height)
+                            .clamp_range(0.1..=100.0)
+                            .speed(0.1)
+                            .ui(ui);
+                        ui.label("Height");
+                    });
+                }
+                SurfaceShapeDTO::Torus => {
+                    ui.horizontal(|ui| {
+                        egui::DragValue::new(&mut self.radius)
+                            .clamp_range(0.1..=100.0)
+                            .speed(0.1)
+                            .ui(ui);
+                        ui.label("Radius");
+                    });
+                    ui.horizontal(|ui| {
+                        egui::DragValue::new(&mut self.minor_radius)
+                            .clamp_range(0.01..=100.0)
+                            .speed(0.1)
+                            .ui(ui);
+                        ui.label("Minor Radius");
+                    });
+                }
+                SurfaceShapeDTO::Sphere => {
+                    ui.horizontal(|ui| {
+                        egui::DragValue::new(&mut self.radius)
+                            .clamp_range(0.1..=100.0)
+                            .speed(0.1)
+                            .ui(ui);
+                        ui.label("Radius");
+                    });
+                }
             }
 
+            let min_size = self.min_size();
+            ui.horizontal(|ui| {
+                egui::DragValue::new(&mut self.size.0)
+                    .clamp_range(min_size.0..=100)
+                    .ui(ui);
+                ui.label("Size X");
+            });
+
             ui.horizontal(|ui| {
                 egui::DragValue::new(&mut self.size.1)
-                    .clamp_range(1..=100)
+                    .clamp_range(min_size.1..=100)
                     .ui(ui);
                 ui.label("Size Y");
             });
@@ -96,24 +149,40 @@ impl Popup for AddSurfaceC2Popup {
             ui.horizontal(|ui| {
                 if ui.button("Create").clicked() {
                     let id = cqrs.handle(&NewId {});
-                    cqrs.execute(&CreateSurfaceC2 {
-                        id,
-                        create_surface_info: CreateSurfaceInfoDTO {
-                            is_cylinder: self.is_cylinder,
-                            length: Some(self.length),
-                            width: Some(self.width),
-                            height: Some(self.height),
-                            radius: Some(self.radius),
-                            size: self.size,
-                        },
-                    });
+                    let create_surface_info = CreateSurfaceInfoDTO {
+                        shape: self.shape,
+                        length: Some(self.length),
+                        width: Some(self.width),
+                        height: Some(self.height),
+                        radius: Some(self.radius),
+                        minor_radius: Some(self.minor_radius),
+                        size: (self.size.0.max(min_size.0), self.size.1.max(min_size.1)),
+                    };
+
+                    if self.rational {
+                        cqrs.execute(&CreateNurbsSurface {
+                            id,
+                            create_surface_info,
+                        });
+
+                        let surface = cqrs.get(&NurbsSurfaceDetails { id });
+                        for point in surface.points.iter().unique_by(|p| p.id) {
+                            result.push(Object::Point(cqrs.get(&PointDetails { id: point.id })));
+                        }
+                        result.push(Object::NurbsSurface(surface));
+                    } else {
+                        cqrs.execute(&CreateSurfaceC2 {
+                            id,
+                            create_surface_info,
+                        });
 
-                    let surface = cqrs.get(&SurfaceC2Details { id });
-                    let points = cqrs.get(&SurfaceC2Points { id });
+                        let surface = cqrs.get(&SurfaceC2Details { id });
+                        let points = cqrs.get(&SurfaceC2Points { id });
 
-                    result.push(Object::SurfaceC2(surface));
-                    for point in points.into_iter().unique_by(|p| p.id) {
-                        result.push(Object::Point(point));
+                        result.push(Object::SurfaceC2(surface));
+                        for point in points.into_iter().unique_by(|p| p.id) {
+                            result.push(Object::Point(point));
+                        }
                     }
 
                     self.is_closed = true;
@@ -0,0 +1,66 @@
+use backend::cqrs::{
+    cqrs::CQRS, intersections::fit_intersection_with_bezier_c2::FitIntersectionWithBezierC2,
+};
+use egui::{Context, Widget};
+
+use crate::object::Object;
+
+use super::popup::Popup;
+
+pub struct FitIntersectionPopup {
+    is_closed: bool,
+    id: u64,
+    tolerance: f64,
+    report: Option<String>,
+}
+
+impl FitIntersectionPopup {
+    pub fn new(id: u64) -> Self {
+        Self {
+            is_closed: false,
+            id,
+            tolerance: 0.001,
+            report: None,
+        }
+    }
+}
+
+impl Popup for FitIntersectionPopup {
+    fn build(&mut self, cqrs: &mut CQRS, context: &Context) -> Vec<Object> {
+        egui::Window::new("Fit Intersection").show(context, |ui| {
+            ui.horizontal(|ui| {
+                egui::DragValue::new(&mut self.tolerance)
+                    .clamp_range(0.00001..=1.0)
+                    .speed(0.0001)
+                    .ui(ui);
+                ui.label("tolerance");
+            });
+
+            if let Some(report) = &self.report {
+                ui.label(report);
+            }
+
+            ui.horizontal(|ui| {
+                if ui.button("Fit").clicked() {
+                    let fit = cqrs.handle(&FitIntersectionWithBezierC2 {
+                        id: self.id,
+                        tolerance: self.tolerance,
+                    });
+                    match fit {
+                        Some(_) => self.is_closed = true,
+                        None => self.report = Some("Not enough intersection points".to_string()),
+                    }
+                }
+                if ui.button("Close").clicked() {
+                    self.is_closed = true;
+                }
+            });
+        });
+
+        vec![]
+    }
+
+    fn is_closed(&self) -> bool {
+        self.is_closed
+    }
+}
@@ -0,0 +1,77 @@
+use egui::{Context, Widget};
+
+use backend::cqrs::common::new_id::NewId;
+use backend::cqrs::cqrs::CQRS;
+use backend::cqrs::surfaces_c2::all_surfaces_c2::AllSurfacesC2;
+use backend::cqrs::surfaces_c2::create_interpolating_surface_c2::CreateInterpolatingSurfaceC2;
+
+use crate::object::Object;
+use crate::popups::popup::Popup;
+
+pub struct InterpolateSurfacePopup {
+    is_closed: bool,
+    points: Vec<u64>,
+    pub size_x: u32,
+}
+
+impl InterpolateSurfacePopup {
+    pub fn new(points: Vec<u64>) -> Self {
+        Self {
+            is_closed: false,
+            points,
+            size_x: 2,
+        }
+    }
+}
+
+impl Popup for InterpolateSurfacePopup {
+    fn build(&mut self, cqrs: &mut CQRS, context: &Context) -> Vec<Object> {
+        let mut result = vec![];
+
+        egui::Window::new("Interpolate Surface").show(context, |ui| {
+            let count = self.points.len() as u32;
+            ui.label(format!("Points: {}", count));
+            ui.horizontal(|ui| {
+                egui::DragValue::new(&mut self.size_x)
+                    .clamp_range(2..=(count / 2).max(2))
+                    .ui(ui);
+                ui.label("Columns");
+            });
+            let is_grid = count.is_multiple_of(self.size_x) && count / self.size_x >= 2;
+            if is_grid {
+                ui.label(format!("Grid: {} x {}", self.size_x, count / self.size_x));
+            } else {
+                ui.label("Points do not form a grid with this many columns");
+            }
+
+            ui.horizontal(|ui| {
+                if ui.button("Create").clicked() && is_grid {
+                    let id = cqrs.handle(&NewId {});
+                    cqrs.execute(&CreateInterpolatingSurfaceC2 {
+                        id,
+                        points: self.points.clone(),
+                        size_x: self.size_x,
+                    });
+
+                    result.extend(
+                        cqrs.get(&AllSurfacesC2)
+                            .into_iter()
+                            .filter(|surface| surface.id == id)
+                            .map(Object::SurfaceC2),
+                    );
+
+                    self.is_closed = true;
+                }
+                if ui.button("Close").clicked() {
+                    self.is_closed = true;
+                }
+            });
+        });
+
+        result
+    }
+
+    fn is_closed(&self) -> bool {
+        self.is_closed
+    }
+}
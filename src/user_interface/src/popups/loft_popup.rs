@@ -0,0 +1,72 @@
+use egui::Context;
+
+use backend::cqrs::common::loft_curves::LoftCurves;
+use backend::cqrs::common::new_id::NewId;
+use backend::cqrs::cqrs::CQRS;
+use backend::cqrs::surfaces_c2::all_surfaces_c2::AllSurfacesC2;
+
+use crate::object::Object;
+use crate::popups::popup::Popup;
+
+pub struct LoftPopup {
+    is_closed: bool,
+    curves: Vec<u64>,
+    pub closed: bool,
+    pub interpolate: bool,
+}
+
+impl LoftPopup {
+    pub fn new(curves: Vec<u64>) -> Self {
+        Self {
+            is_closed: false,
+            curves,
+            closed: false,
+            interpolate: true,
+        }
+    }
+}
+
+impl Popup for LoftPopup {
+    fn build(&mut self, cqrs: &mut CQRS, context: &Context) -> Vec<Object> {
+        let mut result = vec![];
+
+        egui::Window::new("Loft").show(context, |ui| {
+            ui.label(format!("Sections: {}", self.curves.len()));
+            ui.checkbox(&mut self.closed, "Closed Sections");
+            ui.checkbox(&mut self.interpolate, "Interpolate Sections");
+            if !self.interpolate && self.curves.len() < 4 {
+                ui.label("Approximation needs at least 4 sections");
+            }
+
+            ui.horizontal(|ui| {
+                if ui.button("Create").clicked() {
+                    let id = cqrs.handle(&NewId {});
+                    cqrs.execute(&LoftCurves {
+                        id,
+                        curves: self.curves.clone(),
+                        closed: self.closed,
+                        interpolate: self.interpolate,
+                    });
+
+                    result.extend(
+                        cqrs.get(&AllSurfacesC2)
+                            .into_iter()
+                            .filter(|surface| surface.id == id)
+                            .map(Object::SurfaceC2),
+                    );
+
+                    self.is_closed = true;
+                }
+                if ui.button("Close").clicked() {
+                    self.is_closed = true;
+                }
+            });
+        });
+
+        result
+    }
+
+    fn is_closed(&self) -> bool {
+        self.is_closed
+    }
+}
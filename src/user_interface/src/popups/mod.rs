@@ -2,4 +2,9 @@ pub mod add_surface_c0_popup;
 pub mod add_surface_c2_popup;
 pub mod find_intersection_popup;
 pub mod find_self_intersection_popup;
+pub mod fit_intersection_popup;
+pub mod interpolate_surface_popup;
+pub mod loft_popup;
 pub mod popup;
+pub mod surface_of_revolution_popup;
+pub mod sweep_popup;
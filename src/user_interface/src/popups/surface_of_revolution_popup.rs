@@ -0,0 +1,104 @@
+use egui::{Context, Widget};
+
+use backend::cqrs::common::create_surface_of_revolution::CreateSurfaceOfRevolution;
+use backend::cqrs::common::new_id::NewId;
+use backend::cqrs::cqrs::CQRS;
+use backend::cqrs::surfaces_c0::all_surfaces_c0::AllSurfacesC0;
+use backend::cqrs::surfaces_c2::all_surfaces_c2::AllSurfacesC2;
+
+use crate::object::Object;
+use crate::object_id::ObjectId;
+use crate::popups::popup::Popup;
+
+pub struct SurfaceOfRevolutionPopup {
+    is_closed: bool,
+    profile: ObjectId,
+    pub axis_direction: (f64, f64, f64),
+    pub segments: u32,
+}
+
+impl SurfaceOfRevolutionPopup {
+    pub fn new(profile: ObjectId) -> Self {
+        Self {
+            is_closed: false,
+            profile,
+            axis_direction: (0.0, 1.0, 0.0),
+            segments: 8,
+        }
+    }
+}
+
+impl Popup for SurfaceOfRevolutionPopup {
+    fn build(&mut self, cqrs: &mut CQRS, context: &Context) -> Vec<Object> {
+        let mut result = vec![];
+
+        egui::Window::new("Surface Of Revolution").show(context, |ui| {
+            ui.label("Axis through the cursor");
+            ui.horizontal(|ui| {
+                if ui.button("X").clicked() {
+                    self.axis_direction = (1.0, 0.0, 0.0);
+                }
+                if ui.button("Y").clicked() {
+                    self.axis_direction = (0.0, 1.0, 0.0);
+                }
+                if ui.button("Z").clicked() {
+                    self.axis_direction = (0.0, 0.0, 1.0);
+                }
+            });
+            ui.horizontal(|ui| {
+                egui::DragValue::new(&mut self.axis_direction.0)
+                    .speed(0.01)
+                    .ui(ui);
+                egui::DragValue::new(&mut self.axis_direction.1)
+                    .speed(0.01)
+                    .ui(ui);
+                egui::DragValue::new(&mut self.axis_direction.2)
+                    .speed(0.01)
+                    .ui(ui);
+                ui.label("Direction");
+            });
+            ui.horizontal(|ui| {
+                egui::DragValue::new(&mut self.segments)
+                    .clamp_range(3..=100)
+                    .ui(ui);
+                ui.label("Segments");
+            });
+
+            ui.horizontal(|ui| {
+                if ui.button("Create").clicked() {
+                    let id = cqrs.handle(&NewId {});
+                    cqrs.execute(&CreateSurfaceOfRevolution {
+                        id,
+                        profile_id: self.profile.get_id(),
+                        axis_direction: self.axis_direction,
+                        segments: self.segments,
+                    });
+
+                    result.extend(
+                        cqrs.get(&AllSurfacesC0)
+                            .into_iter()
+                            .filter(|surface| surface.id == id)
+                            .map(Object::SurfaceC0),
+                    );
+                    result.extend(
+                        cqrs.get(&AllSurfacesC2)
+                            .into_iter()
+                            .filter(|surface| surface.id == id)
+                            .map(Object::SurfaceC2),
+                    );
+
+                    self.is_closed = true;
+                }
+                if ui.button("Close").clicked() {
+                    self.is_closed = true;
+                }
+            });
+        });
+
+        result
+    }
+
+    fn is_closed(&self) -> bool {
+        self.is_closed
+    }
+}
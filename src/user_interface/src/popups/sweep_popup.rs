@@ -0,0 +1,80 @@
+use egui::{Context, Widget};
+
+use backend::cqrs::common::new_id::NewId;
+use backend::cqrs::common::sweep_curve::SweepCurve;
+use backend::cqrs::cqrs::CQRS;
+use backend::cqrs::surfaces_c0::all_surfaces_c0::AllSurfacesC0;
+use backend::cqrs::surfaces_c2::all_surfaces_c2::AllSurfacesC2;
+
+use crate::object::Object;
+use crate::object_id::ObjectId;
+use crate::popups::popup::Popup;
+
+pub struct SweepPopup {
+    is_closed: bool,
+    profile: ObjectId,
+    path: ObjectId,
+    pub segments: u32,
+}
+
+impl SweepPopup {
+    pub fn new(profile: ObjectId, path: ObjectId) -> Self {
+        Self {
+            is_closed: false,
+            profile,
+            path,
+            segments: 8,
+        }
+    }
+}
+
+impl Popup for SweepPopup {
+    fn build(&mut self, cqrs: &mut CQRS, context: &Context) -> Vec<Object> {
+        let mut result = vec![];
+
+        egui::Window::new("Sweep").show(context, |ui| {
+            ui.horizontal(|ui| {
+                egui::DragValue::new(&mut self.segments)
+                    .clamp_range(1..=100)
+                    .ui(ui);
+                ui.label("Sections");
+            });
+
+            ui.horizontal(|ui| {
+                if ui.button("Create").clicked() {
+                    let id = cqrs.handle(&NewId {});
+                    cqrs.execute(&SweepCurve {
+                        id,
+                        profile_id: self.profile.get_id(),
+                        path_id: self.path.get_id(),
+                        segments: self.segments,
+                    });
+
+                    result.extend(
+                        cqrs.get(&AllSurfacesC0)
+                            .into_iter()
+                            .filter(|surface| surface.id == id)
+                            .map(Object::SurfaceC0),
+                    );
+                    result.extend(
+                        cqrs.get(&AllSurfacesC2)
+                            .into_iter()
+                            .filter(|surface| surface.id == id)
+                            .map(Object::SurfaceC2),
+                    );
+
+                    self.is_closed = true;
+                }
+                if ui.button("Close").clicked() {
+                    self.is_closed = true;
+                }
+            });
+        });
+
+        result
+    }
+
+    fn is_closed(&self) -> bool {
+        self.is_closed
+    }
+}
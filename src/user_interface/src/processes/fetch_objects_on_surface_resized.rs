@@ -0,0 +1,48 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend::cqrs::cqrs::CQRS;
+use backend_events::surfaces_c0::surface_c0_resized::SurfaceC0Resized;
+use backend_events::surfaces_c2::surface_c2_resized::SurfaceC2Resized;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::ui::Ui;
+
+pub struct FetchObjectsOnSurfaceC0Resized {
+    pub ui: Rc<RefCell<Ui>>,
+    pub cqrs: CQRS,
+}
+
+impl Consumer<SurfaceC0Resized> for FetchObjectsOnSurfaceC0Resized {
+    fn consume(&self, _: &SurfaceC0Resized) {
+        let mut ui = self.ui.borrow_mut();
+        ui.surface_edit_report = None;
+        ui.fetch_objects(&self.cqrs);
+    }
+}
+
+impl AnyConsumer for FetchObjectsOnSurfaceC0Resized {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+pub struct FetchObjectsOnSurfaceC2Resized {
+    pub ui: Rc<RefCell<Ui>>,
+    pub cqrs: CQRS,
+}
+
+impl Consumer<SurfaceC2Resized> for FetchObjectsOnSurfaceC2Resized {
+    fn consume(&self, _: &SurfaceC2Resized) {
+        let mut ui = self.ui.borrow_mut();
+        ui.surface_edit_report = None;
+        ui.fetch_objects(&self.cqrs);
+    }
+}
+
+impl AnyConsumer for FetchObjectsOnSurfaceC2Resized {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -1,5 +1,8 @@
 pub mod fetch_objects_on_scene_loaded;
 pub mod fetch_objects_on_selected_points_merged;
+pub mod fetch_objects_on_surface_resized;
+pub mod report_surface_edit_refusal;
+pub mod selected_nurbs_surface_points_on_nurbs_surface_points_selected;
 pub mod selected_surface_c0_points_on_surface_c0_points_selected;
 pub mod selected_surface_c2_points_on_surface_c2_points_selected;
 pub mod sync_bezier_c0_with_backend;
@@ -7,4 +10,6 @@ pub mod sync_bezier_c2_with_backend;
 pub mod sync_bezier_int_with_backend;
 pub mod sync_greogry_with_backend;
 pub mod sync_intersection_with_backend;
+pub mod sync_nurbs_curve_with_backend;
 pub mod sync_point_with_backend;
+pub mod sync_toolpath_with_backend;
@@ -0,0 +1,41 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend_events::surfaces_c0::surface_c0_edit_refused::SurfaceC0EditRefused;
+use backend_events::surfaces_c2::surface_c2_edit_refused::SurfaceC2EditRefused;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::ui::Ui;
+
+pub struct ReportSurfaceC0EditRefusal {
+    pub ui: Rc<RefCell<Ui>>,
+}
+
+impl Consumer<SurfaceC0EditRefused> for ReportSurfaceC0EditRefusal {
+    fn consume(&self, event: &SurfaceC0EditRefused) {
+        self.ui.borrow_mut().surface_edit_report = Some((event.id, event.reason.clone()));
+    }
+}
+
+impl AnyConsumer for ReportSurfaceC0EditRefusal {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+pub struct ReportSurfaceC2EditRefusal {
+    pub ui: Rc<RefCell<Ui>>,
+}
+
+impl Consumer<SurfaceC2EditRefused> for ReportSurfaceC2EditRefusal {
+    fn consume(&self, event: &SurfaceC2EditRefused) {
+        self.ui.borrow_mut().surface_edit_report = Some((event.id, event.reason.clone()));
+    }
+}
+
+impl AnyConsumer for ReportSurfaceC2EditRefusal {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
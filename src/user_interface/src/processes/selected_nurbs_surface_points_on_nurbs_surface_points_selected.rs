@@ -0,0 +1,36 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend::cqrs::cqrs::CQRS;
+use backend::cqrs::nurbs_surfaces::nurbs_surface_points::NurbsSurfacePoints;
+use backend_events::nurbs_surfaces::nurbs_surface_points_selected::NurbsSurfacePointsSelected;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::object_id::ObjectId;
+use crate::ui::Ui;
+
+pub struct SelectedNurbsSurfacePointsOnNurbsSurfacePointsSelected {
+    pub ui: Rc<RefCell<Ui>>,
+    pub cqrs: CQRS,
+}
+
+impl Consumer<NurbsSurfacePointsSelected>
+    for SelectedNurbsSurfacePointsOnNurbsSurfacePointsSelected
+{
+    fn consume(&self, message: &NurbsSurfacePointsSelected) {
+        let points = self.cqrs.get(&NurbsSurfacePoints {
+            id: message.surface_id,
+        });
+        self.ui
+            .borrow_mut()
+            .selected_objects
+            .extend(points.iter().map(|p| ObjectId::Point(p.id)));
+    }
+}
+
+impl AnyConsumer for SelectedNurbsSurfacePointsOnNurbsSurfacePointsSelected {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
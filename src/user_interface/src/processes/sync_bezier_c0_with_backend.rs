@@ -2,6 +2,10 @@ use std::any::Any;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use backend::cqrs::beziers_c0::bezier_c0_details::BezierC0Details;
+use backend::cqrs::cqrs::CQRS;
+use backend_events::beziers_c0::bezier_c0_created::BezierC0Created;
+use backend_events::beziers_c0::bezier_c0_deleted::BezierC0Deleted;
 use backend_events::beziers_c0::bezier_c0_points_deleted::BezierC0PointsDeleted;
 use backend_events::beziers_c0::bezier_c0_renamed::BezierC0Renamed;
 use backend_events::points::point_added_to_bezier_c0::PointAddedToBezierC0;
@@ -9,6 +13,8 @@ use infrastructure::consumer::{AnyConsumer, Consumer};
 
 use crate::domain::bezier_c0::BezierC0Point;
 use crate::object::Object::BezierC0;
+
+type DomainBezierC0 = crate::domain::bezier_c0::BezierC0;
 use crate::ui::Ui;
 
 pub struct SyncBezierC0NameWithBackend {
@@ -74,6 +80,39 @@ impl Consumer<PointAddedToBezierC0> for SyncBezierC0AddedPointsWithBackend {
     }
 }
 
+pub struct SyncBezierC0CreationWithBackend {
+    pub ui: Rc<RefCell<Ui>>,
+    pub cqrs: CQRS,
+}
+
+impl Consumer<BezierC0Created> for SyncBezierC0CreationWithBackend {
+    fn consume(&self, event: &BezierC0Created) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects.push(BezierC0(DomainBezierC0::from_dto(
+            &self.cqrs.get(&BezierC0Details { id: event.id }),
+        )));
+    }
+}
+
+pub struct SyncBezierC0DeletionWithBackend {
+    pub ui: Rc<RefCell<Ui>>,
+}
+
+impl Consumer<BezierC0Deleted> for SyncBezierC0DeletionWithBackend {
+    fn consume(&self, event: &BezierC0Deleted) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects.retain(|object| {
+            if let BezierC0(bezier_c0) = object {
+                bezier_c0.id != event.id
+            } else {
+                true
+            }
+        });
+        ui.selected_objects
+            .retain(|object| object.get_id() != event.id);
+    }
+}
+
 impl AnyConsumer for SyncBezierC0NameWithBackend {
     fn consume_any(&self, message: Rc<dyn Any>) {
         self.consume_any_impl(message);
@@ -91,3 +130,15 @@ impl AnyConsumer for SyncBezierC0AddedPointsWithBackend {
         self.consume_any_impl(message);
     }
 }
+
+impl AnyConsumer for SyncBezierC0CreationWithBackend {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+impl AnyConsumer for SyncBezierC0DeletionWithBackend {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -3,13 +3,17 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use backend::cqrs::beziers_c2::bezier_c2_bernstein_points::BezierC2BernsteinPoints;
+use backend::cqrs::beziers_c2::bezier_c2_details::BezierC2Details;
 use backend::cqrs::cqrs::CQRS;
+use backend_events::beziers_c2::bezier_c2_closed_set::BezierC2ClosedSet;
+use backend_events::beziers_c2::bezier_c2_created::BezierC2Created;
+use backend_events::beziers_c2::bezier_c2_deleted::BezierC2Deleted;
 use backend_events::beziers_c2::bezier_c2_point_moved::BezierC2PointMoved;
 use backend_events::beziers_c2::bezier_c2_points_deleted::BezierC2PointsDeleted;
 use backend_events::points::point_added_to_bezier_c2::PointAddedToBezierC2;
 use infrastructure::consumer::{AnyConsumer, Consumer};
 
-use crate::domain::bezier_c2::BezierC2BSplinePoint;
+use crate::domain::bezier_c2::{BezierC2, BezierC2BSplinePoint};
 use crate::object::Object;
 use crate::ui::Ui;
 
@@ -27,7 +31,7 @@ impl Consumer<PointAddedToBezierC2> for SyncBezierC2AddedPointsWithBackend {
             .for_each(|object| match object {
                 Object::BezierC2(bezier) => {
                     let point = BezierC2BSplinePoint::new(event.point_id, event.point_name.clone());
-                    bezier.b_spline_points.push(point);
+                    bezier.b_spline_points.insert(event.index, point);
                     bezier.set_bernstein_points(&self.cqrs.get(&BezierC2BernsteinPoints {
                         id: event.bezier_id,
                     }));
@@ -85,6 +89,63 @@ impl Consumer<BezierC2PointMoved> for SyncBezierC2PointPositionsWithBackend {
     }
 }
 
+pub struct SyncBezierC2ClosedWithBackend {
+    pub ui: Rc<RefCell<Ui>>,
+    pub cqrs: CQRS,
+}
+
+impl Consumer<BezierC2ClosedSet> for SyncBezierC2ClosedWithBackend {
+    fn consume(&self, event: &BezierC2ClosedSet) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects
+            .iter_mut()
+            .filter(|object| object.get_id() == event.bezier_id)
+            .for_each(|object| match object {
+                Object::BezierC2(bezier) => {
+                    bezier.closed = event.closed;
+                    bezier.selected_bernstein_point = None;
+                    bezier.set_bernstein_points(&self.cqrs.get(&BezierC2BernsteinPoints {
+                        id: event.bezier_id,
+                    }));
+                }
+                _ => {}
+            });
+    }
+}
+
+pub struct SyncBezierC2CreationWithBackend {
+    pub ui: Rc<RefCell<Ui>>,
+    pub cqrs: CQRS,
+}
+
+impl Consumer<BezierC2Created> for SyncBezierC2CreationWithBackend {
+    fn consume(&self, event: &BezierC2Created) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects.push(Object::BezierC2(BezierC2::from_dto(
+            &self.cqrs.get(&BezierC2Details { id: event.id }),
+        )));
+    }
+}
+
+pub struct SyncBezierC2DeletionWithBackend {
+    pub ui: Rc<RefCell<Ui>>,
+}
+
+impl Consumer<BezierC2Deleted> for SyncBezierC2DeletionWithBackend {
+    fn consume(&self, event: &BezierC2Deleted) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects.retain(|object| {
+            if let Object::BezierC2(bezier) = object {
+                bezier.id != event.id
+            } else {
+                true
+            }
+        });
+        ui.selected_objects
+            .retain(|object| object.get_id() != event.id);
+    }
+}
+
 impl AnyConsumer for SyncBezierC2AddedPointsWithBackend {
     fn consume_any(&self, message: Rc<dyn Any>) {
         self.consume_any_impl(message);
@@ -102,3 +163,21 @@ impl AnyConsumer for SyncBezierC2PointPositionsWithBackend {
         self.consume_any_impl(message);
     }
 }
+
+impl AnyConsumer for SyncBezierC2ClosedWithBackend {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+impl AnyConsumer for SyncBezierC2CreationWithBackend {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+impl AnyConsumer for SyncBezierC2DeletionWithBackend {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
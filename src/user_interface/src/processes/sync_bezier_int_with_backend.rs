@@ -5,6 +5,7 @@ use std::rc::Rc;
 use backend::cqrs::beziers_int::bezier_int_details::BezierIntDetails;
 use backend::cqrs::cqrs::CQRS;
 use backend_events::beziers_int::bezier_int_created::BezierIntCreated;
+use backend_events::beziers_int::bezier_int_deleted::BezierIntDeleted;
 use backend_events::beziers_int::bezier_int_points_deleted::BezierIntPointsDeleted;
 use backend_events::points::point_added_to_bezier_int::PointAddedToBezierInt;
 use infrastructure::consumer::{AnyConsumer, Consumer};
@@ -72,6 +73,25 @@ impl Consumer<BezierIntCreated> for SyncBezierIntCreationWithBackend {
     }
 }
 
+pub struct SyncBezierIntDeletionWithBackend {
+    pub ui: Rc<RefCell<Ui>>,
+}
+
+impl Consumer<BezierIntDeleted> for SyncBezierIntDeletionWithBackend {
+    fn consume(&self, event: &BezierIntDeleted) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects.retain(|object| {
+            if let Object::BezierInt(bezier) = object {
+                bezier.id != event.id
+            } else {
+                true
+            }
+        });
+        ui.selected_objects
+            .retain(|object| object.get_id() != event.id);
+    }
+}
+
 impl AnyConsumer for SyncBezierIntAddedPointWithBackend {
     fn consume_any(&self, message: Rc<dyn Any>) {
         self.consume_any_impl(message);
@@ -89,3 +109,9 @@ impl AnyConsumer for SyncBezierIntCreationWithBackend {
         self.consume_any_impl(message);
     }
 }
+
+impl AnyConsumer for SyncBezierIntDeletionWithBackend {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -1,7 +1,9 @@
 use std::{any::Any, cell::RefCell, rc::Rc};
 
+use backend_events::common::surface_display_mode::SurfaceDisplayModeDTO;
 use backend_events::gregories::{gregory_created::GregoryCreated, gregory_renamed::GregoryRenamed};
 use infrastructure::consumer::{AnyConsumer, Consumer};
+use math::vector3::Vector3;
 
 use crate::{domain::gregory::Gregory, object::Object, ui::Ui};
 
@@ -16,7 +18,12 @@ impl Consumer<GregoryCreated> for SyncGregoryCreation {
             id: event.gregory_id,
             name: event.name.clone(),
             tess_level: event.tess_level,
+            display_mode: SurfaceDisplayModeDTO::Wireframe,
             draw_vectors: event.draw_vectors,
+            tangent_scale: 1.0,
+            center_offset: Vector3::zero(),
+            twist_blend: 1.0,
+            coons: event.coons,
         }));
     }
 }
@@ -0,0 +1,142 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use backend::cqrs::cqrs::CQRS;
+use backend::cqrs::nurbs_curves::nurbs_curve_details::NurbsCurveDetails;
+use backend_events::nurbs_curves::nurbs_curve_created::NurbsCurveCreated;
+use backend_events::nurbs_curves::nurbs_curve_deleted::NurbsCurveDeleted;
+use backend_events::nurbs_curves::nurbs_curve_points_deleted::NurbsCurvePointsDeleted;
+use backend_events::nurbs_curves::nurbs_curve_renamed::NurbsCurveRenamed;
+use backend_events::points::point_added_to_nurbs_curve::PointAddedToNurbsCurve;
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::domain::nurbs_curve::NurbsCurvePoint;
+use crate::object::Object::NurbsCurve;
+use crate::ui::Ui;
+
+type DomainNurbsCurve = crate::domain::nurbs_curve::NurbsCurve;
+
+pub struct SyncNurbsCurveNameWithBackend {
+    pub ui: Rc<RefCell<Ui>>,
+}
+
+impl Consumer<NurbsCurveRenamed> for SyncNurbsCurveNameWithBackend {
+    fn consume(&self, event: &NurbsCurveRenamed) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects
+            .iter_mut()
+            .filter(|object| object.get_id() == event.id)
+            .for_each(|object| {
+                if let NurbsCurve(curve) = object {
+                    curve.name = event.name.clone();
+                }
+            });
+    }
+}
+
+pub struct SyncNurbsCurveDeletedPointsWithBackend {
+    pub ui: Rc<RefCell<Ui>>,
+}
+
+impl Consumer<NurbsCurvePointsDeleted> for SyncNurbsCurveDeletedPointsWithBackend {
+    fn consume(&self, event: &NurbsCurvePointsDeleted) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects
+            .iter_mut()
+            .filter(|object| object.get_id() == event.id)
+            .for_each(|object| {
+                if let NurbsCurve(curve) = object {
+                    curve
+                        .points
+                        .retain(|point| !event.deleted_points.contains(&point.id));
+                }
+            });
+    }
+}
+
+pub struct SyncNurbsCurveAddedPointsWithBackend {
+    pub ui: Rc<RefCell<Ui>>,
+}
+
+impl Consumer<PointAddedToNurbsCurve> for SyncNurbsCurveAddedPointsWithBackend {
+    fn consume(&self, event: &PointAddedToNurbsCurve) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects
+            .iter_mut()
+            .filter(|object| object.get_id() == event.curve_id)
+            .for_each(|object| {
+                if let NurbsCurve(curve) = object {
+                    curve.points.push(NurbsCurvePoint {
+                        id: event.point_id,
+                        name: event.point_name.clone(),
+                        weight: 1.0,
+                        is_selected: false,
+                    });
+                }
+            });
+    }
+}
+
+pub struct SyncNurbsCurveCreationWithBackend {
+    pub ui: Rc<RefCell<Ui>>,
+    pub cqrs: CQRS,
+}
+
+impl Consumer<NurbsCurveCreated> for SyncNurbsCurveCreationWithBackend {
+    fn consume(&self, event: &NurbsCurveCreated) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects.push(NurbsCurve(DomainNurbsCurve::from_dto(
+            &self.cqrs.get(&NurbsCurveDetails { id: event.id }),
+        )));
+    }
+}
+
+pub struct SyncNurbsCurveDeletionWithBackend {
+    pub ui: Rc<RefCell<Ui>>,
+}
+
+impl Consumer<NurbsCurveDeleted> for SyncNurbsCurveDeletionWithBackend {
+    fn consume(&self, event: &NurbsCurveDeleted) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects.retain(|object| {
+            if let NurbsCurve(curve) = object {
+                curve.id != event.id
+            } else {
+                true
+            }
+        });
+        ui.selected_objects
+            .retain(|object| object.get_id() != event.id);
+    }
+}
+
+impl AnyConsumer for SyncNurbsCurveNameWithBackend {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+impl AnyConsumer for SyncNurbsCurveDeletedPointsWithBackend {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+impl AnyConsumer for SyncNurbsCurveAddedPointsWithBackend {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+impl AnyConsumer for SyncNurbsCurveCreationWithBackend {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+impl AnyConsumer for SyncNurbsCurveDeletionWithBackend {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -0,0 +1,106 @@
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use backend_events::toolpaths::{
+    toolpath_created::ToolpathCreated, toolpath_deleted::ToolpathDeleted,
+    toolpath_renamed::ToolpathRenamed, toolpath_updated::ToolpathUpdated,
+};
+use infrastructure::consumer::{AnyConsumer, Consumer};
+
+use crate::{domain::toolpath::Toolpath, object::Object, ui::Ui};
+
+pub struct SyncToolpathCreation {
+    pub ui: Rc<RefCell<Ui>>,
+}
+
+impl Consumer<ToolpathCreated> for SyncToolpathCreation {
+    fn consume(&self, event: &ToolpathCreated) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects.push(Object::Toolpath(Toolpath::new(
+            event.toolpath_id,
+            event.name.clone(),
+            event.cutter_name.clone(),
+            event.points.len(),
+            event.visible,
+        )));
+    }
+}
+
+impl AnyConsumer for SyncToolpathCreation {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+pub struct SyncToolpathName {
+    pub ui: Rc<RefCell<Ui>>,
+}
+
+impl Consumer<ToolpathRenamed> for SyncToolpathName {
+    fn consume(&self, event: &ToolpathRenamed) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects
+            .iter_mut()
+            .filter(|object| object.get_id() == event.toolpath_id)
+            .for_each(|object| {
+                if let Object::Toolpath(toolpath) = object {
+                    toolpath.name = event.name.clone();
+                }
+            });
+    }
+}
+
+impl AnyConsumer for SyncToolpathName {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+pub struct SyncToolpathUpdate {
+    pub ui: Rc<RefCell<Ui>>,
+}
+
+impl Consumer<ToolpathUpdated> for SyncToolpathUpdate {
+    fn consume(&self, event: &ToolpathUpdated) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects
+            .iter_mut()
+            .filter(|object| object.get_id() == event.toolpath_id)
+            .for_each(|object| {
+                if let Object::Toolpath(toolpath) = object {
+                    toolpath.update_points_count(event.points.len());
+                    toolpath.visible = event.visible;
+                }
+            });
+    }
+}
+
+impl AnyConsumer for SyncToolpathUpdate {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
+
+pub struct SyncToolpathDeletion {
+    pub ui: Rc<RefCell<Ui>>,
+}
+
+impl Consumer<ToolpathDeleted> for SyncToolpathDeletion {
+    fn consume(&self, event: &ToolpathDeleted) {
+        let mut ui = self.ui.borrow_mut();
+        ui.objects.retain(|object| {
+            if let Object::Toolpath(toolpath) = object {
+                toolpath.id != event.toolpath_id
+            } else {
+                true
+            }
+        });
+        ui.selected_objects
+            .retain(|object| object.get_id() != event.toolpath_id);
+    }
+}
+
+impl AnyConsumer for SyncToolpathDeletion {
+    fn consume_any(&self, message: Rc<dyn Any>) {
+        self.consume_any_impl(message);
+    }
+}
@@ -8,26 +8,34 @@ use std::f32::consts::PI;
 use backend::cqrs::beziers_c0::all_beziers_c0::AllBeziersC0;
 use backend::cqrs::beziers_c2::all_beziers_c2::AllBeziersC2;
 use backend::cqrs::beziers_int::all_beziers_int::AllBeziersInt;
+use backend::cqrs::common::check_continuity::BoundaryContinuityDTO;
 use backend::cqrs::common::select_objects::{SelectObjects, SelectionObjectDTO};
 use backend::cqrs::cqrs::CQRS;
 use backend::cqrs::cursors::cursor_details::CursorDTO;
 use backend::cqrs::cursors::cursor_details::CursorDetails;
+use backend::cqrs::nurbs_curves::all_nurbs_curves::AllNurbsCurves;
+use backend::cqrs::nurbs_surfaces::all_nurbs_surfaces::AllNurbsSurfaces;
 use backend::cqrs::points::all_points::AllPoints;
 use backend::cqrs::surfaces_c0::all_surfaces_c0::AllSurfacesC0;
 use backend::cqrs::surfaces_c2::all_surfaces_c2::AllSurfacesC2;
+use backend::cqrs::toolpaths::all_toolpaths::AllToolpaths;
 use backend::cqrs::toruses::all_toruses::AllToruses;
 use backend::cqrs::toruses::torus_details::TransformerDTO;
 
 use crate::domain::gregory::Gregory;
 use crate::domain::intersection::{Intersection, TextureDraw};
+use crate::domain::toolpath::Toolpath;
 use crate::object::Object;
-use crate::object::Object::{BezierC0, BezierC2, BezierInt, Point, SurfaceC0, SurfaceC2, Torus};
+use crate::object::Object::{
+    BezierC0, BezierC2, BezierInt, NurbsCurve, Point, SurfaceC0, SurfaceC2, Torus,
+};
 use crate::object_id::ObjectId;
 use crate::popups::popup::Popup;
 
 type DomainBezierC0 = crate::domain::bezier_c0::BezierC0;
 type DomainBezierC2 = crate::domain::bezier_c2::BezierC2;
 type DomainBezierInt = crate::domain::bezier_int::BezierInt;
+type DomainNurbsCurve = crate::domain::nurbs_curve::NurbsCurve;
 
 pub struct Ui {
     pub objects: Vec<Object>,
@@ -40,10 +48,27 @@ pub struct Ui {
     pub previous_group_transformation: Option<TransformerDTO>,
     pub previous_time: DateTime<Local>,
     pub popup: Option<Box<dyn Popup>>,
+    pub merge_joined_end_points: bool,
+    pub weld_tolerance: f64,
+    pub conversion_tolerance: f64,
+    pub keep_converted_curves: bool,
+    pub coons_g1: bool,
     pub filter: String,
     pub stereoscopy: bool,
     pub stereoscopy_eye_distance: f32,
     pub stereoscopy_fov: f32,
+    pub shaded: bool,
+    pub wireframe_overlay: bool,
+    pub curvature_comb: bool,
+    pub curvature_comb_scale: f32,
+    pub curvature_comb_samples: usize,
+    pub continuity_samples: usize,
+    pub continuity_match_distance: f64,
+    pub continuity_gap_tolerance: f64,
+    pub continuity_angle_tolerance: f64,
+    pub continuity_highlight: bool,
+    pub continuity_report: Vec<BoundaryContinuityDTO>,
+    pub surface_edit_report: Option<(u64, String)>,
 }
 
 impl Ui {
@@ -59,10 +84,27 @@ impl Ui {
             previous_group_transformation: None,
             previous_time: Local::now(),
             popup: None,
+            merge_joined_end_points: true,
+            weld_tolerance: 0.01,
+            conversion_tolerance: 0.001,
+            keep_converted_curves: true,
+            coons_g1: true,
             filter: String::new(),
             stereoscopy: false,
             stereoscopy_eye_distance: 1f32,
             stereoscopy_fov: PI / 3.0,
+            shaded: false,
+            wireframe_overlay: true,
+            curvature_comb: false,
+            curvature_comb_scale: 1f32,
+            curvature_comb_samples: 16,
+            continuity_samples: 8,
+            continuity_match_distance: 0.01,
+            continuity_gap_tolerance: 0.0001,
+            continuity_angle_tolerance: 1.0,
+            continuity_highlight: false,
+            continuity_report: vec![],
+            surface_edit_report: None,
         }
     }
 
@@ -74,6 +116,11 @@ impl Ui {
         self.control_pressed = control_pressed;
     }
 
+    pub fn is_offending_boundary(&self, boundary: &BoundaryContinuityDTO) -> bool {
+        boundary.max_gap > self.continuity_gap_tolerance
+            || boundary.max_angle > self.continuity_angle_tolerance
+    }
+
     pub fn fetch_objects(&mut self, cqrs: &CQRS) {
         self.objects = cqrs
             .get(&AllToruses)
@@ -99,6 +146,11 @@ impl Ui {
                     .iter()
                     .map(|bezier| BezierInt(DomainBezierInt::from_dto(bezier))),
             )
+            .chain(
+                cqrs.get(&AllNurbsCurves)
+                    .iter()
+                    .map(|curve| NurbsCurve(DomainNurbsCurve::from_dto(curve))),
+            )
             .chain(
                 cqrs.get(&AllSurfacesC0)
                     .iter()
@@ -109,12 +161,22 @@ impl Ui {
                     .iter()
                     .map(|surface| SurfaceC2(surface.clone())),
             )
+            .chain(
+                cqrs.get(&AllNurbsSurfaces)
+                    .iter()
+                    .map(|surface| Object::NurbsSurface(surface.clone())),
+            )
             .chain(cqrs.get(&AllGregories).iter().map(|g| {
                 Object::Gregory(Gregory {
                     id: g.id,
                     name: g.name.clone(),
                     tess_level: g.tess_level,
+                    display_mode: g.display_mode,
                     draw_vectors: g.draw_vectors,
+                    tangent_scale: g.tangent_scale,
+                    center_offset: g.center_offset,
+                    twist_blend: g.twist_blend,
+                    coons: g.coons,
                 })
             }))
             .chain(cqrs.get(&AllIntersections).iter().map(|i| {
@@ -129,6 +191,11 @@ impl Ui {
                     st_draw: TextureDraw::from_bits(i.st_draw.bits()).unwrap(),
                 })
             }))
+            .chain(
+                cqrs.get(&AllToolpaths)
+                    .iter()
+                    .map(|toolpath| Object::Toolpath(Toolpath::from_dto(toolpath))),
+            )
             .sorted_by_key(|object| object.get_id())
             .collect();
         self.selected_objects.clear();
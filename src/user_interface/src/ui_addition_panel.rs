@@ -1,31 +1,44 @@
 use backend::cqrs::gregories::calculate_gregories::CalculateGregories;
 use backend::cqrs::points::merge_selected_points::MergeSelectedPoints;
+use egui::{DragValue, Widget};
 use rfd::FileDialog;
 
 use backend::cqrs::beziers_c0::add_bezier_c0::AddBezierC0;
-use backend::cqrs::beziers_c0::bezier_c0_details::BezierC0Details;
+use backend::cqrs::beziers_c0::transform_bezier_c0_into_bezier_c2::TransformBezierC0IntoBezierC2;
 use backend::cqrs::beziers_c2::add_bezier_c2::AddBezierC2;
-use backend::cqrs::beziers_c2::bezier_c2_details::BezierC2Details;
+use backend::cqrs::beziers_c2::transform_bezier_c2_into_bezier_c0::TransformBezierC2IntoBezierC0;
 use backend::cqrs::beziers_int::add_bezier_int::AddBezierInt;
+use backend::cqrs::beziers_int::transform_bezier_int_into_bezier_c0::TransformBezierIntIntoBezierC0;
+use backend::cqrs::common::join_selected_curves::JoinSelectedCurves;
 use backend::cqrs::common::load_scene::LoadScene;
 use backend::cqrs::common::new_id::NewId;
 use backend::cqrs::common::save_scene::SaveScene;
+use backend::cqrs::common::select_objects::SelectObjects;
+use backend::cqrs::common::weld_selected_objects::WeldSelectedObjects;
 use backend::cqrs::cqrs::CQRS;
+use backend::cqrs::nurbs_curves::add_nurbs_curve::AddNurbsCurve;
 use backend::cqrs::points::add_point::AddPoint;
+use backend::cqrs::surfaces_c0::all_surfaces_c0::AllSurfacesC0;
+use backend::cqrs::surfaces_c0::create_coons_patch::CreateCoonsPatch;
+use backend::cqrs::toolpaths::concatenate_selected_toolpaths::ConcatenateSelectedToolpaths;
+use backend::cqrs::toolpaths::load_toolpath::LoadToolpath;
 use backend::cqrs::toruses::add_torus::AddTorus;
 use backend::cqrs::toruses::torus_details::TorusDetails;
 
 use crate::object::Object;
-use crate::object::Object::{BezierC0, Torus};
+use crate::object::Object::Torus;
+use crate::object_id::ObjectId;
 use crate::popups::add_surface_c0_popup::AddSurfaceC0Popup;
 use crate::popups::add_surface_c2_popup::AddSurfaceC2Popup;
 use crate::popups::find_intersection_popup::FindIntersectionPopup;
 use crate::popups::find_self_intersection_popup::FindSelfIntersectionPopup;
+use crate::popups::fit_intersection_popup::FitIntersectionPopup;
+use crate::popups::interpolate_surface_popup::InterpolateSurfacePopup;
+use crate::popups::loft_popup::LoftPopup;
+use crate::popups::surface_of_revolution_popup::SurfaceOfRevolutionPopup;
+use crate::popups::sweep_popup::SweepPopup;
 use crate::ui::Ui;
 
-type DomainBezierC0 = crate::domain::bezier_c0::BezierC0;
-type DomainBezierC2 = crate::domain::bezier_c2::BezierC2;
-
 impl Ui {
     pub fn build_object_addition_panel(&mut self, ui: &mut egui::Ui, cqrs: &mut CQRS) {
         ui.horizontal(|ui| {
@@ -52,21 +65,81 @@ impl Ui {
             if ui.button("Add Bezier C0").clicked() {
                 let id = cqrs.handle(&NewId {});
                 cqrs.execute(&AddBezierC0 { id });
-                self.objects.push(BezierC0(DomainBezierC0::from_dto(
-                    &cqrs.get(&BezierC0Details { id }),
-                )));
             }
             if ui.button("Add Bezier C2").clicked() {
                 let id = cqrs.handle(&NewId {});
                 cqrs.execute(&AddBezierC2 { id });
-                self.objects.push(Object::BezierC2(DomainBezierC2::from_dto(
-                    &cqrs.get(&BezierC2Details { id }),
-                )));
             }
             if ui.button("Add Bezier Int").clicked() {
                 let id = cqrs.handle(&NewId {});
                 cqrs.execute(&AddBezierInt { id });
             }
+            if ui.button("Add NURBS").clicked() {
+                let id = cqrs.handle(&NewId {});
+                cqrs.execute(&AddNurbsCurve { id });
+            }
+        });
+        ui.horizontal(|ui| {
+            if ui.button("Join Curves").clicked() {
+                let id = cqrs.handle(&NewId {});
+                cqrs.execute(&JoinSelectedCurves {
+                    id,
+                    merge_end_points: self.merge_joined_end_points,
+                });
+            }
+            ui.checkbox(&mut self.merge_joined_end_points, "Merge End Points");
+        });
+        ui.horizontal(|ui| {
+            if ui.button("Weld").clicked() {
+                cqrs.execute(&WeldSelectedObjects {
+                    tolerance: self.weld_tolerance,
+                });
+            }
+            DragValue::new(&mut self.weld_tolerance)
+                .speed(0.001)
+                .clamp_range(0.0..=1.0)
+                .ui(ui);
+            ui.label("Tolerance");
+        });
+        ui.horizontal(|ui| {
+            if ui.button("Convert To Bezier C0").clicked() {
+                let curves = std::mem::take(&mut self.selected_objects);
+                cqrs.execute(&SelectObjects { objects: vec![] });
+                for curve in curves {
+                    match curve {
+                        ObjectId::BezierC2(id) => cqrs.execute(&TransformBezierC2IntoBezierC0 {
+                            id,
+                            keep_source: self.keep_converted_curves,
+                        }),
+                        ObjectId::BezierInt(id) => cqrs.execute(&TransformBezierIntIntoBezierC0 {
+                            id,
+                            keep_source: self.keep_converted_curves,
+                        }),
+                        _ => {}
+                    }
+                }
+            }
+            if ui.button("Convert To Bezier C2").clicked() {
+                let curves = std::mem::take(&mut self.selected_objects);
+                cqrs.execute(&SelectObjects { objects: vec![] });
+                for curve in curves {
+                    if let ObjectId::BezierC0(id) = curve {
+                        cqrs.execute(&TransformBezierC0IntoBezierC2 {
+                            id,
+                            tolerance: self.conversion_tolerance,
+                            keep_source: self.keep_converted_curves,
+                        });
+                    }
+                }
+            }
+        });
+        ui.horizontal(|ui| {
+            DragValue::new(&mut self.conversion_tolerance)
+                .clamp_range(0.00001..=1.0)
+                .speed(0.0001)
+                .ui(ui);
+            ui.label("tolerance");
+            ui.checkbox(&mut self.keep_converted_curves, "Keep Source");
         });
         ui.horizontal(|ui| {
             if ui.button("Add Surface C0").clicked() {
@@ -78,6 +151,85 @@ impl Ui {
             if ui.button("Add Gregory").clicked() {
                 cqrs.execute(&CalculateGregories);
             }
+            if ui.button("Coons Patch").clicked() {
+                let id = cqrs.handle(&NewId {});
+                cqrs.execute(&CreateCoonsPatch {
+                    id,
+                    g1: self.coons_g1,
+                });
+                let surfaces = cqrs
+                    .get(&AllSurfacesC0)
+                    .into_iter()
+                    .filter(|surface| surface.id == id)
+                    .map(Object::SurfaceC0)
+                    .collect::<Vec<_>>();
+                self.objects.extend(surfaces);
+                if self.objects.iter().any(|object| object.get_id() == id) {
+                    self.selected_objects.clear();
+                }
+            }
+            ui.checkbox(&mut self.coons_g1, "G1");
+            if ui.button("Surface Of Revolution").clicked() {
+                if let Some(profile) = self.selected_objects.iter().find_map(|so| match so {
+                    ObjectId::BezierC0(id) => Some(ObjectId::BezierC0(*id)),
+                    ObjectId::BezierC2(id) => Some(ObjectId::BezierC2(*id)),
+                    _ => None,
+                }) {
+                    self.selected_objects.clear();
+                    cqrs.execute(&SelectObjects { objects: vec![] });
+                    self.popup = Some(Box::new(SurfaceOfRevolutionPopup::new(profile)));
+                }
+            }
+            if ui.button("Loft").clicked() {
+                let curves = self
+                    .selected_objects
+                    .iter()
+                    .filter_map(|so| match so {
+                        ObjectId::BezierC2(id) => Some(*id),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                if curves.len() >= 2 {
+                    self.selected_objects.clear();
+                    cqrs.execute(&SelectObjects { objects: vec![] });
+                    self.popup = Some(Box::new(LoftPopup::new(curves)));
+                }
+            }
+            if ui.button("Interpolate Surface").clicked() {
+                let points = self
+                    .selected_objects
+                    .iter()
+                    .filter_map(|so| match so {
+                        ObjectId::Point(id) => Some(*id),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                if points.len() >= 4 {
+                    self.selected_objects.clear();
+                    cqrs.execute(&SelectObjects { objects: vec![] });
+                    self.popup = Some(Box::new(InterpolateSurfacePopup::new(points)));
+                }
+            }
+            if ui.button("Sweep").clicked() {
+                let path = self.selected_objects.iter().rev().find_map(|so| match so {
+                    ObjectId::BezierC2(id) => Some(ObjectId::BezierC2(*id)),
+                    ObjectId::BezierInt(id) => Some(ObjectId::BezierInt(*id)),
+                    _ => None,
+                });
+                if let Some(path) = path {
+                    let path_id = path.get_id();
+                    let profile = self.selected_objects.iter().find_map(|so| match so {
+                        ObjectId::BezierC0(id) if *id != path_id => Some(ObjectId::BezierC0(*id)),
+                        ObjectId::BezierC2(id) if *id != path_id => Some(ObjectId::BezierC2(*id)),
+                        _ => None,
+                    });
+                    if let Some(profile) = profile {
+                        self.selected_objects.clear();
+                        cqrs.execute(&SelectObjects { objects: vec![] });
+                        self.popup = Some(Box::new(SweepPopup::new(profile, path)));
+                    }
+                }
+            }
         });
         ui.horizontal(|ui| {
             if ui.button("Save").clicked() {
@@ -112,6 +264,31 @@ impl Ui {
                     self.popup = Some(Box::new(FindSelfIntersectionPopup::new(ids[0].clone())));
                 }
             }
+            if ui.button("Fit Intersection").clicked() {
+                if let Some(ObjectId::Intersection(id)) = self
+                    .selected_objects
+                    .iter()
+                    .find(|so| matches!(so, ObjectId::Intersection(_)))
+                {
+                    self.popup = Some(Box::new(FitIntersectionPopup::new(*id)));
+                }
+            }
+        });
+        ui.horizontal(|ui| {
+            if ui.button("Load Toolpath").clicked() {
+                let path = FileDialog::new().pick_file();
+                if let Some(path) = path {
+                    let id = cqrs.handle(&NewId {});
+                    cqrs.execute(&LoadToolpath {
+                        id,
+                        file_path: path.to_str().unwrap().to_string(),
+                    });
+                }
+            }
+            if ui.button("Concatenate Toolpaths").clicked() {
+                let id = cqrs.handle(&NewId {});
+                cqrs.execute(&ConcatenateSelectedToolpaths { id });
+            }
         });
     }
 }
@@ -74,12 +74,19 @@ impl Ui {
                                         ObjectTypeDTO::BezierC0 => ObjectId::BezierC0(object_id),
                                         ObjectTypeDTO::BezierC2 => ObjectId::BezierC2(object_id),
                                         ObjectTypeDTO::BezierInt => ObjectId::BezierInt(object_id),
+                                        ObjectTypeDTO::NurbsCurve => {
+                                            ObjectId::NurbsCurve(object_id)
+                                        }
                                         ObjectTypeDTO::SurfaceC0 => ObjectId::SurfaceC0(object_id),
                                         ObjectTypeDTO::SurfaceC2 => ObjectId::SurfaceC2(object_id),
+                                        ObjectTypeDTO::NurbsSurface => {
+                                            ObjectId::NurbsSurface(object_id)
+                                        }
                                         ObjectTypeDTO::Gregory => ObjectId::Gregory(object_id),
                                         ObjectTypeDTO::Intersection => {
                                             ObjectId::Intersection(object_id)
                                         }
+                                        ObjectTypeDTO::Toolpath => ObjectId::Toolpath(object_id),
                                     });
                                     cqrs.execute(&SelectObjects {
                                         objects: self
@@ -1,42 +1,85 @@
 use backend::cqrs::gregories::rename_gregory::RenameGregory;
 use backend::cqrs::gregories::update_gregory_settings::UpdateGregorySettings;
+use backend::cqrs::gregories::update_gregory_shape::UpdateGregoryShape;
 use backend::cqrs::intersections::set_intersection_textures_draw::{
     SetIntersectionTexturesDraw, TextureDrawDTO,
 };
 use backend::cqrs::intersections::transform_intersection_into_bezier_int::TransformIntersectionIntoBezierInt;
-use egui::{Checkbox, ComboBox, DragValue, Resize, ScrollArea, Slider, TextureOptions, Widget};
+use backend_events::common::surface_display_mode::SurfaceDisplayModeDTO;
+use egui::{
+    Button, Checkbox, Color32, ComboBox, DragValue, Resize, ScrollArea, Slider, TextureOptions,
+    Widget,
+};
+use rfd::FileDialog;
 use std::f32::consts::PI;
 
 use backend::cqrs::beziers_c0::add_point_to_bezier_c0::AddPointToBezierC0;
 use backend::cqrs::beziers_c0::delete_bezier_c0_points::DeleteBezierC0Points;
 use backend::cqrs::beziers_c0::rename_bezier_c0::RenameBezierC0;
 use backend::cqrs::beziers_c0::set_bezier_c0_draw_polygon::SetBezierC0DrawPolygon;
+use backend::cqrs::beziers_c0::split_bezier_c0::SplitBezierC0;
 use backend::cqrs::beziers_c2::add_point_to_bezier_c2::AddPointToBezierC2;
 use backend::cqrs::beziers_c2::delete_bezier_c2_points::DeleteBezierC2Points;
+use backend::cqrs::beziers_c2::insert_bezier_c2_knot::InsertBezierC2Knot;
 use backend::cqrs::beziers_c2::move_bezier_c2_selected_bernstein_point::MoveBezierC2SelectedBernsteinPoint;
 use backend::cqrs::beziers_c2::rename_bezier_c2::RenameBezierC2;
+use backend::cqrs::beziers_c2::set_bezier_c2_closed::SetBezierC2Closed;
 use backend::cqrs::beziers_c2::set_bezier_c2_draw_b_spline_polygon::SetBezierC2DrawBSplinePolygon;
 use backend::cqrs::beziers_c2::set_bezier_c2_draw_bernstein_points::SetBezierC2DrawBernsteinPoints;
 use backend::cqrs::beziers_c2::set_bezier_c2_draw_bernstein_polygon::SetBezierC2DrawBernsteinPolygon;
 use backend::cqrs::beziers_c2::set_bezier_c2_selected_bernstein_point::SetBezierC2SelectedBernsteinPoint;
+use backend::cqrs::beziers_c2::split_bezier_c2::SplitBezierC2;
 use backend::cqrs::beziers_int::add_point_to_bezier_int::AddPointToBezierInt;
+use backend::cqrs::beziers_int::bezier_int_details::BezierIntDetails;
 use backend::cqrs::beziers_int::delete_bezier_int_points::DeleteBezierIntPoints;
 use backend::cqrs::beziers_int::rename_bezier_int::RenameBezierInt;
+use backend::cqrs::beziers_int::set_bezier_int_end_tangents::SetBezierIntEndTangents;
+use backend::cqrs::beziers_int::set_bezier_int_options::{
+    BezierIntParameterizationDTO, SetBezierIntOptions,
+};
+use backend::cqrs::beziers_int::split_bezier_int::SplitBezierInt;
+use backend::cqrs::common::check_continuity::CheckContinuity;
 use backend::cqrs::common::transform_selected_objects::TransformSelectedObjects;
 use backend::cqrs::cqrs::CQRS;
 use backend::cqrs::cursors::transform_cursor::TransformCursor;
+use backend::cqrs::curves::curve_length::CurveLength;
+use backend::cqrs::milling::simulate_milling::SimulateMilling;
+use backend::cqrs::nurbs_curves::add_point_to_nurbs_curve::AddPointToNurbsCurve;
+use backend::cqrs::nurbs_curves::delete_nurbs_curve_points::DeleteNurbsCurvePoints;
+use backend::cqrs::nurbs_curves::rename_nurbs_curve::RenameNurbsCurve;
+use backend::cqrs::nurbs_curves::set_nurbs_curve_draw_polygon::SetNurbsCurveDrawPolygon;
+use backend::cqrs::nurbs_curves::set_nurbs_curve_point_weight::SetNurbsCurvePointWeight;
+use backend::cqrs::nurbs_surfaces::nurbs_surface_details::NurbsSurfaceDTO;
+use backend::cqrs::nurbs_surfaces::rename_nurbs_surface::RenameNurbsSurface;
+use backend::cqrs::nurbs_surfaces::select_nurbs_surface_points::SelectNurbsSurfacePoints;
+use backend::cqrs::nurbs_surfaces::set_nurbs_surface_point_weight::SetNurbsSurfacePointWeight;
+use backend::cqrs::nurbs_surfaces::update_nurbs_surface::UpdateNurbsSurface;
 use backend::cqrs::points::all_points::AllPoints;
 use backend::cqrs::points::point_details::{LittleTransformerDTO, PointDTO, PointDetails};
 use backend::cqrs::points::rename_point::RenamePoint;
 use backend::cqrs::points::transform_point::TransformPoint;
+use backend::cqrs::surfaces_c0::append_surface_c0_patches::{
+    AppendSurfaceC0Patches, SurfaceSideDTO,
+};
+use backend::cqrs::surfaces_c0::refine_surface_c0::RefineSurfaceC0;
+use backend::cqrs::surfaces_c0::remove_surface_c0_patches::RemoveSurfaceC0Patches;
 use backend::cqrs::surfaces_c0::rename_surface_c0::RenameSurfaceC0;
 use backend::cqrs::surfaces_c0::select_surface_c0_points::SelectSurfaceC0Points;
 use backend::cqrs::surfaces_c0::surface_c0_details::SurfaceC0DTO;
 use backend::cqrs::surfaces_c0::update_surface_c0::UpdateSurfaceC0;
+use backend::cqrs::surfaces_c2::append_surface_c2_patches::AppendSurfaceC2Patches;
+use backend::cqrs::surfaces_c2::refine_surface_c2::RefineSurfaceC2;
+use backend::cqrs::surfaces_c2::remove_surface_c2_patches::RemoveSurfaceC2Patches;
 use backend::cqrs::surfaces_c2::rename_surface_c2::RenameSurfaceC2;
 use backend::cqrs::surfaces_c2::select_surface_c2_points::SelectSurfaceC2Points;
 use backend::cqrs::surfaces_c2::surface_c2_details::SurfaceC2DTO;
 use backend::cqrs::surfaces_c2::update_surface_c2::UpdateSurfaceC2;
+use backend::cqrs::toolpaths::rename_toolpath::RenameToolpath;
+use backend::cqrs::toolpaths::reverse_toolpath::ReverseToolpath;
+use backend::cqrs::toolpaths::save_toolpath::SaveToolpath;
+use backend::cqrs::toolpaths::set_toolpath_visible::SetToolpathVisible;
+use backend::cqrs::toolpaths::toolpath_details::ToolpathDetails;
+use backend::cqrs::toolpaths::trim_toolpath::TrimToolpath;
 use backend::cqrs::toruses::rename_torus::RenameTorus;
 use backend::cqrs::toruses::torus_details::{TorusDTO, TorusDetails, TransformerDTO};
 use backend::cqrs::toruses::transform_torus::TransformTours;
@@ -48,6 +91,8 @@ use crate::domain::bezier_c2::BezierC2;
 use crate::domain::bezier_int::BezierInt;
 use crate::domain::gregory::Gregory;
 use crate::domain::intersection::{Intersection, TextureDraw};
+use crate::domain::nurbs_curve::NurbsCurve;
+use crate::domain::toolpath::Toolpath;
 use crate::object::Object;
 use crate::object_id::ObjectId;
 use crate::ui::Ui;
@@ -71,6 +116,9 @@ impl Ui {
                         self.build_multiple_object_transformation_panel(ui, cqrs);
                     } else {
                         self.build_stereoscopy_settings_panel(ui);
+                        self.build_shading_settings_panel(ui);
+                        self.build_curvature_comb_settings_panel(ui);
+                        self.build_continuity_panel(ui, cqrs);
                     }
                 });
             });
@@ -140,11 +188,20 @@ impl Ui {
                 let points = cqrs.get(&AllPoints {});
                 Ui::build_bezier_int_transformation_panel(ui, cqrs, bezier, &points);
             }
+            Object::NurbsCurve(curve) => {
+                let points = cqrs.get(&AllPoints {});
+                Ui::build_nurbs_curve_transformation_panel(ui, cqrs, curve, &points);
+            }
             Object::SurfaceC0(surface) => {
-                Ui::build_surface_c0_transformation_panel(ui, cqrs, surface);
+                let report = Self::surface_edit_report(&self.surface_edit_report, surface.id);
+                Ui::build_surface_c0_transformation_panel(ui, cqrs, surface, report);
             }
             Object::SurfaceC2(surface) => {
-                Ui::build_surface_c2_transformation_panel(ui, cqrs, surface);
+                let report = Self::surface_edit_report(&self.surface_edit_report, surface.id);
+                Ui::build_surface_c2_transformation_panel(ui, cqrs, surface, report);
+            }
+            Object::NurbsSurface(surface) => {
+                Ui::build_nurbs_surface_transformation_panel(ui, cqrs, surface);
             }
             Object::Gregory(gregory) => {
                 Ui::build_gregory_transformation_panel(ui, cqrs, gregory);
@@ -152,6 +209,9 @@ impl Ui {
             Object::Intersection(intersection) => {
                 Ui::build_intersection_transformation_panel(ui, cqrs, intersection);
             }
+            Object::Toolpath(toolpath) => {
+                Ui::build_toolpath_transformation_panel(ui, cqrs, toolpath);
+            }
         }
     }
 
@@ -501,6 +561,10 @@ impl Ui {
             });
         }
 
+        if let Some(length) = cqrs.get(&CurveLength { id: bezier.id }) {
+            ui.label(format!("Length: {:.4}", length));
+        }
+
         Resize::default()
             .id_source("resize_bezier_c2")
             .default_height(140.0)
@@ -559,6 +623,13 @@ impl Ui {
             });
         }
 
+        if ui.checkbox(&mut bezier.closed, "Closed").changed() {
+            cqrs.execute(&SetBezierC2Closed {
+                id: bezier.id,
+                closed: bezier.closed,
+            });
+        }
+
         if ui
             .checkbox(&mut bezier.draw_b_spline_polygon, "Draw B-Spline Polygon")
             .changed()
@@ -650,6 +721,24 @@ impl Ui {
                 });
             }
         }
+
+        ui.horizontal(|ui| {
+            Slider::new(&mut bezier.knot_parameter, 0.0..=1.0).ui(ui);
+            if ui.button("Insert Knot").clicked() {
+                cqrs.execute(&InsertBezierC2Knot {
+                    id: bezier.id,
+                    parameter: bezier.knot_parameter,
+                });
+            } else if ui
+                .add_enabled(!bezier.closed, Button::new("Split"))
+                .clicked()
+            {
+                cqrs.execute(&SplitBezierC2 {
+                    id: bezier.id,
+                    parameter: bezier.knot_parameter,
+                });
+            }
+        });
     }
 
     fn build_bezier_c0_transformation_panel(
@@ -665,6 +754,10 @@ impl Ui {
             });
         }
 
+        if let Some(length) = cqrs.get(&CurveLength { id: bezier.id }) {
+            ui.label(format!("Length: {:.4}", length));
+        }
+
         Resize::default()
             .id_source("resize_bezier_c0")
             .default_height(320.0)
@@ -732,6 +825,117 @@ impl Ui {
                 draw_polygon: bezier.draw_polygon,
             });
         }
+
+        ui.horizontal(|ui| {
+            Slider::new(&mut bezier.split_parameter, 0.0..=1.0).ui(ui);
+            if ui.button("Split").clicked() {
+                cqrs.execute(&SplitBezierC0 {
+                    id: bezier.id,
+                    parameter: bezier.split_parameter,
+                });
+            }
+        });
+    }
+
+    fn build_nurbs_curve_transformation_panel(
+        ui: &mut egui::Ui,
+        cqrs: &mut CQRS,
+        curve: &mut NurbsCurve,
+        points: &[PointDTO],
+    ) {
+        if ui.text_edit_singleline(&mut curve.name).lost_focus() {
+            cqrs.execute(&RenameNurbsCurve {
+                id: curve.id,
+                name: curve.name.clone(),
+            });
+        }
+
+        if let Some(length) = cqrs.get(&CurveLength { id: curve.id }) {
+            ui.label(format!("Length: {:.4}", length));
+        }
+
+        Resize::default()
+            .id_source("resize_nurbs_curve")
+            .default_height(320.0)
+            .show(ui, |ui| {
+                ScrollArea::vertical()
+                    .id_source("scroll_nurbs_curve")
+                    .show(ui, |ui| {
+                        for point in curve.points.iter_mut() {
+                            ui.horizontal(|ui| {
+                                if ui
+                                    .selectable_label(point.is_selected, &point.name)
+                                    .clicked()
+                                {
+                                    point.is_selected = !point.is_selected;
+                                }
+                                if DragValue::new(&mut point.weight)
+                                    .clamp_range(0.001..=1000.0)
+                                    .speed(0.01)
+                                    .ui(ui)
+                                    .changed()
+                                {
+                                    cqrs.execute(&SetNurbsCurvePointWeight {
+                                        id: curve.id,
+                                        point_id: point.id,
+                                        weight: point.weight,
+                                    });
+                                }
+                                ui.label("weight");
+                            });
+                        }
+                    })
+            });
+
+        ui.horizontal(|ui| {
+            ComboBox::from_id_source("Nurbs curve select point")
+                .selected_text(if let Some(p) = &curve.selected_point {
+                    &p.1
+                } else {
+                    ""
+                })
+                .show_ui(ui, |ui| {
+                    for point in points
+                        .iter()
+                        .filter(|p| !curve.points.iter().any(|cp| cp.id == p.id))
+                    {
+                        if ui.selectable_label(false, &point.name).clicked() {
+                            curve.selected_point = Some((point.id, point.name.clone()));
+                        }
+                    }
+                });
+            if ui.button("Add Point").clicked() {
+                if let Some((id, _name)) = &curve.selected_point {
+                    cqrs.execute(&AddPointToNurbsCurve {
+                        id: curve.id,
+                        point_id: *id,
+                    });
+                }
+                curve.selected_point = None;
+            }
+        });
+
+        if ui.button("Delete Points").clicked() {
+            cqrs.execute(&DeleteNurbsCurvePoints {
+                id: curve.id,
+                points: curve
+                    .points
+                    .iter()
+                    .filter(|p| p.is_selected)
+                    .map(|p| p.id)
+                    .collect(),
+            });
+        }
+
+        if ui
+            .checkbox(&mut curve.draw_polygon, "Draw Polygon")
+            .changed()
+        {
+            cqrs.execute(&SetNurbsCurveDrawPolygon {
+                id: curve.id,
+                draw_polygon: curve.draw_polygon,
+            });
+        }
     }
 
     fn build_bezier_int_transformation_panel(
@@ -747,6 +951,10 @@ impl Ui {
             });
         }
 
+        if let Some(length) = cqrs.get(&CurveLength { id: bezier.id }) {
+            ui.label(format!("Length: {:.4}", length));
+        }
+
         Resize::default()
             .id_source("resize_bezier_int")
             .default_height(320.0)
@@ -804,12 +1012,91 @@ impl Ui {
                     .collect(),
             });
         }
+
+        let mut options_changed = false;
+        options_changed |= ui.checkbox(&mut bezier.closed, "Closed").changed();
+        options_changed |= ui
+            .add_enabled(
+                !bezier.closed,
+                Checkbox::new(&mut bezier.clamped, "Clamped End Tangents"),
+            )
+            .changed();
+        ComboBox::from_label("Parameterization")
+            .selected_text(format!("{:?}", bezier.parameterization))
+            .show_ui(ui, |ui| {
+                for parameterization in [
+                    BezierIntParameterizationDTO::Uniform,
+                    BezierIntParameterizationDTO::Centripetal,
+                    BezierIntParameterizationDTO::ChordLength,
+                ] {
+                    options_changed |= ui
+                        .selectable_value(
+                            &mut bezier.parameterization,
+                            parameterization,
+                            format!("{:?}", parameterization),
+                        )
+                        .changed();
+                }
+            });
+
+        if options_changed {
+            cqrs.execute(&SetBezierIntOptions {
+                id: bezier.id,
+                closed: bezier.closed,
+                clamped: bezier.clamped,
+                parameterization: bezier.parameterization,
+            });
+            let details = cqrs.get(&BezierIntDetails { id: bezier.id });
+            bezier.start_tangent = details.start_tangent;
+            bezier.end_tangent = details.end_tangent;
+        }
+
+        if bezier.clamped && !bezier.closed {
+            let mut tangent_sliders = vec![];
+            for (label, tangent) in [
+                ("Start Tangent", &mut bezier.start_tangent),
+                ("End Tangent", &mut bezier.end_tangent),
+            ] {
+                ui.label(label);
+                ui.horizontal(|ui| {
+                    tangent_sliders.push(DragValue::new(&mut tangent.0).speed(0.01).ui(ui));
+                    ui.label("X");
+                    tangent_sliders.push(DragValue::new(&mut tangent.1).speed(0.01).ui(ui));
+                    ui.label("Y");
+                    tangent_sliders.push(DragValue::new(&mut tangent.2).speed(0.01).ui(ui));
+                    ui.label("Z");
+                });
+            }
+
+            if tangent_sliders.iter().any(|s| s.changed()) {
+                cqrs.execute(&SetBezierIntEndTangents {
+                    id: bezier.id,
+                    start_tangent: bezier.start_tangent,
+                    end_tangent: bezier.end_tangent,
+                });
+            }
+        }
+
+        let split_point = bezier.points.iter().find(|p| p.is_selected).map(|p| p.id);
+        if ui
+            .add_enabled(
+                !bezier.closed && split_point.is_some(),
+                Button::new("Split At Point"),
+            )
+            .clicked()
+        {
+            cqrs.execute(&SplitBezierInt {
+                id: bezier.id,
+                point_id: split_point.unwrap(),
+            });
+        }
     }
 
     fn build_surface_c0_transformation_panel(
         ui: &mut egui::Ui,
         cqrs: &mut CQRS,
         surface: &mut SurfaceC0DTO,
+        report: Option<String>,
     ) {
         if ui.text_edit_singleline(&mut surface.name).lost_focus() {
             cqrs.execute(&RenameSurfaceC0 {
@@ -835,6 +1122,7 @@ impl Ui {
                     id: surface.id,
                     tess_level: surface.tess_level,
                     draw_polygon: surface.draw_polygon,
+                    display_mode: surface.display_mode,
                 });
             }
         });
@@ -847,14 +1135,56 @@ impl Ui {
                 id: surface.id,
                 tess_level: surface.tess_level,
                 draw_polygon: surface.draw_polygon,
+                display_mode: surface.display_mode,
             });
         }
+
+        if Self::build_display_mode_combo_box(ui, &mut surface.display_mode) {
+            cqrs.execute(&UpdateSurfaceC0 {
+                id: surface.id,
+                tess_level: surface.tess_level,
+                draw_polygon: surface.draw_polygon,
+                display_mode: surface.display_mode,
+            });
+        }
+
+        let id = surface.id;
+        let is_cylinder = surface.is_cylinder;
+        let is_closed_v = surface.is_closed_v;
+        ui.label(format!("Patches {} x {}", surface.size.0, surface.size.1));
+        for (label, side) in [
+            ("Start X", SurfaceSideDTO::StartX),
+            ("End X", SurfaceSideDTO::EndX),
+            ("Start Y", SurfaceSideDTO::StartY),
+            ("End Y", SurfaceSideDTO::EndY),
+        ] {
+            if is_cylinder && side.is_x() || is_closed_v && !side.is_x() {
+                continue;
+            }
+            ui.horizontal(|ui| {
+                ui.label(label);
+                if ui.button("Append").clicked() {
+                    cqrs.execute(&AppendSurfaceC0Patches { id, side });
+                }
+                if ui.button("Remove").clicked() {
+                    cqrs.execute(&RemoveSurfaceC0Patches { id, side });
+                }
+            });
+        }
+
+        if ui.button("Refine").clicked() {
+            cqrs.execute(&RefineSurfaceC0 { id });
+        }
+        if let Some(report) = report {
+            ui.colored_label(Color32::RED, report);
+        }
     }
 
     fn build_surface_c2_transformation_panel(
         ui: &mut egui::Ui,
         cqrs: &mut CQRS,
         surface: &mut SurfaceC2DTO,
+        report: Option<String>,
     ) {
         if ui.text_edit_singleline(&mut surface.name).lost_focus() {
             cqrs.execute(&RenameSurfaceC2 {
@@ -880,6 +1210,7 @@ impl Ui {
                     id: surface.id,
                     tess_level: surface.tess_level,
                     draw_polygon: surface.draw_polygon,
+                    display_mode: surface.display_mode,
                 });
             }
         });
@@ -892,8 +1223,131 @@ impl Ui {
                 id: surface.id,
                 tess_level: surface.tess_level,
                 draw_polygon: surface.draw_polygon,
+                display_mode: surface.display_mode,
             });
         }
+
+        if Self::build_display_mode_combo_box(ui, &mut surface.display_mode) {
+            cqrs.execute(&UpdateSurfaceC2 {
+                id: surface.id,
+                tess_level: surface.tess_level,
+                draw_polygon: surface.draw_polygon,
+                display_mode: surface.display_mode,
+            });
+        }
+
+        let id = surface.id;
+        let is_cylinder = surface.is_cylinder;
+        let is_closed_v = surface.is_closed_v;
+        ui.label(format!("Patches {} x {}", surface.size.0, surface.size.1));
+        for (label, side) in [
+            ("Start X", SurfaceSideDTO::StartX),
+            ("End X", SurfaceSideDTO::EndX),
+            ("Start Y", SurfaceSideDTO::StartY),
+            ("End Y", SurfaceSideDTO::EndY),
+        ] {
+            if is_cylinder && side.is_x() || is_closed_v && !side.is_x() {
+                continue;
+            }
+            ui.horizontal(|ui| {
+                ui.label(label);
+                if ui.button("Append").clicked() {
+                    cqrs.execute(&AppendSurfaceC2Patches { id, side });
+                }
+                if ui.button("Remove").clicked() {
+                    cqrs.execute(&RemoveSurfaceC2Patches { id, side });
+                }
+            });
+        }
+
+        if ui.button("Refine").clicked() {
+            cqrs.execute(&RefineSurfaceC2 { id });
+        }
+        if let Some(report) = report {
+            ui.colored_label(Color32::RED, report);
+        }
+    }
+
+    fn surface_edit_report(report: &Option<(u64, String)>, id: u64) -> Option<String> {
+        report
+            .as_ref()
+            .filter(|(surface_id, _)| *surface_id == id)
+            .map(|(_, reason)| reason.clone())
+    }
+
+    fn build_nurbs_surface_transformation_panel(
+        ui: &mut egui::Ui,
+        cqrs: &mut CQRS,
+        surface: &mut NurbsSurfaceDTO,
+    ) {
+        if ui.text_edit_singleline(&mut surface.name).lost_focus() {
+            cqrs.execute(&RenameNurbsSurface {
+                id: surface.id,
+                name: surface.name.clone(),
+            });
+        }
+
+        if ui.button("Select points").clicked() {
+            cqrs.execute(&SelectNurbsSurfacePoints {
+                surface_id: surface.id,
+            });
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("Tessellation level");
+            if DragValue::new(&mut surface.tess_level)
+                .clamp_range(2..=64)
+                .ui(ui)
+                .changed()
+            {
+                cqrs.execute(&UpdateNurbsSurface {
+                    id: surface.id,
+                    tess_level: surface.tess_level,
+                    draw_polygon: surface.draw_polygon,
+                });
+            }
+        });
+
+        if ui
+            .checkbox(&mut surface.draw_polygon, "Draw Polygon")
+            .changed()
+        {
+            cqrs.execute(&UpdateNurbsSurface {
+                id: surface.id,
+                tess_level: surface.tess_level,
+                draw_polygon: surface.draw_polygon,
+            });
+        }
+
+        ui.label(format!("Patches {} x {}", surface.size.0, surface.size.1));
+
+        Resize::default()
+            .id_source("resize_nurbs_surface")
+            .default_height(320.0)
+            .show(ui, |ui| {
+                ScrollArea::vertical()
+                    .id_source("scroll_nurbs_surface")
+                    .show(ui, |ui| {
+                        for point in surface.points.iter_mut() {
+                            ui.horizontal(|ui| {
+                                ui.label(&point.name);
+                                if DragValue::new(&mut point.weight)
+                                    .clamp_range(0.001..=1000.0)
+                                    .speed(0.01)
+                                    .ui(ui)
+                                    .changed()
+                                {
+                                    cqrs.execute(&SetNurbsSurfacePointWeight {
+                                        id: surface.id,
+                                        point_id: point.id,
+                                        weight: point.weight,
+                                    });
+                                }
+                                ui.label("weight");
+                            });
+                        }
+                    })
+            });
     }
 
     fn build_gregory_transformation_panel(
@@ -919,6 +1373,7 @@ impl Ui {
                     id: gregory.id,
                     tess_level: gregory.tess_level,
                     draw_vectors: gregory.draw_vectors,
+                    display_mode: gregory.display_mode,
                 });
             }
         });
@@ -931,8 +1386,97 @@ impl Ui {
                 id: gregory.id,
                 tess_level: gregory.tess_level,
                 draw_vectors: gregory.draw_vectors,
+                display_mode: gregory.display_mode,
             });
         }
+
+        if Self::build_display_mode_combo_box(ui, &mut gregory.display_mode) {
+            cqrs.execute(&UpdateGregorySettings {
+                id: gregory.id,
+                tess_level: gregory.tess_level,
+                draw_vectors: gregory.draw_vectors,
+                display_mode: gregory.display_mode,
+            });
+        }
+
+        let mut shape_drags = vec![];
+
+        ui.horizontal(|ui| {
+            shape_drags.push(
+                DragValue::new(&mut gregory.tangent_scale)
+                    .speed(0.01)
+                    .clamp_range(0.0..=5.0)
+                    .ui(ui),
+            );
+            ui.label("Tangent scale");
+        });
+        if !gregory.coons {
+            ui.horizontal(|ui| {
+                shape_drags.push(
+                    DragValue::new(&mut gregory.center_offset.x)
+                        .speed(0.01)
+                        .ui(ui),
+                );
+                ui.label("Center offset X");
+            });
+            ui.horizontal(|ui| {
+                shape_drags.push(
+                    DragValue::new(&mut gregory.center_offset.y)
+                        .speed(0.01)
+                        .ui(ui),
+                );
+                ui.label("Center offset Y");
+            });
+            ui.horizontal(|ui| {
+                shape_drags.push(
+                    DragValue::new(&mut gregory.center_offset.z)
+                        .speed(0.01)
+                        .ui(ui),
+                );
+                ui.label("Center offset Z");
+            });
+            ui.horizontal(|ui| {
+                shape_drags.push(
+                    DragValue::new(&mut gregory.twist_blend)
+                        .speed(0.01)
+                        .clamp_range(0.0..=1.0)
+                        .ui(ui),
+                );
+                ui.label("Twist blend");
+            });
+        }
+
+        if shape_drags.iter().any(|f| f.changed()) {
+            cqrs.execute(&UpdateGregoryShape {
+                id: gregory.id,
+                tangent_scale: gregory.tangent_scale,
+                center_offset: gregory.center_offset,
+                twist_blend: gregory.twist_blend,
+            });
+        }
+    }
+
+    fn build_display_mode_combo_box(
+        ui: &mut egui::Ui,
+        display_mode: &mut SurfaceDisplayModeDTO,
+    ) -> bool {
+        let mut changed = false;
+        ComboBox::from_label("Display mode")
+            .selected_text(format!("{:?}", display_mode))
+            .show_ui(ui, |ui| {
+                for mode in [
+                    SurfaceDisplayModeDTO::Wireframe,
+                    SurfaceDisplayModeDTO::Normals,
+                    SurfaceDisplayModeDTO::GaussianCurvature,
+                    SurfaceDisplayModeDTO::MeanCurvature,
+                    SurfaceDisplayModeDTO::Zebra,
+                ] {
+                    changed |= ui
+                        .selectable_value(display_mode, mode, format!("{:?}", mode))
+                        .changed();
+                }
+            });
+        changed
     }
 
     fn build_intersection_transformation_panel(
@@ -1044,6 +1588,147 @@ impl Ui {
         }
     }
 
+    fn build_toolpath_transformation_panel(
+        ui: &mut egui::Ui,
+        cqrs: &mut CQRS,
+        toolpath: &mut Toolpath,
+    ) {
+        if ui.text_edit_singleline(&mut toolpath.name).lost_focus() {
+            cqrs.execute(&RenameToolpath {
+                id: toolpath.id,
+                name: toolpath.name.clone(),
+            });
+        }
+
+        ui.label(format!("Cutter: {}", toolpath.cutter_name));
+        ui.label(format!("Points: {}", toolpath.points_count));
+
+        if ui.checkbox(&mut toolpath.visible, "Visible").changed() {
+            cqrs.execute(&SetToolpathVisible {
+                id: toolpath.id,
+                visible: toolpath.visible,
+            });
+        }
+
+        if ui.button("Reverse").clicked() {
+            cqrs.execute(&ReverseToolpath { id: toolpath.id });
+        }
+
+        let max_index = toolpath.points_count.saturating_sub(1);
+        ui.horizontal(|ui| {
+            ui.label("From");
+            DragValue::new(&mut toolpath.trim_range.0)
+                .clamp_range(0..=max_index)
+                .ui(ui);
+            ui.label("To");
+            DragValue::new(&mut toolpath.trim_range.1)
+                .clamp_range(0..=max_index)
+                .ui(ui);
+            if ui.button("Trim").clicked() {
+                cqrs.execute(&TrimToolpath {
+                    id: toolpath.id,
+                    start: toolpath.trim_range.0,
+                    end: toolpath.trim_range.1,
+                });
+            }
+        });
+
+        if ui.button("Save toolpath").clicked() {
+            let path = FileDialog::new().save_file();
+            if let Some(path) = path {
+                cqrs.execute(&SaveToolpath {
+                    id: toolpath.id,
+                    file_path: path.to_str().unwrap().to_string(),
+                });
+            }
+        }
+
+        Self::build_milling_simulation_panel(ui, cqrs, toolpath);
+    }
+
+    fn build_milling_simulation_panel(ui: &mut egui::Ui, cqrs: &mut CQRS, toolpath: &mut Toolpath) {
+        ui.label("Material block");
+        ui.horizontal(|ui| {
+            DragValue::new(&mut toolpath.block.size.0)
+                .clamp_range(0.1..=100.0)
+                .speed(0.1)
+                .ui(ui);
+            DragValue::new(&mut toolpath.block.size.1)
+                .clamp_range(0.1..=100.0)
+                .speed(0.1)
+                .ui(ui);
+            ui.label("Size");
+        });
+        ui.horizontal(|ui| {
+            DragValue::new(&mut toolpath.block.height)
+                .clamp_range(0.0..=100.0)
+                .speed(0.1)
+                .ui(ui);
+            ui.label("Height");
+        });
+        ui.horizontal(|ui| {
+            DragValue::new(&mut toolpath.block.min_height)
+                .clamp_range(0.0..=toolpath.block.height)
+                .speed(0.1)
+                .ui(ui);
+            ui.label("Min height");
+        });
+        ui.horizontal(|ui| {
+            DragValue::new(&mut toolpath.block.resolution.0)
+                .clamp_range(1..=2000)
+                .ui(ui);
+            DragValue::new(&mut toolpath.block.resolution.1)
+                .clamp_range(1..=2000)
+                .ui(ui);
+            ui.label("Resolution");
+        });
+
+        if ui.button("Simulate milling").clicked() {
+            let details = cqrs.get(&ToolpathDetails { id: toolpath.id });
+            toolpath.simulation = cqrs.get(&SimulateMilling {
+                path: details
+                    .points
+                    .iter()
+                    .map(|p| (p.x as f64, p.y as f64, p.z as f64))
+                    .collect(),
+                cutter: details.cutter,
+                block: toolpath.block.clone(),
+            });
+        }
+
+        if let Some(simulation) = &toolpath.simulation {
+            ui.label(format!(
+                "Removed volume: {:.3}",
+                simulation
+                    .moves
+                    .iter()
+                    .map(|m| m.removed_volume)
+                    .sum::<f64>()
+            ));
+            ui.label(format!(
+                "Cutting moves: {}/{}",
+                simulation.moves.iter().filter(|m| m.cut_cells > 0).count(),
+                simulation.moves.len()
+            ));
+            if simulation.errors.is_empty() {
+                ui.label("No errors");
+            }
+            for error in simulation.errors.iter() {
+                ui.colored_label(
+                    Color32::RED,
+                    format!(
+                        "{:?} at move {} ({:.3}, {:.3}, {:.3})",
+                        error.error_type,
+                        error.move_index,
+                        error.position.0,
+                        error.position.1,
+                        error.position.2
+                    ),
+                );
+            }
+        }
+    }
+
     fn build_stereoscopy_settings_panel(&mut self, ui: &mut egui::Ui) {
         ui.checkbox(&mut self.stereoscopy, "Stereoscopy");
         Slider::new(&mut self.stereoscopy_eye_distance, 0.01..=10.0)
@@ -1055,4 +1740,72 @@ impl Ui {
             .text("Fov")
             .ui(ui);
     }
+
+    fn build_shading_settings_panel(&mut self, ui: &mut egui::Ui) {
+        ui.checkbox(&mut self.shaded, "Shaded");
+        ui.add_enabled(
+            self.shaded,
+            egui::Checkbox::new(&mut self.wireframe_overlay, "Wireframe overlay"),
+        );
+    }
+
+    fn build_curvature_comb_settings_panel(&mut self, ui: &mut egui::Ui) {
+        ui.checkbox(&mut self.curvature_comb, "Curvature Comb");
+        Slider::new(&mut self.curvature_comb_scale, 0.01..=100.0)
+            .logarithmic(true)
+            .text("Comb scale")
+            .ui(ui);
+        Slider::new(&mut self.curvature_comb_samples, 1..=64)
+            .text("Comb samples per segment")
+            .ui(ui);
+    }
+
+    fn build_continuity_panel(&mut self, ui: &mut egui::Ui, cqrs: &mut CQRS) {
+        Slider::new(&mut self.continuity_samples, 1..=64)
+            .text("Continuity samples")
+            .ui(ui);
+        Slider::new(&mut self.continuity_match_distance, 0.0001..=1.0)
+            .logarithmic(true)
+            .text("Match distance")
+            .ui(ui);
+        Slider::new(&mut self.continuity_gap_tolerance, 0.000001..=1.0)
+            .logarithmic(true)
+            .text("G0 tolerance")
+            .ui(ui);
+        Slider::new(&mut self.continuity_angle_tolerance, 0.01..=45.0)
+            .logarithmic(true)
+            .text("G1 tolerance [deg]")
+            .ui(ui);
+        ui.horizontal(|ui| {
+            if ui.button("Check Continuity").clicked() {
+                self.continuity_report = cqrs.get(&CheckContinuity {
+                    samples: self.continuity_samples,
+                    tolerance: self.continuity_match_distance,
+                });
+            }
+            ui.checkbox(&mut self.continuity_highlight, "Highlight Offending");
+        });
+
+        let offending = self
+            .continuity_report
+            .iter()
+            .filter(|boundary| self.is_offending_boundary(boundary))
+            .count();
+        ui.label(format!(
+            "Boundaries: {}, offending: {}",
+            self.continuity_report.len(),
+            offending
+        ));
+        for boundary in self.continuity_report.iter() {
+            let text = format!(
+                "{} | {}: G0 {:.6} G1 {:.3}°",
+                boundary.first, boundary.second, boundary.max_gap, boundary.max_angle
+            );
+            if self.is_offending_boundary(boundary) {
+                ui.colored_label(Color32::RED, text);
+            } else {
+                ui.label(text);
+            }
+        }
+    }
 }